@@ -1,13 +1,28 @@
 use crate::aws;
 use crate::aws::client::AwsClients;
-use crate::config::Config;
+use crate::config::{Config, SavedFilter};
 use crossterm::event::KeyCode;
 use crate::resource::{
-    get_resource, get_all_resource_keys, ResourceDef, ResourceFilter, 
-    fetch_resources_paginated, extract_json_value,
+    get_resource, get_all_resource_keys, ColumnDef, ListFilter, ResourceDef, ResourceFilter, PaginatedResult,
+    fetch_resources_paginated, fetch_resources_all_regions, extract_json_value, CacheKey, ListCache, REGION_FIELD,
 };
+use crate::json_tree::{self, TreeLine};
+use crate::bookmarks::Location;
+use crate::notifications::{Level, Notifications};
+use crate::palette::{PaletteEntry, PaletteTarget, PALETTE_COMMANDS};
+use crate::watch::{Change, Watch};
 use anyhow::Result;
 use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Width (percent) of the REGION column shown in all-regions mode
+const REGION_COLUMN_WIDTH: u16 = 12;
+
+/// How long the selection must rest on a row before the split panel describes it
+const SPLIT_DESCRIBE_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Mode {
@@ -20,7 +35,22 @@ pub enum Mode {
     Regions,     // Region selection
     Describe,    // Viewing JSON details of selected item
     SsoLogin,    // SSO login dialog
+    Mfa,         // MFA code prompt
     LogTail,     // Tailing CloudWatch logs
+    Input,       // Text input prompt for an action
+    Secret,      // One-time secret value dialog
+    Tunnels,     // Active port forwarding sessions
+    Costs,       // Cost Explorer monthly spend
+    Tags,        // Tag editor for the selected resource
+    SsoSessions, // Cached SSO tokens
+    Audit,       // Audit log of write operations
+    Columns,     // Column picker for the current resource
+    Diff,        // Diff of the marked and selected items
+    Filters,     // Saved filters of the current resource
+    Palette,     // Ctrl+P fuzzy palette
+    Jumps,       // Bookmarks and recent views (')
+    Notifications, // History of toasts and errors
+    ApiCalls,    // Recent AWS API calls (:api)
 }
 
 /// Pending action that requires confirmation
@@ -41,10 +71,94 @@ pub struct PendingAction {
     pub destructive: bool,
     /// Currently selected option (true = Yes, false = No)
     pub selected_yes: bool,
+    /// Text collected from an input prompt, passed to the action
+    pub input: Option<String>,
+    /// Secret dialog to show with the action result
+    pub secret: Option<crate::resource::SecretConfig>,
+    /// Text that must be typed to confirm (typed confirmations only)
+    pub expected_text: Option<String>,
+    /// Text typed so far for a typed confirmation
+    pub typed_text: String,
 }
 
-/// Parent context for hierarchical navigation
+/// Secret value returned by an action, shown once in a dialog
+#[derive(Debug, Clone)]
+pub struct SecretDialog {
+    /// Dialog title
+    pub title: String,
+    /// Non-secret result fields shown alongside the secret
+    pub fields: Vec<(String, String)>,
+    /// Label of the secret value
+    pub secret_label: String,
+    /// The secret value itself
+    pub secret: String,
+    /// Whether the secret is currently shown in clear text
+    pub revealed: bool,
+    /// Extra note shown under the secret
+    pub note: Option<String>,
+}
+
+/// Text input prompt collected before running an action
+#[derive(Debug, Clone)]
+pub struct InputPrompt {
+    /// Action to run once input is submitted
+    pub action: crate::resource::ActionDef,
+    /// Resource ID to act on
+    pub resource_id: String,
+    /// Label shown above the input box
+    pub message: String,
+    /// Current input text
+    pub text: String,
+}
+
+/// Tag editor for one resource
+#[derive(Clone)]
+pub struct TagsState {
+    /// Clients for the resource's region
+    pub clients: AwsClients,
+    /// Tagging API ("ec2" or "rgta")
+    pub api: String,
+    /// Resource id (ec2) or ARN (rgta)
+    pub id: String,
+    /// Resource name shown in the panel title
+    pub title: String,
+    pub tags: Vec<(String, String)>,
+    pub selected: usize,
+    /// Tag being edited: the original key (None when adding) and the
+    /// "key=value" text typed so far
+    pub editing: Option<(Option<String>, String)>,
+    /// Waiting for y/n before deleting the selected tag
+    pub confirm_delete: bool,
+}
+
+/// Item marked with `x` to compare against another with `=`
 #[derive(Debug, Clone)]
+pub struct DiffMark {
+    pub resource_key: String,
+    pub id: String,
+    /// List data, used if the describe call fails
+    pub item: Value,
+}
+
+/// Column picker state (`C` / `:columns`)
+#[derive(Debug, Clone)]
+pub struct ColumnPicker {
+    /// Column headers in display order, with whether each is shown
+    pub columns: Vec<(String, bool)>,
+    pub selected: usize,
+}
+
+/// Ctrl+P palette state
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    pub query: String,
+    /// Entries matching the query, best first
+    pub matches: Vec<PaletteEntry>,
+    pub selected: usize,
+}
+
+/// Parent context for hierarchical navigation
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ParentContext {
     /// Parent resource key (e.g., "vpc")
     pub resource_key: String,
@@ -63,7 +177,14 @@ pub struct App {
     
     // Dynamic data storage (JSON)
     pub items: Vec<Value>,
-    pub filtered_items: Vec<Value>,
+    // Indices into `items` of the rows passing the filter, in display order
+    pub filtered_indices: Vec<usize>,
+    // First list row on screen, kept by the last render so only the visible window is built
+    pub list_offset: Cell<usize>,
+    // Columns supplied by the last response (overrides the resource's JSON columns)
+    pub dynamic_columns: Vec<ColumnDef>,
+    // Column shown in full instead of truncated (zoom mode)
+    pub zoomed_column: Option<usize>,
     
     // Navigation state
     pub selected: usize,
@@ -74,33 +195,80 @@ pub struct App {
     // Hierarchical navigation
     pub parent_context: Option<ParentContext>,
     pub navigation_stack: Vec<ParentContext>,
+    // Filters supplied by a command for a top-level view (e.g. :find tag:Env=prod)
+    pub view_filters: Vec<ResourceFilter>,
     
     // Command input
     pub command_text: String,
     pub command_suggestions: Vec<String>,
     pub command_suggestion_selected: usize,
     pub command_preview: Option<String>, // Ghost text for hovered suggestion
+    pub command_history: Vec<String>,     // Executed commands, oldest first
+    pub command_history_index: Option<usize>, // Entry recalled with Up/Down
+    pub history_search: Option<String>,   // Ctrl+R search query
     
     // Profile/Region
     pub profile: String,
     pub region: String,
     pub available_profiles: Vec<String>,
     pub available_regions: Vec<String>,
+    // Opt-in status of each region from DescribeRegions (empty until fetched)
+    pub region_opt_in: HashMap<String, String>,
     pub profiles_selected: usize,
+    // Profile picker details (read when it opens) and accounts resolved with `a`
+    pub profile_details: HashMap<String, aws::credentials::ProfileDetails>,
+    pub profile_accounts: HashMap<String, crate::resource::AccountIdentity>,
+    pub profile_lookups: Vec<(String, tokio::task::JoinHandle<Result<crate::resource::AccountIdentity>>)>,
+    // Aggregate regional lists across all available regions (:regions all)
+    pub all_regions: bool,
     pub regions_selected: usize,
     
     // Confirmation
     pub pending_action: Option<PendingAction>,
     
+    // Action input prompt
+    pub input_prompt: Option<InputPrompt>,
+    pub secret_dialog: Option<SecretDialog>,
+    
     // UI state
     pub loading: bool,
-    pub error_message: Option<String>,
+    // Toasts and the :notifications history
+    pub notifications: Notifications,
+    pub notifications_selected: usize,
+    
+    // Snapshot of recent API calls shown by :api
+    pub api_calls: Vec<aws::api_log::ApiCall>,
+    pub api_calls_selected: usize,
+    // View left by the last navigation, restored if its replacement's load is cancelled
+    view_before_load: Option<Workspace>,
+    // Keys typed while a list load held the terminal, handled once it finishes
+    pub typed_ahead: Arc<Mutex<VecDeque<crossterm::event::KeyEvent>>>,
     pub describe_scroll: usize,
     pub describe_data: Option<Value>,  // Full resource details from describe API
+    pub describe_title: Option<String>, // Title override when showing an action's document
+    pub describe_search: String,        // Search text in the describe view
+    pub describe_search_active: bool,   // Typing the search text
+    pub describe_match: usize,          // Current match (index into describe_match_lines)
+    pub describe_cursor: usize,         // Highlighted line of the describe tree
+    pub describe_yaml: bool,            // Show describe documents as YAML (toggled with y)
+    pub describe_query: String,         // jq-style expression projecting the document
+    pub describe_query_active: bool,    // Typing the expression
+    pub describe_projection: Option<Value>, // Result of the last applied expression
+    pub describe_collapsed: HashSet<String>, // Collapsed objects/arrays (JSON pointer paths)
+    pub describe_height: Cell<usize>,   // Visible describe lines, recorded by the last render
+    
+    // Split layout: list on the left, describe panel for the selected row on the right
+    pub split_view: bool,
+    pub split_describe: Option<(String, Value)>, // Panel data and the selection it belongs to
+    pub split_pending: Option<(String, std::time::Instant)>, // Selection awaiting a describe call
+    pub split_task: Option<(String, tokio::task::JoinHandle<Result<Value>>)>, // Describe call in flight and its selection
     
     // Auto-refresh
     pub last_refresh: std::time::Instant,
     
+    // Recently fetched list pages, reused for a few seconds
+    pub list_cache: ListCache,
+    
     // Persistent configuration
     pub config: Config,
     
@@ -110,6 +278,13 @@ pub struct App {
     // Read-only mode (blocks all write operations)
     pub readonly: bool,
     
+    // Effective key bindings (defaults plus the config's keys section)
+    pub keymap: crate::keys::Keymap,
+    
+    // Read-only mode requested with --readonly; `readonly` is also set for
+    // profiles listed in the config's readonly_profiles
+    pub readonly_flag: bool,
+    
     // Warning message for modal dialog
     pub warning_message: Option<String>,
     
@@ -119,11 +294,145 @@ pub struct App {
     // SSO login state
     pub sso_state: Option<SsoLoginState>,
     
+    // MFA code prompt state
+    pub mfa_state: Option<MfaState>,
+    
     // Pagination state
     pub pagination: PaginationState,
     
     // Log tail state
     pub log_tail_state: Option<LogTailState>,
+    /// Live Tail session pushing events to the log tail, when available
+    pub log_live_tail: Option<aws::live_tail::LiveTail>,
+    
+    // Command to run in the foreground with the TUI suspended (SSM shell, etc.)
+    pub external_command: Option<ExternalCommand>,
+    
+    // Active SSM port forwarding sessions
+    pub tunnels: Vec<Tunnel>,
+    pub tunnels_selected: usize,
+    
+    // Resource actions running in the background
+    pub running_actions: Vec<RunningAction>,
+    
+    // Resources polled for state changes (W)
+    pub watches: Vec<Watch>,
+    
+    // Account and caller of the current credentials (header) and the
+    // account's regions, looked up in the background for each profile and role hop
+    pub identity: Option<crate::resource::AccountIdentity>,
+    account_scope: String,
+    account_task: Option<tokio::task::JoinHandle<AccountLookup>>,
+    
+    // Cost Explorer report shown by :costs
+    pub costs: Option<aws::costs::CostReport>,
+    pub costs_selected: usize,
+    
+    // Tag editor for the selected resource (T)
+    pub tags_state: Option<TagsState>,
+    
+    // Cached SSO tokens shown by :sso sessions
+    pub sso_sessions: Vec<aws::sso::CachedSession>,
+    pub sso_sessions_selected: usize,
+    
+    // Audit log entries shown by :audit (newest first)
+    pub audit_entries: Vec<crate::audit::AuditEntry>,
+    pub audit_selected: usize,
+    
+    // Selection in the saved filter picker (:filters)
+    pub filters_selected: usize,
+    
+    // Ctrl+P palette (Some while Mode::Palette is shown)
+    pub palette: Option<Palette>,
+    
+    // Bookmarks and recent views per profile/region, and the jump list selection
+    pub bookmarks: crate::bookmarks::Store,
+    pub jumps_selected: usize,
+    
+    // Compare mode: marked item and the diff shown in Mode::Diff
+    pub diff_mark: Option<DiffMark>,
+    pub diff_lines: Vec<crate::diff::DiffLine>,
+    pub diff_title: String,
+    pub diff_scroll: usize,
+    
+    // Column picker (visible columns and their order for the current resource)
+    pub column_picker: Option<ColumnPicker>,
+    
+    // Workspace tabs; the active tab's slot is None because its state lives on App
+    pub workspaces: Vec<Option<Workspace>>,
+    pub active_workspace: usize,
+}
+
+/// Saved state of an inactive workspace tab (its own account, region and view)
+pub struct Workspace {
+    pub clients: AwsClients,
+    pub profile: String,
+    pub region: String,
+    pub all_regions: bool,
+    pub current_resource_key: String,
+    pub items: Vec<Value>,
+    pub filtered_indices: Vec<usize>,
+    pub dynamic_columns: Vec<ColumnDef>,
+    pub selected: usize,
+    pub filter_text: String,
+    pub filter_active: bool,
+    pub parent_context: Option<ParentContext>,
+    pub navigation_stack: Vec<ParentContext>,
+    pub view_filters: Vec<ResourceFilter>,
+    pub pagination: PaginationState,
+}
+
+impl Workspace {
+    /// Short tab label, e.g. "prod@eu-west-1 ec2-instances"
+    pub fn label(&self) -> String {
+        format!("{}@{} {}", self.profile, self.region, self.current_resource_key)
+    }
+}
+
+/// SSM port forwarding session running in the background
+pub struct Tunnel {
+    /// Instance the session runs on
+    pub target: String,
+    /// Remote end of the tunnel ("port" or "host:port")
+    pub remote: String,
+    /// Local port listening on localhost
+    pub local_port: u16,
+    /// When the tunnel was started (display string)
+    pub started: String,
+    /// session-manager-plugin process
+    pub child: tokio::process::Child,
+}
+
+/// Results of the account lookup: caller identity and DescribeRegions
+type AccountLookup = (Result<crate::resource::AccountIdentity>, Result<Vec<(String, String)>>);
+
+/// Resource action running in the background so the UI stays responsive
+pub struct RunningAction {
+    /// Shown while it runs, e.g. "Stop Instance i-0abc"
+    pub label: String,
+    /// Audit event ("service:sdk_method") and target
+    event: String,
+    target: String,
+    /// Profile active when it was started, for the audit log
+    profile: String,
+    read_only: bool,
+    clients: AwsClients,
+    task: tokio::task::JoinHandle<Result<()>>,
+}
+
+/// External program run in the foreground while the TUI is suspended
+#[derive(Debug, Clone)]
+pub struct ExternalCommand {
+    /// Program to run
+    pub program: String,
+    /// Program arguments
+    pub args: Vec<String>,
+    /// Message printed before the program starts
+    pub banner: String,
+    /// Extra environment variables for the program
+    pub env: Vec<(String, String)>,
+    /// Wait for Enter after the program exits so its output can be read
+    pub wait: bool,
 }
 
 /// Pagination state for resource listings
@@ -180,6 +489,16 @@ pub enum SsoLoginState {
     },
 }
 
+/// MFA code prompt for a profile with `mfa_serial`
+#[derive(Debug, Clone)]
+pub struct MfaState {
+    pub profile: String,
+    pub mfa_serial: String,
+    pub code: String,
+    /// Error from the last attempt (wrong or reused code)
+    pub error: Option<String>,
+}
+
 /// Result of profile switch attempt
 #[derive(Debug, Clone)]
 pub enum ProfileSwitchResult {
@@ -187,6 +506,8 @@ pub enum ProfileSwitchResult {
     Success,
     /// SSO login required for this profile
     SsoRequired { profile: String, sso_session: String },
+    /// MFA code required for this profile
+    MfaRequired { profile: String, mfa_serial: String },
 }
 
 /// A single log event from CloudWatch
@@ -194,6 +515,8 @@ pub enum ProfileSwitchResult {
 pub struct LogEvent {
     pub timestamp: i64,
     pub message: String,
+    /// Log stream the event was written to
+    pub stream: String,
 }
 
 /// State for log tailing mode
@@ -201,14 +524,16 @@ pub struct LogEvent {
 pub struct LogTailState {
     /// Log group name
     pub log_group: String,
-    /// Log stream name
-    pub log_stream: String,
+    /// Log streams tailed; empty for every stream of the group
+    pub log_streams: Vec<String>,
     /// Collected log events (max 1000)
     pub events: Vec<LogEvent>,
-    /// Scroll position in the log view
+    /// Selected event; the view scrolls to keep it in sight
     pub scroll: usize,
     /// Token for fetching next batch of events
     pub next_forward_token: Option<String>,
+    /// Fetch events from this time (epoch millis) on the next poll without a token
+    pub since: Option<i64>,
     /// Whether to auto-scroll to bottom on new events
     pub auto_scroll: bool,
     /// Whether polling is paused
@@ -217,6 +542,29 @@ pub struct LogTailState {
     pub last_poll: std::time::Instant,
     /// Error message if polling failed
     pub error: Option<String>,
+    /// Wrap long lines instead of cutting them at the pane edge
+    pub wrap: bool,
+    /// Pretty-printed JSON of the selected event's message, shown in a popup
+    pub json_popup: Option<String>,
+    /// Scroll position in the JSON popup
+    pub json_popup_scroll: usize,
+}
+
+impl LogTailState {
+    /// Keep only the last 1000 events
+    fn trim_events(&mut self) {
+        if self.events.len() > 1000 {
+            let drain_count = self.events.len() - 1000;
+            self.events.drain(0..drain_count);
+        }
+    }
+
+    /// Scroll to the newest event if following
+    fn follow(&mut self) {
+        if self.auto_scroll && !self.events.is_empty() {
+            self.scroll = self.events.len().saturating_sub(1);
+        }
+    }
 }
 
 impl App {
@@ -233,47 +581,124 @@ impl App {
         readonly: bool,
         endpoint_url: Option<String>,
     ) -> Self {
-        let filtered_items = initial_items.clone();
+        let filtered_indices = (0..initial_items.len()).collect();
+        let profile_readonly = config.is_readonly_profile(&profile);
+        let (keymap, key_errors) = crate::keys::Keymap::from_config(&config.keys);
+        for error in &key_errors {
+            tracing::warn!("Key bindings: {}", error);
+        }
         
-        Self {
+        let mut app = Self {
             clients,
             current_resource_key: "ec2-instances".to_string(),
             items: initial_items,
-            filtered_items,
+            filtered_indices,
+            list_offset: Cell::new(0),
+            dynamic_columns: Vec::new(),
+            zoomed_column: None,
             selected: 0,
             mode: Mode::Normal,
             filter_text: String::new(),
             filter_active: false,
             parent_context: None,
             navigation_stack: Vec::new(),
+            view_filters: Vec::new(),
             command_text: String::new(),
             command_suggestions: Vec::new(),
             command_suggestion_selected: 0,
             command_preview: None,
+            command_history: crate::history::load(),
+            command_history_index: None,
+            history_search: None,
             profile,
             region,
             available_profiles,
             available_regions,
             profiles_selected: 0,
+            profile_details: HashMap::new(),
+            profile_accounts: HashMap::new(),
+            profile_lookups: Vec::new(),
+            all_regions: false,
             regions_selected: 0,
             pending_action: None,
+            input_prompt: None,
+            secret_dialog: None,
             loading: false,
-            error_message: None,
+            view_before_load: None,
+            typed_ahead: Default::default(),
+            notifications: Notifications::default(),
+            notifications_selected: 0,
+            api_calls: Vec::new(),
+            api_calls_selected: 0,
             describe_scroll: 0,
             describe_data: None,
+            describe_title: None,
+            describe_search: String::new(),
+            describe_search_active: false,
+            describe_match: 0,
+            describe_cursor: 0,
+            describe_yaml: false,
+            describe_query: String::new(),
+            describe_query_active: false,
+            describe_projection: None,
+            describe_collapsed: HashSet::new(),
+            describe_height: Cell::new(0),
+            split_view: false,
+            split_describe: None,
+            split_pending: None,
+            split_task: None,
             last_refresh: std::time::Instant::now(),
+            list_cache: ListCache::default(),
             config,
             last_key_press: None,
-            readonly,
+            readonly: readonly || profile_readonly,
+            readonly_flag: readonly,
+            keymap,
             warning_message: None,
             endpoint_url,
             sso_state: None,
+            mfa_state: None,
             pagination: PaginationState::default(),
             log_tail_state: None,
+            log_live_tail: None,
+            external_command: None,
+            tunnels: Vec::new(),
+            running_actions: Vec::new(),
+            watches: Vec::new(),
+            identity: None,
+            account_scope: String::new(),
+            account_task: None,
+            region_opt_in: HashMap::new(),
+            tunnels_selected: 0,
+            costs: None,
+            costs_selected: 0,
+            tags_state: None,
+            sso_sessions: Vec::new(),
+            sso_sessions_selected: 0,
+            audit_entries: Vec::new(),
+            audit_selected: 0,
+            filters_selected: 0,
+            palette: None,
+            bookmarks: crate::bookmarks::Store::load(),
+            jumps_selected: 0,
+            column_picker: None,
+            diff_mark: None,
+            diff_lines: Vec::new(),
+            diff_title: String::new(),
+            diff_scroll: 0,
+            workspaces: vec![None],
+            active_workspace: 0,
+        };
+        app.reset_filter();
+        app.apply_filter();
+        if let Some(error) = key_errors.first() {
+            app.notify_error(format!("Key bindings: {}", error));
         }
+        app
     }
     
-    /// Check if auto-refresh is needed (every 5 seconds)
+    /// Check if auto-refresh is needed (every 5 seconds, or 2 seconds while
+    /// a CloudFormation stack operation is in progress)
     pub fn needs_refresh(&self) -> bool {
         // Only auto-refresh in Normal mode, not when in dialogs/command/etc.
         if self.mode != Mode::Normal {
@@ -283,9 +708,128 @@ impl App {
         if self.loading {
             return false;
         }
-        self.last_refresh.elapsed() >= std::time::Duration::from_secs(5)
+        let interval = if self.stack_operation_in_progress() { 2 } else { 5 };
+        self.last_refresh.elapsed() >= std::time::Duration::from_secs(interval)
+    }
+    
+    /// Check if the stack events view shows a stack mid-operation
+    /// (its latest stack-level event is still *_IN_PROGRESS)
+    fn stack_operation_in_progress(&self) -> bool {
+        if self.current_resource_key != "cloudformation-stack-events" {
+            return false;
+        }
+        self.items
+            .iter()
+            .find(|e| extract_json_value(e, "ResourceType") == "AWS::CloudFormation::Stack")
+            .map(|e| extract_json_value(e, "ResourceStatus").ends_with("_IN_PROGRESS"))
+            .unwrap_or(false)
     }
     
+    // =========================================================================
+    // Workspace Tabs
+    // =========================================================================
+
+    /// Copy of the current view state, holding the given list data
+    fn view_snapshot(&self, items: Vec<Value>, filtered_indices: Vec<usize>) -> Workspace {
+        Workspace {
+            clients: self.clients.clone(),
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            all_regions: self.all_regions,
+            current_resource_key: self.current_resource_key.clone(),
+            items,
+            filtered_indices,
+            dynamic_columns: self.dynamic_columns.clone(),
+            selected: self.selected,
+            filter_text: self.filter_text.clone(),
+            filter_active: false,
+            parent_context: self.parent_context.clone(),
+            navigation_stack: self.navigation_stack.clone(),
+            view_filters: self.view_filters.clone(),
+            pagination: self.pagination.clone(),
+        }
+    }
+
+    /// Open a new tab starting as a copy of the current one
+    pub fn new_workspace(&mut self) {
+        let copy = self.view_snapshot(self.items.clone(), self.filtered_indices.clone());
+        // The current state stays on App and becomes the new tab
+        self.workspaces[self.active_workspace] = Some(copy);
+        self.workspaces.push(None);
+        self.active_workspace = self.workspaces.len() - 1;
+    }
+
+    /// Switch to the tab `offset` places away (wrapping)
+    pub fn cycle_workspace(&mut self, offset: isize) {
+        let len = self.workspaces.len() as isize;
+        if len <= 1 {
+            return;
+        }
+        let index = (self.active_workspace as isize + offset).rem_euclid(len) as usize;
+        self.switch_workspace(index);
+    }
+
+    /// Make tab `index` the active one
+    pub fn switch_workspace(&mut self, index: usize) {
+        if index == self.active_workspace {
+            return;
+        }
+        let Some(target) = self.workspaces.get_mut(index).and_then(Option::take) else {
+            return;
+        };
+        let current = self.swap_workspace(target);
+        self.workspaces[self.active_workspace] = Some(current);
+        self.active_workspace = index;
+    }
+
+    /// Close the active tab (the last tab can't be closed)
+    pub fn close_workspace(&mut self) {
+        if self.workspaces.len() <= 1 {
+            return;
+        }
+        self.workspaces.remove(self.active_workspace);
+        let index = self.active_workspace.min(self.workspaces.len() - 1);
+        if let Some(target) = self.workspaces[index].take() {
+            self.swap_workspace(target);
+        }
+        self.active_workspace = index;
+    }
+
+    /// Tab labels in order, including the active one
+    pub fn workspace_labels(&self) -> Vec<String> {
+        self.workspaces
+            .iter()
+            .map(|ws| match ws {
+                Some(ws) => ws.label(),
+                None => format!("{}@{} {}", self.profile, self.region, self.current_resource_key),
+            })
+            .collect()
+    }
+
+    /// Load `ws` as the active state, returning the state it replaced
+    fn swap_workspace(&mut self, ws: Workspace) -> Workspace {
+        self.filter_active = false;
+        let previous = Workspace {
+            clients: std::mem::replace(&mut self.clients, ws.clients),
+            profile: std::mem::replace(&mut self.profile, ws.profile),
+            region: std::mem::replace(&mut self.region, ws.region),
+            all_regions: std::mem::replace(&mut self.all_regions, ws.all_regions),
+            current_resource_key: std::mem::replace(&mut self.current_resource_key, ws.current_resource_key),
+            items: std::mem::replace(&mut self.items, ws.items),
+            filtered_indices: std::mem::replace(&mut self.filtered_indices, ws.filtered_indices),
+            dynamic_columns: std::mem::replace(&mut self.dynamic_columns, ws.dynamic_columns),
+            selected: std::mem::replace(&mut self.selected, ws.selected),
+            filter_text: std::mem::replace(&mut self.filter_text, ws.filter_text),
+            filter_active: ws.filter_active,
+            parent_context: std::mem::replace(&mut self.parent_context, ws.parent_context),
+            navigation_stack: std::mem::replace(&mut self.navigation_stack, ws.navigation_stack),
+            view_filters: std::mem::replace(&mut self.view_filters, ws.view_filters),
+            pagination: std::mem::replace(&mut self.pagination, ws.pagination),
+        };
+        self.update_readonly();
+        previous
+    }
+
     /// Reset refresh timer
     pub fn mark_refreshed(&mut self) {
         self.last_refresh = std::time::Instant::now();
@@ -300,6 +844,169 @@ impl App {
         get_resource(&self.current_resource_key)
     }
 
+    /// Columns the current resource can show, before the configured layout
+    fn available_columns(&self) -> Vec<ColumnDef> {
+        let Some(resource) = self.current_resource() else {
+            return Vec::new();
+        };
+        // Response-supplied columns (e.g. Athena query results) replace the JSON ones
+        if !self.dynamic_columns.is_empty() {
+            return self.dynamic_columns.clone();
+        }
+        let mut columns = resource.columns.clone();
+        if self.config.utilization_metrics {
+            columns.extend(resource.metric_columns.iter().cloned());
+        }
+        for key in self.config.tag_columns.get(&self.current_resource_key).into_iter().flatten() {
+            let header = key.to_uppercase();
+            if columns.iter().any(|col| col.header == header) {
+                continue;
+            }
+            columns.push(ColumnDef {
+                header,
+                json_path: format!("tag:{}", key),
+                width: 15,
+                color_map: None,
+                color_field: None,
+                relative_time: false,
+            });
+        }
+        columns
+    }
+
+    /// Columns of the resource table as displayed
+    pub fn table_columns(&self) -> Vec<ColumnDef> {
+        let Some(resource) = self.current_resource() else {
+            return Vec::new();
+        };
+        let mut columns = match self.config.columns.get(&self.current_resource_key) {
+            Some(layout) => apply_column_layout(self.available_columns(), layout),
+            None => self.available_columns(),
+        };
+
+        // Aggregated lists lead with the region each item came from
+        if self.all_regions && !resource.is_global {
+            for col in &mut columns {
+                col.width = col.width * (100 - REGION_COLUMN_WIDTH) / 100;
+            }
+            columns.insert(0, ColumnDef {
+                header: "REGION".to_string(),
+                json_path: REGION_FIELD.to_string(),
+                width: REGION_COLUMN_WIDTH,
+                color_map: None,
+                color_field: None,
+                relative_time: false,
+            });
+        }
+        columns
+    }
+
+    /// Open the column picker for the current resource
+    pub fn enter_column_picker(&mut self) {
+        let available = self.available_columns();
+        if available.is_empty() {
+            return;
+        }
+        // Shown columns in their configured order, then the hidden ones
+        let shown: Vec<String> = match self.config.columns.get(&self.current_resource_key) {
+            Some(layout) => apply_column_layout(available.clone(), layout)
+                .into_iter()
+                .map(|col| col.header)
+                .collect(),
+            None => available.iter().map(|col| col.header.clone()).collect(),
+        };
+        let mut columns: Vec<(String, bool)> = shown.into_iter().map(|header| (header, true)).collect();
+        for col in available {
+            if !columns.iter().any(|(header, _)| *header == col.header) {
+                columns.push((col.header, false));
+            }
+        }
+        self.column_picker = Some(ColumnPicker { columns, selected: 0 });
+        self.mode = Mode::Columns;
+    }
+
+    /// Show or hide the selected column (at least one stays visible)
+    pub fn toggle_picker_column(&mut self) {
+        let Some(picker) = self.column_picker.as_mut() else {
+            return;
+        };
+        let visible = picker.columns.iter().filter(|(_, shown)| *shown).count();
+        if let Some((_, shown)) = picker.columns.get_mut(picker.selected)
+            && (!*shown || visible > 1)
+        {
+            *shown = !*shown;
+        }
+    }
+
+    /// Move the selected column `offset` places up or down
+    pub fn move_picker_column(&mut self, offset: isize) {
+        let Some(picker) = self.column_picker.as_mut() else {
+            return;
+        };
+        let target = picker.selected.saturating_add_signed(offset);
+        if target < picker.columns.len() && target != picker.selected {
+            picker.columns.swap(picker.selected, target);
+            picker.selected = target;
+        }
+    }
+
+    /// Restore the resource's default columns in the picker
+    pub fn reset_column_picker(&mut self) {
+        let defaults = self.available_columns();
+        if let Some(picker) = self.column_picker.as_mut() {
+            picker.columns = defaults.into_iter().map(|col| (col.header, true)).collect();
+            picker.selected = 0;
+        }
+    }
+
+    /// Apply the picker's layout and persist it in the config
+    pub fn save_column_picker(&mut self) {
+        let Some(picker) = self.column_picker.take() else {
+            return;
+        };
+        let shown: Vec<String> = picker
+            .columns
+            .into_iter()
+            .filter(|(_, shown)| *shown)
+            .map(|(header, _)| header)
+            .collect();
+        let defaults: Vec<String> = self.available_columns().into_iter().map(|col| col.header).collect();
+        // The default layout needs no config entry
+        let layout = (shown != defaults).then_some(shown);
+        if let Err(e) = self.config.set_columns(&self.current_resource_key, layout) {
+            self.notify_error(format!("Failed to save columns: {}", e));
+        }
+        self.zoomed_column = None;
+        self.mode = Mode::Normal;
+    }
+
+    /// Zoomed column index, clamped to the current table
+    pub fn zoomed_column(&self) -> Option<usize> {
+        let count = self.table_columns().len();
+        self.zoomed_column.filter(|_| count > 0).map(|i| i.min(count - 1))
+    }
+
+    /// Toggle zoom mode, which shows one column's values in full
+    pub fn toggle_column_zoom(&mut self) {
+        self.zoomed_column = match self.zoomed_column {
+            Some(_) => None,
+            None => Some(0),
+        };
+    }
+
+    /// Move the zoom `offset` columns left or right, zooming in first if needed
+    pub fn shift_column_zoom(&mut self, offset: isize) {
+        let count = self.table_columns().len();
+        if count == 0 {
+            return;
+        }
+        self.zoomed_column = Some(match self.zoomed_column() {
+            Some(current) => current.saturating_add_signed(offset).min(count - 1),
+            None if offset < 0 => count - 1,
+            None => 0,
+        });
+    }
+
     /// Get available commands for autocomplete
     pub fn get_available_commands(&self) -> Vec<String> {
         let mut commands: Vec<String> = get_all_resource_keys()
@@ -310,6 +1017,22 @@ impl App {
         // Add profiles and regions commands
         commands.push("profiles".to_string());
         commands.push("regions".to_string());
+        commands.push("tunnels".to_string());
+        commands.push("costs".to_string());
+        commands.push("find".to_string());
+        commands.push("search".to_string());
+        commands.push("sso".to_string());
+        commands.push("audit".to_string());
+        commands.push("notifications".to_string());
+        commands.push("error".to_string());
+        commands.push("api".to_string());
+        commands.push("metrics".to_string());
+        commands.push("keybar".to_string());
+        commands.push("columns".to_string());
+        commands.push("filters".to_string());
+        commands.push("filter-save".to_string());
+        commands.push("bookmark".to_string());
+        commands.push("goto".to_string());
         
         commands.sort();
         commands
@@ -325,30 +1048,82 @@ impl App {
         self.fetch_page(self.pagination.next_token.clone()).await
     }
     
+    /// Refetch the current page from the API, dropping all cached pages (Ctrl+R)
+    pub async fn force_refresh(&mut self) -> Result<()> {
+        self.list_cache.clear();
+        self.refresh_current().await
+    }
+    
     /// Fetch a specific page of resources
     async fn fetch_page(&mut self, page_token: Option<String>) -> Result<()> {
         if self.current_resource().is_none() {
-            self.error_message = Some(format!("Unknown resource: {}", self.current_resource_key));
+            self.notify_error(format!("Unknown resource: {}", self.current_resource_key));
+            return Ok(());
+        }
+        aws::api_log::start_cycle();
+
+        // Renew expired credentials up front instead of failing every refresh
+        if self.credentials_remaining().is_some_and(|left| left <= chrono::TimeDelta::zero())
+            && !self.renew_credentials().await
+        {
+            self.mark_refreshed();
             return Ok(());
         }
 
         self.loading = true;
-        self.error_message = None;
 
         // Build filters from parent context
-        let filters = self.build_filters_from_context();
+        let mut filters = self.build_filters_from_context();
+        // Ask for utilization metrics only while their columns are shown
+        if self.config.utilization_metrics
+            && self.current_resource().is_some_and(|r| !r.metric_columns.is_empty())
+        {
+            filters.push(ResourceFilter::new("with_metrics", vec!["true".to_string()]));
+        }
         
-        // Use paginated fetch - returns only one page of results
-        match fetch_resources_paginated(
-            &self.current_resource_key, 
-            &self.clients, 
+        // Reuse a page fetched moments ago, except while following a stack operation
+        let cache_key = CacheKey::new(
+            &self.profile,
+            self.clients.assumed_role.as_deref(),
+            &self.region,
+            self.all_regions,
+            &self.current_resource_key,
             &filters,
             page_token.as_deref(),
-        ).await {
+        );
+        let cached = if self.stack_operation_in_progress() { None } else { self.list_cache.get(&cache_key) };
+        let from_cache = cached.is_some();
+        
+        // Use paginated fetch - returns only one page of results
+        let mut result = match cached {
+            Some(cached) => Ok(cached),
+            None => match self.fetch_cancellable(&filters, page_token.as_deref()).await {
+                Some(result) => result,
+                None => {
+                    self.cancel_load();
+                    return Ok(());
+                }
+            },
+        };
+        // The session can end before the reported expiration (e.g. revoked SSO sessions)
+        if result.as_ref().is_err_and(aws::client::is_expired_error) {
+            if self.renew_credentials().await {
+                result = self.fetch_current(&filters, page_token.as_deref()).await;
+            } else if matches!(self.mode, Mode::SsoLogin | Mode::Mfa) {
+                self.loading = false;
+                self.mark_refreshed();
+                return Ok(());
+            }
+        }
+        if !from_cache && let Ok(result) = &result {
+            self.list_cache.insert(cache_key, result.clone());
+        }
+        match result {
             Ok(result) => {
                 // Preserve selection if possible
                 let prev_selected = self.selected;
                 self.items = result.items;
+                self.dynamic_columns = dynamic_columns(&result.columns);
                 self.apply_filter();
                 
                 // Update pagination state
@@ -356,17 +1131,18 @@ impl App {
                 self.pagination.next_token = result.next_token;
                 
                 // Try to keep the same selection index
-                if prev_selected < self.filtered_items.len() {
+                if prev_selected < self.filtered_indices.len() {
                     self.selected = prev_selected;
                 } else {
                     self.selected = 0;
                 }
             }
             Err(e) => {
-                self.error_message = Some(aws::client::format_aws_error(&e));
+                self.notify_aws_error("", &e);
                 // Clear items to prevent mismatch between current_resource_key and stale items
                 self.items.clear();
-                self.filtered_items.clear();
+                self.filtered_indices.clear();
+                self.dynamic_columns.clear();
                 self.selected = 0;
                 self.pagination = PaginationState::default();
             }
@@ -377,6 +1153,134 @@ impl App {
         Ok(())
     }
     
+    /// Fetch the current list, or None if Esc is pressed first. Cancelling drops
+    /// the fetch future, and with it every request and detail loop under it.
+    async fn fetch_cancellable(&self, filters: &[ResourceFilter], page_token: Option<&str>) -> Option<Result<PaginatedResult>> {
+        let cancel = CancellationToken::new();
+        let watcher = tokio::task::spawn_blocking({
+            let cancel = cancel.clone();
+            let typed_ahead = self.typed_ahead.clone();
+            move || cancel_on_escape(&cancel, &typed_ahead)
+        });
+        let result = tokio::select! {
+            result = self.fetch_current(filters, page_token) => Some(result),
+            _ = cancel.cancelled() => None,
+        };
+        // Stop the watcher once the fetch is done
+        cancel.cancel();
+        let _ = watcher.await;
+        result
+    }
+
+    /// Give up on a list load: go back to the view it was replacing, or clear
+    /// the list so no half-refreshed rows are left under the header
+    fn cancel_load(&mut self) {
+        if let Some(previous) = self.view_before_load.take() {
+            self.restore_view(previous);
+            self.mode = Mode::Normal;
+        } else {
+            self.items.clear();
+            self.filtered_indices.clear();
+            self.selected = 0;
+        }
+        self.loading = false;
+        self.notify(Level::Info, "Cancelled");
+        self.mark_refreshed();
+    }
+
+    /// Remember the view being left (moving its rows out rather than copying),
+    /// so cancelling the new view's load can restore it
+    fn begin_view_change(&mut self) {
+        let items = std::mem::take(&mut self.items);
+        let filtered_indices = std::mem::take(&mut self.filtered_indices);
+        self.view_before_load = Some(self.view_snapshot(items, filtered_indices));
+    }
+
+    /// True if the view change completed, false if its load was cancelled
+    fn finish_view_change(&mut self) -> bool {
+        self.view_before_load.take().is_some()
+    }
+
+    /// Load the view set up since `begin_view_change`; false if cancelled.
+    /// The remembered view is dropped on failure too, so a later cancel can't restore it.
+    async fn load_view_change(&mut self) -> Result<bool> {
+        let result = self.refresh_current().await;
+        let completed = self.finish_view_change();
+        result.map(|()| completed)
+    }
+
+    /// Go back to the remembered view when the new one couldn't be set up,
+    /// e.g. a region or profile switch failed before anything was loaded
+    fn abandon_view_change(&mut self) {
+        if let Some(previous) = self.view_before_load.take() {
+            self.restore_view(previous);
+        }
+    }
+
+    /// Swap a remembered view back in, saving its region and profile again if
+    /// a switch had already saved the new ones
+    fn restore_view(&mut self, previous: Workspace) {
+        let left = self.swap_workspace(previous);
+        if left.region != self.region {
+            let _ = self.config.set_region(&self.region);
+        }
+        if left.profile != self.profile {
+            let _ = self.config.set_profile(&self.profile);
+        }
+    }
+
+    /// Fetch the current resource, fanning out across regions in all-regions mode
+    async fn fetch_current(&self, filters: &[ResourceFilter], page_token: Option<&str>) -> Result<PaginatedResult> {
+        let is_global = self.current_resource().is_none_or(|r| r.is_global);
+        if !self.all_regions || is_global {
+            return fetch_resources_paginated(&self.current_resource_key, &self.clients, filters, page_token).await;
+        }
+        
+        // Sub-resources are listed from the region their parent came from
+        let parent_region = self.parent_context.as_ref()
+            .map(|p| extract_json_value(&p.item, REGION_FIELD))
+            .filter(|r| r != "-");
+        if let Some(region) = parent_region {
+            let mut result = fetch_resources_paginated(
+                &self.current_resource_key,
+                &self.region_clients(&region),
+                filters,
+                page_token,
+            ).await?;
+            for item in &mut result.items {
+                if let Value::Object(map) = item {
+                    map.insert(REGION_FIELD.to_string(), Value::String(region.clone()));
+                }
+            }
+            return Ok(result);
+        }
+        
+        let items = fetch_resources_all_regions(
+            &self.current_resource_key,
+            &self.clients,
+            &self.available_regions,
+            filters,
+        ).await?;
+        Ok(PaginatedResult { items, next_token: None, columns: Vec::new() })
+    }
+    
+    /// Clients pointed at another region (same credentials)
+    fn region_clients(&self, region: &str) -> AwsClients {
+        let mut clients = self.clients.clone();
+        clients.http.set_region(region);
+        clients.region = region.to_string();
+        clients
+    }
+    
+    /// Clients for acting on the selected item; in all-regions mode each
+    /// item is routed to the region it was listed from
+    pub fn selected_clients(&self) -> AwsClients {
+        match self.selected_item().map(|item| extract_json_value(item, REGION_FIELD)) {
+            Some(region) if self.all_regions && region != "-" => self.region_clients(&region),
+            _ => self.clients.clone(),
+        }
+    }
+    
     /// Fetch next page of resources
     pub async fn next_page(&mut self) -> Result<()> {
         if !self.pagination.has_more {
@@ -416,7 +1320,7 @@ impl App {
     /// For S3, this collects both bucket_names and prefix from navigation stack
     fn build_filters_from_context(&self) -> Vec<ResourceFilter> {
         let Some(parent) = &self.parent_context else {
-            return Vec::new();
+            return self.view_filters.clone();
         };
         
         let Some(_resource) = self.current_resource() else {
@@ -496,35 +1400,42 @@ impl App {
     // Filtering
     // =========================================================================
 
-    /// Apply text filter to items
+    /// Apply text filter to items (see `resource::ListFilter` for the syntax)
     pub fn apply_filter(&mut self) {
-        let filter = self.filter_text.to_lowercase();
+        let filter = ListFilter::parse(&self.filter_text);
 
         if filter.is_empty() {
-            self.filtered_items = self.items.clone();
+            self.filtered_indices = (0..self.items.len()).collect();
         } else {
             let resource = self.current_resource();
-            self.filtered_items = self
+            let columns = self.table_columns();
+            self.filtered_indices = self
                 .items
                 .iter()
-                .filter(|item| {
-                    // Search in name field and id field
-                    if let Some(res) = resource {
-                        let name = extract_json_value(item, &res.name_field).to_lowercase();
-                        let id = extract_json_value(item, &res.id_field).to_lowercase();
-                        name.contains(&filter) || id.contains(&filter)
-                    } else {
+                .enumerate()
+                .filter(|(_, item)| {
+                    // Search the visible columns, plus the name and id fields
+                    let values: Vec<(&str, String)> = match resource {
+                        Some(res) => columns
+                            .iter()
+                            .map(|col| (col.header.as_str(), extract_json_value(item, &col.json_path)))
+                            .chain([
+                                ("", extract_json_value(item, &res.name_field)),
+                                ("", extract_json_value(item, &res.id_field)),
+                            ])
+                            .collect(),
                         // Fallback: search in JSON string
-                        item.to_string().to_lowercase().contains(&filter)
-                    }
+                        None => vec![("", item.to_string())],
+                    };
+                    filter.matches(&values)
                 })
-                .cloned()
+                .map(|(index, _)| index)
                 .collect();
         }
 
         // Adjust selection
-        if self.selected >= self.filtered_items.len() && !self.filtered_items.is_empty() {
-            self.selected = self.filtered_items.len() - 1;
+        if self.selected >= self.filtered_indices.len() && !self.filtered_indices.is_empty() {
+            self.selected = self.filtered_indices.len() - 1;
         }
     }
 
@@ -538,49 +1449,609 @@ impl App {
         self.apply_filter();
     }
 
-    // =========================================================================
-    // Navigation
-    // =========================================================================
-
-    #[allow(dead_code)]
-    pub fn current_list_len(&self) -> usize {
-        self.filtered_items.len()
+    /// Start a freshly entered resource with its auto-apply saved filter, if any
+    fn reset_filter(&mut self) {
+        self.filter_text = self
+            .config
+            .auto_filter(&self.current_resource_key)
+            .unwrap_or_default()
+            .to_string();
+        self.filter_active = false;
     }
 
-    pub fn selected_item(&self) -> Option<&Value> {
-        self.filtered_items.get(self.selected)
+    /// Saved filters of the current resource
+    pub fn saved_filters(&self) -> &[SavedFilter] {
+        self.config
+            .filters
+            .get(&self.current_resource_key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
     }
 
-    pub fn selected_item_json(&self) -> Option<String> {
-        // Use describe_data if available (full details), otherwise fall back to list data
-        if let Some(ref data) = self.describe_data {
-            return Some(serde_json::to_string_pretty(data).unwrap_or_default());
+    /// Save the current filter under `name` (`:filter-save <name>`)
+    pub fn save_current_filter(&mut self, name: &str) {
+        if self.filter_text.trim().is_empty() {
+            self.notify_error("Nothing to save: filter with / first");
+            return;
+        }
+        let resource = self.current_resource_key.clone();
+        match self.config.save_filter(&resource, name, self.filter_text.trim()) {
+            Ok(()) => self.show_warning(&format!("Saved filter '{}' for {}", name, resource)),
+            Err(e) => self.notify_error(format!("Failed to save filter: {}", e)),
         }
-        self.selected_item()
-            .map(|item| serde_json::to_string_pretty(item).unwrap_or_default())
     }
 
-    /// Get the number of lines in the describe content
-    pub fn describe_line_count(&self) -> usize {
-        self.selected_item_json()
-            .map(|s| s.lines().count())
-            .unwrap_or(0)
+    /// Open the saved filter picker (`:filters`)
+    pub fn enter_filters_mode(&mut self) {
+        if self.saved_filters().is_empty() {
+            self.notify_error(format!(
+                "No saved filters for {}: save one with :filter-save <name>",
+                self.current_resource_key
+            ));
+            return;
+        }
+        self.filters_selected = 0;
+        self.mode = Mode::Filters;
     }
 
-    /// Clamp describe scroll to valid range
-    #[allow(dead_code)]
-    pub fn clamp_describe_scroll(&mut self, visible_lines: usize) {
-        let total = self.describe_line_count();
-        let max_scroll = total.saturating_sub(visible_lines);
-        self.describe_scroll = self.describe_scroll.min(max_scroll);
+    /// Apply the selected saved filter to the list
+    pub fn apply_saved_filter(&mut self) {
+        let Some(saved) = self.saved_filters().get(self.filters_selected) else {
+            return;
+        };
+        self.filter_text = saved.filter.clone();
+        self.filter_active = false;
+        self.selected = 0;
+        self.apply_filter();
+        self.mode = Mode::Normal;
+    }
+
+    /// Delete the selected saved filter
+    pub fn delete_saved_filter(&mut self) {
+        let Some(name) = self.saved_filters().get(self.filters_selected).map(|f| f.name.clone()) else {
+            return;
+        };
+        let resource = self.current_resource_key.clone();
+        if let Err(e) = self.config.remove_filter(&resource, &name) {
+            self.notify_error(format!("Failed to delete filter: {}", e));
+        }
+        let count = self.saved_filters().len();
+        if count == 0 {
+            self.mode = Mode::Normal;
+        }
+        self.filters_selected = self.filters_selected.min(count.saturating_sub(1));
+    }
+
+    /// Toggle auto-apply of the selected saved filter on entering the resource
+    pub fn toggle_auto_filter(&mut self) {
+        let Some(name) = self.saved_filters().get(self.filters_selected).map(|f| f.name.clone()) else {
+            return;
+        };
+        let resource = self.current_resource_key.clone();
+        if let Err(e) = self.config.toggle_auto_filter(&resource, &name) {
+            self.notify_error(format!("Failed to save filter: {}", e));
+        }
+    }
+
+    // =========================================================================
+    // Palette
+    // =========================================================================
+
+    /// Everything the palette can jump to: resources, commands and saved filters
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut resources: Vec<PaletteEntry> = get_all_resource_keys()
+            .into_iter()
+            .filter_map(|key| get_resource(key).map(|resource| (key, resource)))
+            .map(|(key, resource)| PaletteEntry {
+                kind: "resource",
+                label: resource.display_name.clone(),
+                detail: key.to_string(),
+                target: PaletteTarget::Resource(key.to_string()),
+            })
+            .collect();
+        resources.sort_by(|a, b| a.label.cmp(&b.label));
+
+        let commands = PALETTE_COMMANDS.iter().map(|(command, description, prompt)| PaletteEntry {
+            kind: "command",
+            label: description.to_string(),
+            detail: format!(":{}", command.trim_end()),
+            target: if *prompt {
+                PaletteTarget::Prompt(command.to_string())
+            } else {
+                PaletteTarget::Command(command.to_string())
+            },
+        });
+
+        let mut filters: Vec<PaletteEntry> = self
+            .config
+            .filters
+            .iter()
+            .flat_map(|(resource, saved)| {
+                saved.iter().map(move |saved| PaletteEntry {
+                    kind: "filter",
+                    label: saved.name.clone(),
+                    detail: format!("{}: {}", resource, saved.filter),
+                    target: PaletteTarget::Filter {
+                        resource: resource.clone(),
+                        filter: saved.filter.clone(),
+                    },
+                })
+            })
+            .collect();
+        filters.sort_by(|a, b| a.detail.cmp(&b.detail));
+
+        let bookmarks = self.bookmarks.bookmarks(&self.bookmark_scope()).iter().enumerate().map(|(i, location)| PaletteEntry {
+            kind: "bookmark",
+            label: location.label.clone(),
+            detail: location.resource_key.clone(),
+            target: PaletteTarget::Bookmark(i),
+        });
+
+        resources.into_iter().chain(commands).chain(filters).chain(bookmarks).collect()
+    }
+
+    /// Open the Ctrl+P palette
+    pub fn enter_palette(&mut self) {
+        self.palette = Some(Palette::default());
+        self.update_palette();
+        self.mode = Mode::Palette;
+    }
+
+    /// Re-rank the palette entries after its query changed
+    pub fn update_palette(&mut self) {
+        let entries = self.palette_entries();
+        if let Some(palette) = self.palette.as_mut() {
+            palette.matches = crate::palette::rank(entries, &palette.query);
+            palette.selected = 0;
+        }
+    }
+
+    /// Move the palette selection by `offset`, wrapping around
+    pub fn move_palette_selection(&mut self, offset: isize) {
+        if let Some(palette) = self.palette.as_mut()
+            && !palette.matches.is_empty()
+        {
+            let len = palette.matches.len() as isize;
+            palette.selected = (palette.selected as isize + offset).rem_euclid(len) as usize;
+        }
+    }
+
+    /// Run the selected palette entry
+    pub async fn run_palette_entry(&mut self) -> Result<()> {
+        let Some(target) = self
+            .palette
+            .as_ref()
+            .and_then(|palette| palette.matches.get(palette.selected))
+            .map(|entry| entry.target.clone())
+        else {
+            return Ok(());
+        };
+        self.exit_mode();
+        match target {
+            PaletteTarget::Resource(resource) => self.navigate_to_resource(&resource).await?,
+            PaletteTarget::Command(command) => {
+                self.command_text = command;
+                self.command_preview = None;
+                self.execute_command().await?;
+            }
+            PaletteTarget::Prompt(command) => {
+                self.enter_command_mode();
+                self.command_text = command;
+                self.update_command_suggestions();
+                self.command_preview = None;
+            }
+            PaletteTarget::Filter { resource, filter } => {
+                self.navigate_to_resource(&resource).await?;
+                self.filter_text = filter;
+                self.filter_active = false;
+                self.selected = 0;
+                self.apply_filter();
+            }
+            PaletteTarget::Bookmark(index) => {
+                if let Some(location) = self.bookmarks.bookmarks(&self.bookmark_scope()).get(index).cloned() {
+                    self.jump_to_location(location).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Bookmarks and Jump List
+    // =========================================================================
+
+    /// Key of the current profile and region in the bookmark store
+    fn bookmark_scope(&self) -> String {
+        crate::bookmarks::scope(&self.profile, &self.region)
+    }
+
+    /// The current view (and with `item`, the highlighted row) as a location
+    fn current_location(&self, item: bool) -> Location {
+        let mut parents: Vec<ParentContext> = self.navigation_stack.clone();
+        parents.extend(self.parent_context.clone());
+
+        let resource = self.current_resource();
+        let mut label: Vec<String> = parents.iter().map(|p| p.display_name.clone()).collect();
+        label.push(resource.map(|r| r.display_name.clone()).unwrap_or_else(|| self.current_resource_key.clone()));
+        let mut label = label.join(" > ");
+
+        let mut item_id = None;
+        if item && let (Some(row), Some(resource)) = (self.selected_item(), resource) {
+            let id = extract_json_value(row, &resource.id_field);
+            let name = extract_json_value(row, &resource.name_field);
+            label = format!("{}: {}", label, if name != "-" { &name } else { &id });
+            item_id = Some(id);
+        }
+
+        Location {
+            label,
+            resource_key: self.current_resource_key.clone(),
+            parents,
+            item_id,
+        }
+    }
+
+    /// Remember the current view in the recent views of the jump list
+    fn record_recent(&mut self) {
+        let location = self.current_location(false);
+        let scope = self.bookmark_scope();
+        self.bookmarks.push_recent(&scope, location);
+    }
+
+    /// Bookmark the highlighted item (`item`) or the current view, or remove the bookmark
+    pub fn toggle_bookmark(&mut self, item: bool) {
+        if item && self.selected_item().is_none() {
+            return;
+        }
+        let location = self.current_location(item);
+        let label = location.label.clone();
+        let scope = self.bookmark_scope();
+        if self.bookmarks.toggle_bookmark(&scope, location) {
+            self.show_warning(&format!("Bookmarked {}", label));
+        } else {
+            self.show_warning(&format!("Removed bookmark {}", label));
+        }
+    }
+
+    /// Jump list entries: bookmarks, then recent views other than the current one.
+    /// The flag is true for bookmarks.
+    pub fn jump_entries(&self) -> Vec<(bool, Location)> {
+        let scope = self.bookmark_scope();
+        let current = self.current_location(false);
+        let bookmarks = self.bookmarks.bookmarks(&scope).iter().map(|b| (true, b.clone()));
+        let recent = self
+            .bookmarks
+            .recent(&scope)
+            .iter()
+            .filter(|r| !r.same_place(&current))
+            .map(|r| (false, r.clone()));
+        bookmarks.chain(recent).collect()
+    }
+
+    /// Open the jump list (`'`)
+    pub fn enter_jumps_mode(&mut self) {
+        if self.jump_entries().is_empty() {
+            self.notify_error("No bookmarks or recent views yet: bookmark a row with m");
+            return;
+        }
+        self.jumps_selected = 0;
+        self.mode = Mode::Jumps;
+    }
+
+    /// Go to the selected jump list entry
+    pub async fn open_jump(&mut self) -> Result<()> {
+        let Some((_, location)) = self.jump_entries().into_iter().nth(self.jumps_selected) else {
+            return Ok(());
+        };
+        self.jump_to_location(location).await
+    }
+
+    /// Delete the selected entry if it is a bookmark
+    pub fn delete_jump(&mut self) {
+        let bookmark_count = self.bookmarks.bookmarks(&self.bookmark_scope()).len();
+        if self.jumps_selected >= bookmark_count {
+            return;
+        }
+        let scope = self.bookmark_scope();
+        self.bookmarks.remove_bookmark(&scope, self.jumps_selected);
+        let count = self.jump_entries().len();
+        if count == 0 {
+            self.mode = Mode::Normal;
+        }
+        self.jumps_selected = self.jumps_selected.min(count.saturating_sub(1));
+    }
+
+    /// Restore a location's view and parent views, then highlight its item
+    async fn jump_to_location(&mut self, location: Location) -> Result<()> {
+        if get_resource(&location.resource_key).is_none() {
+            self.notify_error(format!("Unknown resource: {}", location.resource_key));
+            return Ok(());
+        }
+        self.begin_view_change();
+        let mut parents = location.parents;
+        self.parent_context = parents.pop();
+        self.navigation_stack = parents;
+        self.view_filters.clear();
+        self.current_resource_key = location.resource_key;
+        self.selected = 0;
+        self.reset_filter();
+        self.mode = Mode::Normal;
+        
+        self.reset_pagination();
+        
+        if !self.load_view_change().await? {
+            return Ok(());
+        }
+        self.record_recent();
+        
+        if let Some(id) = location.item_id {
+            self.highlight_item(&id);
+        }
+        Ok(())
+    }
+
+    /// Highlight the listed item with this id. If it is not on this page or is
+    /// hidden by the filter, filter to it instead and return false.
+    fn highlight_item(&mut self, id: &str) -> bool {
+        let id_field = self.current_resource().map(|r| r.id_field.clone()).unwrap_or_default();
+        let position = self.filtered_items().position(|item| extract_json_value(item, &id_field) == id);
+        match position {
+            Some(index) => {
+                self.selected = index;
+                true
+            }
+            None => {
+                self.filter_text = id.to_string();
+                self.filter_active = false;
+                self.apply_filter();
+                false
+            }
+        }
+    }
+
+    /// Open a startup deep link: `resource-key`, `resource-key/item-id`, an ARN or an EC2 id
+    pub async fn open_deep_link(&mut self, link: &str) -> Result<()> {
+        if crate::resource::resource_for_arn(link).is_some() || crate::resource::resource_for_id(link).is_some() {
+            return self.goto(link).await;
+        }
+        let (resource_key, item_id) = match link.split_once('/') {
+            Some((resource_key, item_id)) if !item_id.is_empty() => (resource_key, Some(item_id)),
+            _ => (link.trim_end_matches('/'), None),
+        };
+        self.navigate_to_resource(resource_key).await?;
+        if let Some(item_id) = item_id
+            && get_resource(resource_key).is_some()
+            && self.highlight_item(item_id)
+        {
+            self.enter_describe_mode().await;
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Navigation
+    // =========================================================================
+
+    #[allow(dead_code)]
+    pub fn current_list_len(&self) -> usize {
+        self.filtered_indices.len()
+    }
+
+    /// Items passing the filter, in display order
+    pub fn filtered_items(&self) -> impl Iterator<Item = &Value> {
+        self.filtered_indices.iter().map(|&index| &self.items[index])
+    }
+
+    pub fn selected_item(&self) -> Option<&Value> {
+        self.filtered_indices.get(self.selected).map(|&index| &self.items[index])
+    }
+
+    /// Document shown in the describe view: the expression result if one is
+    /// applied, else the full details (or the list data)
+    pub fn describe_document(&self) -> Option<&Value> {
+        self.describe_projection
+            .as_ref()
+            .or(self.describe_data.as_ref())
+            .or(self.selected_item())
+    }
+
+    /// Evaluate the typed expression against the document and show the result
+    pub fn apply_describe_query(&mut self) {
+        self.describe_query_active = false;
+        let expr = self.describe_query.trim().to_string();
+        if expr.is_empty() || expr == "." {
+            self.clear_describe_query();
+            return;
+        }
+        let Some(document) = self.describe_data.as_ref().or(self.selected_item()) else {
+            return;
+        };
+        match crate::resource::evaluate_query(&expr, document) {
+            Ok(mut values) => {
+                self.describe_projection = Some(if values.len() == 1 {
+                    values.remove(0)
+                } else {
+                    Value::Array(values)
+                });
+                self.describe_collapsed.clear();
+                self.describe_cursor = 0;
+                self.describe_scroll = 0;
+                self.clear_describe_search();
+            }
+            Err(e) => {
+                self.notify_error(format!("Expression: {}", e));
+            }
+        }
+    }
+
+    /// Show the whole document again
+    pub fn clear_describe_query(&mut self) {
+        self.describe_query.clear();
+        self.describe_query_active = false;
+        self.describe_projection = None;
+        self.describe_collapsed.clear();
+        self.describe_cursor = 0;
+        self.describe_scroll = 0;
+    }
+
+    /// Lines of the describe view: the JSON tree with collapsed nodes folded,
+    /// the document as YAML, or raw text documents (e.g. YAML templates) as-is
+    pub fn describe_lines(&self) -> Vec<TreeLine> {
+        let text_lines = |text: &str| {
+            text.lines()
+                .map(|line| TreeLine {
+                    text: line.to_string(),
+                    node: None,
+                    parent: json_tree::ROOT.to_string(),
+                })
+                .collect()
+        };
+        match self.describe_document() {
+            Some(Value::String(text)) => text_lines(text),
+            Some(data) if self.describe_yaml => text_lines(&serde_yaml::to_string(data).unwrap_or_default()),
+            Some(data) => json_tree::flatten(data, &self.describe_collapsed),
+            None => Vec::new(),
+        }
+    }
+
+    /// Switch the describe view between the JSON tree and YAML
+    pub fn toggle_describe_yaml(&mut self) {
+        self.describe_yaml = !self.describe_yaml;
+        self.describe_cursor = 0;
+        self.describe_scroll = 0;
+        self.describe_match = 0;
+    }
+
+    /// Get the number of lines in the describe content
+    pub fn describe_line_count(&self) -> usize {
+        self.describe_lines().len()
+    }
+
+    /// Move the describe cursor `delta` lines, scrolling to keep it visible
+    pub fn move_describe_cursor(&mut self, delta: isize) {
+        let last = self.describe_line_count().saturating_sub(1);
+        self.set_describe_cursor(self.describe_cursor.saturating_add_signed(delta).min(last));
+    }
+
+    /// Put the describe cursor on `line`, scrolling to keep it visible
+    pub fn set_describe_cursor(&mut self, line: usize) {
+        self.describe_cursor = line;
+        let height = self.describe_height.get().max(1);
+        if line < self.describe_scroll {
+            self.describe_scroll = line;
+        } else if line >= self.describe_scroll + height {
+            self.describe_scroll = line + 1 - height;
+        }
+    }
+
+    /// Collapse or expand the object/array under the cursor. On a plain
+    /// value, collapse the object/array containing it.
+    pub fn toggle_describe_node(&mut self) {
+        let lines = self.describe_lines();
+        let Some(line) = lines.get(self.describe_cursor) else {
+            return;
+        };
+        match &line.node {
+            Some(node) if node != json_tree::ROOT => {
+                if self.describe_collapsed.contains(node) {
+                    self.describe_collapsed.remove(node);
+                } else {
+                    self.describe_collapsed.insert(node.clone());
+                }
+            }
+            _ if line.parent != json_tree::ROOT => {
+                let parent = line.parent.clone();
+                self.describe_collapsed.insert(parent.clone());
+                let lines = self.describe_lines();
+                if let Some(index) = lines.iter().position(|l| l.node.as_ref() == Some(&parent)) {
+                    self.set_describe_cursor(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Expand every node of the describe tree
+    pub fn expand_describe_tree(&mut self) {
+        self.describe_collapsed.clear();
+    }
+
+    /// Collapse every node of the describe tree, leaving the top-level keys visible
+    pub fn collapse_describe_tree(&mut self) {
+        if let Some(data) = self.describe_document() {
+            self.describe_collapsed = json_tree::container_paths(data).into_iter().collect();
+        }
+        self.describe_cursor = 0;
+        self.describe_scroll = 0;
     }
 
-    /// Scroll describe view to bottom
-    pub fn describe_scroll_to_bottom(&mut self, visible_lines: usize) {
+    /// Clamp describe scroll to valid range
+    #[allow(dead_code)]
+    pub fn clamp_describe_scroll(&mut self, visible_lines: usize) {
         let total = self.describe_line_count();
-        self.describe_scroll = total.saturating_sub(visible_lines);
+        let max_scroll = total.saturating_sub(visible_lines);
+        self.describe_scroll = self.describe_scroll.min(max_scroll);
+    }
+
+    /// Lines of the describe document containing the search text (case-insensitive)
+    pub fn describe_match_lines(&self) -> Vec<usize> {
+        if self.describe_search.is_empty() {
+            return Vec::new();
+        }
+        let query = self.describe_search.to_ascii_lowercase();
+        self.describe_lines()
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.text.to_ascii_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Finish typing the search and jump to the first match at or below the scroll position
+    pub fn submit_describe_search(&mut self) {
+        self.describe_search_active = false;
+        let matches = self.describe_match_lines();
+        if matches.is_empty() {
+            return;
+        }
+        self.describe_match = matches
+            .iter()
+            .position(|&line| line >= self.describe_scroll)
+            .unwrap_or(0);
+        self.scroll_to_describe_match(&matches);
+    }
+
+    /// Jump to the next (or previous) search match, wrapping around
+    pub fn step_describe_match(&mut self, forward: bool) {
+        let matches = self.describe_match_lines();
+        if matches.is_empty() {
+            return;
+        }
+        let len = matches.len();
+        self.describe_match = if forward {
+            (self.describe_match + 1) % len
+        } else {
+            (self.describe_match + len - 1) % len
+        };
+        self.scroll_to_describe_match(&matches);
+    }
+
+    /// Put the cursor on the current match, near the top with a little context above it
+    fn scroll_to_describe_match(&mut self, matches: &[usize]) {
+        if let Some(&line) = matches.get(self.describe_match) {
+            self.describe_scroll = line.saturating_sub(2);
+            self.describe_cursor = line;
+        }
     }
 
+    /// Clear the describe search
+    pub fn clear_describe_search(&mut self) {
+        self.describe_search.clear();
+        self.describe_search_active = false;
+        self.describe_match = 0;
+    }
+
+
     pub fn next(&mut self) {
         match self.mode {
             Mode::Profiles => {
@@ -593,9 +2064,48 @@ impl App {
                     self.regions_selected = (self.regions_selected + 1).min(self.available_regions.len() - 1);
                 }
             }
+            Mode::Tunnels => {
+                if !self.tunnels.is_empty() {
+                    self.tunnels_selected = (self.tunnels_selected + 1).min(self.tunnels.len() - 1);
+                }
+            }
+            Mode::Costs => {
+                let len = self.costs.as_ref().map(|c| c.rows.len()).unwrap_or(0);
+                if len > 0 {
+                    self.costs_selected = (self.costs_selected + 1).min(len - 1);
+                }
+            }
+            Mode::Tags => {
+                if let Some(state) = self.tags_state.as_mut() {
+                    state.selected = (state.selected + 1).min(state.tags.len().saturating_sub(1));
+                }
+            }
+            Mode::SsoSessions => {
+                self.sso_sessions_selected = (self.sso_sessions_selected + 1).min(self.sso_sessions.len().saturating_sub(1));
+            }
+            Mode::Audit => {
+                self.audit_selected = (self.audit_selected + 1).min(self.audit_entries.len().saturating_sub(1));
+            }
+            Mode::Notifications => {
+                self.notifications_selected = (self.notifications_selected + 1).min(self.notifications.len().saturating_sub(1));
+            }
+            Mode::ApiCalls => {
+                self.api_calls_selected = (self.api_calls_selected + 1).min(self.api_calls.len().saturating_sub(1));
+            }
+            Mode::Columns => {
+                if let Some(picker) = self.column_picker.as_mut() {
+                    picker.selected = (picker.selected + 1).min(picker.columns.len().saturating_sub(1));
+                }
+            }
+            Mode::Filters => {
+                self.filters_selected = (self.filters_selected + 1).min(self.saved_filters().len().saturating_sub(1));
+            }
+            Mode::Jumps => {
+                self.jumps_selected = (self.jumps_selected + 1).min(self.jump_entries().len().saturating_sub(1));
+            }
             _ => {
-                if !self.filtered_items.is_empty() {
-                    self.selected = (self.selected + 1).min(self.filtered_items.len() - 1);
+                if !self.filtered_indices.is_empty() {
+                    self.selected = (self.selected + 1).min(self.filtered_indices.len() - 1);
                 }
             }
         }
@@ -609,6 +2119,40 @@ impl App {
             Mode::Regions => {
                 self.regions_selected = self.regions_selected.saturating_sub(1);
             }
+            Mode::Tunnels => {
+                self.tunnels_selected = self.tunnels_selected.saturating_sub(1);
+            }
+            Mode::Costs => {
+                self.costs_selected = self.costs_selected.saturating_sub(1);
+            }
+            Mode::Tags => {
+                if let Some(state) = self.tags_state.as_mut() {
+                    state.selected = state.selected.saturating_sub(1);
+                }
+            }
+            Mode::SsoSessions => {
+                self.sso_sessions_selected = self.sso_sessions_selected.saturating_sub(1);
+            }
+            Mode::Audit => {
+                self.audit_selected = self.audit_selected.saturating_sub(1);
+            }
+            Mode::Notifications => {
+                self.notifications_selected = self.notifications_selected.saturating_sub(1);
+            }
+            Mode::ApiCalls => {
+                self.api_calls_selected = self.api_calls_selected.saturating_sub(1);
+            }
+            Mode::Columns => {
+                if let Some(picker) = self.column_picker.as_mut() {
+                    picker.selected = picker.selected.saturating_sub(1);
+                }
+            }
+            Mode::Filters => {
+                self.filters_selected = self.filters_selected.saturating_sub(1);
+            }
+            Mode::Jumps => {
+                self.jumps_selected = self.jumps_selected.saturating_sub(1);
+            }
             _ => {
                 self.selected = self.selected.saturating_sub(1);
             }
@@ -619,6 +2163,24 @@ impl App {
         match self.mode {
             Mode::Profiles => self.profiles_selected = 0,
             Mode::Regions => self.regions_selected = 0,
+            Mode::Tunnels => self.tunnels_selected = 0,
+            Mode::Costs => self.costs_selected = 0,
+            Mode::Tags => {
+                if let Some(state) = self.tags_state.as_mut() {
+                    state.selected = 0;
+                }
+            }
+            Mode::SsoSessions => self.sso_sessions_selected = 0,
+            Mode::Audit => self.audit_selected = 0,
+            Mode::Notifications => self.notifications_selected = 0,
+            Mode::ApiCalls => self.api_calls_selected = 0,
+            Mode::Columns => {
+                if let Some(picker) = self.column_picker.as_mut() {
+                    picker.selected = 0;
+                }
+            }
+            Mode::Filters => self.filters_selected = 0,
+            Mode::Jumps => self.jumps_selected = 0,
             _ => self.selected = 0,
         }
     }
@@ -635,9 +2197,48 @@ impl App {
                     self.regions_selected = self.available_regions.len() - 1;
                 }
             }
+            Mode::Tunnels => {
+                if !self.tunnels.is_empty() {
+                    self.tunnels_selected = self.tunnels.len() - 1;
+                }
+            }
+            Mode::Costs => {
+                let len = self.costs.as_ref().map(|c| c.rows.len()).unwrap_or(0);
+                if len > 0 {
+                    self.costs_selected = len - 1;
+                }
+            }
+            Mode::Tags => {
+                if let Some(state) = self.tags_state.as_mut() {
+                    state.selected = state.tags.len().saturating_sub(1);
+                }
+            }
+            Mode::SsoSessions => {
+                self.sso_sessions_selected = self.sso_sessions.len().saturating_sub(1);
+            }
+            Mode::Audit => {
+                self.audit_selected = self.audit_entries.len().saturating_sub(1);
+            }
+            Mode::Notifications => {
+                self.notifications_selected = self.notifications.len().saturating_sub(1);
+            }
+            Mode::ApiCalls => {
+                self.api_calls_selected = self.api_calls.len().saturating_sub(1);
+            }
+            Mode::Columns => {
+                if let Some(picker) = self.column_picker.as_mut() {
+                    picker.selected = picker.columns.len().saturating_sub(1);
+                }
+            }
+            Mode::Filters => {
+                self.filters_selected = self.saved_filters().len().saturating_sub(1);
+            }
+            Mode::Jumps => {
+                self.jumps_selected = self.jump_entries().len().saturating_sub(1);
+            }
             _ => {
-                if !self.filtered_items.is_empty() {
-                    self.selected = self.filtered_items.len() - 1;
+                if !self.filtered_indices.is_empty() {
+                    self.selected = self.filtered_indices.len() - 1;
                 }
             }
         }
@@ -656,8 +2257,8 @@ impl App {
                 }
             }
             _ => {
-                if !self.filtered_items.is_empty() {
-                    self.selected = (self.selected + page_size).min(self.filtered_items.len() - 1);
+                if !self.filtered_indices.is_empty() {
+                    self.selected = (self.selected + page_size).min(self.filtered_indices.len() - 1);
                 }
             }
         }
@@ -687,6 +2288,8 @@ impl App {
         self.command_suggestions = self.get_available_commands();
         self.command_suggestion_selected = 0;
         self.command_preview = None;
+        self.command_history_index = None;
+        self.history_search = None;
     }
 
     pub fn update_command_suggestions(&mut self) {
@@ -749,17 +2352,85 @@ impl App {
         }
     }
 
-    pub fn enter_help_mode(&mut self) {
+    /// Recall the previous (older) command from history
+    pub fn history_prev(&mut self) {
+        let index = match self.command_history_index {
+            Some(0) => return,
+            Some(i) => i - 1,
+            None if self.command_history.is_empty() => return,
+            None => self.command_history.len() - 1,
+        };
+        self.recall_history(Some(index));
+    }
+
+    /// Recall the next (newer) command; past the newest the box is cleared
+    pub fn history_next(&mut self) {
+        match self.command_history_index {
+            Some(i) if i + 1 < self.command_history.len() => self.recall_history(Some(i + 1)),
+            Some(_) => self.recall_history(None),
+            None => {}
+        }
+    }
+
+    fn recall_history(&mut self, index: Option<usize>) {
+        self.command_text = index
+            .and_then(|i| self.command_history.get(i).cloned())
+            .unwrap_or_default();
+        self.update_command_suggestions();
+        // Run the recalled command as-is rather than a completion of it
+        self.command_preview = None;
+        self.command_history_index = index;
+    }
+
+    /// Start a Ctrl+R history search, or move to the next older match
+    pub fn history_search_next(&mut self) {
+        if self.history_search.is_none() {
+            self.history_search = Some(String::new());
+            self.command_suggestion_selected = 0;
+        } else if !self.command_suggestions.is_empty() {
+            self.command_suggestion_selected = (self.command_suggestion_selected + 1) % self.command_suggestions.len();
+        }
+        self.update_history_search();
+    }
+
+    /// Refresh the matches of the Ctrl+R search after its query changed
+    pub fn update_history_search(&mut self) {
+        let Some(query) = &self.history_search else {
+            return;
+        };
+        self.command_suggestions = crate::history::search(&self.command_history, query);
+        if self.command_suggestion_selected >= self.command_suggestions.len() {
+            self.command_suggestion_selected = 0;
+        }
+        self.update_preview();
+    }
+
+    /// Leave the Ctrl+R search, keeping the selected match in the box if `accept`
+    pub fn end_history_search(&mut self, accept: bool) {
+        if self.history_search.take().is_none() {
+            return;
+        }
+        let selected = self.command_preview.take();
+        if accept && let Some(command) = selected {
+            self.command_text = command;
+        }
+        self.update_command_suggestions();
+        self.command_preview = None;
+    }
+
+    pub fn enter_help_mode(&mut self) {
         self.mode = Mode::Help;
     }
 
     pub async fn enter_describe_mode(&mut self) {
-        if self.filtered_items.is_empty() {
+        if self.filtered_indices.is_empty() {
             return;
         }
         
         self.mode = Mode::Describe;
         self.describe_scroll = 0;
+        self.describe_cursor = 0;
+        self.describe_collapsed.clear();
         self.describe_data = None;
         
         // Get the selected item's ID
@@ -770,7 +2441,7 @@ impl App {
                     // Fetch full details
                     match crate::resource::describe_resource(
                         &self.current_resource_key,
-                        &self.clients,
+                        &self.selected_clients(),
                         &id,
                     ).await {
                         Ok(data) => {
@@ -787,6 +2458,496 @@ impl App {
         }
     }
 
+    /// Toggle the split layout (list plus describe panel)
+    pub fn toggle_split_view(&mut self) {
+        self.split_view = !self.split_view;
+        self.split_describe = None;
+        self.split_pending = None;
+        if let Some((_, task)) = self.split_task.take() {
+            task.abort();
+        }
+    }
+
+    /// Key (resource/id) and id of the selected row, so rows of different views never share data
+    fn selection_key(&self) -> Option<(String, String)> {
+        let item = self.selected_item()?;
+        let resource_def = self.current_resource()?;
+        let id = extract_json_value(item, &resource_def.id_field);
+        if id == "-" || id.is_empty() {
+            return None;
+        }
+        Some((format!("{}/{}", self.current_resource_key, id), id))
+    }
+
+    /// Describe data for the split panel, if it was fetched for the selected row
+    pub fn split_describe_data(&self) -> Option<&Value> {
+        let (key, _) = self.selection_key()?;
+        self.split_describe
+            .as_ref()
+            .filter(|(fetched, _)| *fetched == key)
+            .map(|(_, data)| data)
+    }
+
+    /// Describe the selected row for the split panel once the selection has
+    /// stayed on it for SPLIT_DESCRIBE_DELAY, so scrolling through the list
+    /// does not fire a call per row. The call runs in the background; its
+    /// result is kept only if its row is still highlighted when it finishes.
+    pub async fn update_split_describe(&mut self) {
+        if !self.split_view || self.mode != Mode::Normal {
+            return;
+        }
+        let selection = self.selection_key();
+        if let Some((key, task)) = self.split_task.take_if(|(_, task)| task.is_finished())
+            && selection.as_ref().is_some_and(|(selected, _)| *selected == key)
+        {
+            let data = match task.await.unwrap_or_else(|e| Err(anyhow::anyhow!(e))) {
+                Ok(data) => data,
+                Err(e) => {
+                    tracing::warn!("Failed to fetch describe data: {}", e);
+                    // Fall back to list data
+                    self.selected_item().cloned().unwrap_or(Value::Null)
+                }
+            };
+            self.split_describe = Some((key, data));
+        }
+
+        let Some((key, id)) = selection else {
+            self.split_pending = None;
+            return;
+        };
+        if self.split_describe.as_ref().is_some_and(|(fetched, _)| *fetched == key)
+            || self.split_task.as_ref().is_some_and(|(describing, _)| *describing == key)
+        {
+            return;
+        }
+        match &self.split_pending {
+            Some((pending, since)) if *pending == key => {
+                if since.elapsed() < SPLIT_DESCRIBE_DELAY {
+                    return;
+                }
+            }
+            _ => {
+                self.split_pending = Some((key, std::time::Instant::now()));
+                return;
+            }
+        }
+
+        self.split_pending = None;
+        // The row an earlier call was for is no longer highlighted
+        if let Some((_, task)) = self.split_task.take() {
+            task.abort();
+        }
+        let task = tokio::spawn({
+            let clients = self.selected_clients();
+            let resource_key = self.current_resource_key.clone();
+            async move { crate::resource::describe_resource(&resource_key, &clients, &id).await }
+        });
+        self.split_task = Some((key, task));
+    }
+
+    /// Mark the selected item for comparison, or unmark it if it is already marked
+    pub fn toggle_diff_mark(&mut self) {
+        let Some((_, id)) = self.selection_key() else {
+            return;
+        };
+        if self.is_diff_marked(&id) {
+            self.diff_mark = None;
+        } else if let Some(item) = self.selected_item() {
+            self.diff_mark = Some(DiffMark {
+                resource_key: self.current_resource_key.clone(),
+                id,
+                item: item.clone(),
+            });
+        }
+    }
+
+    /// Whether the item with this id (in the current view) is marked for comparison
+    pub fn is_diff_marked(&self, id: &str) -> bool {
+        self.diff_mark
+            .as_ref()
+            .is_some_and(|mark| mark.resource_key == self.current_resource_key && mark.id == id)
+    }
+
+    /// Compare the marked item with the selected one
+    pub async fn enter_diff_mode(&mut self) {
+        let Some(mark) = self.diff_mark.clone() else {
+            self.notify_error("Mark an item with x first, then select another and press =");
+            return;
+        };
+        let Some((_, id)) = self.selection_key() else {
+            return;
+        };
+        if self.is_diff_marked(&id) {
+            self.notify_error("Select a different item to compare with the marked one");
+            return;
+        }
+        let Some(item) = self.selected_item().cloned() else {
+            return;
+        };
+
+        let left = self.describe_or_item(&mark.resource_key, &mark.id, &mark.item).await;
+        let right = self.describe_or_item(&self.current_resource_key.clone(), &id, &item).await;
+        self.diff_lines = crate::diff::diff_documents(&left, &right);
+        self.diff_title = format!("{} ↔ {}", mark.id, id);
+        self.diff_scroll = 0;
+        self.mode = Mode::Diff;
+    }
+
+    /// Full details of an item, falling back to its list data
+    async fn describe_or_item(&self, resource_key: &str, id: &str, item: &Value) -> Value {
+        match crate::resource::describe_resource(resource_key, &self.selected_clients(), id).await {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!("Failed to fetch describe data: {}", e);
+                item.clone()
+            }
+        }
+    }
+
+    /// Run a view action and show its document in the describe view
+    pub async fn enter_action_view(&mut self, action: &crate::resource::ActionDef, resource_id: &str) {
+        let Some(service) = self.current_resource().map(|r| r.service.clone()) else {
+            return;
+        };
+        
+        match crate::resource::fetch_action_view(&service, &action.sdk_method, &self.selected_clients(), resource_id).await {
+            Ok(data) => {
+                self.mode = Mode::Describe;
+                self.describe_scroll = 0;
+                self.describe_cursor = 0;
+                self.describe_collapsed.clear();
+                self.describe_data = Some(data);
+                self.describe_title = Some(format!("{}: {}", action.display_name, resource_id));
+            }
+            Err(e) => {
+                self.notify_aws_error(&format!("{} failed", action.display_name), &e);
+            }
+        }
+    }
+
+    /// Start a resource action on the selected item in the background. When it
+    /// finishes, `poll_actions` reports it, records it in the audit log (unless
+    /// the action is marked read-only) and refreshes the list.
+    pub fn run_action(&mut self, service: &str, sdk_method: &str, resource_id: &str, input: Option<&str>) {
+        let action = self.current_resource()
+            .and_then(|resource| resource.actions.iter().find(|action| action.sdk_method == sdk_method));
+        if let Some(action) = action
+            && let Some(command) = &action.exec
+        {
+            self.queue_exec_action(action, command, resource_id, input);
+            return;
+        }
+
+        let label = format!("{} {}", action.map_or(sdk_method, |action| action.display_name.as_str()), resource_id);
+        let read_only = action.is_some_and(|action| action.read_only);
+        let api = action.and_then(|action| action.api.clone());
+        let clients = self.selected_clients();
+        let task = tokio::spawn({
+            let clients = clients.clone();
+            let (service, sdk_method, resource_id) = (service.to_string(), sdk_method.to_string(), resource_id.to_string());
+            let input = input.map(String::from);
+            async move {
+                match api {
+                    Some(api) => crate::resource::execute_api_action(&service, &api, &clients, &resource_id, input.as_deref()).await,
+                    None => crate::resource::execute_action(&service, &sdk_method, &clients, &resource_id, input.as_deref()).await,
+                }
+            }
+        });
+        self.running_actions.push(RunningAction {
+            label,
+            event: format!("{}:{}", service, sdk_method),
+            target: resource_id.to_string(),
+            profile: self.profile.clone(),
+            read_only,
+            clients,
+            task,
+        });
+    }
+
+    /// Report background actions that have finished, then refresh the list
+    /// once if any of them changed something
+    pub async fn poll_actions(&mut self) {
+        let (finished, running): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.running_actions).into_iter().partition(|action| action.task.is_finished());
+        self.running_actions = running;
+        if finished.is_empty() {
+            return;
+        }
+
+        let mut changed = false;
+        for action in finished {
+            let result = action.task.await.unwrap_or_else(|e| Err(anyhow::anyhow!(e)));
+            if !action.read_only {
+                self.audit(&action.profile, &action.clients, &action.event, &action.target, &result);
+                changed = true;
+            }
+            match result {
+                Ok(()) => self.notify(Level::Success, format!("✓ {}", action.label)),
+                Err(e) => self.notify_aws_error(&format!("✗ {}", action.label), &e),
+            }
+        }
+        if changed {
+            let _ = self.refresh_current().await;
+        }
+    }
+
+    /// Show a message as a toast and keep it in the notification history
+    pub fn notify(&mut self, level: Level, text: impl Into<String>) {
+        self.notifications.push(level, text);
+    }
+
+    /// Report an error (see `notify`)
+    pub fn notify_error(&mut self, text: impl Into<String>) {
+        self.notify(Level::Error, text);
+    }
+
+    /// Report a failed request as "`context`: short message", keeping the
+    /// full error for `:error`. An empty context reports the message alone.
+    pub fn notify_aws_error(&mut self, context: &str, err: &anyhow::Error) {
+        let message = aws::client::format_aws_error(err);
+        let text = if context.is_empty() { message } else { format!("{}: {}", context, message) };
+        self.notifications.push_with_detail(Level::Error, text, Some(aws::client::error_details(err)));
+    }
+
+    /// Show the details of a notification in the describe view
+    fn show_error_detail(&mut self, notification: &crate::notifications::Notification) {
+        let Some(detail) = notification.detail.clone() else {
+            return;
+        };
+        self.mode = Mode::Describe;
+        self.describe_scroll = 0;
+        self.describe_cursor = 0;
+        self.describe_collapsed.clear();
+        self.describe_data = Some(detail);
+        self.describe_title = Some(format!("Error at {}: {}", notification.time, notification.text));
+    }
+
+    /// Details of the most recent error (`:error`)
+    pub fn show_latest_error(&mut self) {
+        match self.notifications.latest_detailed().cloned() {
+            Some(notification) => self.show_error_detail(&notification),
+            None => self.notify(Level::Info, "No error details to show"),
+        }
+    }
+
+    /// Details of the highlighted notification, if it has any
+    pub fn show_selected_notification(&mut self) {
+        let notification = self.notifications.history().nth(self.notifications_selected).cloned();
+        if let Some(notification) = notification {
+            self.show_error_detail(&notification);
+        }
+    }
+
+    /// Show recent API calls, newest first (a snapshot, `r` takes a new one)
+    pub fn enter_api_calls_mode(&mut self) {
+        self.api_calls = aws::api_log::recent();
+        self.api_calls_selected = 0;
+        self.mode = Mode::ApiCalls;
+    }
+
+    /// Show the raw request and response of the highlighted API call
+    pub fn show_selected_api_call(&mut self) {
+        let Some(call) = self.api_calls.get(self.api_calls_selected) else {
+            return;
+        };
+        let title = format!("{} {} at {}", call.service, call.operation, call.time);
+        let document = serde_json::json!({
+            "Service": call.service,
+            "Operation": call.operation,
+            "Status": call.status,
+            "DurationMs": call.duration.as_millis() as u64,
+            "Bytes": call.bytes,
+            "Request": {
+                "Method": call.method,
+                "Url": call.url,
+                "Headers": call.request_headers.iter().map(|(name, value)| (name.clone(), Value::String(value.clone()))).collect::<serde_json::Map<_, _>>(),
+                "Body": body_document(&call.request_body),
+            },
+            "Response": body_document(&call.response_body),
+        });
+        self.mode = Mode::Describe;
+        self.describe_scroll = 0;
+        self.describe_cursor = 0;
+        self.describe_collapsed.clear();
+        self.describe_data = Some(document);
+        self.describe_title = Some(title);
+    }
+
+    /// Show the notification history, newest first
+    pub fn enter_notifications_mode(&mut self) {
+        self.notifications_selected = 0;
+        self.mode = Mode::Notifications;
+    }
+
+    /// Watch the highlighted row for state changes, or stop watching it
+    pub fn toggle_watch(&mut self) {
+        let Some((_, id)) = self.selection_key() else {
+            return;
+        };
+        if let Some(index) = self
+            .watches
+            .iter()
+            .position(|watch| watch.resource_key == self.current_resource_key && watch.id == id)
+        {
+            let watch = self.watches.remove(index);
+            self.notify(Level::Info, format!("Stopped watching {}", watch.label));
+            return;
+        }
+
+        // The state is the colored status column (STATE, STATUS, ...)
+        let Some(state_field) = self
+            .table_columns()
+            .into_iter()
+            .find(|col| col.color_map.is_some())
+            .map(|col| col.color_field.unwrap_or(col.json_path))
+        else {
+            self.notify_error(format!("{} has no state to watch", self.current_resource_key));
+            return;
+        };
+        let (Some(resource), Some(item)) = (self.current_resource(), self.selected_item()) else {
+            return;
+        };
+        let name = extract_json_value(item, &resource.name_field);
+        let label = format!("{} {}", resource.display_name, if name != "-" { &name } else { &id });
+        let state = extract_json_value(item, &state_field);
+        let watch = Watch::new(
+            &self.current_resource_key,
+            &id,
+            label,
+            state,
+            &resource.id_field,
+            &state_field,
+            self.build_filters_from_context(),
+            self.selected_clients(),
+        );
+        self.notify(Level::Info, format!("Watching {} ({})", watch.label, watch.state));
+        self.watches.push(watch);
+    }
+
+    /// Poll watched resources that are due and report state changes
+    pub async fn poll_watches(&mut self) {
+        let mut changes = Vec::new();
+        for watch in &mut self.watches {
+            if let Some(change) = watch.take_change().await {
+                changes.push((watch.resource_key.clone(), watch.id.clone(), watch.label.clone(), change));
+            }
+            watch.start_poll();
+        }
+
+        for (resource_key, id, label, change) in changes {
+            let text = match change {
+                Change::State { from, to } => format!("{}: {} → {}", label, from, to),
+                Change::Gone => {
+                    self.watches.retain(|watch| watch.resource_key != resource_key || watch.id != id);
+                    format!("{} is no longer listed; stopped watching", label)
+                }
+            };
+            crate::watch::alert(&text, self.config.watch_bell, self.config.watch_desktop_notifications);
+            self.notify(Level::Info, text);
+        }
+    }
+
+    /// Look up the account identity and regions when the profile or role hop
+    /// has changed since the last lookup, and pick up the results once they arrive
+    pub async fn poll_account(&mut self) {
+        let scope = format!("{}|{}", self.profile, self.clients.assumed_role.as_deref().unwrap_or(""));
+        if scope != self.account_scope {
+            self.account_scope = scope;
+            self.identity = None;
+            if let Some(task) = self.account_task.take() {
+                task.abort();
+            }
+            let clients = self.clients.clone();
+            self.account_task = Some(tokio::spawn(async move {
+                tokio::join!(
+                    crate::resource::get_account_identity(&clients),
+                    crate::resource::describe_regions(&clients),
+                )
+            }));
+            return;
+        }
+        if !self.account_task.as_ref().is_some_and(tokio::task::JoinHandle::is_finished) {
+            return;
+        }
+        let Some(task) = self.account_task.take() else {
+            return;
+        };
+        let (identity, regions) = match task.await {
+            Ok(results) => results,
+            Err(e) => {
+                tracing::warn!("Account lookup failed: {}", e);
+                return;
+            }
+        };
+        match identity {
+            Ok(identity) => self.identity = Some(identity),
+            Err(e) => tracing::warn!("GetCallerIdentity failed: {}", aws::client::format_aws_error(&e)),
+        }
+        // Keep the built-in list when DescribeRegions isn't allowed (or emulated)
+        match regions {
+            Ok(regions) => self.set_account_regions(regions),
+            Err(e) => tracing::warn!("DescribeRegions failed: {}", aws::client::format_aws_error(&e)),
+        }
+    }
+
+    /// Offer the regions enabled in the account (not the ones it hasn't opted in to)
+    fn set_account_regions(&mut self, regions: Vec<(String, String)>) {
+        let enabled: Vec<String> = regions
+            .iter()
+            .filter(|(_, status)| status != "not-opted-in")
+            .map(|(region, _)| region.clone())
+            .collect();
+        if enabled.is_empty() {
+            return;
+        }
+        self.available_regions = enabled;
+        self.region_opt_in = regions.into_iter().collect();
+        self.regions_selected = self.regions_selected.min(self.available_regions.len() - 1);
+    }
+
+    /// Record a write operation made under `profile` with `clients`
+    fn audit(&self, profile: &str, clients: &AwsClients, event: &str, target: &str, result: &Result<()>) {
+        // Lists fetched before the write may be out of date
+        self.list_cache.clear();
+        let outcome = match result {
+            Ok(()) => "ok".to_string(),
+            Err(e) => format!("error: {}", aws::client::format_aws_error(e)),
+        };
+        crate::audit::record(profile, &clients.region, event, target, &outcome);
+    }
+
+    /// Run an action whose result holds a secret and show it in the secret dialog
+    pub async fn enter_secret_mode(&mut self, service: &str, sdk_method: &str, resource_id: &str, secret: &crate::resource::SecretConfig) {
+        let data = match crate::resource::fetch_action_view(service, sdk_method, &self.selected_clients(), resource_id).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.notify_aws_error(&format!("{} failed", secret.title), &e);
+                return;
+            }
+        };
+        
+        crate::audit::record(&self.profile, &self.region, &secret.title, resource_id, "ok");
+        
+        let value = crate::resource::extract_json_value(&data, &secret.field);
+        let fields = data.as_object()
+            .map(|obj| obj.iter()
+                .filter(|(k, v)| *k != &secret.field && !v.is_null())
+                .map(|(k, v)| (k.clone(), v.as_str().map(String::from).unwrap_or_else(|| v.to_string())))
+                .collect())
+            .unwrap_or_default();
+        
+        self.pending_action = None;
+        self.secret_dialog = Some(SecretDialog {
+            title: secret.title.clone(),
+            fields,
+            secret_label: secret.field.clone(),
+            secret: value,
+            revealed: !secret.masked,
+            note: secret.note.clone(),
+        });
+        self.mode = Mode::Secret;
+    }
+
     /// Enter confirmation mode for an action
     pub fn enter_confirm_mode(&mut self, pending: PendingAction) {
         self.pending_action = Some(pending);
@@ -808,6 +2969,40 @@ impl App {
         self.mode = Mode::SsoLogin;
     }
     
+    /// Prompt for the MFA code of a profile with `mfa_serial`
+    pub fn enter_mfa_mode(&mut self, profile: &str, mfa_serial: &str) {
+        self.mfa_state = Some(MfaState {
+            profile: profile.to_string(),
+            mfa_serial: mfa_serial.to_string(),
+            code: String::new(),
+            error: None,
+        });
+        self.mode = Mode::Mfa;
+    }
+    
+    /// Exchange the entered MFA code for session credentials and switch to the profile
+    pub async fn submit_mfa_code(&mut self) {
+        let Some(state) = self.mfa_state.clone() else {
+            return;
+        };
+        let result = aws::client::start_mfa_session(&state.profile, &state.code, &self.region, self.endpoint_url.clone()).await;
+        if let Err(e) = result {
+            if let Some(state) = self.mfa_state.as_mut() {
+                state.error = Some(aws::client::format_aws_error(&e));
+                state.code.clear();
+            }
+            return;
+        }
+        
+        self.mfa_state = None;
+        self.exit_mode();
+        if let Err(e) = self.switch_profile(&state.profile).await {
+            self.notify_error(format!("Failed to switch profile: {}", e));
+        } else {
+            let _ = self.refresh_current().await;
+        }
+    }
+    
     /// Create a pending action from an ActionDef
     pub fn create_pending_action(&self, action: &crate::resource::ActionDef, resource_id: &str) -> Option<PendingAction> {
         let config = action.get_confirm_config()?;
@@ -823,7 +3018,7 @@ impl App {
             })
             .unwrap_or_else(|| resource_id.to_string());
         
-        let message = config.message.unwrap_or_else(|| action.display_name.clone());
+        let message = config.message.clone().unwrap_or_else(|| action.display_name.clone());
         let default_no = !config.default_yes;
         
         Some(PendingAction {
@@ -834,18 +3029,383 @@ impl App {
             default_no,
             destructive: config.destructive,
             selected_yes: config.default_yes, // Start with default selection
+            input: None,
+            secret: action.secret.clone(),
+            expected_text: config.typed.then(|| resource_name.clone()),
+            typed_text: String::new(),
         })
     }
 
+    /// Enter input mode to collect text for an action with a prompt
+    pub fn enter_input_mode(&mut self, action: &crate::resource::ActionDef, resource_id: &str) {
+        let Some(prompt) = &action.prompt else {
+            return;
+        };
+        
+        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S").to_string();
+        let mut text = prompt.default.as_deref()
+            .map(|d| {
+                d.replace("{id}", resource_id)
+                    .replace("{timestamp}", &timestamp)
+                    .replace("{org_role}", self.config.effective_org_role())
+            })
+            .unwrap_or_default();
+        
+        // Any other {Field} placeholder is filled from the selected row
+        self.fill_item_placeholders(&mut text);
+        
+        self.input_prompt = Some(InputPrompt {
+            action: action.clone(),
+            resource_id: resource_id.to_string(),
+            message: prompt.message.clone(),
+            text,
+        });
+        self.mode = Mode::Input;
+    }
+
+    /// Replace `{Field}` placeholders with fields of the selected row
+    fn fill_item_placeholders(&self, text: &mut String) {
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+        let mut pos = 0;
+        while let Some(start) = text[pos..].find('{').map(|i| pos + i)
+            && let Some(len) = text[start..].find('}')
+        {
+            let value = extract_json_value(item, &text[start + 1..start + len]);
+            let value = if value == "-" { String::new() } else { value };
+            text.replace_range(start..=start + len, &value);
+            pos = start + value.len();
+        }
+    }
+
+    /// Queue an `exec` action's command; it runs once the TUI is suspended.
+    /// The command runs without a shell, so filled-in values can't inject one.
+    fn queue_exec_action(&mut self, action: &crate::resource::ActionDef, command: &str, resource_id: &str, input: Option<&str>) {
+        let region = self.selected_clients().region;
+        let argv = exec_argv(command, |word| {
+            *word = word
+                .replace("{id}", resource_id)
+                .replace("{region}", &region)
+                .replace("{profile}", &self.profile)
+                .replace("{input}", input.unwrap_or_default());
+            self.fill_item_placeholders(word);
+        });
+        let mut argv = match argv {
+            Ok(argv) if !argv.is_empty() => argv,
+            Ok(_) => return self.notify_error(format!("{}: empty exec command", action.display_name)),
+            Err(e) => return self.notify_error(format!("{}: {}", action.display_name, e)),
+        };
+
+        let program = argv.remove(0);
+        let banner = std::iter::once(&program).chain(&argv).map(String::as_str).collect::<Vec<_>>().join(" ");
+        self.external_command = Some(ExternalCommand {
+            program,
+            args: argv,
+            banner: format!("{}: {}", action.display_name, banner),
+            env: vec![("AWS_PROFILE".to_string(), self.profile.clone()), ("AWS_REGION".to_string(), region)],
+            wait: true,
+        });
+    }
+
     pub fn enter_profiles_mode(&mut self) {
         self.profiles_selected = self
             .available_profiles
             .iter()
             .position(|p| p == &self.profile)
             .unwrap_or(0);
+        self.profile_details = self
+            .available_profiles
+            .iter()
+            .map(|p| (p.clone(), aws::credentials::get_profile_details(p)))
+            .collect();
         self.mode = Mode::Profiles;
     }
 
+    /// Resolve the account of the selected profile in the background (`a` in
+    /// the profile picker), with its credentials and GetCallerIdentity
+    pub fn resolve_profile_account(&mut self) {
+        let Some(profile) = self.available_profiles.get(self.profiles_selected).cloned() else {
+            return;
+        };
+        if self.profile_lookups.iter().any(|(p, _)| *p == profile) {
+            return;
+        }
+        let region = self
+            .profile_details
+            .get(&profile)
+            .and_then(|details| details.region.clone())
+            .unwrap_or_else(|| self.region.clone());
+        let endpoint_url = self.endpoint_url.clone();
+        let task_profile = profile.clone();
+        let task = tokio::spawn(async move {
+            let (clients, _) = AwsClients::new(&task_profile, &region, endpoint_url).await?;
+            crate::resource::get_account_identity(&clients).await
+        });
+        self.profile_lookups.push((profile, task));
+    }
+
+    /// Pick up resolved profile accounts
+    pub async fn poll_profile_lookups(&mut self) {
+        let (finished, running): (Vec<_>, Vec<_>) = std::mem::take(&mut self.profile_lookups)
+            .into_iter()
+            .partition(|(_, task)| task.is_finished());
+        self.profile_lookups = running;
+        for (profile, task) in finished {
+            match task.await {
+                Ok(Ok(identity)) => {
+                    self.profile_accounts.insert(profile, identity);
+                }
+                Ok(Err(e)) => self.notify_aws_error(&profile, &e),
+                Err(e) => self.notify_error(format!("{}: {}", profile, e)),
+            }
+        }
+    }
+
+    pub fn enter_tunnels_mode(&mut self) {
+        self.tunnels_selected = 0;
+        self.mode = Mode::Tunnels;
+    }
+
+    /// List cached SSO tokens (`:sso sessions`)
+    pub fn enter_sso_sessions_mode(&mut self) {
+        match aws::sso::list_cached_sessions() {
+            Ok(sessions) => {
+                self.sso_sessions = sessions;
+                self.sso_sessions_selected = 0;
+                self.mode = Mode::SsoSessions;
+            }
+            Err(e) => {
+                self.notify_error(format!("SSO sessions: {}", e));
+            }
+        }
+    }
+
+    /// Show the audit log of write operations (`:audit`)
+    pub fn enter_audit_mode(&mut self) {
+        match crate::audit::read_entries() {
+            Ok(entries) => {
+                self.audit_entries = entries;
+                self.audit_selected = 0;
+                self.mode = Mode::Audit;
+            }
+            Err(e) => {
+                self.notify_error(format!("Audit log: {}", e));
+            }
+        }
+    }
+
+    /// Delete the cached tokens of an SSO session (`:sso logout [session]`).
+    /// Defaults to the current profile's session.
+    pub fn sso_logout(&mut self, session: Option<&str>) {
+        let session = match session {
+            Some(session) => session.to_string(),
+            None => match aws::sso::get_sso_config(&self.profile) {
+                Some(config) => config.sso_session,
+                None => {
+                    self.notify_error(format!("Profile '{}' does not use SSO", self.profile));
+                    return;
+                }
+            },
+        };
+        match aws::sso::logout(&session) {
+            Ok(0) => {
+                self.notify_error(format!("No cached SSO token for '{}'", session));
+            }
+            Ok(_) => {
+                aws::credentials::clear_sso_cache();
+                self.show_warning(&format!("Logged out of SSO session '{}'", session));
+            }
+            Err(e) => {
+                self.notify_error(format!("SSO logout failed: {}", e));
+            }
+        }
+    }
+
+    /// Delete the selected cached SSO token
+    pub fn delete_selected_sso_session(&mut self) {
+        let Some(session) = self.sso_sessions.get(self.sso_sessions_selected) else {
+            return;
+        };
+        if let Err(e) = std::fs::remove_file(&session.path) {
+            self.notify_error(format!("SSO logout failed: {}", e));
+            return;
+        }
+        aws::credentials::clear_sso_cache();
+        self.sso_sessions.remove(self.sso_sessions_selected);
+        self.sso_sessions_selected = self.sso_sessions_selected.min(self.sso_sessions.len().saturating_sub(1));
+    }
+
+    /// Fetch monthly spend from Cost Explorer and show it.
+    /// `args` are the `:costs` arguments, e.g. `["12", "tag:Team"]`.
+    pub async fn enter_costs_mode(&mut self, args: &[&str]) {
+        let (months, group_by) = match aws::costs::parse_costs_args(args) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.notify_error(e.to_string());
+                return;
+            }
+        };
+
+        self.loading = true;
+        let result = aws::costs::fetch_cost_report(&self.clients, months, &group_by).await;
+        self.loading = false;
+
+        match result {
+            Ok(report) => {
+                self.costs = Some(report);
+                self.costs_selected = 0;
+                        self.mode = Mode::Costs;
+            }
+            Err(e) => {
+                self.notify_aws_error("Cost Explorer", &e);
+            }
+        }
+    }
+
+    /// Tagging API and id for the selected item: the resource's `tagging`
+    /// definition, or the Resource Groups Tagging API when the item has an ARN
+    fn tag_target(&self) -> Option<(String, String)> {
+        let item = self.selected_item()?;
+        let resource = self.current_resource()?;
+        if let Some(tagging) = &resource.tagging {
+            let id = extract_json_value(item, &tagging.field);
+            return (id != "-" && !id.is_empty()).then(|| (tagging.api.clone(), id));
+        }
+        let id = extract_json_value(item, &resource.id_field);
+        if id.starts_with("arn:") {
+            return Some(("rgta".to_string(), id));
+        }
+        item.as_object()?
+            .values()
+            .filter_map(|v| v.as_str())
+            .find(|v| v.starts_with("arn:"))
+            .map(|arn| ("rgta".to_string(), arn.to_string()))
+    }
+
+    /// Open the tag editor for the selected resource
+    pub async fn enter_tags_mode(&mut self) {
+        let Some((api, id)) = self.tag_target() else {
+            self.notify_error("This resource does not support tags");
+            return;
+        };
+        let title = match (self.selected_item(), self.current_resource()) {
+            (Some(item), Some(resource)) => extract_json_value(item, &resource.name_field),
+            _ => id.clone(),
+        };
+        let clients = self.selected_clients();
+
+        self.loading = true;
+        let result = crate::resource::get_resource_tags(&clients, &api, &id).await;
+        self.loading = false;
+
+        match result {
+            Ok(tags) => {
+                self.tags_state = Some(TagsState {
+                    clients,
+                    api,
+                    id,
+                    title,
+                    tags,
+                    selected: 0,
+                    editing: None,
+                    confirm_delete: false,
+                });
+                self.mode = Mode::Tags;
+            }
+            Err(e) => {
+                self.notify_aws_error("Tags", &e);
+            }
+        }
+    }
+
+    /// Start adding a tag (`original` None) or editing the selected one
+    pub fn start_tag_edit(&mut self, edit_selected: bool) {
+        if self.readonly {
+            self.notify_error("This operation is not supported in read-only mode");
+            return;
+        }
+        let Some(state) = self.tags_state.as_mut() else {
+            return;
+        };
+        state.editing = match state.tags.get(state.selected) {
+            Some((key, value)) if edit_selected => Some((Some(key.clone()), format!("{}={}", key, value))),
+            _ => Some((None, String::new())),
+        };
+    }
+
+    /// Apply the "key=value" being edited. Renaming a key removes the old tag.
+    pub async fn save_tag_edit(&mut self) {
+        let Some(state) = self.tags_state.as_mut() else {
+            return;
+        };
+        let Some((original, text)) = state.editing.take() else {
+            return;
+        };
+        let (key, value) = text.split_once('=').unwrap_or((text.as_str(), ""));
+        let (key, value) = (key.trim(), value.trim());
+        if key.is_empty() {
+            self.notify_error("Expected key=value");
+            return;
+        }
+
+        let state = state.clone();
+        let mut result = crate::resource::tag_resource(&state.clients, &state.api, &state.id, key, value).await;
+        self.audit(&self.profile, &state.clients, &format!("{}:tag_resource {}", state.api, key), &state.id, &result);
+        if let (Ok(()), Some(old)) = (&result, original.as_deref().filter(|old| *old != key)) {
+            result = crate::resource::untag_resource(&state.clients, &state.api, &state.id, old).await;
+            self.audit(&self.profile, &state.clients, &format!("{}:untag_resource {}", state.api, old), &state.id, &result);
+        }
+        if let Err(e) = result {
+            self.notify_aws_error("Tags", &e);
+        }
+        self.reload_tags(Some(key.to_string())).await;
+    }
+
+    /// Delete the selected tag (after the y/n confirmation)
+    pub async fn delete_selected_tag(&mut self) {
+        let Some(state) = self.tags_state.as_mut() else {
+            return;
+        };
+        state.confirm_delete = false;
+        let Some((key, _)) = state.tags.get(state.selected).cloned() else {
+            return;
+        };
+        if self.readonly {
+            self.notify_error("This operation is not supported in read-only mode");
+            return;
+        }
+
+        let state = state.clone();
+        let result = crate::resource::untag_resource(&state.clients, &state.api, &state.id, &key).await;
+        self.audit(&self.profile, &state.clients, &format!("{}:untag_resource {}", state.api, key), &state.id, &result);
+        if let Err(e) = result {
+            self.notify_aws_error("Tags", &e);
+        }
+        self.reload_tags(None).await;
+    }
+
+    /// Re-read tags after a change, keeping `select_key` selected if given
+    async fn reload_tags(&mut self, select_key: Option<String>) {
+        let Some(state) = self.tags_state.clone() else {
+            return;
+        };
+        match crate::resource::get_resource_tags(&state.clients, &state.api, &state.id).await {
+            Ok(tags) => {
+                if let Some(state) = self.tags_state.as_mut() {
+                    state.selected = select_key
+                        .and_then(|k| tags.iter().position(|(key, _)| *key == k))
+                        .unwrap_or(state.selected)
+                        .min(tags.len().saturating_sub(1));
+                    state.tags = tags;
+                }
+            }
+            Err(e) => {
+                self.notify_aws_error("Tags", &e);
+            }
+        }
+    }
+
     pub fn enter_regions_mode(&mut self) {
         self.regions_selected = self
             .available_regions
@@ -858,7 +3418,15 @@ impl App {
     pub fn exit_mode(&mut self) {
         self.mode = Mode::Normal;
         self.pending_action = None;
+        self.input_prompt = None;
+        self.secret_dialog = None;
         self.describe_data = None;  // Clear describe data when exiting
+        self.describe_title = None;
+        self.clear_describe_search();
+        self.clear_describe_query();
+        self.tags_state = None;
+        self.column_picker = None;
+        self.palette = None;
     }
 
     // =========================================================================
@@ -867,96 +3435,298 @@ impl App {
 
     /// Navigate to a resource (top-level)
     pub async fn navigate_to_resource(&mut self, resource_key: &str) -> Result<()> {
+        self.navigate_to_filtered_resource(resource_key, Vec::new()).await
+    }
+
+    /// Navigate to a top-level resource listed with API filters, in one fetch
+    pub async fn navigate_to_filtered_resource(&mut self, resource_key: &str, filters: Vec<ResourceFilter>) -> Result<()> {
         if get_resource(resource_key).is_none() {
-            self.error_message = Some(format!("Unknown resource: {}", resource_key));
+            self.notify_error(format!("Unknown resource: {}", resource_key));
             return Ok(());
         }
         
-        // Clear parent context when navigating to top-level resource
-        self.parent_context = None;
-        self.navigation_stack.clear();
-        self.current_resource_key = resource_key.to_string();
+        self.begin_view_change();
+        self.set_top_level_view(resource_key);
+        self.view_filters = filters;
+        if self.load_view_change().await? {
+            self.record_recent();
+        }
+        Ok(())
+    }
+
+    /// Switch to a top-level resource view, ready to be loaded
+    fn set_top_level_view(&mut self, resource_key: &str) {
+        // Clear parent context when navigating to top-level resource
+        self.parent_context = None;
+        self.navigation_stack.clear();
+        self.view_filters.clear();
+        self.current_resource_key = resource_key.to_string();
+        self.selected = 0;
+        self.reset_filter();
+        self.mode = Mode::Normal;
+        
+        // Reset pagination for new resource
+        self.reset_pagination();
+    }
+
+    /// Navigate to sub-resource with parent context
+    pub async fn navigate_to_sub_resource(&mut self, sub_resource_key: &str) -> Result<()> {
+        let Some(selected_item) = self.selected_item().cloned() else {
+            return Ok(());
+        };
+        
+        let Some(current_resource) = self.current_resource() else {
+            return Ok(());
+        };
+        
+        // Verify this is a valid sub-resource
+        let is_valid = current_resource
+            .sub_resources
+            .iter()
+            .any(|s| s.resource_key == sub_resource_key);
+        
+        if !is_valid {
+            self.notify_error(format!(
+                "{} is not a sub-resource of {}",
+                sub_resource_key, self.current_resource_key
+            ));
+            return Ok(());
+        }
+        
+        // Special handling for S3 folder navigation
+        // Only allow navigating into folders, not files
+        if self.current_resource_key == "s3-objects" && sub_resource_key == "s3-objects" {
+            let is_folder = selected_item.get("IsFolder")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            
+            if !is_folder {
+                // Don't navigate into files - could show a message or do nothing
+                return Ok(());
+            }
+        }
+        
+        // Get display name for parent
+        let display_name = extract_json_value(&selected_item, &current_resource.name_field);
+        let id = extract_json_value(&selected_item, &current_resource.id_field);
+        let display = if display_name != "-" { display_name } else { id };
+        
+        self.begin_view_change();
+        
+        // Push current context to stack
+        if let Some(ctx) = self.parent_context.take() {
+            self.navigation_stack.push(ctx);
+        }
+        
+        // Set new parent context
+        self.parent_context = Some(ParentContext {
+            resource_key: self.current_resource_key.clone(),
+            item: selected_item,
+            display_name: display,
+        });
+        
+        // Navigate
+        self.current_resource_key = sub_resource_key.to_string();
+        self.selected = 0;
+        self.reset_filter();
+        
+        // Reset pagination for new resource
+        self.reset_pagination();
+        
+        if self.load_view_change().await? {
+            self.record_recent();
+        }
+        Ok(())
+    }
+
+    /// Jump from a CloudFormation stack resource to the taws view that lists it,
+    /// filtered to its physical ID. Returns false if the type has no matching view.
+    pub async fn jump_to_stack_resource(&mut self) -> Result<bool> {
+        let Some(selected_item) = self.selected_item().cloned() else {
+            return Ok(false);
+        };
+        
+        let resource_type = extract_json_value(&selected_item, "ResourceType");
+        let physical_id = extract_json_value(&selected_item, "PhysicalResourceId");
+        if physical_id == "-" || physical_id.is_empty() {
+            return Ok(false);
+        }
+        let Some(target_key) = crate::resource::get_resource_key_by_cfn_type(&resource_type) else {
+            return Ok(false);
+        };
+        
+        let display = extract_json_value(&selected_item, "LogicalResourceId");
+        
+        self.begin_view_change();
+        
+        // Keep the stack resources view on the stack so Backspace returns to it
+        if let Some(ctx) = self.parent_context.take() {
+            self.navigation_stack.push(ctx);
+        }
+        self.parent_context = Some(ParentContext {
+            resource_key: self.current_resource_key.clone(),
+            item: selected_item,
+            display_name: display,
+        });
+        
+        self.current_resource_key = target_key.to_string();
+        self.selected = 0;
+        self.filter_text = physical_id;
+        self.filter_active = false;
+        
+        self.reset_pagination();
+        
+        self.load_view_change().await?;
+        Ok(true)
+    }
+
+    /// Jump from a row carrying an ARN (e.g. a `:find` result) to the taws view
+    /// that lists the resource, filtered to it. Returns false if there is none.
+    pub async fn jump_to_arn(&mut self, arn_field: &str) -> Result<bool> {
+        let Some(selected_item) = self.selected_item().cloned() else {
+            return Ok(false);
+        };
+        let arn = extract_json_value(&selected_item, arn_field);
+        let Some(target) = crate::resource::resource_for_arn(&arn) else {
+            return Ok(false);
+        };
+        
+        // Regional resources open in their own region
+        let is_global = get_resource(target.resource_key).is_none_or(|r| r.is_global);
+        self.begin_view_change();
+        if !is_global && !self.all_regions && !target.region.is_empty() && target.region != self.region
+            && let Err(e) = self.switch_region(&target.region).await
+        {
+            self.abandon_view_change();
+            return Err(e);
+        }
+        
+        let display = self.current_resource()
+            .map(|r| extract_json_value(&selected_item, &r.name_field))
+            .unwrap_or_else(|| target.name.clone());
+        
+        // Keep the search results on the stack so Backspace returns to them
+        if let Some(ctx) = self.parent_context.take() {
+            self.navigation_stack.push(ctx);
+        }
+        self.parent_context = Some(ParentContext {
+            resource_key: self.current_resource_key.clone(),
+            item: selected_item,
+            display_name: display,
+        });
+        
+        self.current_resource_key = target.resource_key.to_string();
         self.selected = 0;
-        self.filter_text.clear();
+        self.filter_text = target.name;
         self.filter_active = false;
-        self.mode = Mode::Normal;
         
-        // Reset pagination for new resource
         self.reset_pagination();
         
-        self.refresh_current().await?;
-        Ok(())
+        self.load_view_change().await?;
+        Ok(true)
     }
 
-    /// Navigate to sub-resource with parent context
-    pub async fn navigate_to_sub_resource(&mut self, sub_resource_key: &str) -> Result<()> {
-        let Some(selected_item) = self.selected_item().cloned() else {
-            return Ok(());
-        };
-        
-        let Some(current_resource) = self.current_resource() else {
+    /// Open the view listing an ARN or EC2 id (`:goto`), switching region if
+    /// needed, and describe the item
+    pub async fn goto(&mut self, target: &str) -> Result<()> {
+        let Some(target) = crate::resource::resource_for_arn(target).or_else(|| crate::resource::resource_for_id(target)) else {
+            self.notify_error(format!("No view for {}", target));
             return Ok(());
         };
         
-        // Verify this is a valid sub-resource
-        let is_valid = current_resource
-            .sub_resources
-            .iter()
-            .any(|s| s.resource_key == sub_resource_key);
-        
-        if !is_valid {
-            self.error_message = Some(format!(
-                "{} is not a sub-resource of {}",
-                sub_resource_key, self.current_resource_key
-            ));
+        // Regional resources open in their own region
+        let is_global = get_resource(target.resource_key).is_none_or(|r| r.is_global);
+        self.begin_view_change();
+        if !is_global && !self.all_regions && !target.region.is_empty() && target.region != self.region
+            && let Err(e) = self.switch_region(&target.region).await
+        {
+            self.abandon_view_change();
+            return Err(e);
+        }
+        self.set_top_level_view(target.resource_key);
+        if !self.load_view_change().await? {
             return Ok(());
         }
+        self.record_recent();
         
-        // Special handling for S3 folder navigation
-        // Only allow navigating into folders, not files
-        if self.current_resource_key == "s3-objects" && sub_resource_key == "s3-objects" {
-            let is_folder = selected_item.get("IsFolder")
-                .and_then(|v| v.as_bool())
-                .unwrap_or(false);
-            
-            if !is_folder {
-                // Don't navigate into files - could show a message or do nothing
-                return Ok(());
+        let Some(resource) = self.current_resource() else {
+            return Ok(());
+        };
+        let (id_field, name_field) = (resource.id_field.clone(), resource.name_field.clone());
+        let position = self.filtered_items().position(|item| {
+            let id = extract_json_value(item, &id_field);
+            // ARN and URL ids end with the name taken from the ARN
+            id == target.name || id.ends_with(&format!("/{}", target.name)) || id.ends_with(&format!(":{}", target.name))
+                || extract_json_value(item, &name_field) == target.name
+        });
+        match position {
+            Some(index) => {
+                self.selected = index;
+                self.enter_describe_mode().await;
+            }
+            // Not on the first page or hidden by a saved filter: filter to it instead
+            None => {
+                self.filter_text = target.name;
+                self.filter_active = false;
+                self.apply_filter();
             }
         }
+        Ok(())
+    }
+
+    /// Run an Athena query and open its results. `query_context` is
+    /// `workgroup/database` as carried by the Athena list views.
+    pub async fn run_athena_query(&mut self, query_context: &str, query: &str) {
+        let (work_group, database) = query_context.split_once('/').unwrap_or((query_context, ""));
+        let clients = self.selected_clients();
+        let execution_id = match crate::resource::start_query_execution(&clients, work_group, database, query).await {
+            Ok(id) => {
+                self.audit(&self.profile, &clients, "athena:start_query_execution", &id, &Ok(()));
+                id
+            }
+            Err(e) => {
+                let result = Err(e);
+                self.audit(&self.profile, &clients, "athena:start_query_execution", query, &result);
+                if let Err(e) = result {
+                    self.notify_aws_error("Query failed", &e);
+                }
+                return;
+            }
+        };
         
-        // Get display name for parent
-        let display_name = extract_json_value(&selected_item, &current_resource.name_field);
-        let id = extract_json_value(&selected_item, &current_resource.id_field);
-        let display = if display_name != "-" { display_name } else { id };
+        self.begin_view_change();
         
-        // Push current context to stack
+        // Results hang off a query history entry, so Backspace returns to the history
         if let Some(ctx) = self.parent_context.take() {
             self.navigation_stack.push(ctx);
         }
-        
-        // Set new parent context
         self.parent_context = Some(ParentContext {
-            resource_key: self.current_resource_key.clone(),
-            item: selected_item,
-            display_name: display,
+            resource_key: "athena-query-history".to_string(),
+            item: serde_json::json!({
+                "QueryExecutionId": execution_id,
+                "Query": query,
+                "WorkGroup": work_group,
+                "Database": database,
+            }),
+            display_name: execution_id.clone(),
         });
         
-        // Navigate
-        self.current_resource_key = sub_resource_key.to_string();
+        self.current_resource_key = "athena-query-results".to_string();
         self.selected = 0;
         self.filter_text.clear();
         self.filter_active = false;
+        self.mode = Mode::Normal;
         
-        // Reset pagination for new resource
         self.reset_pagination();
         
-        self.refresh_current().await?;
-        Ok(())
+        let _ = self.load_view_change().await;
     }
 
     /// Navigate back to parent resource
     pub async fn navigate_back(&mut self) -> Result<()> {
+        if self.parent_context.is_some() {
+            self.begin_view_change();
+        }
         if let Some(parent) = self.parent_context.take() {
             // Pop from navigation stack if available
             self.parent_context = self.navigation_stack.pop();
@@ -964,13 +3734,14 @@ impl App {
             // Navigate to parent resource
             self.current_resource_key = parent.resource_key;
             self.selected = 0;
-            self.filter_text.clear();
-            self.filter_active = false;
+            self.reset_filter();
             
             // Reset pagination for parent resource
             self.reset_pagination();
             
-            self.refresh_current().await?;
+            if self.load_view_change().await? {
+                self.record_recent();
+            }
         }
         Ok(())
     }
@@ -998,6 +3769,8 @@ impl App {
     // =========================================================================
 
     pub async fn switch_region(&mut self, region: &str) -> Result<()> {
+        // Picking a single region leaves all-regions mode
+        self.all_regions = false;
         let actual_region = self.clients.switch_region(&self.profile, region).await?;
         self.region = actual_region.clone();
         
@@ -1012,6 +3785,7 @@ impl App {
         self.clients = new_clients;
         self.profile = profile.to_string();
         self.region = actual_region.clone();
+        self.update_readonly();
         
         // Save to config (ignore errors - don't fail profile switch if config save fails)
         let _ = self.config.set_profile(profile);
@@ -1020,6 +3794,50 @@ impl App {
         Ok(())
     }
     
+    /// Re-apply read-only mode after a profile switch: on with --readonly or
+    /// when the profile is listed in readonly_profiles
+    fn update_readonly(&mut self) {
+        self.readonly = self.readonly_flag || self.config.is_readonly_profile(&self.profile);
+    }
+    
+    /// Time left before the current credentials expire (None for long-lived keys)
+    pub fn credentials_remaining(&self) -> Option<chrono::TimeDelta> {
+        self.clients.http.credentials_expiration().map(|exp| exp - chrono::Utc::now())
+    }
+
+    /// Reload the profile's credentials after they expired, re-assuming any
+    /// role hop. Enters the SSO login flow when the SSO session itself has
+    /// ended. Returns true if new credentials are in place.
+    pub async fn renew_credentials(&mut self) -> bool {
+        use crate::aws::client::ClientResult;
+
+        let assumed_role = self.clients.assumed_role.clone();
+        match AwsClients::new_with_sso_check(&self.profile, &self.region, self.endpoint_url.clone()).await {
+            Ok(ClientResult::Ok(mut clients, _)) => {
+                if let Some(role_arn) = &assumed_role
+                    && let Err(e) = clients.assume_role(role_arn).await
+                {
+                    self.notify_aws_error("Assume role failed", &e);
+                    return false;
+                }
+                self.clients = clients;
+                true
+            }
+            Ok(ClientResult::SsoLoginRequired { profile, sso_session, .. }) => {
+                self.enter_sso_login_mode(&profile, &sso_session);
+                false
+            }
+            Ok(ClientResult::MfaRequired { profile, mfa_serial }) => {
+                self.enter_mfa_mode(&profile, &mfa_serial);
+                false
+            }
+            Err(e) => {
+                self.notify_aws_error("", &e);
+                false
+            }
+        }
+    }
+    
     /// Switch profile with SSO check - returns SsoRequired if SSO login is needed
     pub async fn switch_profile_with_sso_check(&mut self, profile: &str) -> Result<ProfileSwitchResult> {
         use crate::aws::client::ClientResult;
@@ -1029,6 +3847,7 @@ impl App {
                 self.clients = new_clients;
                 self.profile = profile.to_string();
                 self.region = actual_region.clone();
+                self.update_readonly();
                 
                 // Save to config
                 let _ = self.config.set_profile(profile);
@@ -1039,16 +3858,44 @@ impl App {
             ClientResult::SsoLoginRequired { profile, sso_session, .. } => {
                 Ok(ProfileSwitchResult::SsoRequired { profile, sso_session })
             }
+            ClientResult::MfaRequired { profile, mfa_serial } => {
+                Ok(ProfileSwitchResult::MfaRequired { profile, mfa_serial })
+            }
+        }
+    }
+
+    /// Assume `role_name` in an Organizations member account and switch all
+    /// requests to it, like a profile switch
+    pub async fn assume_org_role(&mut self, account_id: &str, role_name: &str) {
+        // Keep the partition of the management account (aws, aws-cn, aws-us-gov)
+        let partition = self.selected_item()
+            .map(|item| extract_json_value(item, "Arn"))
+            .and_then(|arn| arn.split(':').nth(1).map(String::from))
+            .filter(|p| p.starts_with("aws"))
+            .unwrap_or_else(|| "aws".to_string());
+        let role_arn = format!("arn:{}:iam::{}:role/{}", partition, account_id, role_name);
+        
+        if let Err(e) = self.clients.assume_role(&role_arn).await {
+            self.notify_aws_error("Assume role failed", &e);
+            return;
         }
+        
+        // The member account usually can't list the organization, so start from the default view
+        let _ = self.navigate_to_resource("ec2-instances").await;
     }
 
     /// Select profile - returns true if SSO login is required
     pub async fn select_profile(&mut self) -> Result<bool> {
         if let Some(profile) = self.available_profiles.get(self.profiles_selected) {
             let profile = profile.clone();
-            match self.switch_profile_with_sso_check(&profile).await? {
+            self.begin_view_change();
+            let switched = self.switch_profile_with_sso_check(&profile).await;
+            if !matches!(switched, Ok(ProfileSwitchResult::Success)) {
+                self.abandon_view_change();
+            }
+            match switched? {
                 ProfileSwitchResult::Success => {
-                    self.refresh_current().await?;
+                    self.load_view_change().await?;
                     self.exit_mode();
                     Ok(false)
                 }
@@ -1057,6 +3904,10 @@ impl App {
                     self.enter_sso_login_mode(&profile, &sso_session);
                     Ok(true)
                 }
+                ProfileSwitchResult::MfaRequired { profile, mfa_serial } => {
+                    self.enter_mfa_mode(&profile, &mfa_serial);
+                    Ok(true)
+                }
             }
         } else {
             self.exit_mode();
@@ -1067,8 +3918,12 @@ impl App {
     pub async fn select_region(&mut self) -> Result<()> {
         if let Some(region) = self.available_regions.get(self.regions_selected) {
             let region = region.clone();
-            self.switch_region(&region).await?;
-            self.refresh_current().await?;
+            self.begin_view_change();
+            if let Err(e) = self.switch_region(&region).await {
+                self.abandon_view_change();
+                return Err(e);
+            }
+            self.load_view_change().await?;
         }
         self.exit_mode();
         Ok(())
@@ -1093,11 +3948,20 @@ impl App {
             self.command_text.clone()
         };
         
+        // A pasted ARN or EC2 id goes straight to its item
+        let typed = self.command_text.trim();
+        let command_text = if crate::resource::resource_for_arn(typed).is_some() || crate::resource::resource_for_id(typed).is_some() {
+            format!("goto {}", typed)
+        } else {
+            command_text
+        };
+        
         let parts: Vec<&str> = command_text.split_whitespace().collect();
         
         if parts.is_empty() {
             return Ok(false);
         }
+        crate::history::append(&mut self.command_history, &command_text);
 
         let cmd = parts[0];
 
@@ -1109,16 +3973,85 @@ impl App {
             "profiles" => {
                 self.enter_profiles_mode();
             }
+            "regions" if parts.get(1) == Some(&"all") => {
+                self.begin_view_change();
+                self.all_regions = !self.all_regions;
+                self.reset_pagination();
+                self.load_view_change().await?;
+            }
             "regions" => {
                 self.enter_regions_mode();
             }
+            "tunnels" => {
+                self.enter_tunnels_mode();
+            }
+            "costs" => {
+                self.enter_costs_mode(&parts[1..]).await;
+            }
+            "find" => {
+                match crate::resource::parse_find_args(&parts[1..]) {
+                    Ok(filters) => self.navigate_to_filtered_resource("tagged-resources", filters).await?,
+                    Err(e) => self.notify_error(e.to_string()),
+                }
+            }
+            "search" if parts.len() > 1 => {
+                let query = parts[1..].join(" ");
+                let filters = vec![ResourceFilter::new(crate::resource::SEARCH_QUERY_PARAM, vec![query])];
+                self.navigate_to_filtered_resource("search-results", filters).await?;
+            }
+            "search" => {
+                self.notify_error("Usage: search <query>");
+            }
+            "sso" => match parts.get(1).copied() {
+                Some("sessions") => self.enter_sso_sessions_mode(),
+                Some("logout") => self.sso_logout(parts.get(2).copied()),
+                _ => self.notify_error("Usage: sso sessions | sso logout [session]"),
+            },
+            "audit" => self.enter_audit_mode(),
+            "notifications" => self.enter_notifications_mode(),
+            "error" => self.show_latest_error(),
+            "api" => self.enter_api_calls_mode(),
+            "metrics" => {
+                let enabled = !self.config.utilization_metrics;
+                if let Err(e) = self.config.set_utilization_metrics(enabled) {
+                    self.notify_error(format!("Failed to save config: {}", e));
+                }
+                let state = if enabled { "on (one CloudWatch call per EC2/RDS list fetch)" } else { "off" };
+                self.notify(Level::Info, format!("Utilization columns {}", state));
+                let _ = self.refresh_current().await;
+            }
+            "keybar" => {
+                let enabled = !self.config.key_bar;
+                if let Err(e) = self.config.set_key_bar(enabled) {
+                    self.notify_error(format!("Failed to save config: {}", e));
+                }
+            }
+            "columns" => self.enter_column_picker(),
+            "filters" => self.enter_filters_mode(),
+            "filter-save" if parts.len() > 1 => self.save_current_filter(&parts[1..].join(" ")),
+            "filter-save" => {
+                self.notify_error("Usage: filter-save <name>");
+            }
+            "bookmark" => self.toggle_bookmark(false),
+            "goto" if parts.len() > 1 => self.goto(parts[1]).await?,
+            "goto" => {
+                self.notify_error("Usage: goto <arn-or-id>");
+            }
             "region" if parts.len() > 1 => {
-                self.switch_region(parts[1]).await?;
-                self.refresh_current().await?;
+                self.begin_view_change();
+                if let Err(e) = self.switch_region(parts[1]).await {
+                    self.abandon_view_change();
+                    return Err(e);
+                }
+                self.load_view_change().await?;
             }
             "profile" if parts.len() > 1 => {
-                self.switch_profile(parts[1]).await?;
-                self.refresh_current().await?;
+                self.begin_view_change();
+                if let Err(e) = self.switch_profile(parts[1]).await {
+                    self.abandon_view_change();
+                    return Err(e);
+                }
+                self.load_view_change().await?;
             }
             _ => {
                 // Check if it's a known resource
@@ -1135,7 +4068,7 @@ impl App {
                         self.navigate_to_resource(cmd).await?;
                     }
                 } else {
-                    self.error_message = Some(format!("Unknown command: {}", cmd));
+                    self.notify_error(format!("Unknown command: {}", cmd));
                 }
             }
         }
@@ -1143,13 +4076,126 @@ impl App {
         Ok(false)
     }
 
+    /// Start an SSM session on an instance; the shell runs once the TUI is suspended
+    pub async fn start_ssm_session(&mut self, target: &str) {
+        match aws::ssm_session::start_session(&self.selected_clients(), target, None).await {
+            Ok(args) => {
+                self.external_command = Some(ExternalCommand {
+                    program: aws::ssm_session::PLUGIN.to_string(),
+                    args,
+                    banner: format!("Starting SSM session on {} (type 'exit' to return to taws)", target),
+                    env: Vec::new(),
+                    wait: false,
+                });
+            }
+            Err(e) => {
+                self.notify_aws_error("Start session failed", &e);
+            }
+        }
+    }
+
+    /// Start an SSM port forwarding session in the background.
+    /// `input` is `local[:remote]` for an instance, or `instance local[:remote]`
+    /// to reach a remote host (e.g. an RDS endpoint) through that instance.
+    pub async fn start_port_forward(&mut self, resource_id: &str, input: &str) {
+        let spec = match aws::ssm_session::parse_port_forward_spec(input) {
+            Ok(spec) => spec,
+            Err(e) => {
+                self.notify_error(e.to_string());
+                return;
+            }
+        };
+        
+        let local = spec.local_port.to_string();
+        let remote = spec.remote_port.to_string();
+        let (target, document, remote_display) = match &spec.via {
+            Some(instance) => (
+                instance.clone(),
+                ("AWS-StartPortForwardingSessionToRemoteHost", serde_json::json!({
+                    "host": [resource_id],
+                    "portNumber": [remote],
+                    "localPortNumber": [local],
+                })),
+                format!("{}:{}", resource_id, remote),
+            ),
+            None => (
+                resource_id.to_string(),
+                ("AWS-StartPortForwardingSession", serde_json::json!({
+                    "portNumber": [remote],
+                    "localPortNumber": [local],
+                })),
+                remote.clone(),
+            ),
+        };
+        
+        let args = match aws::ssm_session::start_session(&self.selected_clients(), &target, Some(document)).await {
+            Ok(args) => args,
+            Err(e) => {
+                self.notify_aws_error("Port forward failed", &e);
+                return;
+            }
+        };
+        
+        let child = tokio::process::Command::new(aws::ssm_session::PLUGIN)
+            .args(&args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .spawn();
+        match child {
+            Ok(child) => {
+                self.tunnels.push(Tunnel {
+                    target,
+                    remote: remote_display,
+                    local_port: spec.local_port,
+                    started: chrono::Local::now().format("%H:%M:%S").to_string(),
+                    child,
+                });
+                self.enter_tunnels_mode();
+                self.tunnels_selected = self.tunnels.len() - 1;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.notify_error(format!("{} not found in PATH", aws::ssm_session::PLUGIN));
+            }
+            Err(e) => {
+                self.notify_error(format!("Port forward failed: {}", e));
+            }
+        }
+    }
+    
+    /// Drop tunnels whose plugin process has exited
+    pub fn reap_tunnels(&mut self) {
+        let mut closed = Vec::new();
+        self.tunnels.retain_mut(|tunnel| match tunnel.child.try_wait() {
+            Ok(None) => true,
+            _ => {
+                closed.push(tunnel.local_port);
+                false
+            }
+        });
+        if let Some(port) = closed.first() {
+            self.notify(Level::Info, format!("Tunnel on localhost:{} closed", port));
+        }
+        self.tunnels_selected = self.tunnels_selected.min(self.tunnels.len().saturating_sub(1));
+    }
+    
+    /// Stop the selected tunnel
+    pub fn stop_selected_tunnel(&mut self) {
+        if self.tunnels_selected < self.tunnels.len() {
+            let mut tunnel = self.tunnels.remove(self.tunnels_selected);
+            let _ = tunnel.child.start_kill();
+            self.tunnels_selected = self.tunnels_selected.min(self.tunnels.len().saturating_sub(1));
+        }
+    }
+
     // =========================================================================
     // Log Tail Mode
     // =========================================================================
 
-    /// Enter log tail mode for the selected log stream
+    /// Enter log tail mode for the selected log stream, or for every stream
+    /// of the selected log group
     pub async fn enter_log_tail_mode(&mut self) -> Result<()> {
-        // Get the selected log stream item
         let Some(item) = self.selected_item().cloned() else {
             return Ok(());
         };
@@ -1158,87 +4204,126 @@ impl App {
         let log_group = extract_json_value(&item, "logGroupName");
         let log_stream = extract_json_value(&item, "logStreamName");
 
-        if log_group == "-" || log_stream == "-" {
-            self.error_message = Some("Could not get log group/stream name".to_string());
+        if log_group == "-" {
+            self.notify_error("Could not get log group name");
             return Ok(());
         }
+        let log_streams = if log_stream == "-" { Vec::new() } else { vec![log_stream] };
 
         // Initialize log tail state
         self.log_tail_state = Some(LogTailState {
-            log_group: log_group.clone(),
-            log_stream: log_stream.clone(),
+            log_group,
+            log_streams,
             events: Vec::new(),
             scroll: 0,
             next_forward_token: None,
+            since: None,
             auto_scroll: true,
             paused: false,
             last_poll: std::time::Instant::now(),
             error: None,
+            wrap: false,
+            json_popup: None,
+            json_popup_scroll: 0,
         });
 
         self.mode = Mode::LogTail;
 
-        // Fetch initial log events
+        // Recent events first, then new ones as Live Tail pushes them
         self.poll_log_events().await?;
+        self.start_live_tail().await;
 
         Ok(())
     }
 
-    /// Poll for new log events
-    pub async fn poll_log_events(&mut self) -> Result<()> {
-        let Some(ref mut state) = self.log_tail_state else {
-            return Ok(());
+    /// Switch the log tail to Live Tail; it keeps polling where Live Tail
+    /// isn't available (custom endpoints, replayed sessions, no access)
+    async fn start_live_tail(&mut self) {
+        let (Some(state), Some(identity)) = (&self.log_tail_state, &self.identity) else {
+            return;
         };
+        let arn = aws::live_tail::log_group_arn(&identity.arn, &self.clients.region, &identity.account, &state.log_group);
+        match aws::live_tail::LiveTail::start(&self.clients.http, &arn, &state.log_streams).await {
+            Ok(tail) => self.log_live_tail = Some(tail),
+            Err(e) => tracing::info!("Live Tail unavailable, polling instead: {}", e),
+        }
+    }
 
-        if state.paused {
+    /// Take in events pushed by Live Tail, or poll for new ones
+    pub async fn poll_log_events(&mut self) -> Result<()> {
+        if self.log_tail_state.as_ref().is_none_or(|state| state.paused) {
             return Ok(());
         }
-
-        // Build params for get_log_events
-        let mut params = serde_json::json!({
-            "log_group_name": [state.log_group.clone()],
-            "log_stream_name": [state.log_stream.clone()],
-        });
-
-        if let Some(ref token) = state.next_forward_token {
-            params["next_forward_token"] = serde_json::json!(token);
+        if self.log_live_tail.is_some() {
+            self.drain_live_tail().await;
+            return Ok(());
         }
+        let Some(ref mut state) = self.log_tail_state else {
+            return Ok(());
+        };
 
-        // Call the SDK
-        match crate::resource::sdk_dispatch::invoke_sdk(
-            "cloudwatchlogs",
-            "get_log_events",
-            &self.clients,
-            &params,
-        ).await {
+        let result = if let [log_stream] = state.log_streams.as_slice() {
+            let mut params = serde_json::json!({
+                "log_group_name": [state.log_group.clone()],
+                "log_stream_name": [log_stream.clone()],
+            });
+            if let Some(ref token) = state.next_forward_token {
+                params["next_forward_token"] = serde_json::json!(token);
+            } else if let Some(since) = state.since {
+                params["start_time"] = serde_json::json!(since);
+            }
+            crate::resource::sdk_dispatch::invoke_sdk("cloudwatchlogs", "get_log_events", &self.clients, &params).await
+        } else {
+            // Whole group: the last 10 minutes at first, then what came since
+            let since = state
+                .since
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() - 10 * 60 * 1000);
+            let mut params = serde_json::json!({
+                "log_group_name": [state.log_group.clone()],
+                "start_time": since,
+            });
+            if let Some(ref token) = state.next_forward_token {
+                params["next_token"] = serde_json::json!(token);
+            }
+            crate::resource::sdk_dispatch::invoke_sdk("cloudwatchlogs", "filter_log_events", &self.clients, &params).await
+        };
+
+        match result {
             Ok(response) => {
                 state.error = None;
-                
-                // Extract events
+                let single_stream = match state.log_streams.as_slice() {
+                    [stream] => Some(stream.clone()),
+                    _ => None,
+                };
+
                 if let Some(events) = response.get("events").and_then(|v| v.as_array()) {
                     for event in events {
                         let timestamp = event.get("timestamp").and_then(|v| v.as_i64()).unwrap_or(0);
                         let message = event.get("message").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                        
-                        state.events.push(LogEvent { timestamp, message });
-                    }
-                    
-                    // Keep only last 1000 events
-                    if state.events.len() > 1000 {
-                        let drain_count = state.events.len() - 1000;
-                        state.events.drain(0..drain_count);
+                        let stream = event
+                            .get("logStreamName")
+                            .and_then(|v| v.as_str())
+                            .map(String::from)
+                            .or_else(|| single_stream.clone())
+                            .unwrap_or_default();
+                        state.events.push(LogEvent { timestamp, message, stream });
                     }
+                    state.trim_events();
                 }
 
-                // Update next forward token
-                if let Some(token) = response.get("nextForwardToken").and_then(|v| v.as_str()) {
-                    state.next_forward_token = Some(token.to_string());
-                }
-
-                // Auto-scroll to bottom if enabled
-                if state.auto_scroll && !state.events.is_empty() {
-                    state.scroll = state.events.len().saturating_sub(1);
+                if single_stream.is_some() {
+                    if let Some(token) = response.get("nextForwardToken").and_then(|v| v.as_str()) {
+                        state.next_forward_token = Some(token.to_string());
+                    }
+                } else {
+                    // FilterLogEvents pages through the window before moving it on
+                    state.next_forward_token = response.get("nextToken").and_then(|v| v.as_str()).map(String::from);
+                    if state.next_forward_token.is_none() {
+                        let last = state.events.last().map_or(0, |e| e.timestamp + 1);
+                        state.since = Some(last.max(state.since.unwrap_or(0)));
+                    }
                 }
+                state.follow();
             }
             Err(e) => {
                 state.error = Some(format!("Failed to fetch logs: {}", e));
@@ -1249,6 +4334,45 @@ impl App {
         Ok(())
     }
 
+    /// Add the events Live Tail pushed since the last poll. A session that
+    /// reached its time limit is restarted; one that failed falls back to polling.
+    async fn drain_live_tail(&mut self) {
+        let (Some(tail), Some(state)) = (&mut self.log_live_tail, &mut self.log_tail_state) else {
+            return;
+        };
+        let mut closed = None;
+        for update in tail.drain() {
+            match update {
+                aws::live_tail::Update::Events(events) => {
+                    state.events.extend(events.into_iter().map(|event| LogEvent {
+                        timestamp: event.timestamp,
+                        message: event.message,
+                        stream: event.stream,
+                    }));
+                }
+                aws::live_tail::Update::Closed(reason) => {
+                    closed = Some(reason);
+                    break;
+                }
+            }
+        }
+        state.trim_events();
+        state.follow();
+        state.last_poll = std::time::Instant::now();
+
+        let Some(reason) = closed else {
+            return;
+        };
+        // Polling picks up after the last event shown
+        state.next_forward_token = None;
+        state.since = state.events.last().map(|e| e.timestamp + 1);
+        self.log_live_tail = None;
+        match reason {
+            None => self.start_live_tail().await,
+            Some(e) => state.error = Some(format!("Live Tail stopped, polling instead: {}", e)),
+        }
+    }
+
     /// Toggle pause state for log tailing
     pub fn toggle_log_tail_pause(&mut self) {
         if let Some(ref mut state) = self.log_tail_state {
@@ -1256,6 +4380,46 @@ impl App {
         }
     }
 
+    /// Wrap long log lines, or cut them at the pane edge again
+    pub fn toggle_log_tail_wrap(&mut self) {
+        if let Some(ref mut state) = self.log_tail_state {
+            state.wrap = !state.wrap;
+        }
+    }
+
+    /// Show the selected event's message as pretty-printed JSON
+    pub fn expand_log_event(&mut self) {
+        let Some(ref mut state) = self.log_tail_state else {
+            return;
+        };
+        let Some(event) = state.events.get(state.scroll) else {
+            return;
+        };
+        match log_message_json(&event.message) {
+            Some(json) => {
+                state.json_popup = Some(json);
+                state.json_popup_scroll = 0;
+            }
+            None => self.notify(Level::Info, "Log line is not JSON"),
+        }
+    }
+
+    /// Scroll the JSON popup by `delta` lines
+    pub fn log_json_popup_scroll(&mut self, delta: isize) {
+        if let Some(ref mut state) = self.log_tail_state
+            && let Some(ref json) = state.json_popup
+        {
+            let max_scroll = json.lines().count().saturating_sub(1);
+            state.json_popup_scroll = state.json_popup_scroll.saturating_add_signed(delta).min(max_scroll);
+        }
+    }
+
+    pub fn close_log_json_popup(&mut self) {
+        if let Some(ref mut state) = self.log_tail_state {
+            state.json_popup = None;
+        }
+    }
+
     /// Scroll log tail view up
     pub fn log_tail_scroll_up(&mut self, amount: usize) {
         if let Some(ref mut state) = self.log_tail_state {
@@ -1290,7 +4454,231 @@ impl App {
 
     /// Exit log tail mode
     pub fn exit_log_tail_mode(&mut self) {
+        self.log_live_tail = None;
         self.log_tail_state = None;
         self.mode = Mode::Normal;
     }
 }
+
+/// Keep the columns named in `layout` (by header), in its order. Widths are
+/// scaled so the shown columns fill the table. Falls back to all columns if
+/// the layout names none of them.
+fn apply_column_layout(columns: Vec<ColumnDef>, layout: &[String]) -> Vec<ColumnDef> {
+    let total: u16 = columns.iter().map(|col| col.width).sum();
+    let mut shown: Vec<ColumnDef> = layout
+        .iter()
+        .filter_map(|header| columns.iter().find(|col| col.header.eq_ignore_ascii_case(header)).cloned())
+        .collect();
+    if shown.is_empty() {
+        return columns;
+    }
+    let shown_total: u16 = shown.iter().map(|col| col.width).sum();
+    for col in &mut shown {
+        col.width = (col.width * total).checked_div(shown_total).unwrap_or(col.width);
+    }
+    shown
+}
+
+/// A log message as pretty-printed JSON, if it is a JSON object or array,
+/// possibly after a prefix such as Lambda's "<time> <request id> INFO "
+fn log_message_json(message: &str) -> Option<String> {
+    let message = message.trim();
+    let start = message.find(['{', '['])?;
+    match serde_json::from_str::<Value>(&message[start..]).ok()? {
+        value @ (Value::Object(_) | Value::Array(_)) => serde_json::to_string_pretty(&value).ok(),
+        _ => None,
+    }
+}
+
+/// Split an `exec` template into a program and its arguments the way a
+/// POSIX shell splits words (whitespace, single and double quotes,
+/// backslashes), then `fill` the placeholders of each word. A filled-in value
+/// always stays within its word, whatever characters it contains.
+fn exec_argv(template: &str, mut fill: impl FnMut(&mut String)) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '\'' => {
+                let word = word.get_or_insert_default();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(anyhow::anyhow!("Unterminated ' in exec command")),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_default();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => word.push(c),
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err(anyhow::anyhow!("Unterminated \" in exec command")),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err(anyhow::anyhow!("Unterminated \" in exec command")),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_default().extend(chars.next()),
+            c => word.get_or_insert_default().push(c),
+        }
+    }
+    words.extend(word);
+    for word in &mut words {
+        fill(word);
+    }
+    Ok(words)
+}
+
+/// A recorded request or response body as a browsable document: parsed JSON
+/// or XML, otherwise the raw text
+fn body_document(body: &str) -> Value {
+    if body.is_empty() {
+        return Value::Null;
+    }
+    if let Ok(value) = serde_json::from_str::<Value>(body) {
+        return value;
+    }
+    if body.starts_with('<')
+        && let Ok(value) = aws::http::xml_to_json(body)
+    {
+        return value;
+    }
+    Value::String(body.to_string())
+}
+
+/// Build evenly sized columns from response-supplied column names
+fn dynamic_columns(names: &[String]) -> Vec<ColumnDef> {
+    let width = (100 / names.len().max(1) as u16).max(5);
+    names
+        .iter()
+        .map(|name| ColumnDef {
+            header: name.to_uppercase(),
+            json_path: name.clone(),
+            width,
+            color_map: None,
+            color_field: None,
+            relative_time: false,
+        })
+        .collect()
+}
+
+/// Watch the terminal while a load runs and cancel it when Esc is pressed.
+/// Other keys typed meanwhile are kept for the main loop to handle after.
+fn cancel_on_escape(cancel: &CancellationToken, typed_ahead: &Mutex<VecDeque<crossterm::event::KeyEvent>>) {
+    use crossterm::event::{poll, read, Event};
+    while !cancel.is_cancelled() {
+        match poll(std::time::Duration::from_millis(50)) {
+            Ok(true) => match read() {
+                Ok(Event::Key(key)) if key.code == KeyCode::Esc => cancel.cancel(),
+                Ok(Event::Key(key)) => {
+                    if let Ok(mut typed_ahead) = typed_ahead.lock() {
+                        typed_ahead.push_back(key);
+                    }
+                }
+                Ok(_) => {}
+                Err(_) => return,
+            },
+            Ok(false) => {}
+            Err(_) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        App::from_initialized(
+            AwsClients::offline("test", "us-east-1"),
+            "test".to_string(),
+            "us-east-1".to_string(),
+            vec!["test".to_string()],
+            vec!["us-east-1".to_string()],
+            Vec::new(),
+            Config::default(),
+            false,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exec_action_values_stay_single_arguments() {
+        let mut app = app();
+        let action: crate::resource::ActionDef = serde_json::from_value(serde_json::json!({
+            "key": "note", "display_name": "Note", "sdk_method": "note",
+            "exec": "echo 'note for' \"{id}\" {input}"
+        }))
+        .unwrap();
+        app.queue_exec_action(&action, "echo 'note for' \"{id}\" {input}", "i-1 $(id)", Some("x; rm -rf ~ `id` 'q'"));
+
+        let command = app.external_command.take().unwrap();
+        assert_eq!(command.program, "echo");
+        assert_eq!(command.args, ["note for", "i-1 $(id)", "x; rm -rf ~ `id` 'q'"]);
+
+        assert!(exec_argv("ssh 'unterminated", |_| {}).is_err());
+        assert_eq!(exec_argv(r#"a\ b "c\"d" e''"#, |_| {}).unwrap(), ["a b", "c\"d", "e"]);
+    }
+
+    #[tokio::test]
+    async fn test_split_describe_kept_only_for_highlighted_row() {
+        let mut app = app();
+        app.current_resource_key = "ec2-instances".to_string();
+        app.items = vec![serde_json::json!({ "InstanceId": "i-1" }), serde_json::json!({ "InstanceId": "i-2" })];
+        app.apply_filter();
+        app.split_view = true;
+
+        // A describe of i-1 finishes after the selection has moved on to i-2
+        let describe = |id: &str| {
+            let data = serde_json::json!({ "InstanceId": id, "Described": true });
+            let task = tokio::spawn(async move { Ok(data) });
+            (format!("ec2-instances/{}", id), task)
+        };
+        app.split_task = Some(describe("i-1"));
+        app.selected = 1;
+        while !app.split_task.as_ref().unwrap().1.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        app.update_split_describe().await;
+        assert!(app.split_describe_data().is_none());
+
+        app.split_task = Some(describe("i-2"));
+        while !app.split_task.as_ref().unwrap().1.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        app.update_split_describe().await;
+        assert_eq!(app.split_describe_data().unwrap()["InstanceId"], "i-2");
+        assert!(app.split_task.is_none());
+    }
+
+    #[test]
+    fn test_cancelled_region_switch_leaves_no_rows() {
+        let mut app = app();
+        app.items = vec![serde_json::json!({ "InstanceId": "i-east" })];
+        app.apply_filter();
+
+        // `:region eu-west-1` switches before its load; Esc during the load
+        app.begin_view_change();
+        app.clients.region = "eu-west-1".to_string();
+        app.region = "eu-west-1".to_string();
+        assert!(app.items.is_empty());
+        app.cancel_load();
+        assert_eq!((app.region.as_str(), app.clients.region.as_str()), ("us-east-1", "us-east-1"));
+        assert_eq!(app.filtered_items().count(), 1);
+
+        // A cancelled refresh has nothing to go back to, so its old rows go
+        app.cancel_load();
+        assert!(app.items.is_empty() && app.filtered_indices.is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! Audit trail for write operations and sensitive reads (e.g. revealing secret values)
+//!
+//! Entries are appended to `audit.log` next to the taws log file, one line
+//! per event, regardless of the configured log level. `:audit` shows them.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Path of the audit log file
+pub fn audit_log_path() -> PathBuf {
+    if let Some(config_dir) = dirs::config_dir() {
+        return config_dir.join("taws").join("audit.log");
+    }
+    if let Some(home) = dirs::home_dir() {
+        return home.join(".taws").join("audit.log");
+    }
+    PathBuf::from("audit.log")
+}
+
+/// Append an audit entry. Failures are logged but never interrupt the caller.
+pub fn record(profile: &str, region: &str, event: &str, target: &str, outcome: &str) {
+    let line = format!(
+        "{} profile={} region={} event=\"{}\" target=\"{}\" outcome=\"{}\"\n",
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+        profile,
+        region,
+        quote_safe(event),
+        quote_safe(target),
+        quote_safe(outcome)
+    );
+    tracing::info!(target: "audit", "{}", line.trim_end());
+
+    let path = audit_log_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    if let Err(e) = result {
+        tracing::warn!("Failed to write audit log {:?}: {}", path, e);
+    }
+}
+
+/// Keep values on one line and inside their quotes
+fn quote_safe(value: &str) -> String {
+    value.replace('"', "'").replace(['\n', '\r'], " ")
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub profile: String,
+    pub region: String,
+    pub event: String,
+    pub target: String,
+    /// "ok", "error: ..." (empty for entries written before outcomes were recorded)
+    pub outcome: String,
+}
+
+/// Read the audit log, newest entry first
+pub fn read_entries() -> std::io::Result<Vec<AuditEntry>> {
+    let content = match std::fs::read_to_string(audit_log_path()) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    Ok(content.lines().rev().filter_map(parse_line).collect())
+}
+
+/// Parse `<timestamp> key=value key="quoted value" ...`
+fn parse_line(line: &str) -> Option<AuditEntry> {
+    let (timestamp, mut rest) = line.split_once(' ')?;
+    let mut entry = AuditEntry {
+        timestamp: timestamp.to_string(),
+        ..Default::default()
+    };
+    while let Some((key, after)) = rest.trim_start().split_once('=') {
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => after.split_once(' ').unwrap_or((after, "")),
+        };
+        let field = match key {
+            "profile" => &mut entry.profile,
+            "region" => &mut entry.region,
+            "event" => &mut entry.event,
+            "target" => &mut entry.target,
+            "outcome" => &mut entry.outcome,
+            _ => {
+                rest = remaining;
+                continue;
+            }
+        };
+        *field = value.to_string();
+        rest = remaining;
+    }
+    Some(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let entry = parse_line(
+            r#"2025-01-02T03:04:05Z profile=prod region=eu-west-1 event="ec2:stop_instance" target="i-0abc" outcome="error: Access denied""#,
+        )
+        .unwrap();
+        assert_eq!(entry.timestamp, "2025-01-02T03:04:05Z");
+        assert_eq!(entry.profile, "prod");
+        assert_eq!(entry.region, "eu-west-1");
+        assert_eq!(entry.event, "ec2:stop_instance");
+        assert_eq!(entry.target, "i-0abc");
+        assert_eq!(entry.outcome, "error: Access denied");
+
+        // Entries written before targets were quoted and outcomes recorded
+        let old = parse_line(r#"2024-06-01T00:00:00Z profile=dev region=us-east-1 event="Secret Value" target=db-password"#).unwrap();
+        assert_eq!(old.event, "Secret Value");
+        assert_eq!(old.target, "db-password");
+        assert_eq!(old.outcome, "");
+    }
+}
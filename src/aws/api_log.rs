@@ -0,0 +1,410 @@
+//! Recent AWS API calls, for the API inspector (`:api`)
+//!
+//! Every signed request is recorded with its timing, status and raw bodies.
+//! Secrets (signatures, session tokens, secret values) are redacted before
+//! anything is kept: fields named like secrets in any call, plus the values
+//! returned by operations that reveal secrets (SSM parameters, actions with
+//! a `secret` result). Counts per refresh cycle feed the header's API readout.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Calls kept
+const MAX_CALLS: usize = 100;
+
+/// Bytes kept of each request and response body
+const MAX_BODY: usize = 64 * 1024;
+
+/// Header, JSON field and XML element names whose values are never kept
+const SECRET_NAMES: &[&str] = &[
+    "authorization",
+    "x-amz-security-token",
+    "SecretString",
+    "SecretBinary",
+    "SecretAccessKey",
+    "SessionToken",
+    "Password",
+    "MasterUserPassword",
+    "accessToken",
+    "clientSecret",
+    "X-Amz-Signature",
+    "X-Amz-Credential",
+    "set-cookie",
+];
+
+const REDACTED: &str = "<redacted>";
+
+/// Fields holding secrets only in the responses of some operations, e.g. the
+/// decrypted SecureString in SSM's `Parameter.Value`
+const SECRET_RESPONSE_FIELDS: &[(&str, &str)] = &[
+    ("GetParameter", "Value"),
+    ("GetParameters", "Value"),
+    ("GetParametersByPath", "Value"),
+    ("GetParameterHistory", "Value"),
+];
+
+/// Error codes AWS uses when it throttles a caller
+const THROTTLING_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottledException",
+    "RequestLimitExceeded",
+    "TooManyRequestsException",
+    "RequestThrottled",
+    "SlowDown",
+    "ProvisionedThroughputExceededException",
+];
+
+#[derive(Debug, Clone)]
+pub struct ApiCall {
+    /// Local time the call was made (display string)
+    pub time: String,
+    pub service: String,
+    /// e.g. "DescribeInstances", or "GET /2015-03-31/functions" for REST APIs
+    pub operation: String,
+    pub method: String,
+    pub url: String,
+    pub duration: Duration,
+    /// HTTP status, None when no response came back
+    pub status: Option<u16>,
+    /// Size of the response body
+    pub bytes: usize,
+    /// AWS rejected the call for exceeding a rate limit
+    pub throttled: bool,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: String,
+    pub response_body: String,
+}
+
+impl ApiCall {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        service: &str,
+        method: &str,
+        url: &str,
+        headers: &[(String, String)],
+        body: &str,
+        duration: Duration,
+        status: Option<u16>,
+        response: &str,
+    ) -> Self {
+        let operation = operation_name(method, url, headers, body);
+        Self {
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            service: service.to_string(),
+            method: method.to_string(),
+            url: redact_url(url),
+            duration,
+            status,
+            bytes: response.len(),
+            throttled: is_throttled(status, response),
+            request_headers: redact_headers(headers),
+            request_body: truncate(redact(&operation, body)),
+            response_body: truncate(redact(&operation, response)),
+            operation,
+        }
+    }
+
+    /// Whether AWS answered with an error status
+    pub fn failed(&self) -> bool {
+        self.status.is_none_or(|status| status >= 400)
+    }
+}
+
+/// Calls made during one refresh cycle
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CycleStats {
+    pub calls: usize,
+    pub throttled: usize,
+    total: Duration,
+}
+
+impl CycleStats {
+    const EMPTY: Self = Self { calls: 0, throttled: 0, total: Duration::ZERO };
+
+    fn add(&mut self, call: &ApiCall) {
+        self.calls += 1;
+        self.total += call.duration;
+        if call.throttled {
+            self.throttled += 1;
+        }
+    }
+
+    /// Average call duration
+    pub fn average(&self) -> Duration {
+        if self.calls == 0 { Duration::ZERO } else { self.total / self.calls as u32 }
+    }
+}
+
+static CALLS: OnceLock<Mutex<VecDeque<ApiCall>>> = OnceLock::new();
+
+/// (last finished cycle, current cycle)
+static CYCLES: Mutex<(Option<CycleStats>, CycleStats)> = Mutex::new((None, CycleStats::EMPTY));
+
+/// Keep a finished call
+pub fn record(call: ApiCall) {
+    if let Ok(mut cycles) = CYCLES.lock() {
+        cycles.1.add(&call);
+    }
+    let calls = CALLS.get_or_init(Default::default);
+    if let Ok(mut calls) = calls.lock() {
+        calls.push_back(call);
+        if calls.len() > MAX_CALLS {
+            calls.pop_front();
+        }
+    }
+}
+
+/// Recorded calls, newest first
+pub fn recent() -> Vec<ApiCall> {
+    CALLS
+        .get()
+        .and_then(|calls| calls.lock().ok().map(|calls| calls.iter().rev().cloned().collect()))
+        .unwrap_or_default()
+}
+
+/// Forget every recorded call
+pub fn clear() {
+    if let Some(calls) = CALLS.get()
+        && let Ok(mut calls) = calls.lock()
+    {
+        calls.clear();
+    }
+}
+
+/// A refresh is starting: close the current cycle
+pub fn start_cycle() {
+    if let Ok(mut cycles) = CYCLES.lock() {
+        let finished = std::mem::take(&mut cycles.1);
+        cycles.0 = Some(finished);
+    }
+}
+
+/// Calls of the last full refresh cycle (refresh to refresh), or of the
+/// current one before the first cycle has finished
+pub fn cycle_stats() -> CycleStats {
+    CYCLES
+        .lock()
+        .map(|cycles| cycles.0.unwrap_or(cycles.1))
+        .unwrap_or_default()
+}
+
+/// Operation of a request: the JSON protocol target, the query protocol
+/// Action, or the method and path of a REST request
+pub(super) fn operation_name(method: &str, url: &str, headers: &[(String, String)], body: &str) -> String {
+    if let Some((_, target)) = headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("x-amz-target")) {
+        return target.rsplit('.').next().unwrap_or(target).to_string();
+    }
+    if let Some(action) = body.split('&').find_map(|pair| pair.strip_prefix("Action=")) {
+        return action.to_string();
+    }
+    let path = url::Url::parse(url).map(|u| u.path().to_string()).unwrap_or_default();
+    format!("{} {}", method, path)
+}
+
+/// Whether a response is AWS throttling the caller
+fn is_throttled(status: Option<u16>, response: &str) -> bool {
+    match status {
+        Some(429) => true,
+        Some(status) if status >= 400 => THROTTLING_CODES.iter().any(|code| response.contains(code)),
+        _ => false,
+    }
+}
+
+fn is_secret(name: &str) -> bool {
+    SECRET_NAMES.iter().any(|secret| secret.eq_ignore_ascii_case(name))
+}
+
+/// Field names whose values `operation` returns as secrets: the built-in
+/// ones, and the result field of actions with a `secret` dialog (whose
+/// sdk_method, e.g. `get_secret_value`, names the operation)
+fn operation_secrets(operation: &str) -> Vec<String> {
+    static ACTION_SECRETS: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
+    let actions = ACTION_SECRETS.get_or_init(|| {
+        let mut secrets: HashMap<String, Vec<String>> = HashMap::new();
+        for resource in crate::resource::get_registry().resources.values() {
+            for action in &resource.actions {
+                if let Some(secret) = &action.secret {
+                    let field = secret.field.rsplit('.').next().unwrap_or(&secret.field);
+                    secrets.entry(pascal_case(&action.sdk_method)).or_default().push(field.to_string());
+                }
+            }
+        }
+        secrets
+    });
+    SECRET_RESPONSE_FIELDS
+        .iter()
+        .filter(|(op, _)| *op == operation)
+        .map(|(_, field)| field.to_string())
+        .chain(actions.get(operation).into_iter().flatten().cloned())
+        .collect()
+}
+
+/// "get_secret_value" -> "GetSecretValue"
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Replace secret values in a JSON, XML or form-encoded body of an `operation` call
+pub(super) fn redact(operation: &str, body: &str) -> String {
+    let extra = operation_secrets(operation);
+    let secret = |name: &str| is_secret(name) || extra.iter().any(|field| field.eq_ignore_ascii_case(name));
+    if let Ok(mut value) = serde_json::from_str::<serde_json::Value>(body) {
+        redact_json(&mut value, &secret);
+        return serde_json::to_string(&value).unwrap_or_default();
+    }
+    if !body.trim_start().starts_with('<') && body.contains('=') && !body.contains(char::is_whitespace) {
+        return redact_form(body, &secret);
+    }
+    let mut body = body.to_string();
+    for name in SECRET_NAMES.iter().copied().chain(extra.iter().map(String::as_str)) {
+        let (open, close) = (format!("<{}>", name), format!("</{}>", name));
+        let mut from = 0;
+        while let Some(start) = body[from..].find(&open).map(|i| i + from + open.len()) {
+            let Some(end) = body[start..].find(&close).map(|i| i + start) else {
+                break;
+            };
+            body.replace_range(start..end, REDACTED);
+            from = start + REDACTED.len() + close.len();
+        }
+    }
+    body
+}
+
+/// Replace secret values in `key=value&...` (query protocol bodies, URL
+/// queries). A key matches on its last part, so `Attributes.entry.1.Password`
+/// is redacted like `Password`.
+fn redact_form(form: &str, secret: &dyn Fn(&str) -> bool) -> String {
+    form.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if secret(key.rsplit('.').next().unwrap_or(key)) => format!("{}={}", key, REDACTED),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// A URL with secret query parameters (presigned signatures, tokens) redacted
+pub(super) fn redact_url(url: &str) -> String {
+    match url.split_once('?') {
+        Some((base, query)) => format!("{}?{}", base, redact_form(query, &is_secret)),
+        None => url.to_string(),
+    }
+}
+
+/// Headers with the values of secret ones redacted
+pub(super) fn redact_headers(headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_secret(name) { REDACTED.to_string() } else { value.clone() };
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+fn redact_json(value: &mut serde_json::Value, secret: &dyn Fn(&str) -> bool) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if secret(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value, secret);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(|item| redact_json(item, secret)),
+        _ => {}
+    }
+}
+
+fn truncate(mut body: String) -> String {
+    if body.len() > MAX_BODY {
+        let mut end = MAX_BODY;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+        body.push('…');
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_call_redaction() {
+        let headers = vec![
+            ("x-amz-target".to_string(), "secretsmanager.GetSecretValue".to_string()),
+            ("X-Amz-Security-Token".to_string(), "token".to_string()),
+        ];
+        let call = ApiCall::new(
+            "secretsmanager",
+            "POST",
+            "https://secretsmanager.us-east-1.amazonaws.com/",
+            &headers,
+            r#"{"SecretId":"db"}"#,
+            Duration::from_millis(120),
+            Some(200),
+            r#"{"Name":"db","SecretString":"hunter2"}"#,
+        );
+        assert_eq!(call.operation, "GetSecretValue");
+        assert_eq!(call.request_headers[1].1, REDACTED);
+        assert!(!call.response_body.contains("hunter2"));
+        assert!(call.response_body.contains("\"Name\":\"db\""));
+
+        let xml = "<Credentials><AccessKeyId>ASIA</AccessKeyId><SecretAccessKey>abc</SecretAccessKey></Credentials>";
+        assert_eq!(
+            redact("AssumeRole", xml),
+            "<Credentials><AccessKeyId>ASIA</AccessKeyId><SecretAccessKey><redacted></SecretAccessKey></Credentials>"
+        );
+        assert_eq!(operation_name("POST", "https://ec2", &[], "Action=DescribeInstances&Version=1"), "DescribeInstances");
+        assert!(is_throttled(Some(400), "<Error><Code>Throttling</Code></Error>"));
+        assert!(is_throttled(Some(429), ""));
+        assert!(!is_throttled(Some(403), r#"{"__type":"AccessDeniedException"}"#));
+        assert_eq!(operation_name("GET", "https://lambda.amazonaws.com/2015-03-31/functions", &[], ""), "GET /2015-03-31/functions");
+    }
+
+    #[test]
+    fn test_operation_secrets_redaction() {
+        // SSM returns decrypted SecureStrings in a field named "Value"
+        let headers = vec![("x-amz-target".to_string(), "AmazonSSM.GetParameter".to_string())];
+        let call = ApiCall::new(
+            "ssm",
+            "POST",
+            "https://ssm.us-east-1.amazonaws.com/",
+            &headers,
+            r#"{"Name":"/prod/db/password","WithDecryption":true}"#,
+            Duration::from_millis(80),
+            Some(200),
+            r#"{"Parameter":{"Name":"/prod/db/password","Type":"SecureString","Value":"hunter2","Version":3}}"#,
+        );
+        assert!(!call.response_body.contains("hunter2"), "{}", call.response_body);
+        assert!(call.response_body.contains("/prod/db/password"));
+        let by_path = redact("GetParametersByPath", r#"{"Parameters":[{"Name":"a","Value":"s1"},{"Name":"b","Value":"s2"}]}"#);
+        assert!(!by_path.contains("s1") && !by_path.contains("s2"), "{}", by_path);
+        // Other operations keep their Value fields (e.g. tags)
+        assert!(redact("ListTagsForResource", r#"{"Tags":[{"Key":"env","Value":"prod"}]}"#).contains("prod"));
+        // Actions with a secret dialog redact their secret field
+        assert!(!redact("CreateAccessKey", "<AccessKey><SecretAccessKey>abc</SecretAccessKey></AccessKey>").contains("abc"));
+
+        // Query protocol bodies and presigned URLs
+        assert_eq!(
+            redact("ModifyDBInstance", "Action=ModifyDBInstance&DBInstanceIdentifier=db&MasterUserPassword=hunter2"),
+            "Action=ModifyDBInstance&DBInstanceIdentifier=db&MasterUserPassword=<redacted>"
+        );
+        assert_eq!(
+            redact_url("https://b.s3.amazonaws.com/k?X-Amz-Signature=abc&x-id=GetObject"),
+            "https://b.s3.amazonaws.com/k?X-Amz-Signature=<redacted>&x-id=GetObject"
+        );
+    }
+}
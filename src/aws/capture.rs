@@ -0,0 +1,224 @@
+//! Capture of AWS responses (`--record <dir>`) and playback (`--replay <dir>`)
+//!
+//! Recording saves each response as `<Operation>-<hash>.json`, keyed by the
+//! request's method, URL, target and body (signatures and dates left out), so
+//! replaying the same steps finds them again without credentials or a
+//! network. Secrets are redacted in the files the way the API inspector
+//! redacts them (secret fields, SSM parameter values, signed URL parameters,
+//! secret headers), which makes a capture safe to attach to a bug report.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+
+use super::api_log;
+use super::http::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+
+enum Capture {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+static CAPTURE: OnceLock<Capture> = OnceLock::new();
+
+/// Save every response under `dir` from now on
+pub fn record_to(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Cannot create {:?}", dir))?;
+    CAPTURE
+        .set(Capture::Record(dir.to_path_buf()))
+        .map_err(|_| anyhow!("Capture already configured"))
+}
+
+/// Answer every request from responses saved in `dir`
+pub fn replay_from(dir: &Path) -> Result<()> {
+    if !dir.is_dir() {
+        return Err(anyhow!("No capture directory {:?}", dir));
+    }
+    CAPTURE
+        .set(Capture::Replay(dir.to_path_buf()))
+        .map_err(|_| anyhow!("Capture already configured"))
+}
+
+/// Whether requests are answered from a capture
+pub fn replaying() -> bool {
+    matches!(CAPTURE.get(), Some(Capture::Replay(_)))
+}
+
+/// Transport for new clients while recording or replaying
+pub(super) fn transport() -> Option<Arc<dyn Transport>> {
+    match CAPTURE.get()? {
+        Capture::Record(dir) => Some(Arc::new(RecordTransport {
+            dir: dir.clone(),
+            inner: Box::new(ReqwestTransport::default()),
+        })),
+        Capture::Replay(dir) => Some(Arc::new(ReplayTransport { dir: dir.clone() })),
+    }
+}
+
+/// A saved request and its response
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    method: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(default)]
+    request_body: String,
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+fn target(request: &HttpRequest) -> Option<&str> {
+    request
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("x-amz-target"))
+        .map(|(_, value)| value.as_str())
+}
+
+/// File a request's response is saved in, e.g. "DescribeInstances-3f2a9c1b0d4e.json"
+fn file_name(request: &HttpRequest) -> String {
+    let target = target(request).unwrap_or_default();
+    let mut hasher = Sha1::new();
+    for part in [request.method.as_str(), request.url.as_str(), target, request.body.as_str()] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    let hash = format!("{:x}", hasher.finalize());
+    let operation: String = api_log::operation_name(&request.method, &request.url, &request.headers, &request.body)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{}-{}.json", operation, &hash[..12])
+}
+
+/// Sends requests over the network and saves the responses
+struct RecordTransport {
+    dir: PathBuf,
+    inner: Box<dyn Transport>,
+}
+
+impl Transport for RecordTransport {
+    fn send(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>> {
+        Box::pin(async move {
+            let path = self.dir.join(file_name(&request));
+            let operation = api_log::operation_name(&request.method, &request.url, &request.headers, &request.body);
+            let mut entry = Entry {
+                method: request.method.clone(),
+                url: api_log::redact_url(&request.url),
+                target: target(&request).map(String::from),
+                request_body: api_log::redact(&operation, &request.body),
+                status: 0,
+                headers: BTreeMap::new(),
+                body: String::new(),
+            };
+            let response = self.inner.send(request).await?;
+            entry.status = response.status;
+            entry.headers = api_log::redact_headers(&response.headers).into_iter().collect();
+            entry.body = api_log::redact(&operation, &response.body);
+            let result = serde_json::to_string_pretty(&entry)
+                .map_err(std::io::Error::other)
+                .and_then(|content| std::fs::write(&path, content));
+            if let Err(e) = result {
+                tracing::warn!("Failed to record {:?}: {}", path, e);
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Answers requests from saved responses
+struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>> {
+        let name = file_name(&request);
+        let path = self.dir.join(&name);
+        Box::pin(async move {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|_| anyhow!("No recorded response for {} {} ({})", request.method, request.url, name))?;
+            let entry: Entry = serde_json::from_str(&content).with_context(|| format!("Invalid capture {:?}", path))?;
+            Ok(HttpResponse {
+                status: entry.status,
+                headers: entry.headers.into_iter().collect(),
+                body: entry.body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(body: &str, date: &str) -> HttpRequest {
+        HttpRequest {
+            method: "POST".to_string(),
+            url: "https://ec2.us-east-1.amazonaws.com/".to_string(),
+            headers: vec![("x-amz-date".to_string(), date.to_string())],
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        let name = file_name(&request("Action=DescribeInstances&Version=2016-11-15", "20250101T000000Z"));
+        assert!(name.starts_with("DescribeInstances-"), "{}", name);
+        assert!(name.ends_with(".json"));
+        // Signing headers change every request and must not change the file
+        assert_eq!(name, file_name(&request("Action=DescribeInstances&Version=2016-11-15", "20250102T120000Z")));
+        assert_ne!(name, file_name(&request("Action=DescribeVpcs&Version=2016-11-15", "20250101T000000Z")));
+    }
+
+    /// Answers every request with an SSM GetParameter response
+    struct SsmStub;
+
+    impl Transport for SsmStub {
+        fn send(&self, _request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>> {
+            Box::pin(async {
+                Ok(HttpResponse {
+                    status: 200,
+                    headers: vec![
+                        ("x-amzn-requestid".to_string(), "4f1c".to_string()),
+                        ("set-cookie".to_string(), "session=abc".to_string()),
+                    ],
+                    body: r#"{"Parameter":{"Name":"/prod/db/password","Type":"SecureString","Value":"hunter2"}}"#.to_string(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_redacts_secrets() {
+        let dir = std::env::temp_dir().join(format!("taws-capture-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let transport = RecordTransport { dir: dir.clone(), inner: Box::new(SsmStub) };
+        let request = HttpRequest {
+            method: "POST".to_string(),
+            url: "https://ssm.us-east-1.amazonaws.com/?X-Amz-Signature=abc123".to_string(),
+            headers: vec![("x-amz-target".to_string(), "AmazonSSM.GetParameter".to_string())],
+            body: r#"{"Name":"/prod/db/password","WithDecryption":true}"#.to_string(),
+        };
+        let path = dir.join(file_name(&request));
+
+        // The app still gets the real value; only the file is redacted
+        let response = transport.send(request).await.unwrap();
+        assert!(response.body.contains("hunter2"));
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(saved.contains("/prod/db/password"), "{}", saved);
+        for secret in ["hunter2", "abc123", "session=abc"] {
+            assert!(!saved.contains(secret), "{} in {}", secret, saved);
+        }
+        assert!(saved.contains("4f1c"));
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! Uses SigV4 signing with direct HTTP calls instead of heavy SDK
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
-use super::credentials::{load_credentials, load_credentials_with_sso_check, CredentialsError};
-use super::http::AwsHttpClient;
+use super::credentials::{
+    cache_mfa_credentials, get_mfa_config, load_credentials, load_credentials_with_sso_check, load_mfa_base_credentials,
+    Credentials, CredentialsError,
+};
+use super::http::{xml_to_json, AwsHttpClient};
 
 /// Result type for client creation that may require SSO login
 pub enum ClientResult {
@@ -13,13 +16,18 @@ pub enum ClientResult {
     Ok(AwsClients, String),
     /// SSO login required before client can be created
     SsoLoginRequired { profile: String, sso_session: String, region: String, endpoint_url: Option<String> },
+    /// MFA code required before client can be created
+    MfaRequired { profile: String, mfa_serial: String },
 }
 
 /// Container for AWS HTTP client
+#[derive(Clone)]
 pub struct AwsClients {
     pub http: AwsHttpClient,
     pub region: String,
     pub profile: String,
+    /// Role assumed on top of the profile's credentials (e.g. an Organizations account hop)
+    pub assumed_role: Option<String>,
 }
 
 impl AwsClients {
@@ -41,11 +49,29 @@ impl AwsClients {
             http,
             region: region_str.clone(),
             profile: profile_str,
+            assumed_role: None,
         };
 
         Ok((client, region_str))
     }
     
+    /// Client with placeholder credentials, for `--demo` and `--replay`
+    /// where no request reaches AWS
+    pub fn offline(profile: &str, region: &str) -> Self {
+        let credentials = Credentials {
+            access_key_id: "demo".to_string(),
+            secret_access_key: "demo".to_string(),
+            session_token: None,
+            expiration: None,
+        };
+        Self {
+            http: AwsHttpClient::new(credentials, region, None),
+            region: region.to_string(),
+            profile: profile.to_string(),
+            assumed_role: None,
+        }
+    }
+
     /// Create AWS client with SSO check - returns specific error if SSO login is needed
     /// Note: This runs credential loading on a blocking thread to support SSO
     pub async fn new_with_sso_check(profile: &str, region: &str, endpoint_url: Option<String>) -> Result<ClientResult> {
@@ -66,6 +92,7 @@ impl AwsClients {
                     http,
                     region: region.clone(),
                     profile: prof,
+                    assumed_role: None,
                 };
                 Ok(ClientResult::Ok(client, region))
             }
@@ -77,6 +104,9 @@ impl AwsClients {
                     endpoint_url: endpoint,
                 })
             }
+            Err(CredentialsError::MfaRequired { profile, mfa_serial }) => {
+                Ok(ClientResult::MfaRequired { profile, mfa_serial })
+            }
             Err(CredentialsError::Other(e)) => Err(e),
         }
     }
@@ -86,6 +116,16 @@ impl AwsClients {
     pub async fn switch_region(&mut self, profile: &str, region: &str) -> Result<String> {
         let profile_str = profile.to_string();
         let region_str = region.to_string();
+        
+        // Assumed role credentials are not tied to a region; reloading the
+        // profile would silently drop back to the original account. Demo
+        // and replay clients have no profile to reload.
+        if self.assumed_role.is_some() || crate::demo::enabled() || super::capture::replaying() {
+            self.http.set_region(&region_str);
+            self.region = region_str.clone();
+            return Ok(region_str);
+        }
+
         let profile_for_closure = profile_str.clone();
         
         // Run credential loading on blocking thread (SSO uses blocking HTTP)
@@ -99,6 +139,92 @@ impl AwsClients {
         self.profile = profile_str;
         Ok(region_str)
     }
+
+    /// Assume `role_arn` with the current credentials and use the temporary
+    /// credentials for all further requests
+    pub async fn assume_role(&mut self, role_arn: &str) -> Result<()> {
+        let xml = self.http.query_request("sts", "AssumeRole", &[
+            ("RoleArn", role_arn),
+            ("RoleSessionName", "taws"),
+        ]).await?;
+        self.http.set_credentials(sts_credentials(&xml, "AssumeRole")?);
+        self.assumed_role = Some(role_arn.to_string());
+        Ok(())
+    }
+}
+
+/// Get session credentials for an MFA-protected profile with a TOTP code
+/// (AssumeRole for role profiles, GetSessionToken otherwise) and cache them
+/// so that loading the profile's credentials succeeds until they expire
+pub async fn start_mfa_session(profile: &str, token_code: &str, region: &str, endpoint_url: Option<String>) -> Result<()> {
+    let config = get_mfa_config(profile)
+        .ok_or_else(|| anyhow!("Profile '{}' has no mfa_serial", profile))?;
+    let (profile_for_closure, config_for_closure) = (profile.to_string(), config.clone());
+    let base = tokio::task::spawn_blocking(move || {
+        load_mfa_base_credentials(&profile_for_closure, &config_for_closure)
+    }).await??;
+    let http = AwsHttpClient::new(base, region, endpoint_url);
+
+    let mut params = vec![("SerialNumber", config.mfa_serial.as_str()), ("TokenCode", token_code)];
+    if let Some(duration) = &config.duration_seconds {
+        params.push(("DurationSeconds", duration));
+    }
+    let credentials = match &config.role_arn {
+        Some(role_arn) => {
+            params.push(("RoleArn", role_arn));
+            params.push(("RoleSessionName", config.role_session_name.as_deref().unwrap_or("taws")));
+            if let Some(external_id) = &config.external_id {
+                params.push(("ExternalId", external_id));
+            }
+            sts_credentials(&http.query_request("sts", "AssumeRole", &params).await?, "AssumeRole")?
+        }
+        None => sts_credentials(&http.query_request("sts", "GetSessionToken", &params).await?, "GetSessionToken")?,
+    };
+
+    cache_mfa_credentials(profile, credentials);
+    Ok(())
+}
+
+/// Parse the temporary credentials out of an STS `<Action>Response`
+fn sts_credentials(xml: &str, action: &str) -> Result<Credentials> {
+    let json = xml_to_json(xml)?;
+    let creds = json.pointer(&format!("/{0}Response/{0}Result/Credentials", action))
+        .ok_or_else(|| anyhow!("{} returned no credentials", action))?;
+    let field = |name: &str| creds.get(name).and_then(|v| v.as_str()).map(String::from);
+    let (Some(access_key_id), Some(secret_access_key)) = (field("AccessKeyId"), field("SecretAccessKey")) else {
+        return Err(anyhow!("{} returned incomplete credentials", action));
+    };
+
+    Ok(Credentials {
+        access_key_id,
+        secret_access_key,
+        session_token: field("SessionToken"),
+        expiration: field("Expiration").and_then(|s| s.parse().ok()),
+    })
+}
+
+/// Whether a request failed because the credentials' session has ended
+pub fn is_expired_error(err: &anyhow::Error) -> bool {
+    let err_str = err.to_string();
+    err_str.contains("ExpiredToken") || err_str.contains("TokenRefreshRequired")
+}
+
+/// Everything known about an error, for the error detail view: the short
+/// message, the whole error chain and, for AWS error responses, the HTTP
+/// status, error code, request id and endpoint
+pub fn error_details(err: &anyhow::Error) -> serde_json::Value {
+    let mut details = serde_json::json!({
+        "Message": format_aws_error(err),
+        "Errors": err.chain().map(|cause| cause.to_string()).collect::<Vec<_>>(),
+    });
+    if let Some(api) = err.chain().find_map(|cause| cause.downcast_ref::<super::http::AwsApiError>()) {
+        details["HttpStatus"] = serde_json::json!(api.status.to_string());
+        details["ErrorCode"] = serde_json::json!(api.code());
+        details["ErrorMessage"] = serde_json::json!(api.message());
+        details["RequestId"] = serde_json::json!(api.request_id);
+        details["Endpoint"] = serde_json::json!(format!("{} {}", api.method, api.url));
+    }
+    details
 }
 
 /// Format AWS errors into user-friendly messages
@@ -112,7 +238,7 @@ pub fn format_aws_error(err: &anyhow::Error) -> String {
     if err_str.contains("InvalidClientTokenId") || err_str.contains("SignatureDoesNotMatch") {
         return "Invalid credentials - run 'aws configure'".to_string();
     }
-    if err_str.contains("ExpiredToken") {
+    if is_expired_error(err) {
         return "Credentials expired - refresh or reconfigure".to_string();
     }
     if err_str.contains("AccessDenied") || err_str.contains("UnauthorizedAccess") {
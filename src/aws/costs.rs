@@ -0,0 +1,222 @@
+//! Cost Explorer monthly spend
+//!
+//! Calls GetCostAndUsage for the last N months (including the current,
+//! partial month) grouped by service or by a cost allocation tag, and folds
+//! the per-month groups into one row per service/tag value.
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Months, NaiveDate};
+use serde_json::{json, Value};
+
+use super::client::AwsClients;
+
+/// Default number of months shown by `:costs`
+pub const DEFAULT_MONTHS: u32 = 6;
+
+/// How spend is grouped into rows
+#[derive(Debug, Clone, PartialEq)]
+pub enum CostGroupBy {
+    Service,
+    Tag(String),
+}
+
+/// Spend for one service or tag value, one amount per month
+#[derive(Debug, Clone)]
+pub struct CostRow {
+    pub key: String,
+    pub amounts: Vec<f64>,
+    pub total: f64,
+}
+
+/// Monthly spend table (rows sorted by total, highest first)
+#[derive(Debug, Clone)]
+pub struct CostReport {
+    /// Month labels ("2024-01", ...), oldest first
+    pub months: Vec<String>,
+    pub rows: Vec<CostRow>,
+    /// Total spend per month
+    pub totals: Vec<f64>,
+    /// Currency unit (e.g. "USD")
+    pub unit: String,
+    /// Column header for the row keys ("SERVICE" or the tag key)
+    pub group_label: String,
+}
+
+/// Parse `:costs` arguments: an optional month count and an optional `tag:Key`
+pub fn parse_costs_args(args: &[&str]) -> Result<(u32, CostGroupBy)> {
+    let mut months = DEFAULT_MONTHS;
+    let mut group_by = CostGroupBy::Service;
+    for arg in args {
+        if let Some(key) = arg.strip_prefix("tag:") {
+            if key.is_empty() {
+                return Err(anyhow!("Expected tag:<key>"));
+            }
+            group_by = CostGroupBy::Tag(key.to_string());
+        } else {
+            months = arg
+                .parse::<u32>()
+                .ok()
+                .filter(|m| (1..=12).contains(m))
+                .ok_or_else(|| anyhow!("Months must be between 1 and 12, got '{}'", arg))?;
+        }
+    }
+    Ok((months, group_by))
+}
+
+/// Time period covering `months` calendar months up to and including `today`
+/// (Cost Explorer treats the end date as exclusive)
+fn time_period(today: NaiveDate, months: u32) -> (NaiveDate, NaiveDate) {
+    let first_of_month = today.with_day(1).unwrap_or(today);
+    let start = first_of_month
+        .checked_sub_months(Months::new(months.saturating_sub(1)))
+        .unwrap_or(first_of_month);
+    let end = today.succ_opt().unwrap_or(today);
+    (start, end)
+}
+
+/// Fetch monthly spend for the last `months` months
+pub async fn fetch_cost_report(clients: &AwsClients, months: u32, group_by: &CostGroupBy) -> Result<CostReport> {
+    let (start, end) = time_period(chrono::Local::now().date_naive(), months);
+    let group = match group_by {
+        CostGroupBy::Service => json!({ "Type": "DIMENSION", "Key": "SERVICE" }),
+        CostGroupBy::Tag(key) => json!({ "Type": "TAG", "Key": key }),
+    };
+
+    let mut results = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let mut request = json!({
+            "TimePeriod": {
+                "Start": start.format("%Y-%m-%d").to_string(),
+                "End": end.format("%Y-%m-%d").to_string(),
+            },
+            "Granularity": "MONTHLY",
+            "Metrics": ["UnblendedCost"],
+            "GroupBy": [group],
+        });
+        if let Some(token) = &next_token {
+            request["NextPageToken"] = json!(token);
+        }
+        let response = clients.http.json_request("ce", "GetCostAndUsage", &request.to_string()).await?;
+        let json: Value = serde_json::from_str(&response)?;
+        results.extend(json.get("ResultsByTime").and_then(|v| v.as_array()).cloned().unwrap_or_default());
+
+        next_token = json.get("NextPageToken").and_then(|v| v.as_str()).map(String::from);
+        if next_token.is_none() {
+            break;
+        }
+    }
+
+    let group_label = match group_by {
+        CostGroupBy::Service => "SERVICE".to_string(),
+        CostGroupBy::Tag(key) => key.to_uppercase(),
+    };
+    Ok(build_report(&results, group_label))
+}
+
+/// Fold GetCostAndUsage `ResultsByTime` into a report
+fn build_report(results: &[Value], group_label: String) -> CostReport {
+    // Pages may repeat a period with more groups, so key months by start date
+    let mut months: Vec<String> = results
+        .iter()
+        .filter_map(|r| r.pointer("/TimePeriod/Start").and_then(|v| v.as_str()))
+        .map(|start| start.chars().take(7).collect())
+        .collect();
+    months.dedup();
+
+    let mut rows: Vec<CostRow> = Vec::new();
+    let mut unit = String::from("USD");
+    for result in results {
+        let Some(month) = result.pointer("/TimePeriod/Start").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let Some(index) = months.iter().position(|m| month.starts_with(m.as_str())) else {
+            continue;
+        };
+        for group in result.get("Groups").and_then(|v| v.as_array()).into_iter().flatten() {
+            let raw_key = group.pointer("/Keys/0").and_then(|v| v.as_str()).unwrap_or("-");
+            // Tag keys come back as "Key$value"; an empty value means untagged
+            let key = match raw_key.split_once('$') {
+                Some((_, "")) => "(untagged)".to_string(),
+                Some((_, value)) => value.to_string(),
+                None => raw_key.to_string(),
+            };
+            let amount = group
+                .pointer("/Metrics/UnblendedCost/Amount")
+                .and_then(|v| v.as_str())
+                .and_then(|v| v.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            if let Some(u) = group.pointer("/Metrics/UnblendedCost/Unit").and_then(|v| v.as_str()) {
+                unit = u.to_string();
+            }
+
+            let row = match rows.iter_mut().position(|r| r.key == key) {
+                Some(i) => &mut rows[i],
+                None => {
+                    rows.push(CostRow { key, amounts: vec![0.0; months.len()], total: 0.0 });
+                    rows.last_mut().expect("row was just pushed")
+                }
+            };
+            row.amounts[index] += amount;
+            row.total += amount;
+        }
+    }
+
+    // Hide rounding noise (services with no real spend)
+    rows.retain(|r| r.total.abs() >= 0.005);
+    rows.sort_by(|a, b| b.total.total_cmp(&a.total));
+
+    let totals = (0..months.len())
+        .map(|i| rows.iter().map(|r| r.amounts[i]).sum())
+        .collect();
+
+    CostReport { months, rows, totals, unit, group_label }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_costs_args() {
+        assert_eq!(parse_costs_args(&[]).unwrap(), (DEFAULT_MONTHS, CostGroupBy::Service));
+        assert_eq!(parse_costs_args(&["3"]).unwrap(), (3, CostGroupBy::Service));
+        assert_eq!(
+            parse_costs_args(&["12", "tag:Team"]).unwrap(),
+            (12, CostGroupBy::Tag("Team".to_string()))
+        );
+        assert!(parse_costs_args(&["0"]).is_err());
+        assert!(parse_costs_args(&["tag:"]).is_err());
+        assert!(parse_costs_args(&["lots"]).is_err());
+    }
+
+    #[test]
+    fn test_build_report_groups_months_and_sorts_by_total() {
+        let results: Vec<Value> = serde_json::from_str(r#"[
+            {"TimePeriod": {"Start": "2024-05-01", "End": "2024-06-01"}, "Groups": [
+                {"Keys": ["Team$web"], "Metrics": {"UnblendedCost": {"Amount": "10.5", "Unit": "USD"}}},
+                {"Keys": ["Team$"], "Metrics": {"UnblendedCost": {"Amount": "2", "Unit": "USD"}}}
+            ]},
+            {"TimePeriod": {"Start": "2024-06-01", "End": "2024-06-15"}, "Groups": [
+                {"Keys": ["Team$"], "Metrics": {"UnblendedCost": {"Amount": "20", "Unit": "USD"}}},
+                {"Keys": ["Team$data"], "Metrics": {"UnblendedCost": {"Amount": "0.0001", "Unit": "USD"}}}
+            ]}
+        ]"#).unwrap();
+
+        let report = build_report(&results, "TEAM".to_string());
+        assert_eq!(report.months, vec!["2024-05", "2024-06"]);
+        assert_eq!(report.rows.len(), 2);
+        assert_eq!(report.rows[0].key, "(untagged)");
+        assert_eq!(report.rows[0].amounts, vec![2.0, 20.0]);
+        assert_eq!(report.rows[1].key, "web");
+        assert_eq!(report.totals, vec![12.5, 20.0]);
+    }
+
+    #[test]
+    fn test_time_period_spans_whole_months() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let (start, end) = time_period(today, 6);
+        assert_eq!(start, NaiveDate::from_ymd_opt(2023, 10, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2024, 3, 16).unwrap());
+    }
+}
@@ -4,9 +4,12 @@
 //! - Environment variables (AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY, AWS_SESSION_TOKEN)
 //! - AWS profiles (~/.aws/credentials and ~/.aws/config)
 //! - AWS SSO (IAM Identity Center) via cached tokens
+//! - MFA-protected profiles (`mfa_serial`) via cached session credentials
+//! - ECS/EKS container credentials (AWS_CONTAINER_CREDENTIALS_RELATIVE_URI / FULL_URI)
 //! - IMDSv2 (EC2 instance metadata)
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::env;
 use std::fs;
@@ -25,6 +28,12 @@ pub enum CredentialsError {
         sso_session: String,
     },
 
+    #[error("MFA code required for profile '{profile}' ({mfa_serial})")]
+    MfaRequired {
+        profile: String,
+        mfa_serial: String,
+    },
+
     #[error("{0}")]
     Other(#[from] anyhow::Error),
 }
@@ -35,6 +44,8 @@ pub struct Credentials {
     pub access_key_id: String,
     pub secret_access_key: String,
     pub session_token: Option<String>,
+    /// When temporary credentials stop working (None for long-lived keys)
+    pub expiration: Option<DateTime<Utc>>,
 }
 
 /// Cached IMDS credentials with expiration
@@ -46,8 +57,14 @@ struct CachedImdsCredentials {
 /// Global cache for IMDS credentials
 static IMDS_CACHE: OnceLock<std::sync::Mutex<Option<CachedImdsCredentials>>> = OnceLock::new();
 
-/// Global cache for SSO credentials
-static SSO_CACHE: OnceLock<std::sync::Mutex<Option<CachedImdsCredentials>>> = OnceLock::new();
+/// Global cache for container (ECS task role) credentials
+static CONTAINER_CACHE: OnceLock<std::sync::Mutex<Option<CachedImdsCredentials>>> = OnceLock::new();
+
+/// SSO role credentials, by profile
+static SSO_CACHE: OnceLock<std::sync::Mutex<HashMap<String, CachedImdsCredentials>>> = OnceLock::new();
+
+/// Session credentials obtained with an MFA code, by profile
+static MFA_CACHE: OnceLock<std::sync::Mutex<HashMap<String, CachedImdsCredentials>>> = OnceLock::new();
 
 /// IMDSv2 metadata endpoint
 const IMDS_ENDPOINT: &str = "http://169.254.169.254";
@@ -55,6 +72,8 @@ const IMDS_ENDPOINT: &str = "http://169.254.169.254";
 const IMDS_TOKEN_TTL: u64 = 21600;
 /// Timeout for IMDS requests (2 seconds - fast fail if not on EC2)
 const IMDS_TIMEOUT: Duration = Duration::from_secs(2);
+/// ECS container credentials endpoint (for AWS_CONTAINER_CREDENTIALS_RELATIVE_URI)
+const CONTAINER_ENDPOINT: &str = "http://169.254.170.2";
 /// Refresh credentials 5 minutes before expiration
 const CREDENTIAL_REFRESH_BUFFER: Duration = Duration::from_secs(300);
 
@@ -71,6 +90,7 @@ pub fn load_credentials(profile: &str) -> Result<Credentials> {
                 sso_session
             )
         }
+        e @ CredentialsError::MfaRequired { .. } => anyhow!(e.to_string()),
         CredentialsError::Other(e) => e,
     })
 }
@@ -115,7 +135,20 @@ fn load_credentials_inner(profile: &str) -> Result<Credentials, CredentialsError
         }
     }
 
-    // 3. Try AWS credentials file
+    // 3. MFA-protected profiles only work with session credentials obtained
+    //    with a code, never with the long-lived keys behind them
+    if let Some(mfa_config) = get_mfa_config(profile) {
+        if let Some(creds) = cached_mfa_credentials(profile) {
+            debug!("Using cached MFA session credentials for profile '{}'", profile);
+            return Ok(creds);
+        }
+        return Err(CredentialsError::MfaRequired {
+            profile: profile.to_string(),
+            mfa_serial: mfa_config.mfa_serial,
+        });
+    }
+
+    // 4. Try AWS credentials file
     if let Ok(creds) = load_from_credentials_file(profile) {
         debug!(
             "Loaded credentials from credentials file for profile '{}'",
@@ -124,7 +157,7 @@ fn load_credentials_inner(profile: &str) -> Result<Credentials, CredentialsError
         return Ok(creds);
     }
 
-    // 4. Try config file with direct credentials
+    // 5. Try config file with direct credentials
     if let Ok(creds) = load_from_config_file(profile) {
         debug!(
             "Loaded credentials from config file for profile '{}'",
@@ -133,7 +166,20 @@ fn load_credentials_inner(profile: &str) -> Result<Credentials, CredentialsError
         return Ok(creds);
     }
 
-    // 5. Try IMDSv2 (EC2 instance metadata) - only for default profile
+    // 6. Try container credentials (ECS task role, EKS Pod Identity) - only for default profile
+    if profile == "default" && container_credentials_configured() {
+        match load_from_container() {
+            Ok(creds) => {
+                debug!("Loaded credentials from container credentials endpoint");
+                return Ok(creds);
+            }
+            Err(e) => {
+                debug!("Container credential loading failed: {}", e);
+            }
+        }
+    }
+
+    // 7. Try IMDSv2 (EC2 instance metadata) - only for default profile
     if profile == "default" {
         match load_from_imds() {
             Ok(creds) => {
@@ -160,11 +206,16 @@ fn load_from_env() -> Result<Credentials> {
     let secret_access_key =
         env::var("AWS_SECRET_ACCESS_KEY").map_err(|_| anyhow!("AWS_SECRET_ACCESS_KEY not set"))?;
     let session_token = env::var("AWS_SESSION_TOKEN").ok();
+    // Set by credential_process wrappers and aws-vault alongside temporary keys
+    let expiration = env::var("AWS_CREDENTIAL_EXPIRATION")
+        .ok()
+        .and_then(|s| s.parse().ok());
 
     Ok(Credentials {
         access_key_id,
         secret_access_key,
         session_token,
+        expiration,
     })
 }
 
@@ -249,6 +300,7 @@ fn load_from_credentials_file(profile: &str) -> Result<Credentials> {
         access_key_id,
         secret_access_key,
         session_token,
+        expiration: None,
     })
 }
 
@@ -273,6 +325,7 @@ fn load_from_config_file(profile: &str) -> Result<Credentials> {
             access_key_id: access_key.clone(),
             secret_access_key: secret_key.clone(),
             session_token: section.get("aws_session_token").cloned(),
+            expiration: None,
         });
     }
 
@@ -292,15 +345,9 @@ fn load_from_sso(profile: &str) -> Result<Credentials> {
     use super::sso;
 
     // Check credential cache first
-    let cache = SSO_CACHE.get_or_init(|| std::sync::Mutex::new(None));
-
-    if let Ok(guard) = cache.lock() {
-        if let Some(ref cached) = *guard {
-            if cached.expiration > Instant::now() + CREDENTIAL_REFRESH_BUFFER {
-                trace!("Using cached SSO credentials");
-                return Ok(cached.credentials.clone());
-            }
-        }
+    if let Some(credentials) = cached_sso_credentials(profile) {
+        trace!("Using cached SSO credentials for profile '{}'", profile);
+        return Ok(credentials);
     }
 
     // Get SSO config for this profile
@@ -318,18 +365,159 @@ fn load_from_sso(profile: &str) -> Result<Credentials> {
     // Exchange token for credentials
     let credentials = sso::get_role_credentials(&sso_config, &access_token)?;
 
-    // Cache the credentials
-    let expiration = Instant::now() + Duration::from_secs(3600); // Default 1 hour
-    let cache = SSO_CACHE.get_or_init(|| std::sync::Mutex::new(None));
+    cache_sso_credentials(profile, credentials.clone());
+    Ok(credentials)
+}
+
+fn cached_sso_credentials(profile: &str) -> Option<Credentials> {
+    let cache = SSO_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
+    let guard = cache.lock().ok()?;
+    let cached = guard.get(profile)?;
+    (cached.expiration > Instant::now() + CREDENTIAL_REFRESH_BUFFER).then(|| cached.credentials.clone())
+}
+
+/// Cache a profile's SSO role credentials until the role session ends
+fn cache_sso_credentials(profile: &str, credentials: Credentials) {
+    let expiration = credentials
+        .expiration
+        .and_then(instant_from)
+        .unwrap_or_else(|| Instant::now() + Duration::from_secs(3600)); // Default 1 hour
+    let cache = SSO_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
     if let Ok(mut guard) = cache.lock() {
-        *guard = Some(CachedImdsCredentials {
-            credentials: credentials.clone(),
-            expiration,
-        });
-        debug!("Cached SSO credentials");
+        guard.insert(profile.to_string(), CachedImdsCredentials { credentials, expiration });
+        debug!("Cached SSO credentials for profile '{}'", profile);
     }
+}
 
-    Ok(credentials)
+// =============================================================================
+// MFA-protected Profiles
+// =============================================================================
+
+/// MFA settings of a profile (`mfa_serial`, optionally with `role_arn`)
+#[derive(Debug, Clone)]
+pub struct MfaConfig {
+    pub mfa_serial: String,
+    /// Role to assume with the MFA code (GetSessionToken is used without one)
+    pub role_arn: Option<String>,
+    /// Profile whose credentials call STS (defaults to the profile's own keys)
+    pub source_profile: Option<String>,
+    pub role_session_name: Option<String>,
+    pub external_id: Option<String>,
+    pub duration_seconds: Option<String>,
+}
+
+/// Get the MFA settings for a profile from ~/.aws/config and ~/.aws/credentials
+pub fn get_mfa_config(profile: &str) -> Option<MfaConfig> {
+    let dir = aws_config_dir().ok()?;
+    let mut section = HashMap::new();
+    for file in ["credentials", "config"] {
+        let content = fs::read_to_string(dir.join(file)).unwrap_or_default();
+        if let Some(values) = parse_ini_file(&content).remove(profile) {
+            section.extend(values);
+        }
+    }
+    Some(MfaConfig {
+        mfa_serial: section.get("mfa_serial")?.clone(),
+        role_arn: section.get("role_arn").cloned(),
+        source_profile: section.get("source_profile").cloned(),
+        role_session_name: section.get("role_session_name").cloned(),
+        external_id: section.get("external_id").cloned(),
+        duration_seconds: section.get("duration_seconds").cloned(),
+    })
+}
+
+// =============================================================================
+// Profile Details (profile picker)
+// =============================================================================
+
+/// What the profile picker shows about a profile, read from the config files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfileDetails {
+    pub region: Option<String>,
+    /// "sso", "assume-role", "mfa", "process", "static", or "-" when unknown
+    pub auth: &'static str,
+    pub sso_session: Option<String>,
+    /// Account known without calling AWS (`sso_account_id`, or from `role_arn`)
+    pub account: Option<String>,
+}
+
+/// Get the picker details of a profile from ~/.aws/config and ~/.aws/credentials
+pub fn get_profile_details(profile: &str) -> ProfileDetails {
+    let mut section = HashMap::new();
+    if let Ok(dir) = aws_config_dir() {
+        for file in ["credentials", "config"] {
+            let content = fs::read_to_string(dir.join(file)).unwrap_or_default();
+            if let Some(values) = parse_ini_file(&content).remove(profile) {
+                section.extend(values);
+            }
+        }
+    }
+    profile_details(&section)
+}
+
+fn profile_details(section: &HashMap<String, String>) -> ProfileDetails {
+    let auth = if section.contains_key("sso_session") || section.contains_key("sso_start_url") {
+        "sso"
+    } else if section.contains_key("role_arn") {
+        "assume-role"
+    } else if section.contains_key("mfa_serial") {
+        "mfa"
+    } else if section.contains_key("credential_process") {
+        "process"
+    } else if section.contains_key("aws_access_key_id") {
+        "static"
+    } else {
+        "-"
+    };
+    let role_account = section
+        .get("role_arn")
+        .and_then(|arn| arn.split(':').nth(4))
+        .filter(|account| !account.is_empty())
+        .map(String::from);
+    ProfileDetails {
+        region: section.get("region").cloned(),
+        auth,
+        sso_session: section.get("sso_session").cloned(),
+        account: section.get("sso_account_id").cloned().or(role_account),
+    }
+}
+
+/// Credentials used to call STS for an MFA session: the source profile's
+/// for role profiles, otherwise the profile's own long-lived keys
+pub fn load_mfa_base_credentials(profile: &str, config: &MfaConfig) -> Result<Credentials> {
+    match &config.source_profile {
+        Some(source) if source != profile => load_credentials(source),
+        _ => load_from_credentials_file(profile).or_else(|_| load_from_config_file(profile)),
+    }
+}
+
+fn cached_mfa_credentials(profile: &str) -> Option<Credentials> {
+    let cache = MFA_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
+    let guard = cache.lock().ok()?;
+    let cached = guard.get(profile)?;
+    (cached.expiration > Instant::now() + CREDENTIAL_REFRESH_BUFFER).then(|| cached.credentials.clone())
+}
+
+/// Cache MFA session credentials for a profile until they expire
+pub fn cache_mfa_credentials(profile: &str, credentials: Credentials) {
+    let expiration = credentials
+        .expiration
+        .and_then(instant_from)
+        .unwrap_or_else(|| Instant::now() + Duration::from_secs(3600)); // Default 1 hour
+    let cache = MFA_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
+    if let Ok(mut guard) = cache.lock() {
+        guard.insert(profile.to_string(), CachedImdsCredentials { credentials, expiration });
+        debug!("Cached MFA session credentials for profile '{}'", profile);
+    }
+}
+
+/// Forget cached SSO role credentials (after an SSO logout)
+pub fn clear_sso_cache() {
+    if let Some(cache) = SSO_CACHE.get()
+        && let Ok(mut guard) = cache.lock()
+    {
+        guard.clear();
+    }
 }
 
 /// Get the default region for a profile
@@ -386,6 +574,79 @@ pub fn list_profiles() -> Vec<String> {
     profiles
 }
 
+// =============================================================================
+// Container Credentials (ECS task roles, EKS Pod Identity)
+// =============================================================================
+
+fn container_credentials_configured() -> bool {
+    env::var_os("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI").is_some()
+        || env::var_os("AWS_CONTAINER_CREDENTIALS_FULL_URI").is_some()
+}
+
+/// Load credentials from the container credentials endpoint
+///
+/// The endpoint is `169.254.170.2` plus AWS_CONTAINER_CREDENTIALS_RELATIVE_URI
+/// (ECS), or AWS_CONTAINER_CREDENTIALS_FULL_URI with the token from
+/// AWS_CONTAINER_AUTHORIZATION_TOKEN(_FILE) (EKS Pod Identity, ECS Anywhere).
+/// Credentials are cached until near expiration, like IMDS.
+fn load_from_container() -> Result<Credentials> {
+    let cache = CONTAINER_CACHE.get_or_init(|| std::sync::Mutex::new(None));
+
+    if let Ok(guard) = cache.lock()
+        && let Some(ref cached) = *guard
+        && cached.expiration > Instant::now() + CREDENTIAL_REFRESH_BUFFER
+    {
+        trace!("Using cached container credentials");
+        return Ok(cached.credentials.clone());
+    }
+
+    let url = match env::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI") {
+        Ok(relative) => format!("{}{}", CONTAINER_ENDPOINT, relative),
+        Err(_) => env::var("AWS_CONTAINER_CREDENTIALS_FULL_URI")
+            .map_err(|_| anyhow!("No container credentials URI set"))?,
+    };
+    let token = match env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE") {
+        Ok(path) => Some(
+            fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Could not read {}: {}", path, e))?
+                .trim()
+                .to_string(),
+        ),
+        Err(_) => env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN").ok(),
+    };
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(IMDS_TIMEOUT)
+        .connect_timeout(IMDS_TIMEOUT)
+        .build()
+        .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))?;
+
+    trace!("Fetching container credentials from {}", url);
+    let mut request = client.get(&url);
+    if let Some(token) = token {
+        request = request.header("Authorization", token);
+    }
+    let response = request
+        .send()
+        .map_err(|e| anyhow!("Failed to get container credentials: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "Container credentials request failed with status: {}",
+            response.status()
+        ));
+    }
+
+    let creds_json: serde_json::Value = response
+        .json()
+        .map_err(|e| anyhow!("Failed to parse credentials JSON: {}", e))?;
+
+    let credentials = parse_metadata_credentials(&creds_json, "container")?;
+    cache_until_expiry(&CONTAINER_CACHE, &credentials, "container");
+
+    Ok(credentials)
+}
+
 // =============================================================================
 // IMDSv2 (EC2 Instance Metadata Service) Support
 // =============================================================================
@@ -508,17 +769,24 @@ fn fetch_imds_credentials() -> Result<Credentials> {
         .json()
         .map_err(|e| anyhow!("Failed to parse credentials JSON: {}", e))?;
 
-    // Parse the credentials
+    let credentials = parse_metadata_credentials(&creds_json, "IMDS")?;
+    cache_until_expiry(&IMDS_CACHE, &credentials, "IMDS");
+
+    Ok(credentials)
+}
+
+/// Parse the credentials JSON served by IMDS and the ECS container endpoint
+fn parse_metadata_credentials(creds_json: &serde_json::Value, source: &str) -> Result<Credentials> {
     let access_key_id = creds_json
         .get("AccessKeyId")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| anyhow!("AccessKeyId not found in IMDS response"))?
+        .ok_or_else(|| anyhow!("AccessKeyId not found in {} response", source))?
         .to_string();
 
     let secret_access_key = creds_json
         .get("SecretAccessKey")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| anyhow!("SecretAccessKey not found in IMDS response"))?
+        .ok_or_else(|| anyhow!("SecretAccessKey not found in {} response", source))?
         .to_string();
 
     let session_token = creds_json
@@ -526,46 +794,47 @@ fn fetch_imds_credentials() -> Result<Credentials> {
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
-    // Parse expiration time
-    let expiration = if let Some(exp_str) = creds_json.get("Expiration").and_then(|v| v.as_str()) {
-        // Parse ISO 8601 format: "2024-01-15T12:00:00Z"
-        parse_expiration(exp_str).unwrap_or_else(|| {
-            // Default to 1 hour if parsing fails
-            Instant::now() + Duration::from_secs(3600)
-        })
-    } else {
-        // Default to 1 hour if no expiration provided
-        Instant::now() + Duration::from_secs(3600)
-    };
+    // Parse expiration time (ISO 8601 format: "2024-01-15T12:00:00Z")
+    let expiration = creds_json
+        .get("Expiration")
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse().ok());
 
-    let credentials = Credentials {
+    Ok(Credentials {
         access_key_id,
         secret_access_key,
         session_token,
-    };
+        expiration,
+    })
+}
 
-    // Cache the credentials
-    let cache = IMDS_CACHE.get_or_init(|| std::sync::Mutex::new(None));
+/// Cache credentials until their expiration (1 hour if none is given)
+fn cache_until_expiry(
+    cache: &OnceLock<std::sync::Mutex<Option<CachedImdsCredentials>>>,
+    credentials: &Credentials,
+    source: &str,
+) {
+    let expiration = credentials.expiration.and_then(instant_from).unwrap_or_else(|| {
+        // Default to 1 hour if no (valid) expiration provided
+        Instant::now() + Duration::from_secs(3600)
+    });
+
+    let cache = cache.get_or_init(|| std::sync::Mutex::new(None));
     if let Ok(mut guard) = cache.lock() {
         *guard = Some(CachedImdsCredentials {
             credentials: credentials.clone(),
             expiration,
         });
         debug!(
-            "Cached IMDS credentials, expires in {:?}",
+            "Cached {} credentials, expires in {:?}",
+            source,
             expiration - Instant::now()
         );
     }
-
-    Ok(credentials)
 }
 
-/// Parse ISO 8601 expiration time to Instant
-fn parse_expiration(exp_str: &str) -> Option<Instant> {
-    // Parse "2024-01-15T12:00:00Z" format
-    use chrono::{DateTime, Utc};
-
-    let expiration_time: DateTime<Utc> = exp_str.parse().ok()?;
+/// Convert a wall-clock expiration time to an Instant (None if already past)
+fn instant_from(expiration_time: DateTime<Utc>) -> Option<Instant> {
     let now = Utc::now();
 
     if expiration_time <= now {
@@ -596,3 +865,47 @@ pub fn is_imds_available() -> bool {
         .map(|r| r.status().is_success())
         .unwrap_or(false)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_details() {
+        let sso = parse_ini_file(
+            "[profile dev]\nsso_session = corp\nsso_account_id = 111111111111\nregion = eu-west-1\n",
+        );
+        assert_eq!(
+            profile_details(&sso["dev"]),
+            ProfileDetails {
+                region: Some("eu-west-1".to_string()),
+                auth: "sso",
+                sso_session: Some("corp".to_string()),
+                account: Some("111111111111".to_string()),
+            }
+        );
+
+        let role = parse_ini_file("[profile ops]\nrole_arn = arn:aws:iam::222222222222:role/Ops\nsource_profile = dev\n");
+        let details = profile_details(&role["ops"]);
+        assert_eq!((details.auth, details.account.as_deref()), ("assume-role", Some("222222222222")));
+
+        let keys = parse_ini_file("[default]\naws_access_key_id = AKIA\naws_secret_access_key = secret\n");
+        assert_eq!(profile_details(&keys["default"]).auth, "static");
+        assert_eq!(profile_details(&HashMap::new()).auth, "-");
+    }
+
+    #[test]
+    fn test_sso_credentials_cached_per_profile() {
+        let credentials = |key: &str| Credentials {
+            access_key_id: key.to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: None,
+            expiration: Some(Utc::now() + chrono::Duration::hours(1)),
+        };
+        cache_sso_credentials("sso-test-dev", credentials("ASIADEV"));
+        cache_sso_credentials("sso-test-prod", credentials("ASIAPROD"));
+        assert_eq!(cached_sso_credentials("sso-test-dev").unwrap().access_key_id, "ASIADEV");
+        assert_eq!(cached_sso_credentials("sso-test-prod").unwrap().access_key_id, "ASIAPROD");
+        assert!(cached_sso_credentials("sso-test-other").is_none());
+    }
+}
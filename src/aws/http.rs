@@ -9,10 +9,78 @@ use aws_sigv4::sign::v4::SigningParams;
 use aws_smithy_runtime_api::client::identity::Identity;
 use std::time::SystemTime;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
 use tracing::{debug, trace, warn};
 
+use super::api_log::{self, ApiCall};
 use super::credentials::Credentials;
 
+/// A request AWS answered with an error status, kept whole for the error
+/// detail view (`:error`)
+#[derive(Debug)]
+pub struct AwsApiError {
+    pub status: reqwest::StatusCode,
+    pub method: String,
+    pub url: String,
+    pub request_id: Option<String>,
+    pub body: String,
+}
+
+impl AwsApiError {
+    fn new(status: reqwest::StatusCode, method: &str, url: &str, request_id: Option<String>, body: String) -> Self {
+        Self {
+            status,
+            method: method.to_string(),
+            url: url.to_string(),
+            request_id,
+            body,
+        }
+    }
+
+    /// AWS error code, e.g. "AccessDenied" or "ResourceNotFoundException"
+    pub fn code(&self) -> Option<String> {
+        error_body_field(&self.body, &["Code", "code", "__type"])
+            // JSON protocols may qualify it: "com.amazonaws.dynamodb.v20120810#ResourceNotFoundException"
+            .map(|code| code.rsplit('#').next().unwrap_or(&code).to_string())
+    }
+
+    /// Error message from the response body
+    pub fn message(&self) -> Option<String> {
+        error_body_field(&self.body, &["Message", "message", "errorMessage"])
+    }
+}
+
+impl std::fmt::Display for AwsApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AWS request failed ({}): {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for AwsApiError {}
+
+/// Request id of a response (`x-amzn-RequestId`, or `x-amz-request-id` for S3)
+fn response_request_id(response: &HttpResponse) -> Option<String> {
+    ["x-amzn-requestid", "x-amz-request-id"]
+        .iter()
+        .find_map(|name| response.header(name))
+        .map(String::from)
+}
+
+/// First of `names` found in an error body, as a JSON field or an XML element
+fn error_body_field(body: &str, names: &[&str]) -> Option<String> {
+    if let Ok(serde_json::Value::Object(map)) = serde_json::from_str::<serde_json::Value>(body) {
+        return names.iter().find_map(|name| map.get(*name)?.as_str().map(String::from));
+    }
+    names.iter().find_map(|name| {
+        let open = format!("<{}>", name);
+        let start = body.find(&open)? + open.len();
+        let end = body[start..].find(&format!("</{}>", name))? + start;
+        Some(body[start..end].to_string())
+    })
+}
+
 /// Extract region from S3 URL patterns like:
 /// - https://bucket.s3.us-west-1.amazonaws.com/
 /// - https://bucket.s3-us-west-1.amazonaws.com/
@@ -56,7 +124,6 @@ pub struct ServiceDefinition {
     /// API version (e.g., "2016-11-15" for EC2)
     pub api_version: &'static str,
     /// Protocol: "query", "json", "rest-json", "rest-xml"
-    #[allow(dead_code)]
     pub protocol: Protocol,
     /// Target prefix for JSON protocol (e.g., "AWSCognitoIdentityProviderService")
     pub target_prefix: Option<&'static str>,
@@ -76,6 +143,19 @@ pub enum Protocol {
     RestXml,
 }
 
+impl Protocol {
+    /// Parse "query", "json", "rest-json" or "rest-xml"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "query" => Some(Protocol::Query),
+            "json" => Some(Protocol::Json),
+            "rest-json" => Some(Protocol::RestJson),
+            "rest-xml" => Some(Protocol::RestXml),
+            _ => None,
+        }
+    }
+}
+
 /// Service definitions for all 30 supported services
 pub fn get_service(name: &str) -> Option<ServiceDefinition> {
     match name {
@@ -263,6 +343,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("AWSEvents"),
             is_global: false,
         }),
+        "scheduler" => Some(ServiceDefinition {
+            signing_name: "scheduler",
+            endpoint_prefix: "scheduler",
+            api_version: "2021-06-30",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
         "codepipeline" => Some(ServiceDefinition {
             signing_name: "codepipeline",
             endpoint_prefix: "codepipeline",
@@ -303,7 +391,16 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
-        "elasticloadbalancing" | "elb" | "elbv2" => Some(ServiceDefinition {
+        // Classic Load Balancers use the original API version
+        "elb" => Some(ServiceDefinition {
+            signing_name: "elasticloadbalancing",
+            endpoint_prefix: "elasticloadbalancing",
+            api_version: "2012-06-01",
+            protocol: Protocol::Query,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "elasticloadbalancing" | "elbv2" => Some(ServiceDefinition {
             signing_name: "elasticloadbalancing",
             endpoint_prefix: "elasticloadbalancing",
             api_version: "2015-12-01",
@@ -311,6 +408,86 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
+        "kinesis" => Some(ServiceDefinition {
+            signing_name: "kinesis",
+            endpoint_prefix: "kinesis",
+            api_version: "2013-12-02",
+            protocol: Protocol::Json,
+            target_prefix: Some("Kinesis_20131202"),
+            is_global: false,
+        }),
+        "securityhub" => Some(ServiceDefinition {
+            signing_name: "securityhub",
+            endpoint_prefix: "securityhub",
+            api_version: "2018-10-26",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "organizations" => Some(ServiceDefinition {
+            signing_name: "organizations",
+            endpoint_prefix: "organizations",
+            api_version: "2016-11-28",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSOrganizationsV20161128"),
+            is_global: true,
+        }),
+        "servicequotas" => Some(ServiceDefinition {
+            signing_name: "servicequotas",
+            endpoint_prefix: "servicequotas",
+            api_version: "2019-06-24",
+            protocol: Protocol::Json,
+            target_prefix: Some("ServiceQuotasV20190624"),
+            is_global: false,
+        }),
+        "cloudwatch" => Some(ServiceDefinition {
+            signing_name: "monitoring",
+            endpoint_prefix: "monitoring",
+            api_version: "2010-08-01",
+            protocol: Protocol::Query,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "health" => Some(ServiceDefinition {
+            signing_name: "health",
+            endpoint_prefix: "health",
+            api_version: "2016-08-04",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSHealth_20160804"),
+            is_global: true,
+        }),
+        "resource-explorer-2" => Some(ServiceDefinition {
+            signing_name: "resource-explorer-2",
+            endpoint_prefix: "resource-explorer-2",
+            api_version: "2022-07-28",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "tagging" => Some(ServiceDefinition {
+            signing_name: "tagging",
+            endpoint_prefix: "tagging",
+            api_version: "2017-01-26",
+            protocol: Protocol::Json,
+            target_prefix: Some("ResourceGroupsTaggingAPI_20170126"),
+            is_global: false,
+        }),
+        "ce" => Some(ServiceDefinition {
+            signing_name: "ce",
+            endpoint_prefix: "ce",
+            api_version: "2017-10-25",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSInsightsIndexService"),
+            is_global: true,
+        }),
+        "glue" => Some(ServiceDefinition {
+            signing_name: "glue",
+            endpoint_prefix: "glue",
+            api_version: "2017-03-31",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSGlue"),
+            is_global: false,
+        }),
         "athena" => Some(ServiceDefinition {
             signing_name: "athena",
             endpoint_prefix: "athena",
@@ -319,13 +496,211 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("AmazonAthena"),
             is_global: false,
         }),
-        _ => None,
+        _ => USER_SERVICES.get().and_then(|services| services.get(name)).cloned(),
+    }
+}
+
+/// Services declared by user-defined resource files
+static USER_SERVICES: OnceLock<HashMap<&'static str, ServiceDefinition>> = OnceLock::new();
+
+/// Register the services of user-defined resources (set once at startup)
+pub fn set_user_services(services: HashMap<&'static str, ServiceDefinition>) {
+    let _ = USER_SERVICES.set(services);
+}
+
+/// Proxy from the `proxy_url` config option. HTTP_PROXY/HTTPS_PROXY/NO_PROXY
+/// are honoured by every client already; this overrides them (minus NO_PROXY).
+static PROXY_URL: OnceLock<String> = OnceLock::new();
+
+/// Per-service endpoints from the `endpoints` config map, keyed by endpoint
+/// prefix or signing name (e.g. "s3", "dynamodb", "elasticloadbalancing")
+static SERVICE_ENDPOINTS: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// Send requests for the given services to custom endpoints. These take
+/// precedence over `--endpoint-url`, which applies to all other services.
+pub fn set_service_endpoints(endpoints: HashMap<String, String>) {
+    let _ = SERVICE_ENDPOINTS.set(endpoints);
+}
+
+/// Regions of S3 buckets already looked up. Bucket names are global, so one
+/// cache serves every profile and client.
+static BUCKET_REGIONS: OnceLock<std::sync::Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Cached region of a bucket
+pub fn cached_bucket_region(bucket: &str) -> Option<String> {
+    let cache = BUCKET_REGIONS.get_or_init(Default::default);
+    cache.lock().ok()?.get(bucket).cloned()
+}
+
+/// Remember the region of a bucket (e.g. from ListBuckets)
+pub fn remember_bucket_region(bucket: &str, region: &str) {
+    let cache = BUCKET_REGIONS.get_or_init(Default::default);
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(bucket.to_string(), region.to_string());
+    }
+}
+
+/// Forget a deleted bucket (its name may be reused in another region)
+pub fn forget_bucket_region(bucket: &str) {
+    if let Some(cache) = BUCKET_REGIONS.get()
+        && let Ok(mut cache) = cache.lock()
+    {
+        cache.remove(bucket);
+    }
+}
+
+/// FIPS / dual-stack endpoint variants (`use_fips_endpoints`, `use_dualstack_endpoints`)
+static ENDPOINT_VARIANTS: OnceLock<(bool, bool)> = OnceLock::new();
+
+/// Build endpoints with FIPS (`ec2-fips.us-east-1.amazonaws.com`) and/or
+/// dual-stack (`ec2.us-east-1.api.aws`) hostnames
+pub fn set_endpoint_variants(fips: bool, dualstack: bool) {
+    let _ = ENDPOINT_VARIANTS.set((fips, dualstack));
+}
+
+/// Default AWS endpoint for a service in a region
+fn aws_endpoint(service: &ServiceDefinition, region: &str, fips: bool, dualstack: bool) -> String {
+    let fips_suffix = if fips { "-fips" } else { "" };
+
+    // Special case for S3 (dual-stack keeps the amazonaws.com domain)
+    if service.signing_name == "s3" {
+        let dualstack_part = if dualstack { ".dualstack" } else { "" };
+        return format!("https://s3{}{}.{}.amazonaws.com", fips_suffix, dualstack_part, region);
+    }
+
+    // Special case for global services
+    if service.is_global {
+        match service.signing_name {
+            "iam" => return format!("https://iam{}.amazonaws.com", fips_suffix),
+            "route53" => return format!("https://route53{}.amazonaws.com", fips_suffix),
+            // CloudFront has no FIPS endpoint
+            "cloudfront" => return "https://cloudfront.amazonaws.com".to_string(),
+            _ => {}
+        }
+    }
+
+    let domain = if dualstack { "api.aws" } else { "amazonaws.com" };
+    format!("https://{}{}.{}.{}", service.endpoint_prefix, fips_suffix, region, domain)
+}
+
+/// Route AWS API and SSO requests through `url`
+pub fn set_proxy_url(url: &str) -> Result<()> {
+    reqwest::Proxy::all(url).map_err(|e| anyhow!("Invalid proxy_url '{}': {}", url, e))?;
+    let _ = PROXY_URL.set(url.to_string());
+    Ok(())
+}
+
+fn configured_proxy() -> Option<reqwest::Proxy> {
+    let proxy = reqwest::Proxy::all(PROXY_URL.get()?).ok()?;
+    Some(proxy.no_proxy(reqwest::NoProxy::from_env()))
+}
+
+/// Async client builder with the configured proxy
+pub fn client_builder() -> reqwest::ClientBuilder {
+    let builder = Client::builder();
+    match configured_proxy() {
+        Some(proxy) => builder.proxy(proxy),
+        None => builder,
+    }
+}
+
+/// Blocking client builder with the configured proxy (SSO requests)
+pub fn blocking_client_builder() -> reqwest::blocking::ClientBuilder {
+    let builder = reqwest::blocking::Client::builder();
+    match configured_proxy() {
+        Some(proxy) => builder.proxy(proxy),
+        None => builder,
+    }
+}
+
+/// A request as handed to a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// What a [`Transport`] got back; header names are lowercase
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Sends the requests of an [`AwsHttpClient`]: over the network with
+/// reqwest, or from recorded responses in tests
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>>;
+
+    /// Send a request whose response is read as it arrives (event streams).
+    /// Only the network transport can; callers fall back to plain requests.
+    fn open_stream(&self, _request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<reqwest::Response>> + Send + '_>> {
+        Box::pin(async { Err(anyhow!("Streaming responses are not supported here")) })
+    }
+}
+
+/// Sends requests with reqwest, through the configured proxy
+#[derive(Default)]
+pub struct ReqwestTransport {
+    /// Built on the first request (loading TLS roots is the slow part of
+    /// creating a client, so startup does not pay for it)
+    client: OnceLock<Client>,
+}
+
+impl ReqwestTransport {
+    fn build(&self, request: HttpRequest) -> Result<reqwest::RequestBuilder> {
+        let client = self
+            .client
+            .get_or_init(|| client_builder().build().unwrap_or_else(|_| Client::new()));
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())?;
+        let mut builder = client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if !request.body.is_empty() {
+            builder = builder.body(request.body);
+        }
+        Ok(builder)
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn open_stream(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<reqwest::Response>> + Send + '_>> {
+        Box::pin(async move { Ok(self.build(request)?.send().await?) })
+    }
+
+    fn send(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>> {
+        Box::pin(async move {
+            let response = self.build(request)?.send().await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+            let body = response.text().await?;
+            Ok(HttpResponse { status, headers, body })
+        })
     }
 }
 
 /// AWS HTTP Client
+#[derive(Clone)]
 pub struct AwsHttpClient {
-    http_client: Client,
+    /// Shared by clones
+    transport: Arc<dyn Transport>,
     credentials: Credentials,
     region: String,
     endpoint_url: Option<String>,
@@ -341,13 +716,19 @@ impl AwsHttpClient {
             endpoint_url
         );
         Self {
-            http_client: Client::new(),
+            transport: super::capture::transport().unwrap_or_else(|| Arc::new(ReqwestTransport::default())),
             credentials,
             region: region.to_string(),
             endpoint_url,
         }
     }
 
+    /// Send requests through `transport` instead of the network
+    #[cfg(test)]
+    pub fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
+    }
+
     /// Update region
     pub fn set_region(&mut self, region: &str) {
         debug!("Switching region to: {}", region);
@@ -363,11 +744,36 @@ impl AwsHttpClient {
         self.credentials = credentials;
     }
 
+    /// When the current credentials expire (None for long-lived keys)
+    pub fn credentials_expiration(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.credentials.expiration
+    }
+
+    /// Endpoint URL for a service by name (used when handing off to external tools)
+    pub fn service_endpoint(&self, service_name: &str) -> Result<String> {
+        let service = get_service(service_name)
+            .ok_or_else(|| anyhow!("Unknown service: {}", service_name))?;
+        Ok(self.get_endpoint(&service))
+    }
+
+    /// Custom endpoint for a service: its `endpoints` config entry, else `--endpoint-url`
+    fn custom_endpoint(&self, service: &ServiceDefinition) -> Option<&str> {
+        SERVICE_ENDPOINTS
+            .get()
+            .and_then(|endpoints| {
+                endpoints
+                    .get(service.endpoint_prefix)
+                    .or_else(|| endpoints.get(service.signing_name))
+            })
+            .or(self.endpoint_url.as_ref())
+            .map(|endpoint| endpoint.trim_end_matches('/'))
+    }
+
     /// Get the endpoint URL for a service
     fn get_endpoint(&self, service: &ServiceDefinition) -> String {
-        // If custom endpoint is set, use it for ALL services (LocalStack, etc.)
-        if let Some(ref endpoint) = self.endpoint_url {
-            return endpoint.clone();
+        // If custom endpoint is set, use it (LocalStack, etc.)
+        if let Some(endpoint) = self.custom_endpoint(service) {
+            return endpoint.to_string();
         }
 
         let region = if service.is_global {
@@ -376,22 +782,8 @@ impl AwsHttpClient {
             &self.region
         };
 
-        // Special case for S3
-        if service.signing_name == "s3" {
-            return format!("https://s3.{}.amazonaws.com", region);
-        }
-
-        // Special case for global services
-        if service.is_global {
-            match service.signing_name {
-                "iam" => return "https://iam.amazonaws.com".to_string(),
-                "route53" => return "https://route53.amazonaws.com".to_string(),
-                "cloudfront" => return "https://cloudfront.amazonaws.com".to_string(),
-                _ => {}
-            }
-        }
-
-        format!("https://{}.{}.amazonaws.com", service.endpoint_prefix, region)
+        let (fips, dualstack) = ENDPOINT_VARIANTS.get().copied().unwrap_or_default();
+        aws_endpoint(service, region, fips, dualstack)
     }
 
     /// Make a Query protocol request (EC2, IAM, RDS, etc.)
@@ -431,6 +823,58 @@ impl AwsHttpClient {
         self.signed_request(&service, "POST", &url, body, None).await
     }
 
+    /// Start a JSON protocol call answered with an event stream (e.g.
+    /// StartLiveTail) on the service's `streaming-` endpoint, returning the
+    /// open response to read events from
+    pub async fn json_event_stream(
+        &self,
+        service_name: &str,
+        target: &str,
+        body: &str,
+    ) -> Result<reqwest::Response> {
+        let service = get_service(service_name)
+            .ok_or_else(|| anyhow!("Unknown service: {}", service_name))?;
+        if self.custom_endpoint(&service).is_some() {
+            return Err(anyhow!("Event streams are not available on custom endpoints"));
+        }
+        let endpoint = self.get_endpoint(&service);
+        let host_prefix = format!("://{}.", service.endpoint_prefix);
+        if !endpoint.contains(&host_prefix) {
+            return Err(anyhow!("No streaming endpoint for {}", endpoint));
+        }
+        let url = format!(
+            "{}/",
+            endpoint.replacen(&host_prefix, &format!("://streaming-{}.", service.endpoint_prefix), 1)
+        );
+
+        let mut headers = HashMap::new();
+        headers.insert(
+            "X-Amz-Target".to_string(),
+            format!("{}.{}", service.target_prefix.unwrap_or(service.signing_name), target),
+        );
+        headers.insert("Content-Type".to_string(), "application/x-amz-json-1.1".to_string());
+
+        let region = self.region.clone();
+        let (request, signed_headers) = self.sign(&service, "POST", &url, body, Some(headers), &region)?;
+        trace!("Opening event stream {} at {}", target, url);
+        let started = std::time::Instant::now();
+        let response = self.transport.open_stream(request).await;
+        let status = response.as_ref().ok().map(|r| r.status().as_u16());
+        let call = ApiCall::new(service.signing_name, "POST", &url, &signed_headers, body, started.elapsed(), status, "");
+        api_log::record(call);
+
+        let response = response?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let request_id = ["x-amzn-requestid", "x-amz-request-id"]
+                .iter()
+                .find_map(|name| response.headers().get(*name)?.to_str().ok().map(String::from));
+            let text = response.text().await.unwrap_or_default();
+            return Err(AwsApiError::new(status, "POST", &url, request_id, text).into());
+        }
+        Ok(response)
+    }
+
     /// Make a JSON protocol request (DynamoDB, ECS, Logs, etc.)
     pub async fn json_request(
         &self,
@@ -507,6 +951,32 @@ impl AwsHttpClient {
         self.signed_request(&service, method, &url, body.unwrap_or(""), None).await
     }
 
+    /// Make a REST-XML request that uses optimistic locking (e.g. CloudFront
+    /// config updates): sends `If-Match` when given and returns the response ETag
+    pub async fn rest_xml_request_with_etag(
+        &self,
+        service_name: &str,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<(String, Option<String>)> {
+        debug!("REST-XML request: service={}, method={}, path={}", service_name, method, path);
+
+        let service = get_service(service_name)
+            .ok_or_else(|| anyhow!("Unknown service: {}", service_name))?;
+
+        let endpoint = self.get_endpoint(&service);
+        let url = format!("{}{}", endpoint, path);
+
+        let headers = if_match.map(|etag| {
+            let mut headers = HashMap::new();
+            headers.insert("If-Match".to_string(), etag.to_string());
+            headers
+        });
+        self.signed_request_with_etag(&service, method, &url, body.unwrap_or(""), headers).await
+    }
+
     /// Make a REST-XML request to a specific S3 bucket region
     /// This is needed because S3 buckets exist in specific regions and
     /// requests must be sent to the correct regional endpoint
@@ -524,52 +994,74 @@ impl AwsHttpClient {
         let service = get_service("s3")
             .ok_or_else(|| anyhow!("Unknown service: s3"))?;
 
-        // Build S3 regional endpoint
-        let endpoint = format!("https://{}.s3.{}.amazonaws.com", bucket, bucket_region);
-        let url = format!("{}{}", endpoint, path);
+        // Build S3 regional endpoint (path-style for custom endpoints)
+        let url = match self.custom_endpoint(&service) {
+            Some(endpoint) => format!("{}/{}{}", endpoint, bucket, path),
+            None => {
+                let (fips, dualstack) = ENDPOINT_VARIANTS.get().copied().unwrap_or_default();
+                let endpoint = aws_endpoint(&service, bucket_region, fips, dualstack);
+                format!("{}{}", endpoint.replacen("https://", &format!("https://{}.", bucket), 1), path)
+            }
+        };
         debug!("URL: {}", url);
 
         self.signed_request_with_region(&service, method, &url, body.unwrap_or(""), None, bucket_region).await
     }
 
-    /// Get the region for an S3 bucket using HEAD request to check x-amz-bucket-region header
+    /// Get the region for an S3 bucket, from the cache or looked up once
     pub async fn get_bucket_region(&self, bucket: &str) -> Result<String> {
+        // Custom S3 endpoints (LocalStack, MinIO) serve every bucket from the client's region
+        if let Some(service) = get_service("s3")
+            && self.custom_endpoint(&service).is_some()
+        {
+            return Ok(self.region.clone());
+        }
+        if let Some(region) = cached_bucket_region(bucket) {
+            return Ok(region);
+        }
+        let region = self.lookup_bucket_region(bucket).await?;
+        remember_bucket_region(bucket, &region);
+        Ok(region)
+    }
+
+    /// Look up the region of an S3 bucket using HEAD request to check x-amz-bucket-region header
+    async fn lookup_bucket_region(&self, bucket: &str) -> Result<String> {
         debug!("Getting bucket region for: {}", bucket);
         
         // Use HEAD request to any S3 endpoint - AWS returns x-amz-bucket-region header
         // even for 301/400 responses, which tells us the correct region
         let url = format!("https://{}.s3.amazonaws.com/", bucket);
         
-        let response = self.http_client
-            .head(&url)
-            .send()
+        let response = self.transport
+            .send(HttpRequest {
+                method: "HEAD".to_string(),
+                url,
+                headers: Vec::new(),
+                body: String::new(),
+            })
             .await?;
         
         // Check x-amz-bucket-region header (present in both success and redirect responses)
-        if let Some(region) = response.headers().get("x-amz-bucket-region") {
-            if let Ok(region_str) = region.to_str() {
-                debug!("Bucket {} is in region {} (from x-amz-bucket-region header)", bucket, region_str);
-                return Ok(region_str.to_string());
-            }
+        if let Some(region_str) = response.header("x-amz-bucket-region") {
+            debug!("Bucket {} is in region {} (from x-amz-bucket-region header)", bucket, region_str);
+            return Ok(region_str.to_string());
         }
         
         // Fallback: if we got a 200, bucket is accessible from us-east-1
-        if response.status().is_success() {
+        if (200..300).contains(&response.status) {
             debug!("Bucket {} accessible from us-east-1 (HEAD succeeded)", bucket);
             return Ok("us-east-1".to_string());
         }
         
         // If we got a redirect, try to parse the region from the Location header or body
-        if response.status() == reqwest::StatusCode::MOVED_PERMANENTLY {
+        if response.status == 301 {
             // Check Location header for region hint
-            if let Some(location) = response.headers().get("location") {
-                if let Ok(loc_str) = location.to_str() {
-                    // Location might be like: https://bucket.s3.us-west-1.amazonaws.com/
-                    // or https://bucket.s3-us-west-1.amazonaws.com/
-                    if let Some(region) = extract_region_from_s3_url(loc_str) {
-                        debug!("Bucket {} is in region {} (from Location header)", bucket, region);
-                        return Ok(region);
-                    }
+            if let Some(loc_str) = response.header("location") {
+                // Location might be like: https://bucket.s3.us-west-1.amazonaws.com/
+                // or https://bucket.s3-us-west-1.amazonaws.com/
+                if let Some(region) = extract_region_from_s3_url(loc_str) {
+                    debug!("Bucket {} is in region {} (from Location header)", bucket, region);
+                    return Ok(region);
                 }
             }
         }
@@ -588,130 +1080,90 @@ impl AwsHttpClient {
         body: &str,
         extra_headers: Option<HashMap<String, String>>,
     ) -> Result<String> {
+        self.signed_request_with_etag(service, method, url, body, extra_headers)
+            .await
+            .map(|(text, _)| text)
+    }
+
+    /// Make a signed request, also returning the response ETag header
+    async fn signed_request_with_etag(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+    ) -> Result<(String, Option<String>)> {
         let region = if service.is_global {
             "us-east-1"
         } else {
             &self.region
         };
+        let response = self.send_signed(service, method, url, body, extra_headers, region).await?;
+        let etag = response.header("etag").map(String::from);
+        Ok((response.body, etag))
+    }
 
-        // Parse URL
-        let parsed_url = url::Url::parse(url)?;
-        let host = parsed_url.host_str().ok_or_else(|| anyhow!("Invalid URL"))?;
-        let path_and_query = if let Some(query) = parsed_url.query() {
-            format!("{}?{}", parsed_url.path(), query)
-        } else {
-            parsed_url.path().to_string()
-        };
-
-        // Build headers
-        let mut headers = vec![
-            ("host".to_string(), host.to_string()),
-        ];
-        
-        if let Some(extra) = &extra_headers {
-            for (k, v) in extra {
-                headers.push((k.to_lowercase(), v.clone()));
-            }
-        }
-
-        // Create identity for signing
-        let creds = aws_credential_types::Credentials::new(
-            &self.credentials.access_key_id,
-            &self.credentials.secret_access_key,
-            self.credentials.session_token.clone(),
-            None,
-            "taws",
-        );
-        let identity: Identity = creds.into();
-        
-        // Create signing params
-        let signing_params = SigningParams::builder()
-            .identity(&identity)
-            .region(region)
-            .name(service.signing_name)
-            .time(SystemTime::now())
-            .settings(SigningSettings::default())
-            .build()?
-            .into();
-
-        // Create signable request
-        // For S3, use UnsignedPayload for GET/DELETE requests without body
-        let is_s3_unsigned = service.signing_name == "s3" && body.is_empty() && (method == "GET" || method == "DELETE");
-        let signable_body = if is_s3_unsigned {
-            SignableBody::UnsignedPayload
-        } else if body.is_empty() {
-            SignableBody::Bytes(&[])
-        } else {
-            SignableBody::Bytes(body.as_bytes())
-        };
-        
-        // S3 requires x-amz-content-sha256 header
-        if is_s3_unsigned {
-            headers.push(("x-amz-content-sha256".to_string(), "UNSIGNED-PAYLOAD".to_string()));
-        }
-
-        let signable_request = SignableRequest::new(
-            method,
-            &path_and_query,
-            headers.iter().map(|(k, v)| (k.as_str(), v.as_str())),
-            signable_body,
-        )?;
-
-        // Sign the request
-        let (signing_instructions, _signature) = sign(signable_request, &signing_params)?.into_parts();
-
-        // Build the actual request
-        let mut request = match method {
-            "GET" => self.http_client.get(url),
-            "POST" => self.http_client.post(url),
-            "PUT" => self.http_client.put(url),
-            "DELETE" => self.http_client.delete(url),
-            "PATCH" => self.http_client.patch(url),
-            _ => return Err(anyhow!("Unsupported HTTP method: {}", method)),
-        };
-
-        // Apply signing headers
-        for (name, value) in signing_instructions.headers() {
-            request = request.header(name.to_string(), value.to_string());
-        }
-        
-        // S3 requires x-amz-content-sha256 header explicitly
-        if is_s3_unsigned {
-            request = request.header("x-amz-content-sha256", "UNSIGNED-PAYLOAD");
-        }
-
-        // Apply extra headers
-        if let Some(extra) = extra_headers {
-            for (k, v) in extra {
-                request = request.header(&k, &v);
-            }
-        }
+    /// Make a signed request with explicit region override
+    /// Used for S3 bucket operations where the bucket may be in a different region
+    async fn signed_request_with_region(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+        region: &str,
+    ) -> Result<String> {
+        self.send_signed(service, method, url, body, extra_headers, region)
+            .await
+            .map(|response| response.body)
+    }
 
-        // Set body if present
-        if !body.is_empty() {
-            request = request.body(body.to_string());
-        }
+    /// Sign a request for `region`, send it and record it in the API log.
+    /// Error statuses become an [`AwsApiError`].
+    async fn send_signed(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+        region: &str,
+    ) -> Result<HttpResponse> {
+        let (request, headers) = self.sign(service, method, url, body, extra_headers, region)?;
 
         // Send request
-        trace!("Sending {} request to {}", method, url);
-        let response = request.send().await?;
-        let status = response.status();
-        let text = response.text().await?;
+        trace!("Sending {} request to {} (region: {})", method, url, region);
+        let started = std::time::Instant::now();
+        let response = match self.transport.send(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                let call = ApiCall::new(service.signing_name, method, url, &headers, body, started.elapsed(), None, &e.to_string());
+                api_log::record(call);
+                return Err(e);
+            }
+        };
+        let call = ApiCall::new(service.signing_name, method, url, &headers, body, started.elapsed(), Some(response.status), &response.body);
+        api_log::record(call);
 
-        debug!("Response status: {}", status);
+        debug!("Response status: {}", response.status);
+        let text = &response.body;
         trace!("Response body (first 2000 chars): {}", &text[..text.len().min(2000)]);
 
+        let status = reqwest::StatusCode::from_u16(response.status)?;
         if !status.is_success() {
             warn!("AWS request failed: status={}, body={}", status, &text[..text.len().min(500)]);
-            return Err(anyhow!("AWS request failed ({}): {}", status, text));
+            let request_id = response_request_id(&response);
+            return Err(AwsApiError::new(status, method, url, request_id, response.body).into());
         }
 
-        Ok(text)
+        Ok(response)
     }
 
-    /// Make a signed request with explicit region override
-    /// Used for S3 bucket operations where the bucket may be in a different region
-    async fn signed_request_with_region(
+    /// Sign a request for `region`. Also returns the headers that went into
+    /// the signature, for the API log.
+    fn sign(
         &self,
         service: &ServiceDefinition,
         method: &str,
@@ -719,7 +1171,14 @@ impl AwsHttpClient {
         body: &str,
         extra_headers: Option<HashMap<String, String>>,
         region: &str,
-    ) -> Result<String> {
+    ) -> Result<(HttpRequest, Vec<(String, String)>)> {
+        if crate::demo::enabled() {
+            return Err(anyhow!("Not available in demo mode"));
+        }
+        if !matches!(method, "GET" | "POST" | "PUT" | "DELETE" | "PATCH") {
+            return Err(anyhow!("Unsupported HTTP method: {}", method));
+        }
+
         // Parse URL
         let parsed_url = url::Url::parse(url)?;
         let host = parsed_url.host_str().ok_or_else(|| anyhow!("Invalid URL"))?;
@@ -750,7 +1209,7 @@ impl AwsHttpClient {
         );
         let identity: Identity = creds.into();
         
-        // Create signing params with explicit region
+        // Create signing params
         let signing_params = SigningParams::builder()
             .identity(&identity)
             .region(region)
@@ -761,6 +1220,7 @@ impl AwsHttpClient {
             .into();
 
         // Create signable request
+        // For S3, use UnsignedPayload for GET/DELETE requests without body
         let is_s3_unsigned = service.signing_name == "s3" && body.is_empty() && (method == "GET" || method == "DELETE");
         let signable_body = if is_s3_unsigned {
             SignableBody::UnsignedPayload
@@ -770,6 +1230,7 @@ impl AwsHttpClient {
             SignableBody::Bytes(body.as_bytes())
         };
         
+        // S3 requires x-amz-content-sha256 header
         if is_s3_unsigned {
             headers.push(("x-amz-content-sha256".to_string(), "UNSIGNED-PAYLOAD".to_string()));
         }
@@ -784,112 +1245,90 @@ impl AwsHttpClient {
         // Sign the request
         let (signing_instructions, _signature) = sign(signable_request, &signing_params)?.into_parts();
 
-        // Build the actual request
-        let mut request = match method {
-            "GET" => self.http_client.get(url),
-            "POST" => self.http_client.post(url),
-            "PUT" => self.http_client.put(url),
-            "DELETE" => self.http_client.delete(url),
-            "PATCH" => self.http_client.patch(url),
-            _ => return Err(anyhow!("Unsupported HTTP method: {}", method)),
+        // Build the actual request: signing headers, then the S3 payload
+        // header and the extra headers
+        let mut request = HttpRequest {
+            method: method.to_string(),
+            url: url.to_string(),
+            headers: signing_instructions
+                .headers()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.to_string(),
         };
-
-        // Apply signing headers
-        for (name, value) in signing_instructions.headers() {
-            request = request.header(name.to_string(), value.to_string());
-        }
-        
         if is_s3_unsigned {
-            request = request.header("x-amz-content-sha256", "UNSIGNED-PAYLOAD");
+            request.headers.push(("x-amz-content-sha256".to_string(), "UNSIGNED-PAYLOAD".to_string()));
         }
-
-        // Apply extra headers
         if let Some(extra) = extra_headers {
-            for (k, v) in extra {
-                request = request.header(&k, &v);
-            }
-        }
-
-        // Set body if present
-        if !body.is_empty() {
-            request = request.body(body.to_string());
-        }
-
-        // Send request
-        trace!("Sending {} request to {} (region: {})", method, url, region);
-        let response = request.send().await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        debug!("Response status: {}", status);
-        trace!("Response body (first 2000 chars): {}", &text[..text.len().min(2000)]);
-
-        if !status.is_success() {
-            warn!("AWS request failed: status={}, body={}", status, &text[..text.len().min(500)]);
-            return Err(anyhow!("AWS request failed ({}): {}", status, text));
+            request.headers.extend(extra);
         }
-
-        Ok(text)
+        Ok((request, headers))
     }
 }
 
-/// Parse XML response to JSON using quick-xml
-pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
+/// Parse the rest of the current element (after its start tag) into JSON:
+/// text for leaf elements, an object of children otherwise (repeated
+/// children become arrays)
+fn parse_xml_element(reader: &mut quick_xml::Reader<&[u8]>) -> Result<serde_json::Value> {
     use quick_xml::events::Event;
-    use quick_xml::Reader;
     use serde_json::{Map, Value};
 
-    fn parse_element(reader: &mut Reader<&[u8]>) -> Result<Value> {
-        let mut map: Map<String, Value> = Map::new();
-        let mut buf = Vec::new();
-        let mut current_text = String::new();
-
-        loop {
-            match reader.read_event_into(&mut buf) {
-                Ok(Event::Start(e)) => {
-                    let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    let child_value = parse_element(reader)?;
-
-                    // Handle duplicate keys by converting to array
-                    if let Some(existing) = map.get_mut(&tag_name) {
-                        match existing {
-                            Value::Array(arr) => arr.push(child_value),
-                            _ => {
-                                let old = existing.take();
-                                *existing = Value::Array(vec![old, child_value]);
-                            }
+    let mut map: Map<String, Value> = Map::new();
+    let mut buf = Vec::new();
+    let mut current_text = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                let child_value = parse_xml_element(reader)?;
+
+                // Handle duplicate keys by converting to array
+                if let Some(existing) = map.get_mut(&tag_name) {
+                    match existing {
+                        Value::Array(arr) => arr.push(child_value),
+                        _ => {
+                            let old = existing.take();
+                            *existing = Value::Array(vec![old, child_value]);
                         }
-                    } else {
-                        map.insert(tag_name, child_value);
-                    }
-                }
-                Ok(Event::Text(e)) => {
-                    let text = e.unescape().unwrap_or_default().trim().to_string();
-                    if !text.is_empty() {
-                        current_text = text;
                     }
+                } else {
+                    map.insert(tag_name, child_value);
                 }
-                Ok(Event::End(_)) => {
-                    break;
-                }
-                Ok(Event::Empty(e)) => {
-                    let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    map.insert(tag_name, Value::Null);
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().trim().to_string();
+                if !text.is_empty() {
+                    current_text = text;
                 }
-                Ok(Event::Eof) => break,
-                Err(e) => return Err(anyhow!("XML parse error: {}", e)),
-                _ => {}
             }
-            buf.clear();
+            Ok(Event::End(_)) => {
+                break;
+            }
+            Ok(Event::Empty(e)) => {
+                let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                map.insert(tag_name, Value::Null);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(anyhow!("XML parse error: {}", e)),
+            _ => {}
         }
+        buf.clear();
+    }
 
-        // If we only collected text and no child elements, return the text
-        if map.is_empty() && !current_text.is_empty() {
-            Ok(Value::String(current_text))
-        } else {
-            Ok(Value::Object(map))
-        }
+    // If we only collected text and no child elements, return the text
+    if map.is_empty() && !current_text.is_empty() {
+        Ok(Value::String(current_text))
+    } else {
+        Ok(Value::Object(map))
     }
+}
+
+/// Parse XML response to JSON using quick-xml
+pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+    use serde_json::{Map, Value};
 
     let mut reader = Reader::from_str(xml);
     reader.config_mut().trim_text(true);
@@ -901,7 +1340,7 @@ pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(e)) => {
                 let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                let child_value = parse_element(&mut reader)?;
+                let child_value = parse_xml_element(&mut reader)?;
                 root_map.insert(tag_name, child_value);
             }
             Ok(Event::Eof) => break,
@@ -913,3 +1352,111 @@ pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
 
     Ok(Value::Object(root_map))
 }
+
+/// Stream the elements at `path` (tag names from the root, `*` for any) out of
+/// an XML response, handing each to `f` as JSON (shaped as by `xml_to_json`)
+/// as soon as it is read. Only one item is held at a time, so large lists
+/// (thousands of instances) don't build the whole document first.
+pub fn xml_for_each(xml: &str, path: &[&str], mut f: impl FnMut(serde_json::Value)) -> Result<()> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut stack: Vec<String> = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                stack.push(String::from_utf8_lossy(e.name().as_ref()).to_string());
+                let matches = stack.len() == path.len()
+                    && path.iter().zip(&stack).all(|(want, tag)| *want == "*" || want == tag);
+                if matches {
+                    // Consumes the element through its end tag
+                    f(parse_xml_element(&mut reader)?);
+                    stack.pop();
+                }
+            }
+            Ok(Event::End(_)) => {
+                stack.pop();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(anyhow!("XML parse error: {}", e)),
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_body_fields() {
+        let xml = "<ErrorResponse><Error><Code>AccessDenied</Code><Message>Not allowed</Message></Error></ErrorResponse>";
+        let error = AwsApiError::new(reqwest::StatusCode::FORBIDDEN, "POST", "https://iam.amazonaws.com/", None, xml.to_string());
+        assert_eq!(error.code().as_deref(), Some("AccessDenied"));
+        assert_eq!(error.message().as_deref(), Some("Not allowed"));
+
+        let json = r#"{"__type":"com.amazonaws.dynamodb.v20120810#ResourceNotFoundException","message":"Table not found"}"#;
+        let error = AwsApiError::new(reqwest::StatusCode::BAD_REQUEST, "POST", "https://dynamodb", None, json.to_string());
+        assert_eq!(error.code().as_deref(), Some("ResourceNotFoundException"));
+        assert_eq!(error.message().as_deref(), Some("Table not found"));
+        assert!(error.to_string().starts_with("AWS request failed (400 Bad Request)"));
+    }
+
+    #[test]
+    fn test_bucket_region_cache() {
+        assert_eq!(cached_bucket_region("taws-test-cache"), None);
+        remember_bucket_region("taws-test-cache", "eu-west-2");
+        assert_eq!(cached_bucket_region("taws-test-cache").as_deref(), Some("eu-west-2"));
+        forget_bucket_region("taws-test-cache");
+        assert_eq!(cached_bucket_region("taws-test-cache"), None);
+    }
+
+    #[test]
+    fn test_endpoint_variants() {
+        let ec2 = get_service("ec2").unwrap();
+        assert_eq!(aws_endpoint(&ec2, "us-east-1", false, false), "https://ec2.us-east-1.amazonaws.com");
+        assert_eq!(aws_endpoint(&ec2, "us-east-1", true, false), "https://ec2-fips.us-east-1.amazonaws.com");
+        assert_eq!(aws_endpoint(&ec2, "us-east-1", false, true), "https://ec2.us-east-1.api.aws");
+        assert_eq!(aws_endpoint(&ec2, "us-east-1", true, true), "https://ec2-fips.us-east-1.api.aws");
+
+        let s3 = get_service("s3").unwrap();
+        assert_eq!(aws_endpoint(&s3, "us-west-2", true, true), "https://s3-fips.dualstack.us-west-2.amazonaws.com");
+
+        let iam = get_service("iam").unwrap();
+        assert_eq!(aws_endpoint(&iam, "us-east-1", true, false), "https://iam-fips.amazonaws.com");
+    }
+
+    #[test]
+    fn test_xml_for_each() {
+        let xml = r#"<DescribeInstancesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+            <requestId>r-1</requestId>
+            <reservationSet>
+                <item><instancesSet>
+                    <item><instanceId>i-1</instanceId><tagSet><item><key>Name</key><value>web</value></item></tagSet></item>
+                    <item><instanceId>i-2</instanceId></item>
+                </instancesSet></item>
+                <item><instancesSet><item><instanceId>i-3</instanceId></item></instancesSet></item>
+            </reservationSet>
+        </DescribeInstancesResponse>"#;
+
+        let mut instances = Vec::new();
+        xml_for_each(xml, &["*", "reservationSet", "item", "instancesSet", "item"], |i| instances.push(i)).unwrap();
+        let ids: Vec<&str> = instances.iter().map(|i| i["instanceId"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["i-1", "i-2", "i-3"]);
+        // Items are shaped as by xml_to_json
+        let full = xml_to_json(xml).unwrap();
+        assert_eq!(instances[0], full["DescribeInstancesResponse"]["reservationSet"]["item"][0]["instancesSet"]["item"][0]);
+
+        let mut none = 0;
+        xml_for_each(xml, &["*", "missingSet", "item"], |_| none += 1).unwrap();
+        assert_eq!(none, 0);
+    }
+}
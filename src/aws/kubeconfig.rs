@@ -0,0 +1,172 @@
+//! Kubeconfig export for EKS clusters
+//!
+//! Writes (or updates) a kubeconfig entry for an EKS cluster, equivalent to
+//! `aws eks update-kubeconfig`. Authentication uses an exec credential block
+//! that calls `aws eks get-token` with the active profile and region.
+
+use anyhow::{anyhow, Result};
+use serde_yaml::{Mapping, Value};
+use std::path::PathBuf;
+
+/// EKS cluster details needed to build a kubeconfig entry
+pub struct EksClusterInfo<'a> {
+    pub name: &'a str,
+    pub arn: &'a str,
+    pub endpoint: &'a str,
+    pub certificate_authority: &'a str,
+}
+
+/// Resolve the kubeconfig path ($KUBECONFIG first entry, else ~/.kube/config)
+pub fn kubeconfig_path() -> Result<PathBuf> {
+    let env_path = std::env::var_os("KUBECONFIG")
+        .and_then(|paths| std::env::split_paths(&paths).next())
+        .filter(|p| !p.as_os_str().is_empty());
+    if let Some(path) = env_path {
+        return Ok(path);
+    }
+
+    dirs::home_dir()
+        .map(|h| h.join(".kube").join("config"))
+        .ok_or_else(|| anyhow!("Could not determine home directory"))
+}
+
+/// Add or replace the cluster/user/context entries for an EKS cluster in the
+/// kubeconfig file and make it the current context. Returns the path written.
+pub fn update_kubeconfig(cluster: &EksClusterInfo, region: &str, profile: &str) -> Result<PathBuf> {
+    let path = kubeconfig_path()?;
+
+    let mut doc = if path.exists() {
+        let content = std::fs::read_to_string(&path)?;
+        if content.trim().is_empty() {
+            Value::Mapping(Mapping::new())
+        } else {
+            serde_yaml::from_str(&content)
+                .map_err(|e| anyhow!("Failed to parse {}: {}", path.display(), e))?
+        }
+    } else {
+        Value::Mapping(Mapping::new())
+    };
+
+    merge_cluster_entry(&mut doc, cluster, region, profile)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_yaml::to_string(&doc)?)?;
+
+    Ok(path)
+}
+
+/// Merge the EKS cluster entries into a parsed kubeconfig document
+fn merge_cluster_entry(doc: &mut Value, cluster: &EksClusterInfo, region: &str, profile: &str) -> Result<()> {
+    let root = doc
+        .as_mapping_mut()
+        .ok_or_else(|| anyhow!("Kubeconfig is not a YAML mapping"))?;
+
+    root.entry("apiVersion".into()).or_insert_with(|| "v1".into());
+    root.entry("kind".into()).or_insert_with(|| "Config".into());
+    root.entry("preferences".into()).or_insert_with(|| Value::Mapping(Mapping::new()));
+
+    let mut cluster_data = Mapping::new();
+    cluster_data.insert("server".into(), cluster.endpoint.into());
+    cluster_data.insert("certificate-authority-data".into(), cluster.certificate_authority.into());
+    let mut cluster_entry = Mapping::new();
+    cluster_entry.insert("name".into(), cluster.arn.into());
+    cluster_entry.insert("cluster".into(), Value::Mapping(cluster_data));
+
+    let args = [
+        "--region", region,
+        "eks", "get-token",
+        "--cluster-name", cluster.name,
+        "--output", "json",
+    ];
+    let mut exec = Mapping::new();
+    exec.insert("apiVersion".into(), "client.authentication.k8s.io/v1beta1".into());
+    exec.insert("command".into(), "aws".into());
+    exec.insert("args".into(), Value::Sequence(args.into_iter().map(Value::from).collect()));
+    if profile != "default" {
+        let mut env_var = Mapping::new();
+        env_var.insert("name".into(), "AWS_PROFILE".into());
+        env_var.insert("value".into(), profile.into());
+        exec.insert("env".into(), Value::Sequence(vec![Value::Mapping(env_var)]));
+    }
+    let mut user = Mapping::new();
+    user.insert("exec".into(), Value::Mapping(exec));
+    let mut user_entry = Mapping::new();
+    user_entry.insert("name".into(), cluster.arn.into());
+    user_entry.insert("user".into(), Value::Mapping(user));
+
+    let mut context = Mapping::new();
+    context.insert("cluster".into(), cluster.arn.into());
+    context.insert("user".into(), cluster.arn.into());
+    let mut context_entry = Mapping::new();
+    context_entry.insert("name".into(), cluster.arn.into());
+    context_entry.insert("context".into(), Value::Mapping(context));
+
+    upsert_named(root, "clusters", cluster.arn, Value::Mapping(cluster_entry))?;
+    upsert_named(root, "users", cluster.arn, Value::Mapping(user_entry))?;
+    upsert_named(root, "contexts", cluster.arn, Value::Mapping(context_entry))?;
+    root.insert("current-context".into(), cluster.arn.into());
+
+    Ok(())
+}
+
+/// Replace the entry with a matching `name` in a kubeconfig list, or append it
+fn upsert_named(root: &mut Mapping, key: &str, name: &str, entry: Value) -> Result<()> {
+    let list = root
+        .entry(key.into())
+        .or_insert_with(|| Value::Sequence(Vec::new()));
+    if list.is_null() {
+        *list = Value::Sequence(Vec::new());
+    }
+    let seq = list
+        .as_sequence_mut()
+        .ok_or_else(|| anyhow!("Kubeconfig '{}' is not a list", key))?;
+
+    match seq.iter_mut().find(|e| e.get("name").and_then(|n| n.as_str()) == Some(name)) {
+        Some(existing) => *existing = entry,
+        None => seq.push(entry),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_cluster() -> EksClusterInfo<'static> {
+        EksClusterInfo {
+            name: "prod",
+            arn: "arn:aws:eks:us-east-1:123456789012:cluster/prod",
+            endpoint: "https://ABC.gr7.us-east-1.eks.amazonaws.com",
+            certificate_authority: "LS0tLS1CRUdJTg==",
+        }
+    }
+
+    #[test]
+    fn test_merge_into_empty_kubeconfig() {
+        let mut doc = Value::Mapping(Mapping::new());
+        merge_cluster_entry(&mut doc, &sample_cluster(), "us-east-1", "work").unwrap();
+
+        let arn = "arn:aws:eks:us-east-1:123456789012:cluster/prod";
+        assert_eq!(doc["current-context"].as_str(), Some(arn));
+        assert_eq!(doc["clusters"][0]["cluster"]["server"].as_str(), Some("https://ABC.gr7.us-east-1.eks.amazonaws.com"));
+        assert_eq!(doc["users"][0]["user"]["exec"]["command"].as_str(), Some("aws"));
+        assert_eq!(doc["users"][0]["user"]["exec"]["env"][0]["value"].as_str(), Some("work"));
+        assert_eq!(doc["contexts"][0]["context"]["cluster"].as_str(), Some(arn));
+    }
+
+    #[test]
+    fn test_merge_replaces_existing_entry_and_keeps_others() {
+        let mut doc: Value = serde_yaml::from_str(
+            "apiVersion: v1\nclusters:\n- name: other\n  cluster:\n    server: https://other\n- name: arn:aws:eks:us-east-1:123456789012:cluster/prod\n  cluster:\n    server: https://stale\ncurrent-context: other\n",
+        ).unwrap();
+        merge_cluster_entry(&mut doc, &sample_cluster(), "us-east-1", "default").unwrap();
+
+        let clusters = doc["clusters"].as_sequence().unwrap();
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0]["name"].as_str(), Some("other"));
+        assert_eq!(clusters[1]["cluster"]["server"].as_str(), Some("https://ABC.gr7.us-east-1.eks.amazonaws.com"));
+        assert!(doc["users"][0]["user"]["exec"].get("env").is_none());
+    }
+}
@@ -0,0 +1,279 @@
+//! CloudWatch Logs Live Tail (`StartLiveTail`)
+//!
+//! Events are pushed over an `application/vnd.amazon.eventstream` response
+//! as they are ingested, for one stream or a whole log group, instead of
+//! polling GetLogEvents. A session lasts at most three hours.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::http::AwsHttpClient;
+
+/// A log event pushed by a live tail session
+#[derive(Debug, Clone, PartialEq)]
+pub struct TailEvent {
+    pub timestamp: i64,
+    pub stream: String,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub enum Update {
+    Events(Vec<TailEvent>),
+    /// The session ended, on its time limit (None) or an error
+    Closed(Option<String>),
+}
+
+/// A running live tail session; dropping it closes the stream
+pub struct LiveTail {
+    updates: mpsc::UnboundedReceiver<Update>,
+    task: JoinHandle<()>,
+}
+
+impl LiveTail {
+    /// Start tailing `streams` of a log group, or all of its streams if empty
+    pub async fn start(http: &AwsHttpClient, log_group_arn: &str, streams: &[String]) -> Result<Self> {
+        let mut request = json!({ "logGroupIdentifiers": [log_group_arn] });
+        if !streams.is_empty() {
+            request["logStreamNames"] = json!(streams);
+        }
+        let mut response = http.json_event_stream("logs", "StartLiveTail", &request.to_string()).await?;
+
+        let (sender, updates) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            let mut decoder = Decoder::default();
+            let reason = loop {
+                match response.chunk().await {
+                    Ok(Some(bytes)) => {
+                        decoder.push(&bytes);
+                        match decoder.updates() {
+                            Ok(updates) => {
+                                for update in updates {
+                                    if sender.send(update).is_err() {
+                                        return;
+                                    }
+                                }
+                            }
+                            Err(e) => break Some(e.to_string()),
+                        }
+                    }
+                    Ok(None) => break None,
+                    Err(e) => break Some(e.to_string()),
+                }
+            };
+            let _ = sender.send(Update::Closed(reason));
+        });
+        Ok(Self { updates, task })
+    }
+
+    /// Updates received since the last call
+    pub fn drain(&mut self) -> Vec<Update> {
+        let mut updates = Vec::new();
+        while let Ok(update) = self.updates.try_recv() {
+            updates.push(update);
+        }
+        updates
+    }
+}
+
+impl Drop for LiveTail {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// ARN StartLiveTail needs for a log group, in the partition of `caller_arn`
+pub fn log_group_arn(caller_arn: &str, region: &str, account: &str, log_group: &str) -> String {
+    let partition = caller_arn.split(':').nth(1).filter(|p| p.starts_with("aws")).unwrap_or("aws");
+    format!("arn:{}:logs:{}:{}:log-group:{}", partition, region, account, log_group)
+}
+
+/// A decoded event stream message
+struct Message {
+    headers: HashMap<String, String>,
+    payload: Vec<u8>,
+}
+
+/// Splits event stream bytes into messages: a prelude (total and header
+/// lengths, CRC), headers, payload and a trailing CRC. Checksums are not
+/// verified; TLS already protects the data.
+#[derive(Default)]
+struct Decoder {
+    buffer: Vec<u8>,
+}
+
+impl Decoder {
+    fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Complete messages received so far
+    fn next_message(&mut self) -> Result<Option<Message>> {
+        if self.buffer.len() < 12 {
+            return Ok(None);
+        }
+        let total = u32::from_be_bytes(self.buffer[0..4].try_into()?) as usize;
+        let headers_len = u32::from_be_bytes(self.buffer[4..8].try_into()?) as usize;
+        if total < 16 + headers_len {
+            return Err(anyhow!("Malformed event stream message"));
+        }
+        if self.buffer.len() < total {
+            return Ok(None);
+        }
+        let message: Vec<u8> = self.buffer.drain(..total).collect();
+        Ok(Some(Message {
+            headers: parse_headers(&message[12..12 + headers_len])?,
+            payload: message[12 + headers_len..total - 4].to_vec(),
+        }))
+    }
+
+    /// Updates in the messages received so far
+    fn updates(&mut self) -> Result<Vec<Update>> {
+        let mut updates = Vec::new();
+        while let Some(message) = self.next_message()? {
+            if let Some(update) = update(&message)? {
+                updates.push(update);
+            }
+        }
+        Ok(updates)
+    }
+}
+
+/// String headers of a message; values of other types are skipped
+fn parse_headers(mut bytes: &[u8]) -> Result<HashMap<String, String>> {
+    let truncated = || anyhow!("Truncated event stream header");
+    let mut headers = HashMap::new();
+    while let Some((&name_len, rest)) = bytes.split_first() {
+        let name = rest.get(..name_len as usize).ok_or_else(truncated)?;
+        let name = String::from_utf8_lossy(name).to_string();
+        let (&kind, rest) = rest[name_len as usize..].split_first().ok_or_else(truncated)?;
+        let (value_len, rest) = match kind {
+            0 | 1 => (0, rest),
+            2 => (1, rest),
+            3 => (2, rest),
+            4 => (4, rest),
+            5 | 8 => (8, rest),
+            9 => (16, rest),
+            6 | 7 => {
+                let len = rest.get(..2).ok_or_else(truncated)?;
+                (u16::from_be_bytes([len[0], len[1]]) as usize, &rest[2..])
+            }
+            _ => return Err(anyhow!("Unknown event stream header type {}", kind)),
+        };
+        let value = rest.get(..value_len).ok_or_else(truncated)?;
+        if kind == 7 {
+            headers.insert(name, String::from_utf8_lossy(value).to_string());
+        }
+        bytes = &rest[value_len..];
+    }
+    Ok(headers)
+}
+
+/// What a StartLiveTail message means for the tail
+fn update(message: &Message) -> Result<Option<Update>> {
+    let header = |name: &str| message.headers.get(name).map(String::as_str).unwrap_or_default();
+    match header(":message-type") {
+        "event" if header(":event-type") == "sessionUpdate" => {
+            let payload: Value = serde_json::from_slice(&message.payload)?;
+            let events = payload
+                .get("sessionResults")
+                .and_then(|v| v.as_array())
+                .map(|results| {
+                    results
+                        .iter()
+                        .map(|event| TailEvent {
+                            timestamp: event.get("timestamp").and_then(|v| v.as_i64()).unwrap_or(0),
+                            stream: event.get("logStreamName").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                            message: event.get("message").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            Ok(Some(Update::Events(events)))
+        }
+        "event" => Ok(None),
+        // Sessions end after three hours
+        "exception" if header(":exception-type") == "SessionTimeoutException" => Ok(Some(Update::Closed(None))),
+        "exception" => {
+            let payload: Value = serde_json::from_slice(&message.payload).unwrap_or_default();
+            let text = payload.get("message").and_then(|v| v.as_str()).unwrap_or_default();
+            Err(anyhow!("{}: {}", header(":exception-type"), text))
+        }
+        "error" => Err(anyhow!("{}: {}", header(":error-code"), header(":error-message"))),
+        other => Err(anyhow!("Unexpected event stream message type {:?}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An event stream message with string headers and zeroed checksums
+    fn encode(headers: &[(&str, &str)], payload: &str) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend_from_slice(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend_from_slice(value.as_bytes());
+        }
+        let total = 16 + encoded_headers.len() + payload.len();
+        let mut message = Vec::new();
+        message.extend_from_slice(&(total as u32).to_be_bytes());
+        message.extend_from_slice(&(encoded_headers.len() as u32).to_be_bytes());
+        message.extend_from_slice(&[0; 4]);
+        message.extend_from_slice(&encoded_headers);
+        message.extend_from_slice(payload.as_bytes());
+        message.extend_from_slice(&[0; 4]);
+        message
+    }
+
+    #[test]
+    fn test_decode_live_tail_messages() {
+        let mut bytes = encode(&[(":message-type", "event"), (":event-type", "sessionStart")], "{}");
+        bytes.extend(encode(
+            &[(":message-type", "event"), (":event-type", "sessionUpdate")],
+            r#"{"sessionResults":[{"logStreamName":"web-1","message":"GET /health 200","timestamp":1738400000000}]}"#,
+        ));
+
+        // Messages split across chunks are put back together
+        let mut decoder = Decoder::default();
+        let (first, second) = bytes.split_at(bytes.len() - 10);
+        decoder.push(first);
+        assert!(decoder.updates().unwrap().is_empty());
+        decoder.push(second);
+        let updates = decoder.updates().unwrap();
+        let [Update::Events(events)] = updates.as_slice() else {
+            panic!("{:?}", updates);
+        };
+        assert_eq!(
+            events,
+            &[TailEvent {
+                timestamp: 1738400000000,
+                stream: "web-1".to_string(),
+                message: "GET /health 200".to_string(),
+            }]
+        );
+
+        decoder.push(&encode(
+            &[(":message-type", "exception"), (":exception-type", "SessionTimeoutException")],
+            r#"{"message":"Session timed out"}"#,
+        ));
+        assert!(matches!(decoder.updates().unwrap().as_slice(), [Update::Closed(None)]));
+        decoder.push(&encode(
+            &[(":message-type", "exception"), (":exception-type", "SessionStreamingException")],
+            r#"{"message":"Internal failure"}"#,
+        ));
+        let error = decoder.updates().unwrap_err().to_string();
+        assert_eq!(error, "SessionStreamingException: Internal failure");
+
+        assert_eq!(
+            log_group_arn("arn:aws-cn:sts::123456789012:assumed-role/Admin/alice", "cn-north-1", "123456789012", "/ecs/web"),
+            "arn:aws-cn:logs:cn-north-1:123456789012:log-group:/ecs/web"
+        );
+    }
+}
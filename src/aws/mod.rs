@@ -1,5 +1,11 @@
+pub mod api_log;
+pub mod capture;
 pub mod client;
+pub mod costs;
 pub mod credentials;
 pub mod http;
+pub mod kubeconfig;
+pub mod live_tail;
 pub mod profiles;
 pub mod sso;
+pub mod ssm_session;
@@ -48,7 +48,7 @@ pub fn list_profiles() -> Result<Vec<String>> {
     Ok(profiles)
 }
 
-/// List common AWS regions
+/// List common AWS regions, used until DescribeRegions returns the account's own
 pub fn list_regions() -> Vec<String> {
     vec![
         "us-east-1".to_string(),
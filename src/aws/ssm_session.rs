@@ -0,0 +1,109 @@
+//! SSM Session Manager sessions
+//!
+//! Starts a session with the StartSession API and hands the returned stream
+//! URL and token to `session-manager-plugin`, the same way
+//! `aws ssm start-session` does. The plugin must be installed and on PATH.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::client::AwsClients;
+
+/// Name of the Session Manager plugin executable
+pub const PLUGIN: &str = "session-manager-plugin";
+
+/// Port forwarding request parsed from `[via-instance] local[:remote]`
+#[derive(Debug, PartialEq)]
+pub struct PortForwardSpec {
+    /// Instance to tunnel through when forwarding to a remote host (e.g. RDS)
+    pub via: Option<String>,
+    pub local_port: u16,
+    pub remote_port: u16,
+}
+
+/// Parse port forwarding input such as `8080:80`, `5432` or `i-0abc 15432:5432`
+pub fn parse_port_forward_spec(input: &str) -> Result<PortForwardSpec> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    let (via, ports) = match parts.as_slice() {
+        [ports] => (None, *ports),
+        [via, ports] => (Some(via.to_string()), *ports),
+        _ => return Err(anyhow!("Expected '[instance-id] local:remote', got '{}'", input)),
+    };
+
+    let parse_port = |p: &str| {
+        p.parse::<u16>()
+            .ok()
+            .filter(|port| *port > 0)
+            .ok_or_else(|| anyhow!("Invalid port: '{}'", p))
+    };
+    let (local_port, remote_port) = match ports.split_once(':') {
+        Some((local, remote)) => (parse_port(local)?, parse_port(remote)?),
+        None => {
+            let port = parse_port(ports)?;
+            (port, port)
+        }
+    };
+
+    Ok(PortForwardSpec { via, local_port, remote_port })
+}
+
+/// Start a session against `target` and return the arguments for
+/// `session-manager-plugin`. `document` optionally selects an SSM document
+/// (e.g. port forwarding) and its parameters.
+pub async fn start_session(
+    clients: &AwsClients,
+    target: &str,
+    document: Option<(&str, Value)>,
+) -> Result<Vec<String>> {
+    let mut request = json!({ "Target": target });
+    if let Some((name, parameters)) = document {
+        request["DocumentName"] = json!(name);
+        request["Parameters"] = parameters;
+    }
+
+    let response = clients
+        .http
+        .json_request("ssm", "StartSession", &request.to_string())
+        .await?;
+    let session: Value = serde_json::from_str(&response)?;
+    if session.get("SessionId").is_none() {
+        return Err(anyhow!("StartSession returned no session for {}", target));
+    }
+
+    let endpoint = clients.http.service_endpoint("ssm")?;
+
+    Ok(vec![
+        session.to_string(),
+        clients.region.clone(),
+        "StartSession".to_string(),
+        clients.profile.clone(),
+        request.to_string(),
+        endpoint,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port_forward_spec() {
+        let spec = parse_port_forward_spec("8080:80").unwrap();
+        assert_eq!(spec, PortForwardSpec { via: None, local_port: 8080, remote_port: 80 });
+
+        let spec = parse_port_forward_spec("5432").unwrap();
+        assert_eq!((spec.local_port, spec.remote_port), (5432, 5432));
+
+        let spec = parse_port_forward_spec(" i-0abc  15432:5432 ").unwrap();
+        assert_eq!(spec.via.as_deref(), Some("i-0abc"));
+        assert_eq!((spec.local_port, spec.remote_port), (15432, 5432));
+    }
+
+    #[test]
+    fn test_parse_port_forward_spec_rejects_bad_input() {
+        assert!(parse_port_forward_spec("").is_err());
+        assert!(parse_port_forward_spec("http:80").is_err());
+        assert!(parse_port_forward_spec("0:80").is_err());
+        assert!(parse_port_forward_spec("a b c").is_err());
+    }
+}
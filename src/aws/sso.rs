@@ -24,6 +24,8 @@ pub struct SsoConfig {
     pub sso_role_name: String,
     pub sso_start_url: String,
     pub sso_region: String,
+    /// Legacy profile with `sso_start_url` set directly (no `[sso-session]` section)
+    pub legacy: bool,
 }
 
 /// OIDC client registration response
@@ -105,7 +107,7 @@ pub fn check_existing_token(config: &SsoConfig) -> Option<String> {
 /// Start the SSO OIDC device authorization flow
 /// Returns device authorization info for UI display
 pub fn start_device_authorization(config: &SsoConfig) -> Result<DeviceAuthInfo> {
-    let client = reqwest::blocking::Client::builder()
+    let client = super::http::blocking_client_builder()
         .timeout(Duration::from_secs(30))
         .build()?;
 
@@ -224,7 +226,7 @@ pub fn poll_for_token(config: &SsoConfig) -> Result<Option<String>> {
         .and_then(|v| v.as_str())
         .ok_or_else(|| anyhow!("deviceCode not found"))?;
 
-    let http_client = reqwest::blocking::Client::builder()
+    let http_client = super::http::blocking_client_builder()
         .timeout(Duration::from_secs(10))
         .build()?;
 
@@ -298,12 +300,7 @@ fn cache_sso_token(config: &SsoConfig, access_token: &str, expires_in: i64) -> R
         start_url: config.sso_start_url.clone(),
     };
 
-    // Cache file name is SHA1 of start_url (compatible with AWS CLI for both legacy and new format)
-    let mut hasher = Sha1::new();
-    hasher.update(config.sso_start_url.as_bytes());
-    let hash = hasher.finalize();
-    let cache_file_name = format!("{:x}.json", hash);
-    let cache_path = cache_dir.join(&cache_file_name);
+    let cache_path = cache_dir.join(token_cache_file_name(config));
 
     fs::write(&cache_path, serde_json::to_string_pretty(&cached_token)?)?;
     debug!("Cached SSO token to {:?}", cache_path);
@@ -313,7 +310,7 @@ fn cache_sso_token(config: &SsoConfig, access_token: &str, expires_in: i64) -> R
 
 /// Get role credentials using SSO access token
 pub fn get_role_credentials(config: &SsoConfig, access_token: &str) -> Result<Credentials> {
-    let client = reqwest::blocking::Client::builder()
+    let client = super::http::blocking_client_builder()
         .timeout(Duration::from_secs(10))
         .build()?;
 
@@ -361,10 +358,17 @@ pub fn get_role_credentials(config: &SsoConfig, access_token: &str) -> Result<Cr
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
+    // Milliseconds since the epoch
+    let expiration = role_creds
+        .get("expiration")
+        .and_then(|v| v.as_i64())
+        .and_then(chrono::DateTime::from_timestamp_millis);
+
     Ok(Credentials {
         access_key_id,
         secret_access_key,
         session_token,
+        expiration,
     })
 }
 
@@ -419,6 +423,7 @@ fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConf
             sso_role_name,
             sso_start_url,
             sso_region,
+            legacy: false,
         });
     }
 
@@ -440,6 +445,7 @@ fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConf
         sso_role_name,
         sso_start_url,
         sso_region,
+        legacy: true,
     })
 }
 
@@ -484,14 +490,10 @@ fn parse_ini_sections(
 pub fn read_cached_token(config: &SsoConfig) -> Option<String> {
     let cache_dir = aws_config_dir().ok()?.join("sso").join("cache");
 
-    // Cache file name is SHA1 of start_url (compatible with AWS CLI for both legacy and new format)
-    let mut hasher = Sha1::new();
-    hasher.update(config.sso_start_url.as_bytes());
-    let hash = hasher.finalize();
-    let cache_file_name = format!("{:x}.json", hash);
-    let cache_path = cache_dir.join(&cache_file_name);
-
-    let content = fs::read_to_string(&cache_path).ok()?;
+    // Earlier taws versions keyed sso-session tokens by start URL as well
+    let content = fs::read_to_string(cache_dir.join(token_cache_file_name(config)))
+        .or_else(|_| fs::read_to_string(cache_dir.join(sha1_file_name(&config.sso_start_url))))
+        .ok()?;
     let cached: CachedToken = serde_json::from_str(&content).ok()?;
 
     // Check expiration
@@ -504,3 +506,136 @@ pub fn read_cached_token(config: &SsoConfig) -> Option<String> {
 
     Some(cached.access_token)
 }
+
+/// A cached SSO access token (from taws or `aws sso login`)
+#[derive(Debug, Clone)]
+pub struct CachedSession {
+    /// `[sso-session]` name or legacy profile name(s) using the token
+    pub names: Vec<String>,
+    pub start_url: String,
+    pub region: String,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub path: std::path::PathBuf,
+}
+
+impl CachedSession {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_none_or(|t| t <= chrono::Utc::now())
+    }
+}
+
+/// List cached SSO tokens, matched to the sessions in ~/.aws/config
+pub fn list_cached_sessions() -> Result<Vec<CachedSession>> {
+    let config_dir = aws_config_dir()?;
+    let content = fs::read_to_string(config_dir.join("config")).unwrap_or_default();
+
+    // Cache file name -> session (or legacy profile) names
+    let mut names: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    for (section, values) in parse_ini_sections(&content) {
+        if let Some(session) = section.strip_prefix("sso-session ") {
+            names.entry(sha1_file_name(session)).or_default().push(session.to_string());
+        } else if let (Some(url), None) = (values.get("sso_start_url"), values.get("sso_session")) {
+            names.entry(sha1_file_name(url)).or_default().push(section);
+        }
+    }
+
+    let Ok(entries) = fs::read_dir(config_dir.join("sso").join("cache")) else {
+        return Ok(Vec::new());
+    };
+    let mut sessions = Vec::new();
+    for path in entries.flatten().map(|e| e.path()) {
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()).map(String::from) else {
+            continue;
+        };
+        // Client registrations share the directory; only tokens have an accessToken
+        let Some(token) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+            .filter(|t| t.get("accessToken").is_some())
+        else {
+            continue;
+        };
+        let field = |name: &str| token.get(name).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        let mut session_names = names.get(&file_name).cloned().unwrap_or_default();
+        session_names.sort();
+        sessions.push(CachedSession {
+            names: session_names,
+            start_url: field("startUrl"),
+            region: field("region"),
+            expires_at: chrono::DateTime::parse_from_rfc3339(&field("expiresAt"))
+                .ok()
+                .map(|t| t.with_timezone(&chrono::Utc)),
+            path,
+        });
+    }
+    sessions.sort_by(|a, b| a.names.cmp(&b.names).then_with(|| a.start_url.cmp(&b.start_url)));
+    Ok(sessions)
+}
+
+/// Delete the cached tokens of an SSO session (or legacy SSO profile).
+/// Returns how many files were removed.
+pub fn logout(name: &str) -> Result<usize> {
+    let mut removed = 0;
+    for session in list_cached_sessions()? {
+        if session.names.iter().any(|n| n == name) {
+            fs::remove_file(&session.path)?;
+            removed += 1;
+        }
+    }
+    debug!("Removed {} cached SSO token(s) for {}", removed, name);
+    Ok(removed)
+}
+
+/// Token cache file name, compatible with the AWS CLI: SHA1 of the session
+/// name for `[sso-session]` profiles, SHA1 of the start URL for legacy ones
+fn token_cache_file_name(config: &SsoConfig) -> String {
+    if config.legacy {
+        sha1_file_name(&config.sso_start_url)
+    } else {
+        sha1_file_name(&config.sso_session)
+    }
+}
+
+fn sha1_file_name(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    format!("{:x}.json", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+[profile modern]
+sso_session = corp
+sso_account_id = 111111111111
+sso_role_name = Admin
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = us-east-1
+
+[profile legacy]
+sso_start_url = https://old.awsapps.com/start
+sso_region = eu-west-1
+sso_account_id = 222222222222
+sso_role_name = ReadOnly
+"#;
+
+    #[test]
+    fn test_parse_sso_config_formats() {
+        let modern = parse_sso_config_from_content("modern", CONFIG).unwrap();
+        assert_eq!(modern.sso_session, "corp");
+        assert_eq!(modern.sso_start_url, "https://corp.awsapps.com/start");
+        assert!(!modern.legacy);
+        assert_eq!(token_cache_file_name(&modern), sha1_file_name("corp"));
+
+        let legacy = parse_sso_config_from_content("legacy", CONFIG).unwrap();
+        assert_eq!(legacy.sso_session, "legacy");
+        assert_eq!(legacy.sso_region, "eu-west-1");
+        assert_eq!(legacy.sso_account_id, "222222222222");
+        assert!(legacy.legacy);
+        assert_eq!(token_cache_file_name(&legacy), sha1_file_name("https://old.awsapps.com/start"));
+    }
+}
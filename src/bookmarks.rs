@@ -0,0 +1,163 @@
+//! Bookmarked items and views plus recently visited views, for the `'` jump list
+//!
+//! Stored in `bookmarks.json` next to the config file, per profile and region.
+
+use crate::app::ParentContext;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Recent views kept per profile and region
+const MAX_RECENT: usize = 15;
+
+/// A view to come back to, with the parent views it was reached through
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+    /// Shown in the jump list, e.g. "S3 Buckets: logs > S3 Objects"
+    pub label: String,
+    pub resource_key: String,
+    /// Parent views, outermost first (the navigation stack, then the parent context)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<ParentContext>,
+    /// Id of the bookmarked item; None for the whole view
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+}
+
+impl Location {
+    /// Same view and item, regardless of the label or the parents' list data
+    pub fn same_place(&self, other: &Location) -> bool {
+        self.resource_key == other.resource_key
+            && self.item_id == other.item_id
+            && self.parents.len() == other.parents.len()
+            && self
+                .parents
+                .iter()
+                .zip(&other.parents)
+                .all(|(a, b)| a.resource_key == b.resource_key && a.display_name == b.display_name)
+    }
+}
+
+/// Bookmarks and recent views, keyed by "profile/region"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Store {
+    #[serde(default)]
+    bookmarks: HashMap<String, Vec<Location>>,
+    #[serde(default)]
+    recent: HashMap<String, Vec<Location>>,
+}
+
+/// Key of a profile and region in the store
+pub fn scope(profile: &str, region: &str) -> String {
+    format!("{}/{}", profile, region)
+}
+
+/// Path of the bookmarks file
+pub fn bookmarks_path() -> PathBuf {
+    if let Some(config_dir) = dirs::config_dir() {
+        return config_dir.join("taws").join("bookmarks.json");
+    }
+    if let Some(home) = dirs::home_dir() {
+        return home.join(".taws").join("bookmarks.json");
+    }
+    PathBuf::from(".taws").join("bookmarks.json")
+}
+
+impl Store {
+    /// Load the store, empty if missing or unreadable
+    pub fn load() -> Self {
+        std::fs::read_to_string(bookmarks_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        // Tests never touch the user's bookmarks
+        if cfg!(test) {
+            return;
+        }
+        let path = bookmarks_path();
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|content| std::fs::write(&path, content));
+        if let Err(e) = result {
+            tracing::warn!("Failed to save bookmarks {:?}: {}", path, e);
+        }
+    }
+
+    /// Bookmarks of a profile and region, oldest first
+    pub fn bookmarks(&self, scope: &str) -> &[Location] {
+        self.bookmarks.get(scope).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Recently visited views of a profile and region, newest first
+    pub fn recent(&self, scope: &str) -> &[Location] {
+        self.recent.get(scope).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Bookmark `location`, or remove the bookmark if it exists. Returns true if added.
+    pub fn toggle_bookmark(&mut self, scope: &str, location: Location) -> bool {
+        let bookmarks = self.bookmarks.entry(scope.to_string()).or_default();
+        let before = bookmarks.len();
+        bookmarks.retain(|b| !b.same_place(&location));
+        let added = bookmarks.len() == before;
+        if added {
+            bookmarks.push(location);
+        }
+        self.save();
+        added
+    }
+
+    /// Delete the bookmark at `index`
+    pub fn remove_bookmark(&mut self, scope: &str, index: usize) {
+        if let Some(bookmarks) = self.bookmarks.get_mut(scope)
+            && index < bookmarks.len()
+        {
+            bookmarks.remove(index);
+            self.save();
+        }
+    }
+
+    /// Record a visit to `location` (moving any earlier visit to the front)
+    pub fn push_recent(&mut self, scope: &str, location: Location) {
+        let recent = self.recent.entry(scope.to_string()).or_default();
+        recent.retain(|r| !r.same_place(&location));
+        recent.insert(0, location);
+        recent.truncate(MAX_RECENT);
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(resource_key: &str, parent: Option<&str>, item_id: Option<&str>) -> Location {
+        Location {
+            label: resource_key.to_string(),
+            resource_key: resource_key.to_string(),
+            parents: parent
+                .map(|name| ParentContext {
+                    resource_key: "s3-buckets".to_string(),
+                    item: serde_json::json!({ "Name": name }),
+                    display_name: name.to_string(),
+                })
+                .into_iter()
+                .collect(),
+            item_id: item_id.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_same_place() {
+        let objects = location("s3-objects", Some("logs"), None);
+        assert!(objects.same_place(&location("s3-objects", Some("logs"), None)));
+        assert!(!objects.same_place(&location("s3-objects", Some("assets"), None)));
+        assert!(!objects.same_place(&location("s3-objects", Some("logs"), Some("a.txt"))));
+        assert!(!objects.same_place(&location("s3-objects", None, None)));
+    }
+}
@@ -0,0 +1,25 @@
+//! System clipboard access for copy actions
+
+use anyhow::{anyhow, Result};
+use std::sync::{Mutex, OnceLock};
+
+/// Clipboard handle kept alive for the whole session - on X11/Wayland the
+/// copied contents are served by this process and vanish when it is dropped
+static CLIPBOARD: OnceLock<Mutex<Option<arboard::Clipboard>>> = OnceLock::new();
+
+/// Copy text to the system clipboard
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let lock = CLIPBOARD.get_or_init(|| Mutex::new(None));
+    let mut guard = lock.lock().map_err(|_| anyhow!("Clipboard lock poisoned"))?;
+
+    let clipboard = match guard.as_mut() {
+        Some(clipboard) => clipboard,
+        None => guard.insert(
+            arboard::Clipboard::new().map_err(|e| anyhow!("Clipboard unavailable: {}", e))?,
+        ),
+    };
+
+    clipboard
+        .set_text(text.to_string())
+        .map_err(|e| anyhow!("Failed to copy to clipboard: {}", e))
+}
@@ -5,9 +5,20 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+/// Regions on the number keys unless `region_shortcuts` is set
+const DEFAULT_REGION_SHORTCUTS: &[&str] = &[
+    "us-east-1",
+    "us-west-2",
+    "eu-west-1",
+    "eu-central-1",
+    "ap-northeast-1",
+    "ap-southeast-1",
+];
+
 /// User configuration stored on disk
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
@@ -22,6 +33,101 @@ pub struct Config {
     /// Last viewed resource type
     #[serde(default)]
     pub last_resource: Option<String>,
+    
+    /// Role assumed when hopping into an Organizations member account
+    #[serde(default)]
+    pub org_role: Option<String>,
+    
+    /// Proxy for AWS API and SSO requests (overrides HTTP_PROXY/HTTPS_PROXY)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    
+    /// Per-service endpoint overrides, e.g. `s3: http://localhost:4566`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub endpoints: HashMap<String, String>,
+    
+    /// Use FIPS endpoints (also enabled by AWS_USE_FIPS_ENDPOINT=true)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_fips_endpoints: bool,
+    
+    /// Use dual-stack (IPv6) endpoints (also enabled by AWS_USE_DUALSTACK_ENDPOINT=true)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_dualstack_endpoints: bool,
+    
+    /// Profiles that always run in read-only mode, whatever the CLI flags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub readonly_profiles: Vec<String>,
+    
+    /// Regions switched to with the number keys, in key order (`0` is the first)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub region_shortcuts: Vec<String>,
+    
+    /// Key remapping, e.g. `down: [n, down]` or `action.terminate_instance: ctrl+x`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keys: HashMap<String, KeySpec>,
+    
+    /// Visible columns per resource, in display order, e.g. `ec2-instances: [NAME, STATE, TYPE]`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub columns: HashMap<String, Vec<String>>,
+    
+    /// Extra tag columns per resource, e.g. `ec2-instances: [Environment, Team]`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tag_columns: HashMap<String, Vec<String>>,
+    
+    /// Saved list filters per resource (`:filter-save`, `:filters`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub filters: HashMap<String, Vec<SavedFilter>>,
+    
+    /// Skip the splash screen at startup (same as --no-splash)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_splash: bool,
+    
+    /// Timezone for displayed dates: `local` (default), `utc` or an offset like `+05:30`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    
+    /// Show CloudWatch utilization columns (EC2 and RDS CPU, RDS free memory), toggled by `:metrics`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub utilization_metrics: bool,
+    
+    /// Show the bar of keys valid in the current view, toggled by `:keybar`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub key_bar: bool,
+    
+    /// Ring the terminal bell when a watched resource changes state
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub watch_bell: bool,
+    
+    /// Post a desktop notification when a watched resource changes state
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub watch_desktop_notifications: bool,
+}
+
+/// A named filter expression, e.g. `{ name: prod-running, filter: "state=running name=prod-*" }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedFilter {
+    pub name: String,
+    pub filter: String,
+    /// Apply on entering the resource (at most one per resource)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto: bool,
+}
+
+/// One key or a list of keys in the `keys` section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySpec {
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            KeySpec::One(key) => vec![key.as_str()],
+            KeySpec::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
 }
 
 impl Config {
@@ -50,6 +156,10 @@ impl Config {
     
     /// Save config to disk
     pub fn save(&self) -> Result<()> {
+        // Tests never touch the user's config
+        if cfg!(test) {
+            return Ok(());
+        }
         let path = Self::config_path();
         
         // Ensure parent directory exists
@@ -99,6 +209,71 @@ impl Config {
         self.save()
     }
     
+    /// Turn utilization columns on or off and save
+    pub fn set_utilization_metrics(&mut self, enabled: bool) -> Result<()> {
+        self.utilization_metrics = enabled;
+        self.save()
+    }
+    
+    /// Turn the key bar on or off and save
+    pub fn set_key_bar(&mut self, enabled: bool) -> Result<()> {
+        self.key_bar = enabled;
+        self.save()
+    }
+    
+    /// Update (or with None, reset) a resource's column layout and save
+    pub fn set_columns(&mut self, resource: &str, columns: Option<Vec<String>>) -> Result<()> {
+        match columns {
+            Some(columns) => self.columns.insert(resource.to_string(), columns),
+            None => self.columns.remove(resource),
+        };
+        self.save()
+    }
+    
+    /// Save a named filter for a resource (replacing one with the same name)
+    pub fn save_filter(&mut self, resource: &str, name: &str, filter: &str) -> Result<()> {
+        let filters = self.filters.entry(resource.to_string()).or_default();
+        match filters.iter_mut().find(|f| f.name == name) {
+            Some(saved) => saved.filter = filter.to_string(),
+            None => filters.push(SavedFilter {
+                name: name.to_string(),
+                filter: filter.to_string(),
+                auto: false,
+            }),
+        }
+        self.save()
+    }
+    
+    /// Delete a resource's saved filter
+    pub fn remove_filter(&mut self, resource: &str, name: &str) -> Result<()> {
+        if let Some(filters) = self.filters.get_mut(resource) {
+            filters.retain(|f| f.name != name);
+            if filters.is_empty() {
+                self.filters.remove(resource);
+            }
+        }
+        self.save()
+    }
+    
+    /// Make a saved filter the resource's auto-applied one, or turn auto-apply off if it already is
+    pub fn toggle_auto_filter(&mut self, resource: &str, name: &str) -> Result<()> {
+        if let Some(filters) = self.filters.get_mut(resource) {
+            for saved in filters.iter_mut() {
+                saved.auto = saved.name == name && !saved.auto;
+            }
+        }
+        self.save()
+    }
+    
+    /// Filter applied on entering a resource
+    pub fn auto_filter(&self, resource: &str) -> Option<&str> {
+        self.filters
+            .get(resource)?
+            .iter()
+            .find(|f| f.auto)
+            .map(|f| f.filter.as_str())
+    }
+    
     /// Get effective profile (config -> env -> default)
     pub fn effective_profile(&self) -> String {
         // Priority: 1. Environment variable, 2. Config file, 3. Default
@@ -108,6 +283,36 @@ impl Config {
             .unwrap_or_else(|| "default".to_string())
     }
     
+    /// Whether to use FIPS endpoints (config or AWS_USE_FIPS_ENDPOINT)
+    pub fn effective_use_fips(&self) -> bool {
+        self.use_fips_endpoints || env_flag("AWS_USE_FIPS_ENDPOINT")
+    }
+    
+    /// Whether to use dual-stack endpoints (config or AWS_USE_DUALSTACK_ENDPOINT)
+    pub fn effective_use_dualstack(&self) -> bool {
+        self.use_dualstack_endpoints || env_flag("AWS_USE_DUALSTACK_ENDPOINT")
+    }
+    
+    /// Number key and region of each region shortcut (at most ten, `0`-`9`)
+    pub fn region_shortcuts(&self) -> Vec<(char, &str)> {
+        let regions: Vec<&str> = if self.region_shortcuts.is_empty() {
+            DEFAULT_REGION_SHORTCUTS.to_vec()
+        } else {
+            self.region_shortcuts.iter().map(String::as_str).collect()
+        };
+        ('0'..='9').zip(regions).collect()
+    }
+    
+    /// Whether `profile` is listed in `readonly_profiles`
+    pub fn is_readonly_profile(&self, profile: &str) -> bool {
+        self.readonly_profiles.iter().any(|p| p == profile)
+    }
+    
+    /// Get the role name used for Organizations account hops
+    pub fn effective_org_role(&self) -> &str {
+        self.org_role.as_deref().unwrap_or("OrganizationAccountAccessRole")
+    }
+    
     /// Get effective region (config -> env -> default)
     pub fn effective_region(&self) -> String {
         // Priority: 1. Environment variable, 2. Config file, 3. Default
@@ -119,6 +324,10 @@ impl Config {
     }
 }
 
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +337,7 @@ mod tests {
         let config = Config::default();
         assert!(config.profile.is_none());
         assert!(config.region.is_none());
+        assert_eq!(config.effective_org_role(), "OrganizationAccountAccessRole");
     }
     
     #[test]
@@ -136,6 +346,8 @@ mod tests {
             profile: Some("my-profile".to_string()),
             region: Some("eu-west-1".to_string()),
             last_resource: Some("ec2-instances".to_string()),
+            org_role: Some("AdminRole".to_string()),
+            ..Default::default()
         };
         
         let yaml = serde_yaml::to_string(&config).unwrap();
@@ -144,5 +356,33 @@ mod tests {
         assert_eq!(parsed.profile, config.profile);
         assert_eq!(parsed.region, config.region);
         assert_eq!(parsed.last_resource, config.last_resource);
+        assert_eq!(parsed.effective_org_role(), "AdminRole");
+        assert!(!yaml.contains("endpoints"));
+    }
+    
+    #[test]
+    fn test_endpoints_map() {
+        let yaml = "region: us-east-1\nendpoints:\n  s3: http://localhost:4566\n  dynamodb: http://other:8000\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.endpoints.get("s3").map(String::as_str), Some("http://localhost:4566"));
+        assert_eq!(config.endpoints.len(), 2);
+    }
+    
+    #[test]
+    fn test_readonly_profiles() {
+        let config: Config = serde_yaml::from_str("readonly_profiles: [prod, prod-eu]\n").unwrap();
+        assert!(config.is_readonly_profile("prod"));
+        assert!(config.is_readonly_profile("prod-eu"));
+        assert!(!config.is_readonly_profile("dev"));
+        assert!(!serde_yaml::to_string(&Config::default()).unwrap().contains("readonly_profiles"));
+    }
+    
+    #[test]
+    fn test_saved_filters() {
+        let yaml = "filters:\n  ec2-instances:\n    - { name: prod, filter: \"env=prod\" }\n    - { name: running, filter: \"state=running\", auto: true }\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.filters["ec2-instances"].len(), 2);
+        assert_eq!(config.auto_filter("ec2-instances"), Some("state=running"));
+        assert_eq!(config.auto_filter("lambda-functions"), None);
     }
 }
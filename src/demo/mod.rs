@@ -0,0 +1,62 @@
+//! Offline demo mode (`--demo`)
+//!
+//! Resource lists come from canned fixtures served through the fetcher
+//! instead of AWS, so taws can be shown, recorded or worked on without
+//! credentials or a network. Any other AWS request fails with a demo-mode
+//! error.
+
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+/// Profile name shown in demo mode
+pub const PROFILE: &str = "demo";
+
+/// Account the fixtures pretend to live in
+pub const ACCOUNT: &str = "123456789012";
+
+/// Fixture items by resource key
+const FIXTURES: &str = include_str!("fixtures.json");
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn fixtures() -> &'static Value {
+    static PARSED: OnceLock<Value> = OnceLock::new();
+    PARSED.get_or_init(|| serde_json::from_str(FIXTURES).expect("invalid demo fixtures"))
+}
+
+/// Items listed for a resource; empty for resources without a fixture
+pub fn items(resource_key: &str) -> Vec<Value> {
+    fixtures()
+        .get(resource_key)
+        .and_then(|items| items.as_array())
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::{extract_json_value, get_resource};
+
+    #[test]
+    fn test_fixtures_match_resources() {
+        let fixtures = fixtures().as_object().unwrap();
+        assert!(fixtures.contains_key("ec2-instances"));
+        for (resource_key, items) in fixtures {
+            let resource = get_resource(resource_key).unwrap_or_else(|| panic!("unknown resource {}", resource_key));
+            for item in items.as_array().unwrap() {
+                assert_ne!(extract_json_value(item, &resource.id_field), "-", "{} item without id", resource_key);
+            }
+        }
+        assert!(items("no-such-resource").is_empty());
+    }
+}
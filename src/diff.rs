@@ -0,0 +1,77 @@
+//! Line diff of two describe documents (`x` to mark, `=` to compare)
+
+use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
+
+/// Unchanged lines kept around each change
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffKind {
+    Same,
+    Removed,
+    Added,
+    /// Separator between groups of changes
+    Gap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// Unified diff of the pretty-printed documents. Empty when they are equal.
+pub fn diff_documents(left: &Value, right: &Value) -> Vec<DiffLine> {
+    let left = pretty(left);
+    let right = pretty(right);
+    let diff = TextDiff::from_lines(&left, &right);
+
+    let mut lines = Vec::new();
+    for (i, group) in diff.grouped_ops(CONTEXT_LINES).iter().enumerate() {
+        if i > 0 {
+            lines.push(DiffLine { kind: DiffKind::Gap, text: "...".to_string() });
+        }
+        for op in group {
+            for change in diff.iter_changes(op) {
+                let kind = match change.tag() {
+                    ChangeTag::Equal => DiffKind::Same,
+                    ChangeTag::Delete => DiffKind::Removed,
+                    ChangeTag::Insert => DiffKind::Added,
+                };
+                lines.push(DiffLine {
+                    kind,
+                    text: change.value().trim_end_matches('\n').to_string(),
+                });
+            }
+        }
+    }
+    lines
+}
+
+/// Raw text documents (e.g. templates) are compared as-is
+fn pretty(value: &Value) -> String {
+    let text = match value {
+        Value::String(text) => text.clone(),
+        other => serde_json::to_string_pretty(other).unwrap_or_default(),
+    };
+    format!("{}\n", text.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_documents() {
+        let left = json!({"GroupName": "web", "Rules": [22, 80]});
+        let right = json!({"GroupName": "web", "Rules": [22, 443]});
+        let lines = diff_documents(&left, &right);
+        assert!(lines.contains(&DiffLine { kind: DiffKind::Removed, text: "    80".to_string() }));
+        assert!(lines.contains(&DiffLine { kind: DiffKind::Added, text: "    443".to_string() }));
+        assert!(lines.iter().any(|l| l.kind == DiffKind::Same));
+
+        assert!(diff_documents(&left, &left).is_empty());
+    }
+}
@@ -1,10 +1,16 @@
 use crate::app::{App, Mode, SsoLoginState};
 use crate::aws::sso;
+use crate::keys::{KeyAction, KeyBinding};
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use std::time::Duration;
 
 pub async fn handle_events(app: &mut App) -> Result<bool> {
+    // Keys typed during a list load first, in the order they were typed
+    let typed_ahead = app.typed_ahead.lock().ok().and_then(|mut keys| keys.pop_front());
+    if let Some(key) = typed_ahead {
+        return handle_key_event(app, key).await;
+    }
     if event::poll(Duration::from_millis(100))? {
         if let Event::Key(key) = event::read()? {
             return handle_key_event(app, key).await;
@@ -13,7 +19,7 @@ pub async fn handle_events(app: &mut App) -> Result<bool> {
     Ok(false)
 }
 
-async fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<bool> {
+pub async fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<bool> {
     match app.mode {
         Mode::Normal => handle_normal_mode(app, key).await,
         Mode::Command => handle_command_mode(app, key).await,
@@ -24,232 +30,265 @@ async fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<bool> {
         Mode::Profiles => handle_profiles_mode(app, key).await,
         Mode::Regions => handle_regions_mode(app, key).await,
         Mode::SsoLogin => handle_sso_login_mode(app, key).await,
+        Mode::Mfa => handle_mfa_mode(app, key).await,
         Mode::LogTail => handle_log_tail_mode(app, key).await,
+        Mode::Input => handle_input_mode(app, key).await,
+        Mode::Secret => handle_secret_mode(app, key),
+        Mode::Tunnels => handle_tunnels_mode(app, key),
+        Mode::SsoSessions => handle_sso_sessions_mode(app, key),
+        Mode::Audit => handle_audit_mode(app, key),
+        Mode::Columns => handle_columns_mode(app, key),
+        Mode::Diff => handle_diff_mode(app, key),
+        Mode::Filters => handle_filters_mode(app, key),
+        Mode::Palette => handle_palette_mode(app, key).await,
+        Mode::Jumps => handle_jumps_mode(app, key).await,
+        Mode::Notifications => handle_notifications_mode(app, key),
+        Mode::ApiCalls => handle_api_calls_mode(app, key),
+        Mode::Costs => handle_costs_mode(app, key),
+        Mode::Tags => handle_tags_mode(app, key).await,
     }
 }
 
-// Region shortcuts matching the header display
-const REGION_SHORTCUTS: &[&str] = &[
-    "us-east-1",
-    "us-west-2",
-    "eu-west-1",
-    "eu-central-1",
-    "ap-northeast-1",
-    "ap-southeast-1",
-];
-
 async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     // If filter is active, handle filter input
     if app.filter_active {
         return handle_filter_input(app, key).await;
     }
 
-    match key.code {
-        // Quit with Ctrl+C
-        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+    // Quit with Ctrl+C
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Ok(true);
+    }
 
-        // Region shortcuts (0-5)
-        KeyCode::Char('0') => {
-            if let Some(region) = REGION_SHORTCUTS.first() {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('1') => {
-            if let Some(region) = REGION_SHORTCUTS.get(1) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('2') => {
-            if let Some(region) = REGION_SHORTCUTS.get(2) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('3') => {
-            if let Some(region) = REGION_SHORTCUTS.get(3) {
-                app.switch_region(region).await?;
+    // Built-in actions win over resource shortcuts, except that a resource
+    // action on the page-down key (e.g. Ctrl+d terminate) takes it over, as
+    // do shortcuts on the split view and mark keys (e.g. v: Reveal Value, x: Shell)
+    let action = app.keymap.action_for(&key);
+    if matches!(action, None | Some(KeyAction::PageDown | KeyAction::SplitView | KeyAction::Mark)) && handle_resource_shortcut(app, &key).await? {
+        app.last_key_press = None;
+        return Ok(false);
+    }
+    if let Some(action) = action {
+        app.last_key_press = None;
+        handle_key_action(app, action).await?;
+        return Ok(false);
+    }
+
+    match key.code {
+        // Region shortcuts (region_shortcuts in the config)
+        KeyCode::Char(c @ '0'..='9') => {
+            let region = app
+                .config
+                .region_shortcuts()
+                .into_iter()
+                .find(|(key, _)| *key == c)
+                .map(|(_, region)| region.to_string());
+            if let Some(region) = region {
+                app.switch_region(&region).await?;
                 app.refresh_current().await?;
             }
         }
-        KeyCode::Char('4') => {
-            if let Some(region) = REGION_SHORTCUTS.get(4) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
+
+        // Escape leaves column zoom, then clears filter if present
+        KeyCode::Esc => {
+            if app.zoomed_column.is_some() {
+                app.zoomed_column = None;
+            } else if !app.filter_text.is_empty() {
+                app.clear_filter();
+            } else if app.parent_context.is_some() {
+                app.navigate_back().await?;
             }
         }
-        KeyCode::Char('5') => {
-            if let Some(region) = REGION_SHORTCUTS.get(5) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
+
+        // Handle 'gg' for go_to_top
+        KeyCode::Char('g') => {
+            if let Some((last_key, last_time)) = app.last_key_press
+                && last_key == KeyCode::Char('g')
+                && last_time.elapsed() < Duration::from_millis(250)
+            {
+                app.go_to_top();
+                app.last_key_press = None;
+            } else {
+                app.last_key_press = Some((KeyCode::Char('g'), std::time::Instant::now()));
             }
+            return Ok(false);
         }
+        _ => {}
+    }
+    app.last_key_press = None;
+    Ok(false)
+}
 
-        // Navigation - vim style
-        KeyCode::Char('j') | KeyCode::Down => app.next(),
-        KeyCode::Char('k') | KeyCode::Up => app.previous(),
-        KeyCode::Home => app.go_to_top(),
-        KeyCode::Char('G') | KeyCode::End => app.go_to_bottom(),
+/// Run a built-in action from the keymap in the resource list
+async fn handle_key_action(app: &mut App, action: KeyAction) -> Result<()> {
+    match action {
+        // Navigation
+        KeyAction::Down => app.next(),
+        KeyAction::Up => app.previous(),
+        KeyAction::Top => app.go_to_top(),
+        KeyAction::Bottom => app.go_to_bottom(),
+        KeyAction::PageDown => app.page_down(10),
+        KeyAction::PageUp => app.page_up(10),
 
-        // Page navigation / Destructive action (ctrl+d)
-        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            // Check if current resource has a ctrl+d action defined
-            let mut action_triggered = false;
-            if let Some(resource) = app.current_resource() {
-                for action in &resource.actions {
-                    if action.shortcut.as_deref() == Some("ctrl+d") {
-                        if let Some(item) = app.selected_item() {
-                            let id = crate::resource::extract_json_value(item, &resource.id_field);
-                            if id != "-" && !id.is_empty() {
-                                // Block action in readonly mode
-                                if app.readonly {
-                                    app.show_warning("This operation is not supported in read-only mode");
-                                    action_triggered = true;
-                                } else if let Some(pending) = app.create_pending_action(action, &id) {
-                                    app.enter_confirm_mode(pending);
-                                    action_triggered = true;
-                                }
-                            }
-                        }
-                        break;
-                    }
-                }
-            }
-            // If no action, use as page down
-            if !action_triggered {
-                app.page_down(10);
+        // Describe mode (d or Enter)
+        KeyAction::Describe => app.enter_describe_mode().await,
+        KeyAction::Open => {
+            // Stack resources jump to their own view when taws supports the type
+            let jumped = match app.current_resource_key.as_str() {
+                "cloudformation-stack-resources" => app.jump_to_stack_resource().await?,
+                // Search results jump to the view that lists them
+                "tagged-resources" => app.jump_to_arn("ResourceARN").await?,
+                "search-results" => app.jump_to_arn("Arn").await?,
+                _ => false,
+            };
+            if !jumped {
+                app.enter_describe_mode().await;
             }
         }
-        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_up(10);
-        }
-        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_down(10);
-        }
-        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_up(10);
-        }
 
-        // Describe mode (d or Enter)
-        KeyCode::Char('d') => app.enter_describe_mode().await,
-        KeyCode::Enter => app.enter_describe_mode().await,
+        // Mode switches
+        KeyAction::Filter => app.toggle_filter(),
+        KeyAction::Command => app.enter_command_mode(),
+        KeyAction::Help => app.enter_help_mode(),
+        KeyAction::Tags => app.enter_tags_mode().await,
+        KeyAction::SplitView => app.toggle_split_view(),
 
-        // Filter toggle
-        KeyCode::Char('/') => {
-            app.toggle_filter();
+        // Column zoom (shows one column's values in full)
+        KeyAction::ZoomColumn => app.toggle_column_zoom(),
+        KeyAction::ColumnLeft => app.shift_column_zoom(-1),
+        KeyAction::ColumnRight => app.shift_column_zoom(1),
+        KeyAction::Columns => app.enter_column_picker(),
+
+        // Compare two items
+        KeyAction::Mark => app.toggle_diff_mark(),
+        KeyAction::Diff => app.enter_diff_mode().await,
+
+        // Fuzzy palette of resources, commands and saved filters
+        KeyAction::Palette => app.enter_palette(),
+
+        // Bookmark the highlighted row; ' lists bookmarks and recent views
+        KeyAction::Bookmark => app.toggle_bookmark(true),
+        KeyAction::Jumps => app.enter_jumps_mode(),
+
+        // Poll the highlighted row and notify when its state changes
+        KeyAction::Watch => app.toggle_watch(),
+
+        // Refetch the list, bypassing the short-lived list cache
+        KeyAction::Refresh => app.force_refresh().await?,
+
+        // Backspace goes back in navigation
+        KeyAction::Back => {
+            if app.parent_context.is_some() {
+                app.navigate_back().await?;
+            }
         }
 
         // Pagination - next/previous page of results (using ] and [ to avoid conflicts with sub-resource shortcuts)
-        KeyCode::Char(']') => {
+        KeyAction::NextPage => {
             if app.pagination.has_more {
                 app.next_page().await?;
             }
         }
-        KeyCode::Char('[') => {
+        KeyAction::PrevPage => {
             if app.pagination.current_page > 1 {
                 app.prev_page().await?;
             }
         }
 
-        // Mode switches
-        KeyCode::Char(':') => app.enter_command_mode(),
-        KeyCode::Char('?') => app.enter_help_mode(),
-
-        // Backspace goes back in navigation
-        KeyCode::Backspace => {
-            if app.parent_context.is_some() {
-                app.navigate_back().await?;
+        // Workspace tabs
+        KeyAction::NewTab => app.new_workspace(),
+        KeyAction::CloseTab => {
+            if app.workspaces.len() > 1 {
+                app.close_workspace();
+                app.refresh_current().await?;
             }
         }
-
-        // Escape clears filter if present
-        KeyCode::Esc => {
-            if !app.filter_text.is_empty() {
-                app.clear_filter();
-            } else if app.parent_context.is_some() {
-                app.navigate_back().await?;
+        KeyAction::NextTab | KeyAction::PrevTab => {
+            if app.workspaces.len() > 1 {
+                app.cycle_workspace(if action == KeyAction::NextTab { 1 } else { -1 });
+                app.refresh_current().await?;
             }
         }
+    }
+    Ok(())
+}
 
-        // Dynamic shortcuts: sub-resources and EC2 actions
-        _ => {
-            if let KeyCode::Char(c) = key.code {
-                let mut handled = false;
-                
-                // Check if it's a sub-resource shortcut for current resource
-                if let Some(resource) = app.current_resource() {
-                    for sub in &resource.sub_resources {
-                        if sub.shortcut == c.to_string() && app.selected_item().is_some() {
-                            app.navigate_to_sub_resource(&sub.resource_key).await?;
-                            handled = true;
-                            break;
-                        }
-                    }
-                }
-                
-                // Check if it matches an action shortcut
-                if !handled {
-                    if let Some(resource) = app.current_resource() {
-                        for action in &resource.actions {
-                            if action.shortcut.as_deref() == Some(&c.to_string()) {
-                                if let Some(item) = app.selected_item() {
-                                    let id = crate::resource::extract_json_value(item, &resource.id_field);
-                                    if id != "-" && !id.is_empty() {
-                                        // Special handling for log tailing action
-                                        if action.sdk_method == "tail_logs" {
-                                            app.enter_log_tail_mode().await?;
-                                            handled = true;
-                                        // Block action in readonly mode
-                                        } else if app.readonly {
-                                            app.show_warning("This operation is not supported in read-only mode");
-                                            handled = true;
-                                        } else if action.requires_confirm() {
-                                            // Check if action requires confirmation
-                                            if let Some(pending) = app.create_pending_action(action, &id) {
-                                                app.enter_confirm_mode(pending);
-                                                handled = true;
-                                            }
-                                        } else {
-                                            // Execute directly
-                                            if let Err(e) = crate::resource::execute_action(
-                                                &resource.service,
-                                                &action.sdk_method,
-                                                &app.clients,
-                                                &id
-                                            ).await {
-                                                app.error_message = Some(format!("Action failed: {}", e));
-                                            }
-                                            let _ = app.refresh_current().await;
-                                            handled = true;
-                                        }
-                                    }
-                                }
-                                break;
-                            }
-                        }
-                    }
-                }
+/// Navigate to the sub-resource or run the resource action bound to `key`
+/// for the selected item. Returns false if nothing is bound to it.
+async fn handle_resource_shortcut(app: &mut App, key: &KeyEvent) -> Result<bool> {
+    let Some(resource) = app.current_resource() else {
+        return Ok(false);
+    };
+    let Some(item) = app.selected_item() else {
+        return Ok(false);
+    };
 
-                // Handle 'gg' for go_to_top
-                if c == 'g' {
-                    if let Some((last_key, last_time)) = app.last_key_press {
-                        if last_key == KeyCode::Char('g') && last_time.elapsed() < Duration::from_millis(250) {
-                            app.go_to_top();
-                            app.last_key_press = None;
-                            handled = true;
-                        }
-                    }
-                }
-                if !handled && c == 'g' {
-                    app.last_key_press = Some((KeyCode::Char('g'), std::time::Instant::now()));
-                } else {
-                    app.last_key_press = None;
-                }
-            }
-        }
+    // Check if it's a sub-resource shortcut for current resource
+    let bound = |shortcut: &str| KeyBinding::parse(shortcut).is_some_and(|b| b.matches(key));
+    if let Some(sub) = resource.sub_resources.iter().find(|sub| bound(&sub.shortcut)) {
+        app.navigate_to_sub_resource(&sub.resource_key).await?;
+        return Ok(true);
     }
-    Ok(false)
+
+    // Check if it matches an action shortcut (as remapped in the config)
+    let Some(action) = resource.actions.iter().find(|action| {
+        app.keymap
+            .action_shortcut(&action.sdk_method, action.shortcut.as_deref())
+            .is_some_and(|b| b.matches(key))
+    }) else {
+        return Ok(false);
+    };
+    let id_field = action.value_field.as_deref().unwrap_or(&resource.id_field);
+    let id = crate::resource::extract_json_value(item, id_field);
+    if id == "-" || id.is_empty() {
+        return Ok(false);
+    }
+
+    // Special handling for log tailing action
+    if action.sdk_method == "tail_logs" {
+        app.enter_log_tail_mode().await?;
+    // View actions only read, so they are allowed in readonly mode
+    } else if action.view {
+        app.enter_action_view(action, &id).await;
+    // Block mutating actions in readonly mode
+    } else if app.readonly && !action.read_only {
+        app.show_warning("This operation is not supported in read-only mode");
+    } else if action.sdk_method == "start_session" {
+        app.start_ssm_session(&id).await;
+    } else if action.sdk_method == "start_query_execution" && action.prompt.is_none() {
+        // Re-run a query from history or a saved query
+        let query = crate::resource::extract_json_value(item, "QueryString");
+        app.run_athena_query(&id, &query).await;
+    } else if action.prompt.is_some() {
+        // Collect input first; confirmation follows on submit
+        let action = action.clone();
+        app.enter_input_mode(&action, &id);
+    } else if action.requires_confirm() {
+        // Check if action requires confirmation
+        match app.create_pending_action(action, &id) {
+            Some(pending) => app.enter_confirm_mode(pending),
+            None => return Ok(false),
+        }
+    } else if let Some(secret) = &action.secret {
+        app.enter_secret_mode(&resource.service, &action.sdk_method, &id, secret).await;
+    } else {
+        // Execute directly, in the background
+        let service = resource.service.clone();
+        app.run_action(&service, &action.sdk_method, &id, None);
+    }
+    Ok(true)
+}
+
+/// Move the selection in list views with the keymap's navigation keys
+fn handle_list_navigation(app: &mut App, key: &KeyEvent) -> bool {
+    match app.keymap.action_for(key) {
+        Some(KeyAction::Down) => app.next(),
+        Some(KeyAction::Up) => app.previous(),
+        Some(KeyAction::Top) => app.go_to_top(),
+        Some(KeyAction::Bottom) => app.go_to_bottom(),
+        _ => return false,
+    }
+    true
 }
 
 async fn handle_filter_input(app: &mut App, key: KeyEvent) -> Result<bool> {
@@ -274,11 +313,17 @@ async fn handle_filter_input(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.history_search.is_some() {
+        return handle_history_search(app, key).await;
+    }
     match key.code {
         KeyCode::Esc => {
             app.command_text.clear();
             app.exit_mode();
         }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history_search_next();
+        }
         KeyCode::Enter => {
             let should_quit = app.execute_command().await?;
             if should_quit {
@@ -291,6 +336,13 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Tab | KeyCode::Right => {
             app.apply_suggestion();
         }
+        // Up/Down browse history from an empty box (or once browsing), suggestions otherwise
+        KeyCode::Down if app.command_history_index.is_some() => {
+            app.history_next();
+        }
+        KeyCode::Up if app.command_history_index.is_some() || (app.command_text.is_empty() && !app.command_history.is_empty()) => {
+            app.history_prev();
+        }
         KeyCode::Down => {
             app.next_suggestion();
         }
@@ -299,10 +351,12 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         KeyCode::Backspace => {
             app.command_text.pop();
+            app.command_history_index = None;
             app.update_command_suggestions();
         }
         KeyCode::Char(c) => {
             app.command_text.push(c);
+            app.command_history_index = None;
             app.update_command_suggestions();
         }
         _ => {}
@@ -310,6 +364,82 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+/// Ctrl+P palette: type to narrow the list, Enter to run the selected entry
+async fn handle_palette_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => {
+            app.exit_mode();
+        }
+        KeyCode::Enter => {
+            app.run_palette_entry().await?;
+        }
+        KeyCode::Down => app.move_palette_selection(1),
+        KeyCode::Up => app.move_palette_selection(-1),
+        KeyCode::Char('n') if ctrl => app.move_palette_selection(1),
+        KeyCode::Char('p') if ctrl => app.move_palette_selection(-1),
+        KeyCode::Backspace => {
+            if let Some(palette) = app.palette.as_mut() {
+                palette.query.pop();
+            }
+            app.update_palette();
+        }
+        KeyCode::Char(c) if !ctrl => {
+            if let Some(palette) = app.palette.as_mut() {
+                palette.query.push(c);
+            }
+            app.update_palette();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Ctrl+R search through the command history
+async fn handle_history_search(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.end_history_search(false);
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history_search_next();
+        }
+        KeyCode::Enter => {
+            app.end_history_search(true);
+            let should_quit = app.execute_command().await?;
+            if should_quit {
+                return Ok(true);
+            }
+            if app.mode == Mode::Command {
+                app.exit_mode();
+            }
+        }
+        KeyCode::Tab | KeyCode::Right => {
+            app.end_history_search(true);
+        }
+        KeyCode::Down => {
+            app.next_suggestion();
+        }
+        KeyCode::Up => {
+            app.prev_suggestion();
+        }
+        KeyCode::Backspace => {
+            if let Some(query) = app.history_search.as_mut() {
+                query.pop();
+            }
+            app.update_history_search();
+        }
+        KeyCode::Char(c) => {
+            if let Some(query) = app.history_search.as_mut() {
+                query.push(c);
+            }
+            app.update_history_search();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 fn handle_help_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => {
@@ -321,31 +451,94 @@ fn handle_help_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // Typing a search
+    if app.describe_search_active {
+        match key.code {
+            KeyCode::Esc => app.clear_describe_search(),
+            KeyCode::Enter => app.submit_describe_search(),
+            KeyCode::Backspace => {
+                app.describe_search.pop();
+            }
+            KeyCode::Char(c) => app.describe_search.push(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Typing an expression
+    if app.describe_query_active {
+        match key.code {
+            KeyCode::Esc => app.describe_query_active = false,
+            KeyCode::Enter => app.apply_describe_query(),
+            KeyCode::Backspace => {
+                app.describe_query.pop();
+            }
+            KeyCode::Char(c) => app.describe_query.push(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
     match key.code {
+        // Escape clears the search, then the expression
+        KeyCode::Esc if !app.describe_search.is_empty() => {
+            app.clear_describe_search();
+        }
+        KeyCode::Esc if app.describe_projection.is_some() => {
+            app.clear_describe_query();
+        }
+        KeyCode::Char('.') => {
+            if app.describe_query.is_empty() {
+                app.describe_query.push('.');
+            }
+            app.describe_query_active = true;
+        }
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
+        KeyCode::Char('/') => {
+            app.clear_describe_search();
+            app.describe_search_active = true;
+        }
+        KeyCode::Char('n') => {
+            app.step_describe_match(true);
+        }
+        KeyCode::Char('N') => {
+            app.step_describe_match(false);
+        }
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.describe_scroll = app.describe_scroll.saturating_add(10);
+            app.move_describe_cursor(10);
         }
         KeyCode::Char('d') => {
             app.exit_mode();
         }
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.describe_scroll = app.describe_scroll.saturating_sub(10);
+            app.move_describe_cursor(-10);
         }
         KeyCode::Char('j') | KeyCode::Down => {
-            app.describe_scroll = app.describe_scroll.saturating_add(1);
+            app.move_describe_cursor(1);
         }
         KeyCode::Char('k') | KeyCode::Up => {
-            app.describe_scroll = app.describe_scroll.saturating_sub(1);
+            app.move_describe_cursor(-1);
         }
         KeyCode::Char('g') | KeyCode::Home => {
-            app.describe_scroll = 0;
+            app.set_describe_cursor(0);
         }
         KeyCode::Char('G') | KeyCode::End => {
-            // Scroll to bottom - use a large visible_lines estimate, will be clamped in render
-            app.describe_scroll_to_bottom(50);
+            app.set_describe_cursor(app.describe_line_count().saturating_sub(1));
+        }
+        // Collapsible tree
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            app.toggle_describe_node();
+        }
+        KeyCode::Char('E') => {
+            app.expand_describe_tree();
+        }
+        KeyCode::Char('C') => {
+            app.collapse_describe_tree();
+        }
+        KeyCode::Char('y') => {
+            app.toggle_describe_yaml();
         }
         _ => {}
     }
@@ -364,6 +557,30 @@ fn handle_warning_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // Typed confirmation: keys edit the text, Enter runs only if it matches
+    if let Some(pending) = app.pending_action.as_mut()
+        && let Some(expected) = &pending.expected_text
+    {
+        match key.code {
+            KeyCode::Char(c) => {
+                pending.typed_text.push(c);
+                return Ok(false);
+            }
+            KeyCode::Backspace => {
+                pending.typed_text.pop();
+                return Ok(false);
+            }
+            KeyCode::Enter => {
+                pending.selected_yes = pending.typed_text == *expected;
+                if !pending.selected_yes {
+                    app.notify_error("Confirmation text did not match; nothing was changed");
+                }
+            }
+            KeyCode::Esc => {}
+            _ => return Ok(false),
+        }
+    }
+
     match key.code {
         // Toggle selection with arrow keys or tab
         KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::Char('h') | KeyCode::Char('l') => {
@@ -377,17 +594,22 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                 if pending.selected_yes {
                     // Execute the action (if not in readonly mode)
                     if app.readonly {
-                        app.error_message = Some("This operation is not supported in read-only mode".to_string());
+                        app.notify_error("This operation is not supported in read-only mode");
                     } else {
                         let service = pending.service.clone();
                         let method = pending.sdk_method.clone();
                         let resource_id = pending.resource_id.clone();
+                        let input = pending.input.clone();
                         
-                        if let Err(e) = crate::resource::execute_action(&service, &method, &app.clients, &resource_id).await {
-                            app.error_message = Some(format!("Action failed: {}", e));
+                        if let Some(secret) = pending.secret.clone() {
+                            app.enter_secret_mode(&service, &method, &resource_id, &secret).await;
+                            // Keep the secret dialog open over the list
+                            if app.mode == Mode::Secret {
+                                return Ok(false);
+                            }
+                        } else {
+                            app.run_action(&service, &method, &resource_id, input.as_deref());
                         }
-                        // Refresh after action
-                        let _ = app.refresh_current().await;
                     }
                 }
             }
@@ -396,16 +618,21 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         // Quick yes/no
         KeyCode::Char('y') | KeyCode::Char('Y') => {
             if app.readonly {
-                app.error_message = Some("This operation is not supported in read-only mode".to_string());
+                app.notify_error("This operation is not supported in read-only mode");
             } else if let Some(ref pending) = app.pending_action {
                 let service = pending.service.clone();
                 let method = pending.sdk_method.clone();
                 let resource_id = pending.resource_id.clone();
+                let input = pending.input.clone();
                 
-                if let Err(e) = crate::resource::execute_action(&service, &method, &app.clients, &resource_id).await {
-                    app.error_message = Some(format!("Action failed: {}", e));
+                if let Some(secret) = pending.secret.clone() {
+                    app.enter_secret_mode(&service, &method, &resource_id, &secret).await;
+                    if app.mode == Mode::Secret {
+                        return Ok(false);
+                    }
+                } else {
+                    app.run_action(&service, &method, &resource_id, input.as_deref());
                 }
-                let _ = app.refresh_current().await;
             }
             app.exit_mode();
         }
@@ -417,11 +644,76 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
-async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+fn handle_tunnels_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Char('x') => {
+            app.stop_selected_tunnel();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.stop_selected_tunnel();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_costs_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_tags_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let Some(state) = app.tags_state.as_mut() else {
+        app.exit_mode();
+        return Ok(false);
+    };
+
+    // Typing a key=value
+    if let Some((_, text)) = state.editing.as_mut() {
+        match key.code {
+            KeyCode::Esc => state.editing = None,
+            KeyCode::Enter => app.save_tag_edit().await,
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) => text.push(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    if state.confirm_delete {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.delete_selected_tag().await,
+            _ => state.confirm_delete = false,
+        }
+        return Ok(false);
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('T') => {
+            app.exit_mode();
+        }
         KeyCode::Char('j') | KeyCode::Down => {
             app.next();
         }
@@ -434,33 +726,377 @@ async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Char('G') | KeyCode::End => {
             app.go_to_bottom();
         }
+        KeyCode::Char('a') => {
+            app.start_tag_edit(false);
+        }
+        KeyCode::Char('e') | KeyCode::Enter => {
+            app.start_tag_edit(true);
+        }
+        KeyCode::Char('x') => {
+            state.confirm_delete = !state.tags.is_empty();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.confirm_delete = !state.tags.is_empty();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_secret_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('y') => {
+            if let Some(ref mut dialog) = app.secret_dialog {
+                dialog.revealed = !dialog.revealed;
+            }
+        }
+        KeyCode::Char('c') => {
+            if let Some(ref dialog) = app.secret_dialog
+                && let Err(e) = crate::clipboard::copy_to_clipboard(&dialog.secret)
+            {
+                app.notify_error(format!("Copy failed: {}", e));
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_input_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.exit_mode();
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut prompt) = app.input_prompt {
+                prompt.text.pop();
+            }
+        }
+        KeyCode::Enter => {
+            let Some(prompt) = app.input_prompt.take() else {
+                app.exit_mode();
+                return Ok(false);
+            };
+            let input = prompt.text.trim().to_string();
+            if input.is_empty() {
+                // Keep prompting until a value is entered
+                app.input_prompt = Some(prompt);
+                return Ok(false);
+            }
+            
+            // Port forwards run locally in the background rather than as an API action
+            if prompt.action.sdk_method == "port_forward" {
+                app.exit_mode();
+                app.start_port_forward(&prompt.resource_id, &input).await;
+                return Ok(false);
+            }
+            
+            // Role hops swap the clients rather than calling an API on the resource
+            if prompt.action.sdk_method == "assume_role" {
+                app.exit_mode();
+                app.assume_org_role(&prompt.resource_id, &input).await;
+                return Ok(false);
+            }
+            
+            // Athena queries open their results view instead of returning to the list
+            if prompt.action.sdk_method == "start_query_execution" {
+                app.exit_mode();
+                app.run_athena_query(&prompt.resource_id, &input).await;
+                return Ok(false);
+            }
+            
+            if prompt.action.requires_confirm()
+                && let Some(mut pending) = app.create_pending_action(&prompt.action, &prompt.resource_id)
+            {
+                // Never echo masked values back in the confirmation
+                if !prompt.action.prompt.as_ref().is_some_and(|p| p.masked) {
+                    pending.message = format!("{} as '{}'?", pending.message.trim_end_matches('?'), input);
+                }
+                pending.input = Some(input);
+                app.enter_confirm_mode(pending);
+                return Ok(false);
+            }
+            
+            if let Some(resource) = app.current_resource() {
+                let service = resource.service.clone();
+                app.run_action(&service, &prompt.action.sdk_method, &prompt.resource_id, Some(&input));
+            }
+            app.exit_mode();
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut prompt) = app.input_prompt {
+                prompt.text.push(c);
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
         KeyCode::Enter => {
             app.select_profile().await?;
         }
+        KeyCode::Char('a') => {
+            app.resolve_profile_account();
+        }
         _ => {}
     }
     Ok(false)
 }
 
 async fn handle_regions_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Enter => {
+            app.select_region().await?;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_sso_sessions_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Char('x') => {
+            app.delete_selected_sso_session();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.delete_selected_sso_session();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_audit_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_notifications_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Char('c') => {
+            app.notifications.clear();
+            app.notifications_selected = 0;
+        }
+        KeyCode::Enter => {
+            app.show_selected_notification();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_api_calls_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Char('r') => {
+            app.enter_api_calls_mode();
+        }
+        KeyCode::Char('c') => {
+            crate::aws::api_log::clear();
+            app.enter_api_calls_mode();
+        }
+        KeyCode::Enter => {
+            app.show_selected_api_call();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_diff_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let last = app.diff_lines.len().saturating_sub(1);
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('=') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.diff_scroll = (app.diff_scroll + 10).min(last);
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.diff_scroll = app.diff_scroll.saturating_sub(10);
+        }
         KeyCode::Char('j') | KeyCode::Down => {
-            app.next();
+            app.diff_scroll = (app.diff_scroll + 1).min(last);
         }
         KeyCode::Char('k') | KeyCode::Up => {
-            app.previous();
+            app.diff_scroll = app.diff_scroll.saturating_sub(1);
         }
         KeyCode::Char('g') | KeyCode::Home => {
-            app.go_to_top();
+            app.diff_scroll = 0;
         }
         KeyCode::Char('G') | KeyCode::End => {
-            app.go_to_bottom();
+            app.diff_scroll = last;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_columns_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Char(' ') | KeyCode::Char('x') => {
+            app.toggle_picker_column();
+        }
+        KeyCode::Char('K') => {
+            app.move_picker_column(-1);
+        }
+        KeyCode::Char('J') => {
+            app.move_picker_column(1);
+        }
+        KeyCode::Char('r') => {
+            app.reset_column_picker();
         }
         KeyCode::Enter => {
-            app.select_region().await?;
+            app.save_column_picker();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_filters_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Enter => {
+            app.apply_saved_filter();
+        }
+        KeyCode::Char('a') => {
+            app.toggle_auto_filter();
+        }
+        KeyCode::Char('x') => {
+            app.delete_saved_filter();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.delete_saved_filter();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_jumps_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if handle_list_navigation(app, &key) {
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('g') => {
+            app.go_to_top();
+        }
+        KeyCode::Enter => {
+            app.open_jump().await?;
+        }
+        KeyCode::Char('x') => {
+            app.delete_jump();
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.delete_jump();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_mfa_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let Some(state) = app.mfa_state.as_mut() else {
+        app.exit_mode();
+        return Ok(false);
+    };
+
+    match key.code {
+        KeyCode::Esc => {
+            app.mfa_state = None;
+            app.exit_mode();
+        }
+        KeyCode::Enter if !state.code.is_empty() => {
+            app.submit_mfa_code().await;
+        }
+        KeyCode::Backspace => {
+            state.code.pop();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() && state.code.len() < 8 => {
+            state.code.push(c);
+            state.error = None;
         }
         _ => {}
     }
@@ -576,7 +1212,7 @@ async fn handle_sso_login_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                     app.exit_mode();
                     // Actually switch the profile now that SSO is complete
                     if let Err(e) = app.switch_profile(&profile_to_switch).await {
-                        app.error_message = Some(format!("Failed to switch profile: {}", e));
+                        app.notify_error(format!("Failed to switch profile: {}", e));
                     } else {
                         let _ = app.refresh_current().await;
                     }
@@ -644,6 +1280,19 @@ pub async fn poll_sso_if_waiting(app: &mut App) {
 }
 
 async fn handle_log_tail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.log_tail_state.as_ref().is_some_and(|state| state.json_popup.is_some()) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => app.close_log_json_popup(),
+            KeyCode::Char('k') | KeyCode::Up => app.log_json_popup_scroll(-1),
+            KeyCode::Char('j') | KeyCode::Down => app.log_json_popup_scroll(1),
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.log_json_popup_scroll(-10),
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.log_json_popup_scroll(10),
+            KeyCode::Char('g') | KeyCode::Home => app.log_json_popup_scroll(isize::MIN),
+            KeyCode::Char('G') | KeyCode::End => app.log_json_popup_scroll(isize::MAX),
+            _ => {}
+        }
+        return Ok(false);
+    }
     match key.code {
         // Exit log tail mode
         KeyCode::Esc | KeyCode::Char('q') => {
@@ -677,6 +1326,14 @@ async fn handle_log_tail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Char(' ') => {
             app.toggle_log_tail_pause();
         }
+        // Toggle line wrapping
+        KeyCode::Char('w') => {
+            app.toggle_log_tail_wrap();
+        }
+        // Pretty-print the selected line as JSON
+        KeyCode::Enter => {
+            app.expand_log_event();
+        }
         _ => {}
     }
     Ok(false)
@@ -688,8 +1345,9 @@ pub async fn poll_logs_if_tailing(app: &mut App) {
         return;
     }
 
+    // Live Tail events are taken in every loop; polling runs every 2 seconds
     let should_poll = if let Some(ref state) = app.log_tail_state {
-        !state.paused && state.last_poll.elapsed() >= Duration::from_secs(2)
+        !state.paused && (app.log_live_tail.is_some() || state.last_poll.elapsed() >= Duration::from_secs(2))
     } else {
         false
     };
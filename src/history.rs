@@ -0,0 +1,84 @@
+//! Command history for the `:` command box, persisted across sessions
+//!
+//! Stored in `history` next to the config file, oldest command first.
+
+use std::path::PathBuf;
+
+/// Commands kept on disk
+const MAX_ENTRIES: usize = 500;
+
+/// Path of the history file
+pub fn history_path() -> PathBuf {
+    if let Some(config_dir) = dirs::config_dir() {
+        return config_dir.join("taws").join("history");
+    }
+    if let Some(home) = dirs::home_dir() {
+        return home.join(".taws").join("history");
+    }
+    PathBuf::from(".taws").join("history")
+}
+
+/// Load saved commands, oldest first
+pub fn load() -> Vec<String> {
+    std::fs::read_to_string(history_path())
+        .map(|content| content.lines().filter(|l| !l.trim().is_empty()).map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Add a command (moving any earlier copy to the end) and save the history
+pub fn append(history: &mut Vec<String>, command: &str) {
+    let command = command.trim();
+    if command.is_empty() {
+        return;
+    }
+    history.retain(|c| c != command);
+    history.push(command.to_string());
+    if history.len() > MAX_ENTRIES {
+        history.drain(..history.len() - MAX_ENTRIES);
+    }
+    // Tests never touch the user's history
+    if cfg!(test) {
+        return;
+    }
+
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let mut content = history.join("\n");
+    content.push('\n');
+    if let Err(e) = std::fs::write(&path, content) {
+        tracing::warn!("Failed to save command history {:?}: {}", path, e);
+    }
+}
+
+/// Case-insensitive fuzzy match: the query's characters appear in order
+pub fn fuzzy_match(query: &str, candidate: &str) -> bool {
+    let mut chars = candidate.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// Commands matching `query`, most recent first
+pub fn search(history: &[String], query: &str) -> Vec<String> {
+    history.iter().rev().filter(|c| fuzzy_match(query, c)).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_search() {
+        assert!(fuzzy_match("ec2i", "ec2-instances"));
+        assert!(fuzzy_match("FTE", "find tag:Env=prod"));
+        assert!(!fuzzy_match("ie", "ec2"));
+
+        let history = vec!["ec2-instances".to_string(), "lambda".to_string(), "ecs-clusters".to_string()];
+        assert_eq!(search(&history, "ec"), vec!["ecs-clusters", "ec2-instances"]);
+        assert_eq!(search(&history, "").len(), 3);
+    }
+}
@@ -0,0 +1,151 @@
+//! Collapsible JSON tree for the describe view
+//!
+//! Flattens a document into pretty-printed lines (the same layout as
+//! `serde_json::to_string_pretty`), folding the objects and arrays whose
+//! path is in the collapsed set into a single `{ N keys }` / `[ N items ]` line.
+
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// One displayed line of the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeLine {
+    pub text: String,
+    /// Path of the object or array this line opens (None for other lines)
+    pub node: Option<String>,
+    /// Path of the object or array the line belongs to
+    pub parent: String,
+}
+
+/// Path of the document root
+pub const ROOT: &str = "";
+
+/// Flatten `value` into display lines, folding the `collapsed` paths
+pub fn flatten(value: &Value, collapsed: &HashSet<String>) -> Vec<TreeLine> {
+    let mut walker = Walker { collapsed, lines: Vec::new() };
+    walker.walk(value, ROOT, ROOT, "", 0, false);
+    walker.lines
+}
+
+/// Paths of every non-empty object and array below the root (for collapse-all)
+pub fn container_paths(value: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut stack = vec![(ROOT.to_string(), value)];
+    while let Some((path, value)) = stack.pop() {
+        for (child_path, child) in children(&path, value) {
+            if is_container(child) {
+                paths.push(child_path.clone());
+                stack.push((child_path, child));
+            }
+        }
+    }
+    paths
+}
+
+fn is_container(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(arr) => !arr.is_empty(),
+        _ => false,
+    }
+}
+
+/// Child values with their paths (`/Key`, `/0`, as in JSON pointers)
+fn children<'a>(path: &str, value: &'a Value) -> Vec<(String, &'a Value)> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, child)| (format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1")), child))
+            .collect(),
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .map(|(i, child)| (format!("{}/{}", path, i), child))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+struct Walker<'a> {
+    collapsed: &'a HashSet<String>,
+    lines: Vec<TreeLine>,
+}
+
+impl Walker<'_> {
+    fn walk(&mut self, value: &Value, path: &str, parent: &str, label: &str, depth: usize, comma: bool) {
+        let indent = "  ".repeat(depth);
+        let comma = if comma { "," } else { "" };
+        let line = |text: String, node: Option<&str>| TreeLine {
+            text,
+            node: node.map(str::to_string),
+            parent: parent.to_string(),
+        };
+
+        if !is_container(value) {
+            let scalar = serde_json::to_string(value).unwrap_or_default();
+            self.lines.push(line(format!("{}{}{}{}", indent, label, scalar, comma), None));
+            return;
+        }
+
+        let (open, close) = if value.is_object() { ('{', '}') } else { ('[', ']') };
+        if self.collapsed.contains(path) {
+            let summary = match value {
+                Value::Object(map) => format!("{} keys", map.len()),
+                Value::Array(arr) => format!("{} items", arr.len()),
+                _ => String::new(),
+            };
+            self.lines.push(line(format!("{}{}{} {} {}{}", indent, label, open, summary, close, comma), Some(path)));
+            return;
+        }
+
+        self.lines.push(line(format!("{}{}{}", indent, label, open), Some(path)));
+        let kids = children(path, value);
+        let last = kids.len().saturating_sub(1);
+        let keys: Vec<Option<&String>> = match value {
+            Value::Object(map) => map.keys().map(Some).collect(),
+            _ => vec![None; kids.len()],
+        };
+        for (i, ((child_path, child), key)) in kids.iter().zip(keys).enumerate() {
+            let label = match key {
+                Some(key) => format!("{}: ", serde_json::to_string(key).unwrap_or_default()),
+                None => String::new(),
+            };
+            self.walk(child, child_path, path, &label, depth + 1, i < last);
+        }
+        self.lines.push(TreeLine {
+            text: format!("{}{}{}", indent, close, comma),
+            node: None,
+            parent: path.to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten() {
+        let value = serde_json::json!({
+            "Id": "i-0abc",
+            "Tags": {"Env": "prod", "Team": "web"},
+            "Ports": [22, 443],
+            "Empty": {}
+        });
+
+        // Fully expanded, the tree reads exactly like pretty-printed JSON
+        let expanded: Vec<String> = flatten(&value, &HashSet::new()).into_iter().map(|l| l.text).collect();
+        assert_eq!(expanded.join("\n"), serde_json::to_string_pretty(&value).unwrap());
+
+        let collapsed: HashSet<String> = ["/Tags".to_string(), "/Ports".to_string()].into();
+        let lines = flatten(&value, &collapsed);
+        let tags = lines.iter().find(|l| l.node.as_deref() == Some("/Tags")).unwrap();
+        assert_eq!(tags.text.trim(), r#""Tags": { 2 keys }"#);
+        let ports = lines.iter().find(|l| l.node.as_deref() == Some("/Ports")).unwrap();
+        assert_eq!(ports.text.trim(), r#""Ports": [ 2 items ],"#);
+
+        let mut paths = container_paths(&value);
+        paths.sort();
+        assert_eq!(paths, vec!["/Ports", "/Tags"]);
+    }
+}
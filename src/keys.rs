@@ -0,0 +1,338 @@
+//! Key bindings for list views, remappable from the `keys` config section
+//!
+//! Built-in actions (navigation, describe, filter, ...) have default keys
+//! that `keys` entries replace. Resource actions are remapped with
+//! `action.<sdk_method>` entries, e.g. `action.terminate_instance: ctrl+x`.
+
+use crate::config::KeySpec;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// Config prefix for remapping resource actions
+const ACTION_PREFIX: &str = "action.";
+
+/// A key with an optional Ctrl modifier (Shift is part of the character)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyBinding {
+    /// Parse "j", "G", "ctrl+d", "enter", "shift+tab", "f5", ...
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let lower = text.to_lowercase();
+        let (ctrl, key) = match lower.strip_prefix("ctrl+").or_else(|| lower.strip_prefix("ctrl-")) {
+            Some(_) => (true, &text[5..]),
+            None => (false, text),
+        };
+        let code = match key.to_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "shift+tab" | "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            name if name.len() > 1 && name.starts_with('f') => KeyCode::F(name[1..].parse().ok()?),
+            _ => {
+                let mut chars = key.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                // Terminals report Ctrl+letter in lowercase
+                KeyCode::Char(if ctrl { c.to_ascii_lowercase() } else { c })
+            }
+        };
+        Some(Self { code, ctrl })
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        key.code == self.code && key.modifiers.contains(KeyModifiers::CONTROL) == self.ctrl
+    }
+
+    /// Label for the help screen, e.g. "Ctrl+d", "Enter", "G"
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+        if self.ctrl { format!("Ctrl+{}", key) } else { key }
+    }
+}
+
+/// Built-in actions that can be remapped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Down,
+    Up,
+    Top,
+    Bottom,
+    PageDown,
+    PageUp,
+    Open,
+    Describe,
+    Filter,
+    Command,
+    Help,
+    Tags,
+    SplitView,
+    ZoomColumn,
+    ColumnLeft,
+    ColumnRight,
+    Columns,
+    Mark,
+    Diff,
+    Palette,
+    Bookmark,
+    Jumps,
+    Watch,
+    Refresh,
+    Back,
+    NextPage,
+    PrevPage,
+    NewTab,
+    CloseTab,
+    NextTab,
+    PrevTab,
+}
+
+/// Config name and default keys of every built-in action
+const DEFAULT_BINDINGS: &[(KeyAction, &str, &[&str])] = &[
+    (KeyAction::Down, "down", &["j", "down"]),
+    (KeyAction::Up, "up", &["k", "up"]),
+    (KeyAction::Top, "top", &["home"]),
+    (KeyAction::Bottom, "bottom", &["G", "end"]),
+    (KeyAction::PageDown, "page_down", &["ctrl+d", "ctrl+f"]),
+    (KeyAction::PageUp, "page_up", &["ctrl+u", "ctrl+b"]),
+    (KeyAction::Open, "open", &["enter"]),
+    (KeyAction::Describe, "describe", &["d"]),
+    (KeyAction::Filter, "filter", &["/"]),
+    (KeyAction::Command, "command", &[":"]),
+    (KeyAction::Help, "help", &["?"]),
+    (KeyAction::Tags, "tags", &["T"]),
+    (KeyAction::SplitView, "split_view", &["v"]),
+    (KeyAction::ZoomColumn, "zoom_column", &["z"]),
+    (KeyAction::ColumnLeft, "column_left", &["left"]),
+    (KeyAction::ColumnRight, "column_right", &["right"]),
+    (KeyAction::Columns, "columns", &["C"]),
+    (KeyAction::Mark, "mark", &["x", "M"]),
+    (KeyAction::Diff, "diff", &["="]),
+    (KeyAction::Palette, "palette", &["ctrl+p"]),
+    (KeyAction::Bookmark, "bookmark", &["m"]),
+    (KeyAction::Jumps, "jumps", &["'"]),
+    (KeyAction::Watch, "watch", &["W"]),
+    (KeyAction::Refresh, "refresh", &["ctrl+r"]),
+    (KeyAction::Back, "back", &["backspace"]),
+    (KeyAction::NextPage, "next_page", &["]"]),
+    (KeyAction::PrevPage, "prev_page", &["["]),
+    (KeyAction::NewTab, "new_tab", &["ctrl+t"]),
+    (KeyAction::CloseTab, "close_tab", &["ctrl+w"]),
+    (KeyAction::NextTab, "next_tab", &["tab"]),
+    (KeyAction::PrevTab, "prev_tab", &["shift+tab"]),
+];
+
+/// Effective key bindings (defaults plus the `keys` config section)
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    /// Built-in actions in lookup order; remapped actions come first so
+    /// they win over a default they conflict with
+    bindings: Vec<(KeyAction, Vec<KeyBinding>)>,
+    /// Resource action shortcuts remapped by sdk_method
+    action_shortcuts: HashMap<String, KeyBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&HashMap::new()).0
+    }
+}
+
+impl Keymap {
+    /// Build the keymap from the `keys` config section. Returns a message for
+    /// every unknown name, unparsable key and conflicting binding.
+    pub fn from_config(keys: &HashMap<String, KeySpec>) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut remapped = Vec::new();
+        let mut defaults = Vec::new();
+        let mut action_shortcuts = HashMap::new();
+
+        let parse = |name: &str, keys: &[&str], errors: &mut Vec<String>| -> Vec<KeyBinding> {
+            keys.iter()
+                .filter_map(|key| {
+                    let binding = KeyBinding::parse(key);
+                    if binding.is_none() {
+                        errors.push(format!("{}: unknown key '{}'", name, key));
+                    }
+                    binding
+                })
+                .collect()
+        };
+
+        for (action, name, default_keys) in DEFAULT_BINDINGS {
+            match keys.get(*name) {
+                Some(spec) => remapped.push((*action, parse(name, &spec.keys(), &mut errors))),
+                None => defaults.push((*action, parse(name, default_keys, &mut errors))),
+            }
+        }
+
+        let mut names: Vec<&String> = keys.keys().collect();
+        names.sort();
+        for name in names {
+            if let Some(method) = name.strip_prefix(ACTION_PREFIX) {
+                if let Some(binding) = parse(name, &keys[name].keys(), &mut errors).first() {
+                    action_shortcuts.insert(method.to_string(), *binding);
+                }
+            } else if !DEFAULT_BINDINGS.iter().any(|(_, n, _)| n == name) {
+                errors.push(format!("unknown action '{}'", name));
+            }
+        }
+
+        // Report keys bound twice; the first binding in lookup order wins
+        let mut seen: HashMap<KeyBinding, KeyAction> = HashMap::new();
+        for (action, bindings) in remapped.iter().chain(defaults.iter()) {
+            for binding in bindings {
+                match seen.get(binding) {
+                    Some(other) if other != action => errors.push(format!(
+                        "{} is bound to both {} and {}; using {}",
+                        binding.label(),
+                        action_name(*other),
+                        action_name(*action),
+                        action_name(*other)
+                    )),
+                    _ => {
+                        seen.insert(*binding, *action);
+                    }
+                }
+            }
+        }
+        for (method, binding) in &action_shortcuts {
+            if let Some(other) = seen.get(binding) {
+                errors.push(format!(
+                    "{} is bound to both {} and {}{}; using {}",
+                    binding.label(),
+                    action_name(*other),
+                    ACTION_PREFIX,
+                    method,
+                    action_name(*other)
+                ));
+            }
+        }
+
+        remapped.extend(defaults);
+        (Self { bindings: remapped, action_shortcuts }, errors)
+    }
+
+    /// The built-in action bound to `key`
+    pub fn action_for(&self, key: &KeyEvent) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|(_, bindings)| bindings.iter().any(|b| b.matches(key)))
+            .map(|(action, _)| *action)
+    }
+
+    /// Effective key of a resource action: the configured override or its
+    /// `shortcut` from the resource definition
+    pub fn action_shortcut(&self, sdk_method: &str, shortcut: Option<&str>) -> Option<KeyBinding> {
+        self.action_shortcuts
+            .get(sdk_method)
+            .copied()
+            .or_else(|| shortcut.and_then(KeyBinding::parse))
+    }
+
+    /// Help screen label of a built-in action's keys, e.g. "j / ↓"
+    pub fn label(&self, action: KeyAction) -> String {
+        let labels: Vec<String> = self
+            .bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, bindings)| bindings.iter().map(KeyBinding::label).collect())
+            .unwrap_or_default();
+        if labels.is_empty() { "-".to_string() } else { labels.join(" / ") }
+    }
+
+    /// Help screen label of a resource action's key
+    pub fn action_label(&self, sdk_method: &str, shortcut: Option<&str>) -> String {
+        self.action_shortcut(sdk_method, shortcut)
+            .map(|b| b.label())
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+fn action_name(action: KeyAction) -> &'static str {
+    DEFAULT_BINDINGS
+        .iter()
+        .find(|(a, _, _)| *a == action)
+        .map(|(_, name, _)| *name)
+        .unwrap_or("?")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_key_binding() {
+        assert_eq!(KeyBinding::parse("ctrl+d"), Some(KeyBinding { code: KeyCode::Char('d'), ctrl: true }));
+        assert_eq!(KeyBinding::parse("G"), Some(KeyBinding { code: KeyCode::Char('G'), ctrl: false }));
+        assert_eq!(KeyBinding::parse("Shift+Tab").map(|b| b.code), Some(KeyCode::BackTab));
+        assert_eq!(KeyBinding::parse("f5").map(|b| b.code), Some(KeyCode::F(5)));
+        assert!(KeyBinding::parse("jk").is_none());
+        assert_eq!(KeyBinding::parse("ctrl+x").unwrap().label(), "Ctrl+x");
+    }
+
+    #[test]
+    fn test_remap_and_conflicts() {
+        let keys: HashMap<String, KeySpec> = serde_yaml::from_str(
+            "down: [n, down]\ndescribe: i\naction.terminate_instance: ctrl+x\nbottom: T\nbogus: x\n",
+        )
+        .unwrap();
+        let (keymap, errors) = Keymap::from_config(&keys);
+
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('n'), KeyModifiers::NONE)), Some(KeyAction::Down));
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('j'), KeyModifiers::NONE)), None);
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('i'), KeyModifiers::NONE)), Some(KeyAction::Describe));
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('d'), KeyModifiers::NONE)), None);
+        assert_eq!(keymap.label(KeyAction::Down), "n / ↓");
+        assert_eq!(
+            keymap.action_shortcut("terminate_instance", Some("ctrl+d")),
+            KeyBinding::parse("ctrl+x")
+        );
+        assert_eq!(keymap.action_shortcut("stop_instance", Some("S")), KeyBinding::parse("S"));
+
+        // The remapped binding wins over the default it collides with
+        assert_eq!(keymap.action_for(&key(KeyCode::Char('T'), KeyModifiers::SHIFT)), Some(KeyAction::Bottom));
+        assert!(errors.iter().any(|e| e.contains("T is bound to both bottom and tags")));
+        assert!(errors.iter().any(|e| e.contains("unknown action 'bogus'")));
+    }
+}
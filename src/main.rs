@@ -1,9 +1,22 @@
 mod app;
+mod audit;
+mod bookmarks;
 mod aws;
+mod clipboard;
 mod config;
+mod demo;
+mod diff;
 mod event;
+mod history;
+mod json_tree;
+mod keys;
+mod notifications;
+mod palette;
 mod resource;
+#[cfg(test)]
+mod tests;
 mod ui;
+mod watch;
 
 /// Version injected at compile time via TAWS_VERSION env var (set by CI/CD),
 /// or "dev" for local builds.
@@ -13,7 +26,7 @@ pub const VERSION: &str = match option_env!("TAWS_VERSION") {
 };
 
 use anyhow::Result;
-use app::{App, Mode, SsoLoginState};
+use app::{App, ExternalCommand, Mode, SsoLoginState};
 use aws::client::ClientResult;
 use clap::{Parser, ValueEnum};
 use config::Config;
@@ -53,6 +66,33 @@ struct Args {
     /// Custom AWS endpoint URL (for LocalStack, etc.). Also reads from AWS_ENDPOINT_URL env var.
     #[arg(long)]
     endpoint_url: Option<String>,
+
+    /// Skip the splash screen and start as soon as the first view is loaded
+    #[arg(long)]
+    no_splash: bool,
+
+    /// Offline demo: show canned sample data instead of calling AWS (no
+    /// credentials needed)
+    #[arg(long, conflicts_with_all = ["profile", "endpoint_url"])]
+    demo: bool,
+
+    /// Save every AWS response under this directory, e.g. to attach to a bug
+    /// report (secrets are redacted)
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["demo", "replay"])]
+    record: Option<PathBuf>,
+
+    /// Answer AWS requests from responses saved with --record instead of
+    /// calling AWS (no credentials needed)
+    #[arg(long, value_name = "DIR", conflicts_with = "demo")]
+    replay: Option<PathBuf>,
+
+    /// Resource view to open at startup (e.g. s3-buckets)
+    #[arg(long, conflicts_with = "target")]
+    resource: Option<String>,
+
+    /// View or item to open at startup: a resource key, resource/item-id
+    /// (e.g. ec2-instances/i-0123456789abcdef0), an ARN or an EC2 id
+    target: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -146,6 +186,13 @@ async fn main() -> Result<()> {
 
     match result {
         Ok(Some(mut app)) => {
+            // Land on the view or item given on the command line
+            if let Some(link) = args.target.as_ref().or(args.resource.as_ref())
+                && let Err(e) = app.open_deep_link(link).await
+            {
+                app.notify_aws_error("", &e);
+            }
+
             // Run the main app
             let run_result = run_app(&mut terminal, &mut app).await;
 
@@ -197,6 +244,16 @@ enum InitResult {
         available_regions: Vec<String>,
         readonly: bool,
     },
+    MfaRequired {
+        profile: String,
+        mfa_serial: String,
+        region: String,
+        endpoint_url: Option<String>,
+        config: Config,
+        available_profiles: Vec<String>,
+        available_regions: Vec<String>,
+        readonly: bool,
+    },
 }
 
 async fn initialize_with_splash<B: Backend>(terminal: &mut Terminal<B>, args: &Args) -> Result<Option<App>>
@@ -229,6 +286,28 @@ where
                 readonly,
             ).await
         }
+        Some(InitResult::MfaRequired {
+            profile,
+            mfa_serial,
+            region,
+            endpoint_url,
+            config,
+            available_profiles,
+            available_regions,
+            readonly,
+        }) => {
+            handle_mfa_flow(
+                terminal,
+                profile,
+                mfa_serial,
+                region,
+                endpoint_url,
+                config,
+                available_profiles,
+                available_regions,
+                readonly,
+            ).await
+        }
     }
 }
 
@@ -236,20 +315,56 @@ async fn initialize_inner<B: Backend>(terminal: &mut Terminal<B>, args: &Args) -
 where
     B::Error: Send + Sync + 'static,
 {
+    // Step 1: Load configuration (CLI args > env vars > saved config)
+    let config = Config::load();
+    let show_splash = !args.no_splash && !config.skip_splash;
+    
     let mut splash = SplashState::new();
+    let mut draw_splash = |splash: &SplashState| -> Result<()> {
+        if show_splash {
+            terminal.draw(|f| render_splash(f, splash))?;
+        }
+        Ok(())
+    };
 
     // Render initial splash
-    terminal.draw(|f| render_splash(f, &splash))?;
+    draw_splash(&splash)?;
 
     // Check for abort
     if check_abort()? {
         return Ok(None);
     }
 
-    // Step 1: Load configuration (CLI args > env vars > saved config)
-    let config = Config::load();
-    let profile = args.profile.clone()
-        .unwrap_or_else(|| config.effective_profile());
+    if let Some(proxy_url) = &config.proxy_url
+        && let Err(e) = aws::http::set_proxy_url(proxy_url)
+    {
+        tracing::warn!("{}", e);
+    }
+    aws::http::set_service_endpoints(config.endpoints.clone());
+    aws::http::set_endpoint_variants(config.effective_use_fips(), config.effective_use_dualstack());
+    if let Some(timezone) = &config.timezone {
+        match resource::DisplayTimezone::parse(timezone) {
+            Some(timezone) => resource::set_display_timezone(timezone),
+            None => tracing::warn!("Unknown timezone '{}', showing local time", timezone),
+        }
+    }
+    for error in resource::load_user_resources() {
+        tracing::warn!("User-defined resources: {}", error);
+    }
+    if args.demo {
+        demo::enable();
+    }
+    if let Some(dir) = &args.record {
+        aws::capture::record_to(dir)?;
+    }
+    if let Some(dir) = &args.replay {
+        aws::capture::replay_from(dir)?;
+    }
+    let profile = if args.demo {
+        demo::PROFILE.to_string()
+    } else {
+        args.profile.clone().unwrap_or_else(|| config.effective_profile())
+    };
     let region = args.region.clone()
         .unwrap_or_else(|| config.effective_region());
     
@@ -260,7 +375,7 @@ where
     tracing::info!("Using profile: {}, region: {}, endpoint_url: {:?}", profile, region, endpoint_url);
     
     splash.set_message(&format!("Loading AWS config [profile: {}]", profile));
-    terminal.draw(|f| render_splash(f, &splash))?;
+    draw_splash(&splash)?;
     splash.complete_step();
 
     if check_abort()? {
@@ -269,9 +384,13 @@ where
 
     // Step 2: Load profiles early (needed for SSO flow too)
     splash.set_message("Reading ~/.aws/config");
-    terminal.draw(|f| render_splash(f, &splash))?;
+    draw_splash(&splash)?;
 
-    let available_profiles = aws::profiles::list_profiles().unwrap_or_else(|_| vec!["default".to_string()]);
+    let available_profiles = if args.demo {
+        vec![demo::PROFILE.to_string()]
+    } else {
+        aws::profiles::list_profiles().unwrap_or_else(|_| vec!["default".to_string()])
+    };
     let available_regions = aws::profiles::list_regions();
     splash.complete_step();
 
@@ -280,10 +399,14 @@ where
     }
 
     // Step 3: Initialize all AWS clients (check for SSO requirement)
-    splash.set_message(&format!("Connecting to AWS services [{}]", region));
-    terminal.draw(|f| render_splash(f, &splash))?;
+    splash.set_message(&format!("Loading credentials [{}]", region));
+    draw_splash(&splash)?;
 
-    let client_result = aws::client::AwsClients::new_with_sso_check(&profile, &region, endpoint_url.clone()).await?;
+    let client_result = if args.demo || args.replay.is_some() {
+        ClientResult::Ok(aws::client::AwsClients::offline(&profile, &region), region.clone())
+    } else {
+        aws::client::AwsClients::new_with_sso_check(&profile, &region, endpoint_url.clone()).await?
+    };
     
     let (clients, actual_region) = match client_result {
         ClientResult::Ok(clients, actual_region) => (clients, actual_region),
@@ -300,6 +423,19 @@ where
                 readonly: args.readonly,
             }));
         }
+        ClientResult::MfaRequired { profile, mfa_serial } => {
+            // MFA code required - prompt before continuing
+            return Ok(Some(InitResult::MfaRequired {
+                profile,
+                mfa_serial,
+                region,
+                endpoint_url,
+                config,
+                available_profiles,
+                available_regions,
+                readonly: args.readonly,
+            }));
+        }
     };
     
     splash.complete_step();
@@ -310,7 +446,7 @@ where
 
     // Step 4: Fetch EC2 instances using new dynamic system
     splash.set_message(&format!("Fetching instances from {}", actual_region));
-    terminal.draw(|f| render_splash(f, &splash))?;
+    draw_splash(&splash)?;
 
     let (instances, initial_error) = {
         // Use the new JSON-driven resource system
@@ -325,10 +461,12 @@ where
 
     splash.complete_step();
     splash.set_message("Ready!");
-    terminal.draw(|f| render_splash(f, &splash))?;
+    draw_splash(&splash)?;
 
     // Small delay to show completion
-    tokio::time::sleep(Duration::from_millis(200)).await;
+    if show_splash {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
 
     // Create the app with config
     let mut app = App::from_initialized(
@@ -345,7 +483,7 @@ where
 
     // Set initial error if any
     if let Some(err) = initial_error {
-        app.error_message = Some(err);
+        app.notify_error(err);
     }
 
     Ok(Some(InitResult::App(app)))
@@ -495,36 +633,15 @@ where
                         match key.code {
                             KeyCode::Enter | KeyCode::Esc => {
                                 // SSO successful - now create the client and continue initialization
-                                // AwsClients::new handles blocking internally via spawn_blocking
-                                let (clients, actual_region) = aws::client::AwsClients::new(&profile, &region, endpoint_url.clone()).await?;
-                                
-                                // Fetch initial resources
-                                let (instances, initial_error) = {
-                                    match resource::fetch_resources("ec2-instances", &clients, &[]).await {
-                                        Ok(items) => (items, None),
-                                        Err(e) => {
-                                            let error_msg = aws::client::format_aws_error(&e);
-                                            (Vec::new(), Some(error_msg))
-                                        }
-                                    }
-                                };
-                                
-                                let mut app = App::from_initialized(
-                                    clients,
+                                let app = create_authenticated_app(
                                     profile,
-                                    actual_region,
+                                    region,
+                                    endpoint_url,
+                                    config,
                                     available_profiles,
                                     available_regions,
-                                    instances,
-                                    config,
                                     readonly,
-                                    endpoint_url,
-                                );
-                                
-                                if let Some(err) = initial_error {
-                                    app.error_message = Some(err);
-                                }
-                                
+                                ).await?;
                                 return Ok(Some(app));
                             }
                             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -578,6 +695,172 @@ where
     }
 }
 
+/// Create the app once SSO login or MFA has made the profile's credentials available
+#[allow(clippy::too_many_arguments)]
+async fn create_authenticated_app(
+    profile: String,
+    region: String,
+    endpoint_url: Option<String>,
+    config: Config,
+    available_profiles: Vec<String>,
+    available_regions: Vec<String>,
+    readonly: bool,
+) -> Result<App> {
+    // AwsClients::new handles blocking internally via spawn_blocking
+    let (clients, actual_region) = aws::client::AwsClients::new(&profile, &region, endpoint_url.clone()).await?;
+    
+    // Fetch initial resources
+    let (instances, initial_error) = {
+        match resource::fetch_resources("ec2-instances", &clients, &[]).await {
+            Ok(items) => (items, None),
+            Err(e) => {
+                let error_msg = aws::client::format_aws_error(&e);
+                (Vec::new(), Some(error_msg))
+            }
+        }
+    };
+    
+    let mut app = App::from_initialized(
+        clients,
+        profile,
+        actual_region,
+        available_profiles,
+        available_regions,
+        instances,
+        config,
+        readonly,
+        endpoint_url,
+    );
+    
+    if let Some(err) = initial_error {
+        app.notify_error(err);
+    }
+    
+    Ok(app)
+}
+
+/// Prompt for the MFA code of the startup profile, then create the app
+#[allow(clippy::too_many_arguments)]
+async fn handle_mfa_flow<B: Backend>(
+    terminal: &mut Terminal<B>,
+    profile: String,
+    mfa_serial: String,
+    region: String,
+    endpoint_url: Option<String>,
+    config: Config,
+    available_profiles: Vec<String>,
+    available_regions: Vec<String>,
+    readonly: bool,
+) -> Result<Option<App>>
+where
+    B::Error: Send + Sync + 'static,
+{
+    let mut state = app::MfaState {
+        profile: profile.clone(),
+        mfa_serial,
+        code: String::new(),
+        error: None,
+    };
+    
+    loop {
+        terminal.draw(|f| render_mfa_standalone(f, &state))?;
+        
+        if !poll(Duration::from_millis(100))? {
+            continue;
+        }
+        let Event::Key(key) = read()? else {
+            continue;
+        };
+        match key.code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            KeyCode::Enter if !state.code.is_empty() => {
+                match aws::client::start_mfa_session(&profile, &state.code, &region, endpoint_url.clone()).await {
+                    Ok(()) => {
+                        let app = create_authenticated_app(
+                            profile,
+                            region,
+                            endpoint_url,
+                            config,
+                            available_profiles,
+                            available_regions,
+                            readonly,
+                        ).await?;
+                        return Ok(Some(app));
+                    }
+                    Err(e) => {
+                        state.error = Some(aws::client::format_aws_error(&e));
+                        state.code.clear();
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                state.code.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() && state.code.len() < 8 => {
+                state.code.push(c);
+                state.error = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Render the MFA prompt standalone (during initialization, before app is created)
+fn render_mfa_standalone(f: &mut ratatui::Frame, state: &app::MfaState) {
+    use ratatui::{
+        layout::{Alignment, Constraint, Direction, Layout},
+        style::{Color, Modifier, Style},
+        text::{Line, Span},
+        widgets::{Block, Borders, Clear, Paragraph},
+    };
+    
+    let area = f.area();
+    f.render_widget(Clear, area);
+    f.render_widget(Block::default().style(Style::default().bg(Color::Black)), area);
+    
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Length(10), Constraint::Percentage(40)])
+        .split(area);
+    let dialog_area = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(60), Constraint::Percentage(20)])
+        .split(rows[1])[1];
+    
+    let text = vec![
+        Line::from(Span::styled(
+            "<MFA Required>",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Enter the MFA code for profile '{}'", state.profile),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(Span::styled(state.mfa_serial.as_str(), Style::default().fg(Color::DarkGray))),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{}_", state.code), Style::default().fg(Color::White)),
+        ]),
+        Line::from(Span::styled(
+            state.error.as_deref().unwrap_or_default(),
+            Style::default().fg(Color::Red),
+        )),
+        Line::from(Span::styled(
+            "Enter: submit | Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    
+    let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
+    f.render_widget(paragraph, dialog_area);
+}
+
 /// Render SSO dialog standalone (during initialization, before app is created)
 fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
     use ratatui::{
@@ -760,6 +1043,34 @@ where
             return Ok(());
         }
         
+        // Run any requested external program (SSM shell) with the TUI suspended
+        if let Some(command) = app.external_command.take() {
+            run_external_command(terminal, app, command)?;
+        }
+        
+        // Drop port forwards whose session has ended
+        if !app.tunnels.is_empty() {
+            app.reap_tunnels();
+        }
+        
+        // Report background actions that finished
+        if !app.running_actions.is_empty() {
+            app.poll_actions().await;
+        }
+        
+        // Show which account the current credentials act in, and its regions
+        app.poll_account().await;
+        
+        // Pick up profile accounts resolved in the profile picker
+        if !app.profile_lookups.is_empty() {
+            app.poll_profile_lookups().await;
+        }
+        
+        // Poll watched resources for state changes
+        if !app.watches.is_empty() {
+            app.poll_watches().await;
+        }
+        
         // Poll SSO if in waiting state
         if app.mode == Mode::SsoLogin {
             event::poll_sso_if_waiting(app).await;
@@ -770,9 +1081,61 @@ where
             event::poll_logs_if_tailing(app).await;
         }
         
+        // Describe the highlighted row for the split panel (debounced)
+        if app.split_view {
+            app.update_split_describe().await;
+        }
+        
         // Auto-refresh every 5 seconds (only in Normal mode)
         if app.needs_refresh() {
             let _ = app.refresh_current().await;
         }
     }
 }
+
+/// Suspend the TUI, run a program attached to the terminal, then restore the TUI
+fn run_external_command<B: Backend>(terminal: &mut Terminal<B>, app: &mut App, command: ExternalCommand) -> Result<()>
+where
+    B::Error: Send + Sync + 'static,
+{
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    terminal.show_cursor()?;
+    println!("{}", command.banner);
+
+    // Ctrl+C belongs to the child; keep it from terminating taws while it runs
+    let ignore_ctrl_c = tokio::spawn(async {
+        loop {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    });
+    let status = std::process::Command::new(&command.program)
+        .args(&command.args)
+        .envs(command.env.iter().map(|(k, v)| (k, v)))
+        .status();
+    ignore_ctrl_c.abort();
+
+    if command.wait {
+        println!("\n[Press Enter to return to taws]");
+        let mut line = String::new();
+        let _ = io::stdin().read_line(&mut line);
+    }
+
+    match status {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            app.notify_error(format!("{} not found in PATH", command.program));
+        }
+        Err(e) => {
+            app.notify_error(format!("Failed to run {}: {}", command.program, e));
+        }
+        Ok(status) if !status.success() => {
+            app.notify_error(format!("{} exited with {}", command.program, status));
+        }
+        Ok(_) => {}
+    }
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Messages for the user: toasts in the corner of the screen, kept in a
+//! history shown by `:notifications` so a later message doesn't hide them
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Messages kept in the history
+const MAX_HISTORY: usize = 200;
+
+/// Toasts shown at once
+const MAX_TOASTS: usize = 5;
+
+/// How long a toast stays on screen (errors stay longer)
+const TOAST_TTL: Duration = Duration::from_secs(4);
+const ERROR_TOAST_TTL: Duration = Duration::from_secs(8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Success,
+    Error,
+}
+
+impl Level {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Success => "ok",
+            Level::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub level: Level,
+    pub text: String,
+    /// Local time it was posted (display string)
+    pub time: String,
+    /// Full error details (`:error`), for errors reported with their cause
+    pub detail: Option<serde_json::Value>,
+    posted: Instant,
+}
+
+impl Notification {
+    fn ttl(&self) -> Duration {
+        if self.level == Level::Error { ERROR_TOAST_TTL } else { TOAST_TTL }
+    }
+}
+
+/// Posted messages, oldest first
+#[derive(Debug, Default)]
+pub struct Notifications {
+    entries: VecDeque<Notification>,
+}
+
+impl Notifications {
+    pub fn push(&mut self, level: Level, text: impl Into<String>) {
+        self.push_with_detail(level, text, None);
+    }
+
+    /// Post a message with details shown by `:error`
+    pub fn push_with_detail(&mut self, level: Level, text: impl Into<String>, detail: Option<serde_json::Value>) {
+        let text = text.into();
+        if level == Level::Error {
+            tracing::warn!("{}", text);
+        }
+        self.entries.push_back(Notification {
+            level,
+            text,
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            detail,
+            posted: Instant::now(),
+        });
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Recent messages still shown as toasts, oldest first
+    pub fn toasts(&self) -> impl Iterator<Item = &Notification> {
+        let recent: Vec<&Notification> = self
+            .entries
+            .iter()
+            .rev()
+            .take_while(|n| n.posted.elapsed() < ERROR_TOAST_TTL)
+            .filter(|n| n.posted.elapsed() < n.ttl())
+            .take(MAX_TOASTS)
+            .collect();
+        recent.into_iter().rev()
+    }
+
+    /// Every kept message, newest first
+    pub fn history(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter().rev()
+    }
+
+    /// Newest message that has details
+    pub fn latest_detailed(&self) -> Option<&Notification> {
+        self.history().find(|n| n.detail.is_some())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifications() {
+        let mut notifications = Notifications::default();
+        for i in 0..MAX_HISTORY + 3 {
+            notifications.push(Level::Info, format!("message {}", i));
+        }
+        notifications.push(Level::Error, "failed");
+        assert_eq!(notifications.len(), MAX_HISTORY);
+        assert_eq!(notifications.history().next().unwrap().text, "failed");
+
+        let toasts: Vec<&str> = notifications.toasts().map(|n| n.text.as_str()).collect();
+        assert_eq!(toasts.len(), MAX_TOASTS);
+        assert_eq!(toasts.last(), Some(&"failed"));
+
+        notifications.push_with_detail(Level::Error, "denied", Some(serde_json::json!({ "ErrorCode": "AccessDenied" })));
+        notifications.push(Level::Info, "later");
+        assert_eq!(notifications.latest_detailed().unwrap().text, "denied");
+
+        notifications.clear();
+        assert_eq!(notifications.toasts().count(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+//! Ctrl+P palette: one fuzzy-matched list of resources, commands, saved filters and bookmarks
+
+/// What choosing a palette entry does
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteTarget {
+    /// Open a resource view
+    Resource(String),
+    /// Run a `:` command as-is
+    Command(String),
+    /// Open the `:` box with the command typed, for commands that take arguments
+    Prompt(String),
+    /// Open a resource with a saved filter applied
+    Filter { resource: String, filter: String },
+    /// Jump to a bookmark (index into the current profile/region's bookmarks)
+    Bookmark(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+    /// Kind shown in the first column ("resource", "command", ...)
+    pub kind: &'static str,
+    pub label: String,
+    /// Secondary text, also matched (the resource key, the command, ...)
+    pub detail: String,
+    pub target: PaletteTarget,
+}
+
+/// Commands offered by the palette: (command, description, takes arguments)
+pub const PALETTE_COMMANDS: &[(&str, &str, bool)] = &[
+    ("profiles", "Switch AWS profile", false),
+    ("regions", "Switch AWS region", false),
+    ("regions all", "Toggle all-regions view", false),
+    ("tunnels", "Active port forwards", false),
+    ("costs", "Monthly spend by service", false),
+    ("find tag:", "Find resources by tag", true),
+    ("search ", "Resource Explorer search", true),
+    ("goto ", "Go to an ARN or EC2 id", true),
+    ("sso sessions", "Cached SSO tokens", false),
+    ("sso logout", "Delete SSO token", false),
+    ("audit", "Audit log of changes", false),
+    ("notifications", "Past toasts and errors", false),
+    ("error", "Details of the last AWS error", false),
+    ("api", "Recent AWS API calls", false),
+    ("metrics", "Toggle EC2/RDS utilization columns", false),
+    ("keybar", "Toggle the bar of keys for the current view", false),
+    ("columns", "Pick and reorder columns", false),
+    ("filters", "Apply a saved filter", false),
+    ("filter-save ", "Save the current filter", true),
+];
+
+/// Entries matching `query`, best match first (all entries when it is empty)
+pub fn rank(entries: Vec<PaletteEntry>, query: &str) -> Vec<PaletteEntry> {
+    let mut scored: Vec<(i64, PaletteEntry)> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let best = score(query, &entry.label).max(score(query, &entry.detail))?;
+            Some((best, entry))
+        })
+        .collect();
+    // Stable sort keeps the given order among equal scores
+    scored.sort_by_key(|(points, _)| std::cmp::Reverse(*points));
+    scored.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Fuzzy score of `candidate` for `query` (characters in order, ignoring case),
+/// or None if it does not match. Runs of consecutive characters and matches at
+/// word starts score higher; among equals, shorter candidates win.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+
+    let mut points = 0i64;
+    let mut matched = 0;
+    let mut previous: Option<usize> = None;
+    for (i, &c) in candidate.iter().enumerate() {
+        if matched == query.len() {
+            break;
+        }
+        if c != query[matched] {
+            continue;
+        }
+        points += 1;
+        if previous.is_some_and(|p| p + 1 == i) {
+            points += 5;
+        }
+        if i == 0 || !candidate[i - 1].is_alphanumeric() {
+            points += 3;
+        }
+        previous = Some(i);
+        matched += 1;
+    }
+    (matched == query.len()).then(|| points * 100 - candidate.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str, detail: &str) -> PaletteEntry {
+        PaletteEntry {
+            kind: "resource",
+            label: label.to_string(),
+            detail: detail.to_string(),
+            target: PaletteTarget::Resource(detail.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rank() {
+        let entries = vec![
+            entry("EC2 Volumes", "ec2-volumes"),
+            entry("EC2 Instances", "ec2-instances"),
+            entry("ECS Clusters", "ecs-clusters"),
+            entry("Lambda Functions", "lambda-functions"),
+        ];
+        let labels = |query: &str| -> Vec<String> {
+            rank(entries.clone(), query).into_iter().map(|e| e.label).collect()
+        };
+
+        assert_eq!(labels("ec2 inst"), vec!["EC2 Instances"]);
+        assert_eq!(labels("lf"), vec!["Lambda Functions"]);
+        assert_eq!(labels("clus")[0], "ECS Clusters");
+        // Word-start and consecutive matches beat scattered ones
+        assert_eq!(labels("ecs")[0], "ECS Clusters");
+        assert_eq!(labels("").len(), 4);
+        assert!(labels("xyz").is_empty());
+    }
+}
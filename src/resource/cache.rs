@@ -0,0 +1,128 @@
+//! Short-lived cache of list responses
+//!
+//! Switching back and forth between views reuses a page fetched moments ago
+//! instead of calling the API again. Writes clear the whole cache, and
+//! Ctrl+R refreshes without it.
+
+use super::fetcher::{PaginatedResult, ResourceFilter};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long a fetched page is reused
+pub const CACHE_TTL: Duration = Duration::from_secs(3);
+
+/// What a list page was fetched for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub profile: String,
+    /// Role assumed on top of the profile (e.g. an Organizations account hop)
+    pub assumed_role: Option<String>,
+    pub region: String,
+    pub all_regions: bool,
+    pub resource_key: String,
+    pub filters: Vec<(String, Vec<String>)>,
+    pub page_token: Option<String>,
+}
+
+impl CacheKey {
+    pub fn new(
+        profile: &str,
+        assumed_role: Option<&str>,
+        region: &str,
+        all_regions: bool,
+        resource_key: &str,
+        filters: &[ResourceFilter],
+        page_token: Option<&str>,
+    ) -> Self {
+        Self {
+            profile: profile.to_string(),
+            assumed_role: assumed_role.map(String::from),
+            region: region.to_string(),
+            all_regions,
+            resource_key: resource_key.to_string(),
+            filters: filters.iter().map(|f| (f.name.clone(), f.values.clone())).collect(),
+            page_token: page_token.map(String::from),
+        }
+    }
+}
+
+/// Recently fetched list pages. Interior mutability lets writes clear it
+/// from `&self` paths such as the audit hook.
+#[derive(Debug)]
+pub struct ListCache {
+    ttl: Duration,
+    entries: RefCell<HashMap<CacheKey, (Instant, PaginatedResult)>>,
+}
+
+impl Default for ListCache {
+    fn default() -> Self {
+        Self::new(CACHE_TTL)
+    }
+}
+
+impl ListCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// The page fetched for `key`, if it is still fresh
+    pub fn get(&self, key: &CacheKey) -> Option<PaginatedResult> {
+        let mut entries = self.entries.borrow_mut();
+        entries.retain(|_, (fetched, _)| fetched.elapsed() < self.ttl);
+        entries.get(key).map(|(_, result)| result.clone())
+    }
+
+    /// Remember a page just fetched from the API
+    pub fn insert(&self, key: CacheKey, result: PaginatedResult) {
+        self.entries.borrow_mut().insert(key, (Instant::now(), result));
+    }
+
+    /// Forget every page (after a write, or to force a refresh)
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(resource_key: &str, page_token: Option<&str>) -> CacheKey {
+        role_key(None, resource_key, page_token)
+    }
+
+    fn role_key(assumed_role: Option<&str>, resource_key: &str, page_token: Option<&str>) -> CacheKey {
+        let filters = [ResourceFilter::new("bucket_names", vec!["logs".to_string()])];
+        CacheKey::new("default", assumed_role, "us-east-1", false, resource_key, &filters, page_token)
+    }
+
+    fn page(id: &str) -> PaginatedResult {
+        PaginatedResult {
+            items: vec![serde_json::json!({ "Id": id })],
+            next_token: None,
+            columns: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_list_cache() {
+        let cache = ListCache::default();
+        cache.insert(key("s3-objects", None), page("a"));
+        assert_eq!(cache.get(&key("s3-objects", None)).unwrap().items, page("a").items);
+        assert!(cache.get(&key("s3-objects", Some("next"))).is_none());
+        assert!(cache.get(&key("s3-buckets", None)).is_none());
+        // Another account reached from the same profile doesn't see these rows
+        let member = Some("arn:aws:iam::210987654321:role/OrganizationAccountAccessRole");
+        assert!(cache.get(&role_key(member, "s3-objects", None)).is_none());
+        cache.clear();
+        assert!(cache.get(&key("s3-objects", None)).is_none());
+
+        let expired = ListCache::new(Duration::ZERO);
+        expired.insert(key("s3-objects", None), page("a"));
+        assert!(expired.get(&key("s3-objects", None)).is_none());
+    }
+}
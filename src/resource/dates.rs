@@ -0,0 +1,174 @@
+//! Timestamp parsing and formatting for list columns and log events
+//!
+//! Documents keep timestamps in UTC (as AWS returns them); lists and logs show
+//! them in the display timezone (`timezone` config option, local by default).
+//! Columns flagged with `relative_time` show "3h ago" / "in 12d" instead;
+//! Describe keeps the absolute timestamp.
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, SecondsFormat, TimeDelta, Utc};
+use std::sync::OnceLock;
+
+/// Layout of displayed timestamps
+const DISPLAY_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Timezone timestamps are displayed in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayTimezone {
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse `local`, `utc` or a fixed offset such as `+05:30` / `-0800`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "local" => Some(Self::Local),
+            "utc" | "z" => Some(Self::Utc),
+            offset => offset.parse().ok().map(Self::Fixed),
+        }
+    }
+}
+
+static DISPLAY_TIMEZONE: OnceLock<DisplayTimezone> = OnceLock::new();
+
+/// Set the display timezone (once, at startup, from the config)
+pub fn set_display_timezone(timezone: DisplayTimezone) {
+    let _ = DISPLAY_TIMEZONE.set(timezone);
+}
+
+fn display_timezone() -> DisplayTimezone {
+    DISPLAY_TIMEZONE.get().copied().unwrap_or(DisplayTimezone::Local)
+}
+
+/// `2024-01-02 03:04:05` in the display timezone
+pub fn format_datetime(dt: DateTime<Utc>) -> String {
+    format_datetime_in(dt, display_timezone())
+}
+
+fn format_datetime_in(dt: DateTime<Utc>, timezone: DisplayTimezone) -> String {
+    match timezone {
+        DisplayTimezone::Local => dt.with_timezone(&Local).format(DISPLAY_FORMAT).to_string(),
+        DisplayTimezone::Utc => dt.format(DISPLAY_FORMAT).to_string(),
+        DisplayTimezone::Fixed(offset) => dt.with_timezone(&offset).format(DISPLAY_FORMAT).to_string(),
+    }
+}
+
+/// Epoch milliseconds (log events) in the display timezone
+pub fn format_log_timestamp(millis: i64) -> String {
+    DateTime::from_timestamp_millis(millis)
+        .map(format_datetime)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Epoch milliseconds as an RFC 3339 UTC string, for documents built from
+/// APIs that return numbers
+pub fn epoch_millis_to_rfc3339(millis: i64) -> String {
+    DateTime::from_timestamp_millis(millis)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// A date-time string shown in the display timezone, or None if `value` is
+/// not one (plain numbers are left alone: they are as likely sizes or counts)
+pub fn format_timestamp_text(value: &str) -> Option<String> {
+    parse_timestamp_text(value).map(format_datetime)
+}
+
+/// Parse the timestamp formats AWS APIs (and taws' own formatting) produce:
+/// RFC 3339, `2024-01-02T03:04:05.000+0000`, `2024-01-02 03:04:05` (UTC) and
+/// epoch seconds or milliseconds
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Some(dt) = parse_timestamp_text(value) {
+        return Some(dt);
+    }
+    let number: f64 = value.trim().parse().ok()?;
+    if number >= 1e12 {
+        DateTime::from_timestamp_millis(number as i64)
+    } else if number >= 1e8 {
+        DateTime::from_timestamp_millis((number * 1000.0) as i64)
+    } else {
+        None
+    }
+}
+
+/// The non-numeric formats of `parse_timestamp`
+fn parse_timestamp_text(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    // Cheap rejection of the many cells that are not dates
+    if value.len() < 19 || value.as_bytes()[4] != b'-' {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(dt.and_utc());
+    }
+    None
+}
+
+/// "3h ago", "12d ago", "in 5d" relative to `now`, or None if `value` is not a timestamp
+pub fn format_relative(value: &str, now: DateTime<Utc>) -> Option<String> {
+    let delta = now.signed_duration_since(parse_timestamp(value)?);
+    let span = compact_span(delta.abs());
+    Some(if span == "now" {
+        span
+    } else if delta < TimeDelta::zero() {
+        format!("in {}", span)
+    } else {
+        format!("{} ago", span)
+    })
+}
+
+/// Largest whole unit of a duration: "45s", "12m", "3h", "12d", "2y"
+fn compact_span(delta: TimeDelta) -> String {
+    let secs = delta.num_seconds();
+    if secs < 5 {
+        "now".to_string()
+    } else if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h", secs / 3600)
+    } else if secs < 365 * 86400 {
+        format!("{}d", secs / 86400)
+    } else {
+        format!("{}y", secs / (365 * 86400))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_relative() {
+        let now = parse_timestamp("2025-03-10T12:00:00Z").unwrap();
+        assert_eq!(format_relative("2025-03-10T09:00:00.000Z", now).as_deref(), Some("3h ago"));
+        assert_eq!(format_relative("2025-02-26T12:00:00.000+0000", now).as_deref(), Some("12d ago"));
+        assert_eq!(format_relative("2025-03-10 11:59:30", now).as_deref(), Some("30s ago"));
+        assert_eq!(format_relative("2025-03-15T12:00:00+00:00", now).as_deref(), Some("in 5d"));
+        assert_eq!(format_relative("1741608000", now).as_deref(), Some("now"));
+        assert_eq!(format_relative("1741600800000", now).as_deref(), Some("2h ago"));
+        assert_eq!(format_relative("-", now), None);
+        assert_eq!(format_relative("running", now), None);
+    }
+
+    #[test]
+    fn test_display_timezone() {
+        let dt = parse_timestamp("2024-02-29T23:30:00Z").unwrap();
+        assert_eq!(format_datetime_in(dt, DisplayTimezone::Utc), "2024-02-29 23:30:00");
+        let ist = DisplayTimezone::parse("+05:30").unwrap();
+        assert_eq!(format_datetime_in(dt, ist), "2024-03-01 05:00:00");
+        assert_eq!(DisplayTimezone::parse("UTC"), Some(DisplayTimezone::Utc));
+        assert_eq!(DisplayTimezone::parse("Mars/Olympus"), None);
+
+        assert_eq!(epoch_millis_to_rfc3339(1709249400000), "2024-02-29T23:30:00Z");
+        assert_eq!(parse_timestamp_text("1709249400000"), None);
+    }
+}
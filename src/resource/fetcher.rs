@@ -3,8 +3,9 @@
 //! This module provides a single generic function to fetch any AWS resource.
 //! All the logic is driven by the resources.json configuration.
 
+use super::query::extract_query_value;
 use super::registry::get_resource;
-use super::sdk_dispatch::invoke_sdk;
+use super::sdk_dispatch::{invoke_api, invoke_sdk};
 use crate::aws::client::AwsClients;
 use anyhow::{anyhow, Result};
 use serde_json::Value;
@@ -30,6 +31,9 @@ impl ResourceFilter {
 pub struct PaginatedResult {
     pub items: Vec<Value>,
     pub next_token: Option<String>,
+    /// Column names chosen by the response (e.g. Athena query results),
+    /// empty when the resource's JSON columns apply
+    pub columns: Vec<String>,
 }
 
 /// Fetch resources using the JSON-driven configuration
@@ -52,6 +56,9 @@ pub async fn fetch_resources(
     // 1. Look up resource definition from JSON
     let resource_def = get_resource(resource_key)
         .ok_or_else(|| anyhow!("Unknown resource: {}", resource_key))?;
+    if crate::demo::enabled() {
+        return Ok(crate::demo::items(resource_key));
+    }
 
     // 2. Build params (merge default params with filters)
     let mut params = resource_def.sdk_method_params.clone();
@@ -67,13 +74,16 @@ pub async fn fetch_resources(
         }
     }
 
-    // 3. Call SDK dispatcher
-    let response = invoke_sdk(
-        &resource_def.service,
-        &resource_def.sdk_method,
-        clients,
-        &params,
-    ).await?;
+    // 3. Call SDK dispatcher (or the user-defined API call)
+    let response = match &resource_def.api {
+        Some(api) => invoke_api(&resource_def.service, api, clients, &params).await?,
+        None => invoke_sdk(
+            &resource_def.service,
+            &resource_def.sdk_method,
+            clients,
+            &params,
+        ).await?,
+    };
 
     // 4. Extract items using response_path
     let items = extract_items(&response, &resource_def.response_path)?;
@@ -93,6 +103,9 @@ pub async fn fetch_resources_paginated(
     // 1. Look up resource definition from JSON
     let resource_def = get_resource(resource_key)
         .ok_or_else(|| anyhow!("Unknown resource: {}", resource_key))?;
+    if crate::demo::enabled() {
+        return Ok(PaginatedResult { items: crate::demo::items(resource_key), next_token: None, columns: Vec::new() });
+    }
 
     // 2. Build params (merge default params with filters)
     let mut params = resource_def.sdk_method_params.clone();
@@ -115,13 +128,16 @@ pub async fn fetch_resources_paginated(
         }
     }
 
-    // 3. Call SDK dispatcher
-    let response = invoke_sdk(
-        &resource_def.service,
-        &resource_def.sdk_method,
-        clients,
-        &params,
-    ).await?;
+    // 3. Call SDK dispatcher (or the user-defined API call)
+    let response = match &resource_def.api {
+        Some(api) => invoke_api(&resource_def.service, api, clients, &params).await?,
+        None => invoke_sdk(
+            &resource_def.service,
+            &resource_def.sdk_method,
+            clients,
+            &params,
+        ).await?,
+    };
 
     // 4. Extract items using response_path
     let items = extract_items(&response, &resource_def.response_path)?;
@@ -130,8 +146,92 @@ pub async fn fetch_resources_paginated(
     let next_token = response.get("_next_token")
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
+    
+    // 6. Extract dynamic column names (if present)
+    let columns = response.get("_columns")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|c| c.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+
+    Ok(PaginatedResult { items, next_token, columns })
+}
+
+/// Field added to each item fetched by [`fetch_resources_all_regions`]
+/// naming the region it came from
+pub const REGION_FIELD: &str = "_region";
+
+/// Maximum pages followed per region when aggregating across regions
+const MAX_PAGES_PER_REGION: usize = 5;
+
+/// Fetch a resource list from every region in `regions` concurrently
+///
+/// Items are tagged with [`REGION_FIELD`] and returned in region order.
+/// Regions that fail (e.g. opt-in regions that aren't enabled) are skipped;
+/// an error is only returned if every region failed.
+pub async fn fetch_resources_all_regions(
+    resource_key: &str,
+    clients: &AwsClients,
+    regions: &[String],
+    filters: &[ResourceFilter],
+) -> Result<Vec<Value>> {
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, region) in regions.iter().enumerate() {
+        let mut regional = clients.clone();
+        regional.http.set_region(region);
+        regional.region = region.clone();
+        let resource_key = resource_key.to_string();
+        let filters = filters.to_vec();
+        let region = region.clone();
+        tasks.spawn(async move {
+            let result = fetch_region_pages(&resource_key, &regional, &filters).await;
+            (index, region, result)
+        });
+    }
+
+    let mut per_region = Vec::new();
+    let mut first_error = None;
+    while let Some(joined) = tasks.join_next().await {
+        let (index, region, result) = joined?;
+        match result {
+            Ok(items) => per_region.push((index, region, items)),
+            Err(e) => {
+                tracing::warn!("Skipping region {} for {}: {}", region, resource_key, e);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
 
-    Ok(PaginatedResult { items, next_token })
+    if per_region.is_empty() && let Some(e) = first_error {
+        return Err(e);
+    }
+
+    per_region.sort_by_key(|(index, _, _)| *index);
+    Ok(per_region
+        .into_iter()
+        .flat_map(|(_, region, items)| {
+            items.into_iter().map(move |mut item| {
+                if let Value::Object(ref mut map) = item {
+                    map.insert(REGION_FIELD.to_string(), Value::String(region.clone()));
+                }
+                item
+            })
+        })
+        .collect())
+}
+
+/// Fetch up to [`MAX_PAGES_PER_REGION`] pages of a resource list from one region
+async fn fetch_region_pages(resource_key: &str, clients: &AwsClients, filters: &[ResourceFilter]) -> Result<Vec<Value>> {
+    let mut items = Vec::new();
+    let mut page_token: Option<String> = None;
+    for _ in 0..MAX_PAGES_PER_REGION {
+        let page = fetch_resources_paginated(resource_key, clients, filters, page_token.as_deref()).await?;
+        items.extend(page.items);
+        page_token = page.next_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+    Ok(items)
 }
 
 /// Extract items array from response using the response_path
@@ -148,16 +248,24 @@ fn extract_items(response: &Value, path: &str) -> Result<Vec<Value>> {
             .ok_or_else(|| anyhow!("Path '{}' not found in response", path))?;
     }
 
-    // Expect an array
+    // Expect an array (XML lists with a single element parse as an object)
     match current {
         Value::Array(arr) => Ok(arr),
+        Value::Object(_) => Ok(vec![current]),
         _ => Err(anyhow!("Expected array at path '{}', got {:?}", path, current)),
     }
 }
 
 /// Extract a value from a JSON object using dot notation path
-/// Supports: "Field", "Field.SubField", "Field.0", "Tags.Name"
+/// Supports: "Field", "Field.SubField", "Field.0", "Tags.Name", "tag:Name", and
+/// jq-style expressions starting with "." (e.g. ".Attachments[].VpcId")
 pub fn extract_json_value(item: &Value, path: &str) -> String {
+    if path.starts_with('.') {
+        return extract_query_value(item, path);
+    }
+    if let Some(key) = path.strip_prefix("tag:") {
+        return tag_value(item, key).unwrap_or_else(|| "-".to_string());
+    }
     let parts: Vec<&str> = path.split('.').collect();
     let mut current = item.clone();
 
@@ -204,3 +312,36 @@ pub fn extract_json_value(item: &Value, path: &str) -> String {
         _ => "-".to_string(),
     }
 }
+
+/// Value of a tag, whether `Tags` is a `{ key: value }` map (as built by the
+/// dispatchers) or the raw `[{ Key, Value }]` list of the API response
+fn tag_value(item: &Value, key: &str) -> Option<String> {
+    match item.get("Tags").or_else(|| item.get("TagList"))? {
+        Value::Object(tags) => tags.get(key)?.as_str().map(String::from),
+        Value::Array(tags) => tags
+            .iter()
+            .find(|tag| tag.get("Key").and_then(Value::as_str) == Some(key))?
+            .get("Value")?
+            .as_str()
+            .map(String::from),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tag_paths() {
+        let map = json!({ "Tags": { "Name": "web", "Team": "platform" } });
+        assert_eq!(extract_json_value(&map, "tag:Name"), "web");
+        assert_eq!(extract_json_value(&map, "tag:Team"), "platform");
+        assert_eq!(extract_json_value(&map, "tag:Env"), "-");
+
+        let list = json!({ "TagList": [{ "Key": "Env", "Value": "prod" }] });
+        assert_eq!(extract_json_value(&list, "tag:Env"), "prod");
+        assert_eq!(extract_json_value(&json!({}), "tag:Env"), "-");
+    }
+}
@@ -0,0 +1,155 @@
+//! Client-side list filter (`/`)
+//!
+//! Whitespace-separated terms that must all match:
+//! - `web` matches items where any visible column contains "web"
+//! - `!terminated` matches items where no column contains "terminated"
+//! - `state=running` / `state!=running` compare one column (by header,
+//!   ignoring case, spaces and `_`/`-`), with `*` as a wildcard: `type=t3.*`
+//!
+//! Matching is case-insensitive; quote a term to keep spaces in it.
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    /// Normalized column header for `field=value` terms
+    field: Option<String>,
+    pattern: String,
+    negate: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFilter {
+    terms: Vec<Term>,
+}
+
+impl ListFilter {
+    pub fn parse(text: &str) -> Self {
+        let terms = split_terms(text)
+            .into_iter()
+            .filter_map(|term| {
+                let (field, pattern, negate) = if let Some((field, value)) = term.split_once("!=") {
+                    (Some(field), value, true)
+                } else if let Some((field, value)) = term.split_once('=') {
+                    (Some(field), value, false)
+                } else if let Some(value) = term.strip_prefix('!') {
+                    (None, value, true)
+                } else {
+                    (None, term.as_str(), false)
+                };
+                let field = field.map(normalize_header).filter(|f| !f.is_empty());
+                (field.is_some() || !pattern.is_empty()).then(|| Term {
+                    field,
+                    pattern: pattern.to_lowercase(),
+                    negate,
+                })
+            })
+            .collect();
+        Self { terms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Whether an item matches, given its `(header, value)` column values.
+    /// Values with an empty header are only searched by bare terms.
+    pub fn matches(&self, columns: &[(&str, String)]) -> bool {
+        self.terms.iter().all(|term| {
+            let found = match &term.field {
+                Some(field) => columns
+                    .iter()
+                    .filter(|(header, _)| !header.is_empty() && normalize_header(header) == *field)
+                    .any(|(_, value)| wildcard_match(&term.pattern, &value.to_lowercase())),
+                None => columns.iter().any(|(_, value)| value.to_lowercase().contains(&term.pattern)),
+            };
+            found != term.negate
+        })
+    }
+}
+
+/// `PRIVATE IP`, `private_ip` and `private-ip` all name the same column
+fn normalize_header(header: &str) -> String {
+    header
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Split on whitespace outside double quotes (the quotes are dropped)
+fn split_terms(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    terms
+}
+
+/// Whole-value match where `*` stands for any run of characters
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = value.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_filter() {
+        let row = |name: &str, state: &str, kind: &str| {
+            vec![
+                ("NAME", name.to_string()),
+                ("STATE", state.to_string()),
+                ("INSTANCE TYPE", kind.to_string()),
+            ]
+        };
+        let web = row("web-1", "running", "t3.micro");
+        let db = row("db-1", "terminated", "m5.large");
+
+        let matching = |text: &str| {
+            let filter = ListFilter::parse(text);
+            [&web, &db].iter().filter(|r| filter.matches(r)).count()
+        };
+        assert_eq!(matching("WEB"), 1);
+        assert_eq!(matching("-1"), 2);
+        assert_eq!(matching("state=running type=t3.*"), 0);
+        assert_eq!(matching("state=running instance_type=t3.*"), 1);
+        assert_eq!(matching("state=run"), 0);
+        assert_eq!(matching("state=*ing"), 1);
+        assert_eq!(matching("!terminated"), 1);
+        assert_eq!(matching("state!=running"), 1);
+        assert_eq!(matching("\"db-1 \""), 0);
+        assert!(ListFilter::parse("  ").is_empty());
+
+        assert!(wildcard_match("t3.*", "t3.micro"));
+        assert!(wildcard_match("*.*.*", "a.b.c"));
+        assert!(!wildcard_match("a*a", "a"));
+    }
+}
@@ -1,7 +1,20 @@
 mod registry;
+mod cache;
+mod dates;
 mod fetcher;
+mod filter;
+mod query;
+mod search;
 pub mod sdk_dispatch;
 
 pub use registry::*;
-pub use fetcher::{fetch_resources, fetch_resources_paginated, extract_json_value, ResourceFilter};
-pub use sdk_dispatch::{execute_action, describe_resource, format_log_timestamp};
+pub use fetcher::{fetch_resources, fetch_resources_paginated, fetch_resources_all_regions, extract_json_value, PaginatedResult, ResourceFilter, REGION_FIELD};
+pub use cache::{CacheKey, ListCache};
+pub use dates::{format_datetime, format_log_timestamp, format_relative, format_timestamp_text, set_display_timezone, DisplayTimezone};
+pub use filter::ListFilter;
+pub use query::evaluate as evaluate_query;
+pub use search::{parse_find_args, resource_for_arn, resource_for_id, SEARCH_QUERY_PARAM};
+pub use sdk_dispatch::{
+    execute_action, execute_api_action, describe_resource, fetch_action_view, start_query_execution,
+    get_resource_tags, tag_resource, untag_resource, get_account_identity, describe_regions, AccountIdentity,
+};
@@ -0,0 +1,216 @@
+//! jq-style path expressions
+//!
+//! Projects documents in the describe view (`.Reservations[].Instances[].PrivateIpAddress`)
+//! and extracts column values when a column's `json_path` starts with `.`.
+//!
+//! Supported: `.`, `.Key`, `."Key with spaces"`, `.[0]`, `.[-1]`, `.["Key"]`,
+//! `.[]` (array elements or object values), and pipes into `length` or `keys`.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Iterate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Stage {
+    Path(Vec<Step>),
+    Length,
+    Keys,
+}
+
+/// Evaluate `expr` against `value`, returning every result it produces
+pub fn evaluate(expr: &str, value: &Value) -> Result<Vec<Value>> {
+    let stages = parse(expr)?;
+    let mut values = vec![value.clone()];
+    for stage in &stages {
+        values = values.iter().flat_map(|v| apply(stage, v)).collect();
+    }
+    Ok(values)
+}
+
+/// Column value for a `.`-prefixed json_path: results joined with ", ",
+/// "-" when there are none (or the expression does not parse)
+pub fn extract_query_value(item: &Value, expr: &str) -> String {
+    let values = evaluate(expr, item).unwrap_or_default();
+    let parts: Vec<String> = values
+        .iter()
+        .filter(|v| !v.is_null())
+        .map(|v| match v {
+            Value::String(s) => s.clone(),
+            Value::Bool(true) => "Yes".to_string(),
+            Value::Bool(false) => "No".to_string(),
+            other => other.to_string(),
+        })
+        .collect();
+    if parts.is_empty() {
+        "-".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+fn apply(stage: &Stage, value: &Value) -> Vec<Value> {
+    match stage {
+        Stage::Length => vec![Value::from(match value {
+            Value::Array(arr) => arr.len(),
+            Value::Object(map) => map.len(),
+            Value::String(s) => s.chars().count(),
+            _ => 0,
+        })],
+        Stage::Keys => match value {
+            Value::Object(map) => vec![Value::Array(map.keys().cloned().map(Value::String).collect())],
+            Value::Array(arr) => vec![Value::Array((0..arr.len()).map(Value::from).collect())],
+            _ => vec![Value::Null],
+        },
+        Stage::Path(steps) => {
+            let mut values = vec![value.clone()];
+            for step in steps {
+                values = values.iter().flat_map(|v| apply_step(step, v)).collect();
+            }
+            values
+        }
+    }
+}
+
+fn apply_step(step: &Step, value: &Value) -> Vec<Value> {
+    match (step, value) {
+        (Step::Key(key), Value::Object(map)) => vec![map.get(key).cloned().unwrap_or(Value::Null)],
+        (Step::Index(index), Value::Array(arr)) => {
+            let index = if *index < 0 { arr.len() as i64 + index } else { *index };
+            vec![usize::try_from(index).ok().and_then(|i| arr.get(i)).cloned().unwrap_or(Value::Null)]
+        }
+        (Step::Iterate, Value::Array(arr)) => arr.clone(),
+        (Step::Iterate, Value::Object(map)) => map.values().cloned().collect(),
+        (Step::Iterate, _) => Vec::new(),
+        _ => vec![Value::Null],
+    }
+}
+
+fn parse(expr: &str) -> Result<Vec<Stage>> {
+    split_pipes(expr)
+        .into_iter()
+        .map(|stage| match stage.trim() {
+            "length" => Ok(Stage::Length),
+            "keys" => Ok(Stage::Keys),
+            path => parse_path(path).map(Stage::Path),
+        })
+        .collect()
+}
+
+/// Split on `|` outside quoted keys
+fn split_pipes(expr: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '|' if !quoted => {
+                parts.push(&expr[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&expr[start..]);
+    parts
+}
+
+fn parse_path(path: &str) -> Result<Vec<Step>> {
+    let Some(rest) = path.strip_prefix('.') else {
+        return Err(anyhow!("Expression must start with '.', got '{}'", path));
+    };
+    let mut steps = Vec::new();
+    let mut chars = rest.chars().peekable();
+    // A key may follow the leading '.' directly
+    let mut expect_key = true;
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                expect_key = true;
+            }
+            '[' => {
+                chars.next();
+                let mut inner = String::new();
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    inner.push(c);
+                }
+                let inner = inner.trim();
+                steps.push(if inner.is_empty() {
+                    Step::Iterate
+                } else if let Some(key) = inner.strip_prefix('"').and_then(|k| k.strip_suffix('"')) {
+                    Step::Key(key.to_string())
+                } else {
+                    Step::Index(inner.parse().map_err(|_| anyhow!("Invalid index [{}]", inner))?)
+                });
+                expect_key = false;
+            }
+            '"' if expect_key => {
+                chars.next();
+                let key: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                steps.push(Step::Key(key));
+                expect_key = false;
+            }
+            c if expect_key && (c.is_alphanumeric() || c == '_' || c == '-' || c == ':' || c == '@') => {
+                let mut key = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break;
+                    }
+                    key.push(c);
+                    chars.next();
+                }
+                steps.push(Step::Key(key.trim_end().to_string()));
+                expect_key = false;
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            c => return Err(anyhow!("Unexpected '{}' in '{}'", c, path)),
+        }
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_evaluate() {
+        let doc = json!({
+            "Reservations": [
+                {"Instances": [{"PrivateIpAddress": "10.0.0.1"}, {"PrivateIpAddress": "10.0.0.2"}]},
+                {"Instances": [{"PrivateIpAddress": "10.0.1.1"}]}
+            ],
+            "Tags": {"Name": "web", "aws:cloudformation:stack-name": "app"}
+        });
+
+        assert_eq!(evaluate(".", &doc).unwrap(), vec![doc.clone()]);
+        assert_eq!(
+            evaluate(".Reservations[].Instances[].PrivateIpAddress", &doc).unwrap(),
+            vec![json!("10.0.0.1"), json!("10.0.0.2"), json!("10.0.1.1")]
+        );
+        assert_eq!(evaluate(".Reservations[-1].Instances[0].PrivateIpAddress", &doc).unwrap(), vec![json!("10.0.1.1")]);
+        assert_eq!(evaluate(".Tags[\"aws:cloudformation:stack-name\"]", &doc).unwrap(), vec![json!("app")]);
+        assert_eq!(evaluate(".Reservations | length", &doc).unwrap(), vec![json!(2)]);
+        assert_eq!(evaluate(".Tags | keys", &doc).unwrap(), vec![json!(["Name", "aws:cloudformation:stack-name"])]);
+        assert_eq!(evaluate(".Missing.Deeper", &doc).unwrap(), vec![Value::Null]);
+        assert!(evaluate("Reservations", &doc).is_err());
+        assert!(evaluate(".Reservations[x]", &doc).is_err());
+
+        assert_eq!(extract_query_value(&doc, ".Reservations[0].Instances[].PrivateIpAddress"), "10.0.0.1, 10.0.0.2");
+        assert_eq!(extract_query_value(&doc, ".Nope"), "-");
+    }
+}
@@ -1,11 +1,14 @@
 //! Resource Registry - Load resource definitions from JSON
 //!
 //! This module loads all AWS resource definitions from embedded JSON files
-//! and provides lookup functions for the rest of the application.
+//! (plus user-defined ones from `~/.config/taws/resources/`) and provides
+//! lookup functions for the rest of the application.
 
+use crate::aws::http::{set_user_services, Protocol, ServiceDefinition};
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Embedded resource JSON files (compiled into the binary)
@@ -28,19 +31,28 @@ const RESOURCE_FILES: &[&str] = &[
     include_str!("../resources/ecs.json"),
     include_str!("../resources/eks.json"),
     include_str!("../resources/elasticache.json"),
+    include_str!("../resources/elb.json"),
     include_str!("../resources/elbv2.json"),
     include_str!("../resources/eventbridge.json"),
+    include_str!("../resources/glue.json"),
+    include_str!("../resources/health.json"),
     include_str!("../resources/iam.json"),
+    include_str!("../resources/kinesis.json"),
     include_str!("../resources/kms.json"),
     include_str!("../resources/lambda.json"),
+    include_str!("../resources/organizations.json"),
     include_str!("../resources/rds.json"),
+    include_str!("../resources/resourceexplorer.json"),
     include_str!("../resources/route53.json"),
     include_str!("../resources/s3.json"),
     include_str!("../resources/secretsmanager.json"),
+    include_str!("../resources/securityhub.json"),
+    include_str!("../resources/servicequotas.json"),
     include_str!("../resources/sns.json"),
     include_str!("../resources/sqs.json"),
     include_str!("../resources/ssm.json"),
     include_str!("../resources/sts.json"),
+    include_str!("../resources/tagging.json"),
     include_str!("../resources/vpc.json"),
 ];
 
@@ -59,6 +71,12 @@ pub struct ColumnDef {
     pub width: u16,
     #[serde(default)]
     pub color_map: Option<String>,
+    /// Item field looked up in `color_map` instead of the column's own value
+    #[serde(default)]
+    pub color_field: Option<String>,
+    /// Show timestamps as "3h ago" / "in 12d"
+    #[serde(default)]
+    pub relative_time: bool,
 }
 
 /// Sub-resource definition from JSON
@@ -83,6 +101,39 @@ pub struct ConfirmConfig {
     /// If true, action is destructive (shown in red)
     #[serde(default)]
     pub destructive: bool,
+    /// If true, the resource name must be typed to confirm
+    #[serde(default)]
+    pub typed: bool,
+}
+
+/// Text input prompt shown before running an action
+#[derive(Debug, Clone, Deserialize)]
+pub struct PromptConfig {
+    /// Label shown above the input box
+    pub message: String,
+    /// Pre-filled value; supports `{id}`, `{timestamp}` and `{org_role}`
+    /// (configured Organizations access role) placeholders, and `{Field}`
+    /// for a field of the selected row
+    #[serde(default)]
+    pub default: Option<String>,
+    /// If true, typed text is masked (for secret values)
+    #[serde(default)]
+    pub masked: bool,
+}
+
+/// One-time secret dialog shown with the result of an action
+#[derive(Debug, Clone, Deserialize)]
+pub struct SecretConfig {
+    /// Dialog title
+    pub title: String,
+    /// Result field holding the secret value
+    pub field: String,
+    /// If true, the secret is masked until revealed
+    #[serde(default)]
+    pub masked: bool,
+    /// Extra note shown under the secret
+    #[serde(default)]
+    pub note: Option<String>,
 }
 
 /// Action definition from JSON
@@ -105,6 +156,28 @@ pub struct ActionDef {
     /// Confirmation configuration
     #[serde(default)]
     pub confirm: Option<ConfirmConfig>,
+    /// If true, the action does not modify AWS resources and is allowed in read-only mode
+    #[serde(default)]
+    pub read_only: bool,
+    /// Optional text input collected before the action runs
+    #[serde(default)]
+    pub prompt: Option<PromptConfig>,
+    /// If true, the action fetches a document that is shown in the describe view
+    #[serde(default)]
+    pub view: bool,
+    /// Item field passed to the action instead of the resource's id_field
+    #[serde(default)]
+    pub value_field: Option<String>,
+    /// Show the action result in a dismissable secret dialog
+    #[serde(default)]
+    pub secret: Option<SecretConfig>,
+    /// Generic API call for user-defined actions
+    #[serde(default)]
+    pub api: Option<ApiDef>,
+    /// Shell command run with the TUI suspended; supports `{id}`, `{region}`,
+    /// `{profile}`, `{input}` and `{Field}` placeholders
+    #[serde(default)]
+    pub exec: Option<String>,
 }
 
 impl ActionDef {
@@ -122,6 +195,7 @@ impl ActionDef {
                 message: Some(self.display_name.clone()),
                 default_yes: false,
                 destructive: false,
+                typed: false,
             })
         } else {
             None
@@ -129,6 +203,71 @@ impl ActionDef {
     }
 }
 
+/// How the tag editor reads and writes a resource's tags
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaggingDef {
+    /// "ec2" (CreateTags/DeleteTags on a resource id) or "rgta"
+    /// (Resource Groups Tagging API on an ARN)
+    #[serde(default = "default_tagging_api")]
+    pub api: String,
+    /// Item field holding the resource id (ec2) or ARN (rgta)
+    pub field: String,
+}
+
+fn default_tagging_api() -> String {
+    "rgta".to_string()
+}
+
+/// Generic API call used by user-defined resources and actions. Built-in
+/// resources are dispatched by `sdk_method` instead.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiDef {
+    /// Query action or JSON target (e.g. "ListThings")
+    #[serde(default)]
+    pub action: Option<String>,
+    /// HTTP method for REST services
+    #[serde(default = "default_http_method")]
+    pub method: String,
+    /// Request path for REST services; `{param}` placeholders are filled from the params
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Params sent by actions; `{id}` and `{input}` are replaced with the
+    /// resource id and the prompt input (resources use `sdk_method_params`)
+    #[serde(default)]
+    pub params: Value,
+    /// Request param carrying the page token
+    #[serde(default)]
+    pub next_token_param: Option<String>,
+    /// Response path of the next page token (no pagination when unset)
+    #[serde(default)]
+    pub next_token_path: Option<String>,
+}
+
+fn default_http_method() -> String {
+    "GET".to_string()
+}
+
+/// Service used by user-defined resources that taws has no built-in definition for
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceDef {
+    /// "query", "json", "rest-json" or "rest-xml"
+    pub protocol: String,
+    #[serde(default)]
+    pub api_version: String,
+    /// Defaults to the service key
+    #[serde(default)]
+    pub signing_name: Option<String>,
+    /// Defaults to the service key
+    #[serde(default)]
+    pub endpoint_prefix: Option<String>,
+    /// X-Amz-Target prefix for the JSON protocol
+    #[serde(default)]
+    pub target_prefix: Option<String>,
+    /// Global services are called in us-east-1
+    #[serde(default)]
+    pub global: bool,
+}
+
 /// Resource definition from JSON
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResourceDef {
@@ -143,10 +282,25 @@ pub struct ResourceDef {
     #[serde(default)]
     pub is_global: bool,
     pub columns: Vec<ColumnDef>,
+    /// CloudWatch utilization columns, added while `:metrics` is on (they cost
+    /// a GetMetricData call per list fetch)
+    #[serde(default)]
+    pub metric_columns: Vec<ColumnDef>,
     #[serde(default)]
     pub sub_resources: Vec<SubResourceDef>,
     #[serde(default)]
     pub actions: Vec<ActionDef>,
+    /// CloudFormation resource type (e.g. "AWS::EC2::Instance") this view lists,
+    /// used to jump from stack resources to the matching view
+    #[serde(default)]
+    pub cfn_type: Option<String>,
+    /// Tagging API used by the tag editor; resources without one fall back to
+    /// the Resource Groups Tagging API when their id is an ARN
+    #[serde(default)]
+    pub tagging: Option<TaggingDef>,
+    /// Generic API call for user-defined resources
+    #[serde(default)]
+    pub api: Option<ApiDef>,
 }
 
 /// Root structure of resources/*.json
@@ -156,6 +310,17 @@ pub struct ResourceConfig {
     pub color_maps: HashMap<String, Vec<ColorDef>>,
     #[serde(default)]
     pub resources: HashMap<String, ResourceDef>,
+    /// Services declared by user-defined resource files
+    #[serde(default)]
+    pub services: HashMap<String, ServiceDef>,
+}
+
+impl ResourceConfig {
+    fn merge(&mut self, other: ResourceConfig) {
+        self.color_maps.extend(other.color_maps);
+        self.resources.extend(other.resources);
+        self.services.extend(other.services);
+    }
 }
 
 /// Global registry loaded from JSON
@@ -163,21 +328,93 @@ static REGISTRY: OnceLock<ResourceConfig> = OnceLock::new();
 
 /// Get the resource registry (loads from embedded JSON on first access)
 pub fn get_registry() -> &'static ResourceConfig {
-    REGISTRY.get_or_init(|| {
-        let mut final_config = ResourceConfig {
-            color_maps: HashMap::new(),
-            resources: HashMap::new(),
-        };
+    REGISTRY.get_or_init(embedded_registry)
+}
+
+fn embedded_registry() -> ResourceConfig {
+    let mut final_config = ResourceConfig {
+        color_maps: HashMap::new(),
+        resources: HashMap::new(),
+        services: HashMap::new(),
+    };
+
+    for content in RESOURCE_FILES {
+        let partial: ResourceConfig = serde_json::from_str(content)
+            .unwrap_or_else(|e| panic!("Failed to parse embedded resource JSON: {}", e));
+        final_config.merge(partial);
+    }
+
+    final_config
+}
+
+/// Directory holding user-defined resource files
+pub fn user_resources_dir() -> PathBuf {
+    if let Some(config_dir) = dirs::config_dir() {
+        return config_dir.join("taws").join("resources");
+    }
+    if let Some(home) = dirs::home_dir() {
+        return home.join(".taws").join("resources");
+    }
+    PathBuf::from(".taws").join("resources")
+}
+
+/// Parse a user-defined resource file (`.json` or `.toml`, same layout as
+/// the embedded resource files)
+fn parse_resource_file(path: &Path) -> Result<ResourceConfig, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+        _ => serde_json::from_str(&content).map_err(|e| e.to_string()),
+    }
+}
 
-        for content in RESOURCE_FILES {
-            let partial: ResourceConfig = serde_json::from_str(content)
-                .unwrap_or_else(|e| panic!("Failed to parse embedded resource JSON: {}", e));
-            final_config.color_maps.extend(partial.color_maps);
-            final_config.resources.extend(partial.resources);
+/// Merge the resource files in [`user_resources_dir`] into the registry.
+/// Must run before the registry is first used. Returns one message per file
+/// that could not be loaded.
+pub fn load_user_resources() -> Vec<String> {
+    let dir = user_resources_dir();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| matches!(path.extension().and_then(|e| e.to_str()), Some("json" | "toml")))
+        .collect();
+    paths.sort();
+
+    let mut errors = Vec::new();
+    let mut config = embedded_registry();
+    for path in paths {
+        match parse_resource_file(&path) {
+            Ok(partial) => {
+                tracing::info!("Loaded {} user-defined resources from {:?}", partial.resources.len(), path);
+                config.merge(partial);
+            }
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
         }
+    }
+
+    if REGISTRY.set(config).is_err() {
+        errors.push("Resource registry already loaded; user-defined resources ignored".to_string());
+    }
 
-        final_config
-    })
+    let mut services = HashMap::new();
+    for (name, service) in &get_registry().services {
+        let Some(protocol) = Protocol::from_name(&service.protocol) else {
+            errors.push(format!("Service '{}': unknown protocol '{}'", name, service.protocol));
+            continue;
+        };
+        services.insert(name.as_str(), ServiceDefinition {
+            signing_name: service.signing_name.as_deref().unwrap_or(name),
+            endpoint_prefix: service.endpoint_prefix.as_deref().unwrap_or(name),
+            api_version: &service.api_version,
+            protocol,
+            target_prefix: service.target_prefix.as_deref(),
+            is_global: service.global,
+        });
+    }
+    set_user_services(services);
+    errors
 }
 
 /// Get a resource definition by key
@@ -199,6 +436,15 @@ pub fn get_color_map(name: &str) -> Option<&'static Vec<ColorDef>> {
     get_registry().color_maps.get(name)
 }
 
+/// Find the top-level resource key that lists a CloudFormation resource type
+pub fn get_resource_key_by_cfn_type(cfn_type: &str) -> Option<&'static str> {
+    get_registry()
+        .resources
+        .iter()
+        .find(|(_, r)| r.cfn_type.as_deref() == Some(cfn_type))
+        .map(|(key, _)| key.as_str())
+}
+
 /// Get color for a value based on color map name
 pub fn get_color_for_value(color_map_name: &str, value: &str) -> Option<[u8; 3]> {
     get_color_map(color_map_name)?
@@ -263,6 +509,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ecs_services_has_rollout_sub_resources() {
+        let resource = get_resource("ecs-services").unwrap();
+        for key in ["ecs-service-deployments", "ecs-service-events"] {
+            let sub = resource.sub_resources.iter().find(|s| s.resource_key == key);
+            assert!(sub.is_some(), "ECS services should have {} sub-resource", key);
+            assert_eq!(sub.unwrap().parent_id_field, "serviceArn");
+            assert!(get_resource(key).is_some(), "{} resource should exist", key);
+        }
+    }
+
     #[test]
     fn test_ec2_instances_has_actions() {
         let resource = get_resource("ec2-instances").unwrap();
@@ -312,6 +569,13 @@ mod tests {
         assert!(keys.contains(&"s3-buckets"), "Should contain s3-buckets");
     }
 
+    #[test]
+    fn test_resource_key_by_cfn_type() {
+        assert_eq!(get_resource_key_by_cfn_type("AWS::EC2::Instance"), Some("ec2-instances"));
+        assert_eq!(get_resource_key_by_cfn_type("AWS::Lambda::Function"), Some("lambda-functions"));
+        assert_eq!(get_resource_key_by_cfn_type("AWS::Made::Up"), None);
+    }
+
     #[test]
     fn test_common_color_maps_exist() {
         let state_map = get_color_map("state");
@@ -487,6 +751,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_user_resource_file() {
+        let path = std::env::temp_dir().join(format!("taws-user-resources-{}.toml", std::process::id()));
+        std::fs::write(&path, r#"
+[services.things]
+protocol = "json"
+api_version = "2024-01-01"
+target_prefix = "ThingsService"
+
+[resources.things]
+display_name = "Things"
+service = "things"
+sdk_method = "list_things"
+response_path = "Things"
+id_field = "ThingId"
+name_field = "Name"
+columns = [{ header = "NAME", json_path = "Name", width = 30 }]
+
+[resources.things.api]
+action = "ListThings"
+next_token_path = "NextToken"
+
+[[resources.things.actions]]
+key = "delete"
+display_name = "Delete Thing"
+sdk_method = "delete_thing"
+api = { action = "DeleteThing", params = { ThingId = "{id}" } }
+"#).unwrap();
+        let config = parse_resource_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.services["things"].protocol, "json");
+        let things = &config.resources["things"];
+        let api = things.api.as_ref().unwrap();
+        assert_eq!(api.action.as_deref(), Some("ListThings"));
+        assert_eq!(api.method, "GET");
+        assert_eq!(things.actions[0].api.as_ref().unwrap().params["ThingId"], "{id}");
+    }
+
     #[test]
     fn test_elbv2_health_color_map_exists() {
         let health_map = get_color_map("health");
@@ -4,9 +4,12 @@
 //! Supports 30 core AWS services without heavy SDK dependencies.
 
 use crate::aws::client::AwsClients;
-use crate::aws::http::xml_to_json;
+use crate::aws::http::{get_service, xml_for_each, xml_to_json, Protocol};
+use super::dates::epoch_millis_to_rfc3339;
+use super::registry::ApiDef;
 use anyhow::{anyhow, Result};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use tracing::debug;
 
 // =============================================================================
@@ -43,35 +46,50 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
-/// Format epoch milliseconds to human-readable date string
-fn format_epoch_millis(millis: i64) -> String {
-    use std::time::{Duration, UNIX_EPOCH};
-    
-    let duration = Duration::from_millis(millis as u64);
-    let datetime = UNIX_EPOCH + duration;
-    
-    // Convert to a simple date/time string
-    if let Ok(elapsed) = datetime.duration_since(UNIX_EPOCH) {
-        let secs = elapsed.as_secs();
-        let days = secs / 86400;
-        let years = 1970 + days / 365;
-        let remaining_days = days % 365;
-        let months = remaining_days / 30;
-        let day = remaining_days % 30 + 1;
-        let hours = (secs % 86400) / 3600;
-        let minutes = (secs % 3600) / 60;
-        let seconds = secs % 60;
-        
-        format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", 
-            years, months + 1, day, hours, minutes, seconds)
+/// Extract the cluster name from a long-format ECS service or task ARN
+/// (arn:aws:ecs:region:account:service/cluster/name)
+fn ecs_cluster_from_arn(arn: &str) -> Option<&str> {
+    let parts: Vec<&str> = arn.split('/').collect();
+    if parts.len() >= 3 {
+        Some(parts[parts.len() - 2])
     } else {
-        "-".to_string()
+        None
     }
 }
 
-/// Format epoch milliseconds to human-readable date string (public for log tail UI)
-pub fn format_log_timestamp(millis: i64) -> String {
-    format_epoch_millis(millis)
+/// Split an ECR image reference (registry/repo@digest) into repository name and digest
+fn parse_ecr_image_ref(image_ref: &str) -> Option<(&str, &str)> {
+    let (_registry, rest) = image_ref.split_once('/')?;
+    rest.split_once('@')
+}
+
+/// Fetch an ECR authorization token, returning (registry endpoint, password)
+async fn ecr_login_credentials(clients: &AwsClients) -> Result<(String, String)> {
+    use base64::Engine;
+    
+    let response = clients.http.json_request("ecr", "GetAuthorizationToken", "{}").await?;
+    let json: Value = serde_json::from_str(&response)?;
+    let auth = json.pointer("/authorizationData/0")
+        .ok_or_else(|| anyhow!("No authorization data returned"))?;
+    let token = auth.get("authorizationToken").and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("No authorization token returned"))?;
+    let endpoint = auth.get("proxyEndpoint").and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("No proxy endpoint returned"))?;
+    
+    // Token is base64("AWS:<password>")
+    let decoded = base64::engine::general_purpose::STANDARD.decode(token)?;
+    let decoded = String::from_utf8(decoded)?;
+    let password = decoded.split_once(':').map(|(_, p)| p).unwrap_or(&decoded);
+    
+    Ok((endpoint.to_string(), password.to_string()))
+}
+
+/// Format an epoch-seconds JSON number (as returned by JSON protocol APIs)
+fn format_epoch_seconds_value(value: Option<&Value>) -> String {
+    value
+        .and_then(|v| v.as_f64())
+        .map(|secs| epoch_millis_to_rfc3339((secs * 1000.0) as i64))
+        .unwrap_or_else(|| "-".to_string())
 }
 
 /// Parse XML list response from Query protocol APIs
@@ -97,18 +115,46 @@ fn parse_query_list(xml: &str, list_key: &str, item_key: &str) -> Result<Vec<Val
     }
 }
 
+/// Start an Athena query in a workgroup/database and return its execution ID
+pub async fn start_query_execution(
+    clients: &AwsClients,
+    work_group: &str,
+    database: &str,
+    query: &str,
+) -> Result<String> {
+    let mut request = json!({ "QueryString": query });
+    if !work_group.is_empty() && work_group != "-" {
+        request["WorkGroup"] = json!(work_group);
+    }
+    if !database.is_empty() && database != "-" {
+        request["QueryExecutionContext"] = json!({ "Catalog": "AwsDataCatalog", "Database": database });
+    }
+    let response = clients.http.json_request("athena", "StartQueryExecution", &request.to_string()).await?;
+    let json: Value = serde_json::from_str(&response)?;
+    json.get("QueryExecutionId")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .ok_or_else(|| anyhow!("StartQueryExecution returned no execution ID"))
+}
+
 // =============================================================================
 // Action Functions (write operations)
 // =============================================================================
 
 /// Execute an action on a resource (start, stop, terminate, etc.)
+///
+/// `input` carries text collected from the action's prompt, if it has one.
 pub async fn execute_action(
     service: &str,
     action: &str,
     clients: &AwsClients,
     resource_id: &str,
+    input: Option<&str>,
 ) -> Result<()> {
     match (service, action) {
+        // Local actions shared across resources
+        (_, "copy_to_clipboard") => crate::clipboard::copy_to_clipboard(resource_id),
+
         // EC2 Instance Actions
         ("ec2", "start_instance") => {
             clients.http.query_request("ec2", "StartInstances", &[
@@ -182,6 +228,23 @@ pub async fn execute_action(
             Ok(())
         }
 
+        ("rds", "create_db_snapshot") => {
+            let snapshot_id = input.ok_or_else(|| anyhow!("Snapshot name is required"))?;
+            clients.http.query_request("rds", "CreateDBSnapshot", &[
+                ("DBInstanceIdentifier", resource_id),
+                ("DBSnapshotIdentifier", snapshot_id)
+            ]).await?;
+            Ok(())
+        }
+        ("rds", "restore_db_instance_from_snapshot") => {
+            let instance_id = input.ok_or_else(|| anyhow!("New instance identifier is required"))?;
+            clients.http.query_request("rds", "RestoreDBInstanceFromDBSnapshot", &[
+                ("DBSnapshotIdentifier", resource_id),
+                ("DBInstanceIdentifier", instance_id)
+            ]).await?;
+            Ok(())
+        }
+
         // ECS Actions
         ("ecs", "delete_cluster") => {
             clients.http.json_request("ecs", "DeleteCluster", &json!({
@@ -213,6 +276,364 @@ pub async fn execute_action(
             Ok(())
         }
 
+        ("ecr", "docker_login") => {
+            use tokio::io::AsyncWriteExt;
+            
+            let (endpoint, password) = ecr_login_credentials(clients).await?;
+            let mut child = tokio::process::Command::new("docker")
+                .args(["login", "--username", "AWS", "--password-stdin", &endpoint])
+                .stdin(std::process::Stdio::piped())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::piped())
+                .spawn()
+                .map_err(|e| anyhow!("Failed to run docker: {}", e))?;
+            
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(password.as_bytes()).await?;
+            }
+            
+            let output = child.wait_with_output().await?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(anyhow!("docker login failed: {}", stderr.trim()));
+            }
+            debug!("docker login succeeded for {}", endpoint);
+            Ok(())
+        }
+        ("ecr", "copy_login_command") => {
+            let (endpoint, password) = ecr_login_credentials(clients).await?;
+            let command = format!(
+                "printf '%s' '{}' | docker login --username AWS --password-stdin {}",
+                password, endpoint
+            );
+            crate::clipboard::copy_to_clipboard(&command)
+        }
+        // EventBridge Scheduler Actions
+        ("scheduler", "enable_schedule") | ("scheduler", "disable_schedule") => {
+            let (group, name) = parse_schedule_arn(resource_id)
+                .ok_or_else(|| anyhow!("Invalid schedule ARN: {}", resource_id))?;
+            let path = format!("/schedules/{}?groupName={}", urlencoding::encode(name), urlencoding::encode(group));
+            
+            // UpdateSchedule replaces the whole definition, so start from the current one
+            let response = clients.http.rest_json_request("scheduler", "GET", &path, None).await?;
+            let mut schedule: Value = serde_json::from_str(&response)?;
+            if let Some(obj) = schedule.as_object_mut() {
+                for read_only in ["Arn", "Name", "CreationDate", "LastModificationDate"] {
+                    obj.remove(read_only);
+                }
+                let state = if action == "enable_schedule" { "ENABLED" } else { "DISABLED" };
+                obj.insert("State".to_string(), json!(state));
+            }
+            
+            clients.http.rest_json_request(
+                "scheduler",
+                "PUT",
+                &format!("/schedules/{}", urlencoding::encode(name)),
+                Some(&schedule.to_string())
+            ).await?;
+            Ok(())
+        }
+        ("scheduler", "delete_schedule") => {
+            let (group, name) = parse_schedule_arn(resource_id)
+                .ok_or_else(|| anyhow!("Invalid schedule ARN: {}", resource_id))?;
+            clients.http.rest_json_request(
+                "scheduler",
+                "DELETE",
+                &format!("/schedules/{}?groupName={}", urlencoding::encode(name), urlencoding::encode(group)),
+                None
+            ).await?;
+            Ok(())
+        }
+
+        ("codepipeline", "start_pipeline_execution") => {
+            clients.http.json_request("codepipeline", "StartPipelineExecution", &json!({
+                "name": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("codepipeline", "approve_action") | ("codepipeline", "reject_action") => {
+            let parts: Vec<&str> = resource_id.splitn(4, '|').collect();
+            let [pipeline, stage, approval, token] = parts[..] else {
+                return Err(anyhow!("Invalid approval reference: {}", resource_id));
+            };
+            let status = if action == "approve_action" { "Approved" } else { "Rejected" };
+            clients.http.json_request("codepipeline", "PutApprovalResult", &json!({
+                "pipelineName": pipeline,
+                "stageName": stage,
+                "actionName": approval,
+                "token": token,
+                "result": {
+                    "status": status,
+                    "summary": input.unwrap_or(status)
+                }
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("cognitoidentityprovider", "admin_enable_user")
+        | ("cognitoidentityprovider", "admin_disable_user")
+        | ("cognitoidentityprovider", "admin_reset_user_password")
+        | ("cognitoidentityprovider", "admin_delete_user") => {
+            let (user_pool_id, username) = resource_id.split_once('|')
+                .ok_or_else(|| anyhow!("Invalid user reference: {}", resource_id))?;
+            let operation = match action {
+                "admin_enable_user" => "AdminEnableUser",
+                "admin_disable_user" => "AdminDisableUser",
+                "admin_reset_user_password" => "AdminResetUserPassword",
+                _ => "AdminDeleteUser",
+            };
+            clients.http.json_request("cognito-idp", operation, &json!({
+                "UserPoolId": user_pool_id,
+                "Username": username
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("codebuild", "start_build") => {
+            clients.http.json_request("codebuild", "StartBuild", &json!({
+                "projectName": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("route53", "create_record") | ("route53", "upsert_record") => {
+            let spec = input.ok_or_else(|| anyhow!("No record given"))?;
+            let (name, record_type, ttl, values) = parse_record_spec(spec)?;
+            let records: String = values.iter()
+                .map(|v| format!("<ResourceRecord><Value>{}</Value></ResourceRecord>", xml_escape(v)))
+                .collect();
+            let record_xml = format!(
+                "<ResourceRecordSet><Name>{}</Name><Type>{}</Type><TTL>{}</TTL><ResourceRecords>{}</ResourceRecords></ResourceRecordSet>",
+                xml_escape(&name), xml_escape(&record_type), ttl, records
+            );
+            let change_action = if action == "create_record" { "CREATE" } else { "UPSERT" };
+            route53_change(clients, resource_id, change_action, &record_xml).await
+        }
+
+        ("route53", "delete_record") => {
+            let parts: Vec<String> = serde_json::from_str(resource_id)
+                .map_err(|_| anyhow!("Invalid record reference: {}", resource_id))?;
+            let [zone_id, name, record_type, set_identifier] = &parts[..] else {
+                return Err(anyhow!("Invalid record reference: {}", resource_id));
+            };
+            // DELETE must match the current record exactly, so send it back as stored
+            let record = route53_find_record_set(clients, zone_id, name, record_type, set_identifier).await?;
+            route53_change(clients, zone_id, "DELETE", &route53_record_set_xml(&record)).await
+        }
+
+        ("cloudfront", "create_invalidation") => {
+            let paths: Vec<&str> = input.unwrap_or("/*")
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|p| !p.is_empty())
+                .collect();
+            let items: String = paths.iter()
+                .map(|p| format!("<Path>{}</Path>", xml_escape(p)))
+                .collect();
+            let body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><InvalidationBatch xmlns="http://cloudfront.amazonaws.com/doc/2020-05-31/"><Paths><Quantity>{}</Quantity><Items>{}</Items></Paths><CallerReference>taws-{}</CallerReference></InvalidationBatch>"#,
+                paths.len(), items, chrono::Utc::now().timestamp_millis()
+            );
+            clients.http.rest_xml_request(
+                "cloudfront",
+                "POST",
+                &format!("/2020-05-31/distribution/{}/invalidation", resource_id),
+                Some(&body)
+            ).await?;
+            Ok(())
+        }
+
+        ("cloudfront", "enable_distribution") | ("cloudfront", "disable_distribution") => {
+            // Config updates replace the whole config and must carry its current ETag
+            let path = format!("/2020-05-31/distribution/{}/config", resource_id);
+            let (config, etag) = clients.http.rest_xml_request_with_etag("cloudfront", "GET", &path, None, None).await?;
+            let etag = etag.ok_or_else(|| anyhow!("GetDistributionConfig returned no ETag"))?;
+            let enabled = if action == "enable_distribution" { "true" } else { "false" };
+            let updated = set_root_child_text(&config, "Enabled", enabled)?;
+            clients.http.rest_xml_request_with_etag("cloudfront", "PUT", &path, Some(&updated), Some(&etag)).await?;
+            Ok(())
+        }
+
+        ("acm", "create_validation_record") => {
+            let parts: Vec<String> = serde_json::from_str(resource_id)
+                .map_err(|_| anyhow!("Invalid validation record: {}", resource_id))?;
+            let [name, record_type, value] = &parts[..] else {
+                return Err(anyhow!("Invalid validation record: {}", resource_id));
+            };
+            
+            // Use the most specific public hosted zone in this account that contains the record
+            let zones = invoke_sdk("route53", "list_hosted_zones", clients, &json!({})).await?;
+            let record_name = name.trim_end_matches('.').to_lowercase();
+            let zone_id = zones.get("hosted_zones")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter(|z| z.get("Config.PrivateZone").and_then(|v| v.as_str()) == Some("Public"))
+                .filter_map(|z| {
+                    let zone_name = z.get("Name")?.as_str()?.trim_end_matches('.').to_lowercase();
+                    let matches = record_name == zone_name || record_name.ends_with(&format!(".{}", zone_name));
+                    if !matches {
+                        return None;
+                    }
+                    Some((zone_name.len(), z.get("Id")?.as_str()?.to_string()))
+                })
+                .max_by_key(|(len, _)| *len)
+                .map(|(_, id)| id)
+                .ok_or_else(|| anyhow!("No public hosted zone in this account for {}", name))?;
+            
+            let record_xml = format!(
+                "<ResourceRecordSet><Name>{}</Name><Type>{}</Type><TTL>300</TTL><ResourceRecords><ResourceRecord><Value>{}</Value></ResourceRecord></ResourceRecords></ResourceRecordSet>",
+                xml_escape(name), xml_escape(record_type), xml_escape(value)
+            );
+            route53_change(clients, &zone_id, "UPSERT", &record_xml).await
+        }
+
+        ("securityhub", "update_workflow_status") => {
+            let parts: Vec<String> = serde_json::from_str(resource_id)
+                .map_err(|_| anyhow!("Invalid finding reference: {}", resource_id))?;
+            let [id, product_arn] = &parts[..] else {
+                return Err(anyhow!("Invalid finding reference: {}", resource_id));
+            };
+            let status = input.unwrap_or("").trim().to_uppercase();
+            if !["NEW", "NOTIFIED", "RESOLVED", "SUPPRESSED"].contains(&status.as_str()) {
+                return Err(anyhow!("Workflow status must be NEW, NOTIFIED, RESOLVED or SUPPRESSED"));
+            }
+            let response = clients.http.rest_json_request("securityhub", "PATCH", "/findings/batchupdate", Some(&json!({
+                "FindingIdentifiers": [{ "Id": id, "ProductArn": product_arn }],
+                "Workflow": { "Status": status }
+            }).to_string())).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            if let Some(failed) = json.pointer("/UnprocessedFindings/0") {
+                return Err(anyhow!("{}", failed.get("ErrorMessage").and_then(|v| v.as_str()).unwrap_or("Finding was not updated")));
+            }
+            Ok(())
+        }
+
+        ("servicequotas", "request_service_quota_increase") => {
+            let Some((service_code, quota_code)) = resource_id.split_once('|') else {
+                return Err(anyhow!("Invalid quota reference: {}", resource_id));
+            };
+            let desired: f64 = input.unwrap_or("").trim().parse()
+                .map_err(|_| anyhow!("Desired value must be a number"))?;
+            clients.http.json_request("servicequotas", "RequestServiceQuotaIncrease", &json!({
+                "ServiceCode": service_code,
+                "QuotaCode": quota_code,
+                "DesiredValue": desired
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("kms", "enable_key") => {
+            clients.http.json_request("kms", "EnableKey", &json!({ "KeyId": resource_id }).to_string()).await?;
+            Ok(())
+        }
+        ("kms", "disable_key") => {
+            clients.http.json_request("kms", "DisableKey", &json!({ "KeyId": resource_id }).to_string()).await?;
+            Ok(())
+        }
+        ("kms", "enable_key_rotation") => {
+            clients.http.json_request("kms", "EnableKeyRotation", &json!({ "KeyId": resource_id }).to_string()).await?;
+            Ok(())
+        }
+        ("kms", "disable_key_rotation") => {
+            clients.http.json_request("kms", "DisableKeyRotation", &json!({ "KeyId": resource_id }).to_string()).await?;
+            Ok(())
+        }
+        ("kms", "schedule_key_deletion") => {
+            let days: u32 = input.unwrap_or("30").trim().parse()
+                .ok()
+                .filter(|d| (7..=30).contains(d))
+                .ok_or_else(|| anyhow!("Waiting period must be between 7 and 30 days"))?;
+            clients.http.json_request("kms", "ScheduleKeyDeletion", &json!({
+                "KeyId": resource_id,
+                "PendingWindowInDays": days
+            }).to_string()).await?;
+            Ok(())
+        }
+        ("kms", "cancel_key_deletion") => {
+            clients.http.json_request("kms", "CancelKeyDeletion", &json!({ "KeyId": resource_id }).to_string()).await?;
+            Ok(())
+        }
+
+        ("glue", "start_job_run") => {
+            clients.http.json_request("glue", "StartJobRun", &json!({
+                "JobName": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("glue", "start_crawler") => {
+            clients.http.json_request("glue", "StartCrawler", &json!({
+                "Name": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("athena", "stop_query_execution") => {
+            clients.http.json_request("athena", "StopQueryExecution", &json!({
+                "QueryExecutionId": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("athena", "delete_named_query") => {
+            clients.http.json_request("athena", "DeleteNamedQuery", &json!({
+                "NamedQueryId": resource_id
+            }).to_string()).await?;
+            Ok(())
+        }
+
+        ("iam", "activate_access_key") | ("iam", "deactivate_access_key") => {
+            let (user_name, key_id) = resource_id.split_once(':')
+                .ok_or_else(|| anyhow!("Invalid access key reference: {}", resource_id))?;
+            let status = if action == "activate_access_key" { "Active" } else { "Inactive" };
+            clients.http.query_request("iam", "UpdateAccessKey", &[
+                ("UserName", user_name),
+                ("AccessKeyId", key_id),
+                ("Status", status)
+            ]).await?;
+            Ok(())
+        }
+        ("iam", "delete_access_key") => {
+            let (user_name, key_id) = resource_id.split_once(':')
+                .ok_or_else(|| anyhow!("Invalid access key reference: {}", resource_id))?;
+            clients.http.query_request("iam", "DeleteAccessKey", &[
+                ("UserName", user_name),
+                ("AccessKeyId", key_id)
+            ]).await?;
+            Ok(())
+        }
+        ("ecr", "batch_delete_image") => {
+            let (repository, digest) = parse_ecr_image_ref(resource_id)
+                .ok_or_else(|| anyhow!("Invalid image reference: {}", resource_id))?;
+            let response = clients.http.json_request("ecr", "BatchDeleteImage", &json!({
+                "repositoryName": repository,
+                "imageIds": [{ "imageDigest": digest }]
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            if let Some(failure) = json.get("failures").and_then(|v| v.as_array()).and_then(|a| a.first()) {
+                let reason = failure.get("failureReason").and_then(|v| v.as_str()).unwrap_or("unknown failure");
+                return Err(anyhow!("Delete failed: {}", reason));
+            }
+            Ok(())
+        }
+        ("ecr", "copy_pull_uri") => {
+            // Prefer a tag-based URI when the image is tagged, otherwise pull by digest
+            let (repository, digest) = parse_ecr_image_ref(resource_id)
+                .ok_or_else(|| anyhow!("Invalid image reference: {}", resource_id))?;
+            let response = clients.http.json_request("ecr", "DescribeImages", &json!({
+                "repositoryName": repository,
+                "imageIds": [{ "imageDigest": digest }]
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let tag = json.pointer("/imageDetails/0/imageTags/0").and_then(|v| v.as_str());
+            let uri = match (tag, resource_id.split_once('@')) {
+                (Some(tag), Some((base, _))) => format!("{}:{}", base, tag),
+                _ => resource_id.to_string(),
+            };
+            crate::clipboard::copy_to_clipboard(&uri)
+        }
+
         // EKS Actions
         ("eks", "delete_cluster") => {
             clients.http.rest_json_request(
@@ -224,14 +645,38 @@ pub async fn execute_action(
             Ok(())
         }
 
-        // S3 Actions
-        ("s3", "delete_bucket") => {
-            clients.http.rest_xml_request(
-                "s3",
-                "DELETE",
-                &format!("/{}", resource_id),
+        ("eks", "update_kubeconfig") => {
+            let desc_response = clients.http.rest_json_request(
+                "eks",
+                "GET",
+                &format!("/clusters/{}", resource_id),
                 None
             ).await?;
+            let desc_json: Value = serde_json::from_str(&desc_response)?;
+            let cluster = desc_json.get("cluster").ok_or_else(|| anyhow!("Cluster not found: {}", resource_id))?;
+            
+            let endpoint = cluster.get("endpoint").and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("Cluster {} has no endpoint yet", resource_id))?;
+            let certificate_authority = cluster.pointer("/certificateAuthority/data").and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("Cluster {} has no certificate authority data", resource_id))?;
+            let arn = cluster.get("arn").and_then(|v| v.as_str()).unwrap_or(resource_id);
+            
+            let info = crate::aws::kubeconfig::EksClusterInfo {
+                name: resource_id,
+                arn,
+                endpoint,
+                certificate_authority,
+            };
+            let path = crate::aws::kubeconfig::update_kubeconfig(&info, &clients.region, &clients.profile)?;
+            debug!("Updated kubeconfig {} with context {}", path.display(), arn);
+            Ok(())
+        }
+
+        // S3 Actions
+        ("s3", "delete_bucket") => {
+            let bucket_region = clients.http.get_bucket_region(resource_id).await?;
+            clients.http.rest_xml_request_s3_bucket("DELETE", resource_id, "/", None, &bucket_region).await?;
+            crate::aws::http::forget_bucket_region(resource_id);
             Ok(())
         }
 
@@ -266,6 +711,18 @@ pub async fn execute_action(
         }
 
         // CloudFormation Actions
+        ("cloudformation", "execute_change_set") => {
+            clients.http.query_request("cloudformation", "ExecuteChangeSet", &[
+                ("ChangeSetName", resource_id)
+            ]).await?;
+            Ok(())
+        }
+        ("cloudformation", "delete_change_set") => {
+            clients.http.query_request("cloudformation", "DeleteChangeSet", &[
+                ("ChangeSetName", resource_id)
+            ]).await?;
+            Ok(())
+        }
         ("cloudformation", "delete_stack") => {
             clients.http.query_request("cloudformation", "DeleteStack", &[
                 ("StackName", resource_id)
@@ -280,6 +737,14 @@ pub async fn execute_action(
             }).to_string()).await?;
             Ok(())
         }
+        ("secretsmanager", "put_secret_value") => {
+            let value = input.ok_or_else(|| anyhow!("Secret value is required"))?;
+            clients.http.json_request("secretsmanager", "PutSecretValue", &json!({
+                "SecretId": resource_id,
+                "SecretString": value
+            }).to_string()).await?;
+            Ok(())
+        }
         ("secretsmanager", "delete_secret") => {
             clients.http.json_request("secretsmanager", "DeleteSecret", &json!({
                 "SecretId": resource_id,
@@ -288,6 +753,18 @@ pub async fn execute_action(
             Ok(())
         }
 
+        // SSM Actions
+        ("ssm", "put_parameter") => {
+            let value = input.ok_or_else(|| anyhow!("Parameter value is required"))?;
+            // Overwrite keeps the existing type, tier and KMS key
+            clients.http.json_request("ssm", "PutParameter", &json!({
+                "Name": resource_id,
+                "Value": value,
+                "Overwrite": true
+            }).to_string()).await?;
+            Ok(())
+        }
+
         // Auto Scaling Actions
         ("autoscaling", "delete_auto_scaling_group") => {
             clients.http.query_request("autoscaling", "DeleteAutoScalingGroup", &[
@@ -297,6 +774,25 @@ pub async fn execute_action(
             Ok(())
         }
 
+        // Classic ELB Actions
+        ("elb", "delete_load_balancer") => {
+            clients.http.query_request("elb", "DeleteLoadBalancer", &[
+                ("LoadBalancerName", resource_id)
+            ]).await?;
+            Ok(())
+        }
+        ("elb", "deregister_instances") => {
+            // resource_id format: "load_balancer_name|instance_id"
+            let Some((lb_name, instance_id)) = resource_id.split_once('|') else {
+                return Err(anyhow!("Invalid instance reference: {}", resource_id));
+            };
+            clients.http.query_request("elb", "DeregisterInstancesFromLoadBalancer", &[
+                ("LoadBalancerName", lb_name),
+                ("Instances.member.1.InstanceId", instance_id)
+            ]).await?;
+            Ok(())
+        }
+
         // ELBv2 Actions
         ("elbv2", "delete_load_balancer") => {
             clients.http.query_request("elbv2", "DeleteLoadBalancer", &[
@@ -316,6 +812,68 @@ pub async fn execute_action(
             ]).await?;
             Ok(())
         }
+        ("elbv2", "set_rule_priorities") => {
+            let priority: u32 = input.unwrap_or("").trim().parse()
+                .map_err(|_| anyhow!("Priority must be a number between 1 and 50000"))?;
+            clients.http.query_request("elbv2", "SetRulePriorities", &[
+                ("RulePriorities.member.1.RuleArn", resource_id),
+                ("RulePriorities.member.1.Priority", &priority.to_string())
+            ]).await?;
+            Ok(())
+        }
+        ("elbv2", "modify_rule_forward") => {
+            let target = input.unwrap_or("").trim();
+            if target.is_empty() {
+                return Err(anyhow!("Target group is required"));
+            }
+            // Accept a target group name as well as an ARN
+            let target_group_arn = if target.starts_with("arn:") {
+                target.to_string()
+            } else {
+                let xml = clients.http.query_request("elbv2", "DescribeTargetGroups", &[
+                    ("Names.member.1", target)
+                ]).await?;
+                let json = xml_to_json(&xml)?;
+                let groups = json.pointer("/DescribeTargetGroupsResponse/DescribeTargetGroupsResult/TargetGroups/member");
+                let group = match groups {
+                    Some(Value::Array(arr)) => arr.first(),
+                    Some(obj @ Value::Object(_)) => Some(obj),
+                    _ => None,
+                };
+                group.and_then(|g| g.pointer("/TargetGroupArn"))
+                    .and_then(|v| v.as_str())
+                    .map(String::from)
+                    .ok_or_else(|| anyhow!("Target group not found: {}", target))?
+            };
+            clients.http.query_request("elbv2", "ModifyRule", &[
+                ("RuleArn", resource_id),
+                ("Actions.member.1.Type", "forward"),
+                ("Actions.member.1.TargetGroupArn", &target_group_arn)
+            ]).await?;
+            Ok(())
+        }
+        ("elbv2", "add_listener_certificates") => {
+            let certificate_arn = input.unwrap_or("").trim();
+            if certificate_arn.is_empty() {
+                return Err(anyhow!("Certificate ARN is required"));
+            }
+            clients.http.query_request("elbv2", "AddListenerCertificates", &[
+                ("ListenerArn", resource_id),
+                ("Certificates.member.1.CertificateArn", certificate_arn)
+            ]).await?;
+            Ok(())
+        }
+        ("elbv2", "remove_listener_certificates") => {
+            // resource_id format: "listener_arn|certificate_arn"
+            let Some((listener_arn, certificate_arn)) = resource_id.split_once('|') else {
+                return Err(anyhow!("Invalid certificate reference: {}", resource_id));
+            };
+            clients.http.query_request("elbv2", "RemoveListenerCertificates", &[
+                ("ListenerArn", listener_arn),
+                ("Certificates.member.1.CertificateArn", certificate_arn)
+            ]).await?;
+            Ok(())
+        }
         ("elbv2", "delete_target_group") => {
             clients.http.query_request("elbv2", "DeleteTargetGroup", &[
                 ("TargetGroupArn", resource_id)
@@ -472,7 +1030,12 @@ pub async fn describe_resource(
             let json = xml_to_json(&xml)?;
             
             if let Some(role) = json.pointer("/GetRoleResponse/GetRoleResult/Role") {
-                return Ok(role.clone());
+                let mut role = role.clone();
+                // Trust policy is returned URL-encoded
+                if let Some(doc) = role.get("AssumeRolePolicyDocument").and_then(|v| v.as_str()) {
+                    role["AssumeRolePolicyDocument"] = decode_policy_document(doc);
+                }
+                return Ok(role);
             }
             Err(anyhow!("IAM role not found"))
         }
@@ -567,62 +1130,418 @@ pub async fn describe_resource(
             Err(anyhow!("Target group not found"))
         }
         
-        // Default: return an error indicating describe is not implemented
-        _ => {
-            tracing::debug!("No describe implementation for {}, falling back to list data", resource_key);
-            Err(anyhow!("Describe not implemented for {}", resource_key))
+        "eventbridge-schedules" => {
+            let (group, name) = parse_schedule_arn(resource_id)
+                .ok_or_else(|| anyhow!("Invalid schedule ARN: {}", resource_id))?;
+            let response = clients.http.rest_json_request(
+                "scheduler",
+                "GET",
+                &format!("/schedules/{}?groupName={}", urlencoding::encode(name), urlencoding::encode(group)),
+                None
+            ).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            Ok(json)
         }
-    }
-}
-
-// =============================================================================
-// List/Describe Functions (read operations)
-// =============================================================================
-
-/// Invoke an AWS API method and return the response as JSON.
-pub async fn invoke_sdk(
-    service: &str,
-    method: &str,
-    clients: &AwsClients,
-    params: &Value,
-) -> Result<Value> {
-    match (service, method) {
-        // =====================================================================
-        // IAM Operations (Query protocol, global service)
-        // =====================================================================
-        ("iam", "list_users") => {
-            let xml = clients.http.query_request("iam", "ListUsers", &[]).await?;
-            let json = xml_to_json(&xml)?;
-            
-            let users = extract_iam_list(&json, "Users", "member");
-            let result: Vec<Value> = users.iter().map(|u| {
-                json!({
-                    "UserId": u.get("UserId").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "UserName": u.get("UserName").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Arn": u.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Path": u.get("Path").and_then(|v| v.as_str()).unwrap_or("/"),
-                    "CreateDate": u.get("CreateDate").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
-            }).collect();
+        
+        "health-events" => {
+            let details = clients.http.json_request("health", "DescribeEventDetails", &json!({
+                "eventArns": [resource_id]
+            }).to_string()).await?;
+            let details: Value = serde_json::from_str(&details)?;
+            let detail = details.pointer("/successfulSet/0").ok_or_else(|| anyhow!("Event not found"))?;
             
-            Ok(json!({ "users": result }))
+            let entities = clients.http.json_request("health", "DescribeAffectedEntities", &json!({
+                "filter": { "eventArns": [resource_id] },
+                "maxResults": 100
+            }).to_string()).await?;
+            let entities: Value = serde_json::from_str(&entities)?;
+            let affected: Vec<Value> = entities.get("entities").and_then(|v| v.as_array()).cloned().unwrap_or_default()
+                .iter()
+                .map(|e| json!({
+                    "EntityValue": e.get("entityValue").cloned().unwrap_or(Value::Null),
+                    "EntityArn": e.get("entityArn").cloned().unwrap_or(Value::Null),
+                    "AwsAccountId": e.get("awsAccountId").cloned().unwrap_or(Value::Null),
+                    "StatusCode": e.get("statusCode").cloned().unwrap_or(Value::Null),
+                    "LastUpdatedTime": format_epoch_seconds_value(e.get("lastUpdatedTime")),
+                }))
+                .collect();
+            
+            let event = detail.get("event").cloned().unwrap_or(Value::Null);
+            Ok(json!({
+                "Service": event.get("service").cloned().unwrap_or(Value::Null),
+                "EventTypeCode": event.get("eventTypeCode").cloned().unwrap_or(Value::Null),
+                "Category": event.get("eventTypeCategory").cloned().unwrap_or(Value::Null),
+                "Region": event.get("region").cloned().unwrap_or(Value::Null),
+                "Status": event.get("statusCode").cloned().unwrap_or(Value::Null),
+                "StartTime": format_epoch_seconds_value(event.get("startTime")),
+                "EndTime": format_epoch_seconds_value(event.get("endTime")),
+                "LastUpdatedTime": format_epoch_seconds_value(event.get("lastUpdatedTime")),
+                "Description": detail.pointer("/eventDescription/latestDescription").cloned().unwrap_or(Value::Null),
+                "AffectedEntities": affected,
+                "Arn": resource_id,
+            }))
         }
 
-        ("iam", "list_roles") => {
-            let xml = clients.http.query_request("iam", "ListRoles", &[]).await?;
-            let json = xml_to_json(&xml)?;
+        "acm-certificates" => {
+            let response = clients.http.json_request("acm", "DescribeCertificate", &json!({
+                "CertificateArn": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let cert = json.get("Certificate").ok_or_else(|| anyhow!("Certificate not found"))?;
+            
+            let days_left = cert.get("NotAfter").and_then(|v| v.as_f64())
+                .map(|t| ((t - chrono::Utc::now().timestamp() as f64) / 86400.0).floor() as i64);
+            let validation: Vec<Value> = cert.get("DomainValidationOptions").and_then(|v| v.as_array()).cloned().unwrap_or_default()
+                .iter()
+                .map(|opt| json!({
+                    "DomainName": opt.get("DomainName").cloned().unwrap_or(Value::Null),
+                    "ValidationStatus": opt.get("ValidationStatus").cloned().unwrap_or(Value::Null),
+                    "ValidationMethod": opt.get("ValidationMethod").cloned().unwrap_or(Value::Null),
+                    "ResourceRecord": opt.get("ResourceRecord").cloned().unwrap_or(Value::Null),
+                }))
+                .collect();
             
-            let roles = extract_iam_list(&json, "Roles", "member");
-            let result: Vec<Value> = roles.iter().map(|r| {
-                json!({
-                    "RoleId": r.get("RoleId").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "RoleName": r.get("RoleName").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Arn": r.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+            Ok(json!({
+                "DomainName": cert.get("DomainName").cloned().unwrap_or(Value::Null),
+                "SubjectAlternativeNames": cert.get("SubjectAlternativeNames").cloned().unwrap_or(json!([])),
+                "Status": cert.get("Status").cloned().unwrap_or(Value::Null),
+                "Type": cert.get("Type").cloned().unwrap_or(Value::Null),
+                "NotBefore": format_epoch_seconds_value(cert.get("NotBefore")),
+                "NotAfter": format_epoch_seconds_value(cert.get("NotAfter")),
+                "DaysUntilExpiry": days_left,
+                "RenewalEligibility": cert.get("RenewalEligibility").cloned().unwrap_or(Value::Null),
+                "RenewalSummary": cert.get("RenewalSummary").cloned().unwrap_or(Value::Null),
+                "InUseBy": cert.get("InUseBy").cloned().unwrap_or(json!([])),
+                "DomainValidation": validation,
+                "Certificate": cert,
+            }))
+        }
+        
+        "cloudfront-distributions" => {
+            let xml = clients.http.rest_xml_request(
+                "cloudfront",
+                "GET",
+                &format!("/2020-05-31/distribution/{}/config", resource_id),
+                None
+            ).await?;
+            let json = xml_to_json(&xml)?;
+            let config = json.get("DistributionConfig").ok_or_else(|| anyhow!("Distribution not found"))?;
+            
+            let list = |path: &str| -> Vec<Value> {
+                match config.pointer(path) {
+                    Some(Value::Array(arr)) => arr.clone(),
+                    Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                    _ => vec![],
+                }
+            };
+            let origins: Vec<Value> = list("/Origins/Items/Origin").iter().map(|o| json!({
+                "Id": o.get("Id").and_then(|v| v.as_str()).unwrap_or("-"),
+                "DomainName": o.get("DomainName").and_then(|v| v.as_str()).unwrap_or("-"),
+                "OriginPath": o.get("OriginPath").and_then(|v| v.as_str()).unwrap_or(""),
+                "OriginAccessControlId": o.get("OriginAccessControlId").and_then(|v| v.as_str()).unwrap_or(""),
+            })).collect();
+            let behavior = |b: &Value| json!({
+                "PathPattern": b.get("PathPattern").and_then(|v| v.as_str()).unwrap_or("*"),
+                "TargetOriginId": b.get("TargetOriginId").and_then(|v| v.as_str()).unwrap_or("-"),
+                "ViewerProtocolPolicy": b.get("ViewerProtocolPolicy").and_then(|v| v.as_str()).unwrap_or("-"),
+                "CachePolicyId": b.get("CachePolicyId").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Compress": b.get("Compress").and_then(|v| v.as_str()).unwrap_or("-"),
+            });
+            let mut behaviors = vec![];
+            if let Some(default) = config.get("DefaultCacheBehavior") {
+                behaviors.push(behavior(default));
+            }
+            behaviors.extend(list("/CacheBehaviors/Items/CacheBehavior").iter().map(behavior));
+            
+            Ok(json!({
+                "Id": resource_id,
+                "Enabled": config.get("Enabled").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Comment": config.get("Comment").and_then(|v| v.as_str()).unwrap_or(""),
+                "Aliases": list("/Aliases/Items/CNAME"),
+                "PriceClass": config.get("PriceClass").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Origins": origins,
+                "CacheBehaviors": behaviors,
+                "DistributionConfig": config,
+            }))
+        }
+        
+        "cloudfront-invalidations" => {
+            let (distribution_id, invalidation_id) = resource_id.split_once('|')
+                .ok_or_else(|| anyhow!("Invalid invalidation reference: {}", resource_id))?;
+            let xml = clients.http.rest_xml_request(
+                "cloudfront",
+                "GET",
+                &format!("/2020-05-31/distribution/{}/invalidation/{}", distribution_id, invalidation_id),
+                None
+            ).await?;
+            let json = xml_to_json(&xml)?;
+            json.get("Invalidation").cloned().ok_or_else(|| anyhow!("Invalidation not found"))
+        }
+        
+        "cognito-users" => {
+            let (user_pool_id, username) = resource_id.split_once('|')
+                .ok_or_else(|| anyhow!("Invalid user reference: {}", resource_id))?;
+            let response = clients.http.json_request("cognito-idp", "AdminGetUser", &json!({
+                "UserPoolId": user_pool_id,
+                "Username": username
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            Ok(json)
+        }
+        
+        "cognito-app-clients" => {
+            let (user_pool_id, client_id) = resource_id.split_once('|')
+                .ok_or_else(|| anyhow!("Invalid app client reference: {}", resource_id))?;
+            let response = clients.http.json_request("cognito-idp", "DescribeUserPoolClient", &json!({
+                "UserPoolId": user_pool_id,
+                "ClientId": client_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.get("UserPoolClient").cloned().ok_or_else(|| anyhow!("App client not found"))
+        }
+        
+        "codebuild-builds" => {
+            let response = clients.http.json_request("codebuild", "BatchGetBuilds", &json!({
+                "ids": [resource_id]
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.pointer("/builds/0").cloned().ok_or_else(|| anyhow!("Build not found"))
+        }
+        
+        "glue-tables" => {
+            let (database_name, table_name) = resource_id.split_once('/')
+                .ok_or_else(|| anyhow!("Invalid table reference: {}", resource_id))?;
+            let response = clients.http.json_request("glue", "GetTable", &json!({
+                "DatabaseName": database_name,
+                "Name": table_name
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let table = json.get("Table").ok_or_else(|| anyhow!("Table not found"))?;
+            
+            // Lead with the schema; the full table definition follows
+            let schema = |path: &str| -> Vec<Value> {
+                table.pointer(path).and_then(|v| v.as_array()).map(|cols| cols.iter().map(|c| json!({
+                    "Name": c.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Type": c.get("Type").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Comment": c.get("Comment").and_then(|v| v.as_str()).unwrap_or("-"),
+                })).collect()).unwrap_or_default()
+            };
+            Ok(json!({
+                "Name": table_name,
+                "DatabaseName": database_name,
+                "Columns": schema("/StorageDescriptor/Columns"),
+                "PartitionKeys": schema("/PartitionKeys"),
+                "Table": table,
+            }))
+        }
+        
+        "glue-jobs" => {
+            let response = clients.http.json_request("glue", "GetJob", &json!({
+                "JobName": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.get("Job").cloned().ok_or_else(|| anyhow!("Job not found"))
+        }
+        
+        "glue-crawlers" => {
+            let response = clients.http.json_request("glue", "GetCrawler", &json!({
+                "Name": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.get("Crawler").cloned().ok_or_else(|| anyhow!("Crawler not found"))
+        }
+        
+        "athena-query-history" => {
+            let response = clients.http.json_request("athena", "GetQueryExecution", &json!({
+                "QueryExecutionId": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.get("QueryExecution").cloned().ok_or_else(|| anyhow!("Query execution not found"))
+        }
+        
+        "athena-saved-queries" => {
+            let response = clients.http.json_request("athena", "GetNamedQuery", &json!({
+                "NamedQueryId": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.get("NamedQuery").cloned().ok_or_else(|| anyhow!("Saved query not found"))
+        }
+        
+        "cloudformation-change-sets" => {
+            // Change set ARN identifies the stack as well, so no StackName is needed
+            let xml = clients.http.query_request("cloudformation", "DescribeChangeSet", &[
+                ("ChangeSetName", resource_id)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let result = json.pointer("/DescribeChangeSetResponse/DescribeChangeSetResult")
+                .ok_or_else(|| anyhow!("Change set not found"))?;
+            
+            let changes = match result.pointer("/Changes/member") {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            let planned: Vec<Value> = changes.iter().filter_map(|c| c.get("ResourceChange")).map(|rc| {
+                json!({
+                    "Action": rc.pointer("/Action").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LogicalResourceId": rc.pointer("/LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PhysicalResourceId": rc.pointer("/PhysicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": rc.pointer("/ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Replacement": rc.pointer("/Replacement").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            Ok(json!({
+                "ChangeSetName": result.pointer("/ChangeSetName").and_then(|v| v.as_str()).unwrap_or("-"),
+                "ChangeSetId": result.pointer("/ChangeSetId").and_then(|v| v.as_str()).unwrap_or("-"),
+                "StackName": result.pointer("/StackName").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Status": result.pointer("/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                "StatusReason": result.pointer("/StatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                "ExecutionStatus": result.pointer("/ExecutionStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                "CreationTime": result.pointer("/CreationTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Description": result.pointer("/Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Changes": planned,
+            }))
+        }
+        
+        // Default: return an error indicating describe is not implemented
+        _ => {
+            tracing::debug!("No describe implementation for {}, falling back to list data", resource_key);
+            Err(anyhow!("Describe not implemented for {}", resource_key))
+        }
+    }
+}
+
+/// Fetch a document for a view action (template, policy, secret value, etc.)
+/// to be shown in the describe view. Plain strings are shown as raw text.
+pub async fn fetch_action_view(
+    service: &str,
+    action: &str,
+    clients: &AwsClients,
+    resource_id: &str,
+) -> Result<Value> {
+    match (service, action) {
+        ("kms", "get_key_policy") => {
+            let response = clients.http.json_request("kms", "GetKeyPolicy", &json!({
+                "KeyId": resource_id,
+                "PolicyName": "default"
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let policy = json.get("Policy").and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("No key policy returned for {}", resource_id))?;
+            Ok(serde_json::from_str::<Value>(policy).unwrap_or_else(|_| Value::String(policy.to_string())))
+        }
+        ("cloudformation", "get_template") => {
+            let xml = clients.http.query_request("cloudformation", "GetTemplate", &[
+                ("StackName", resource_id),
+                ("TemplateStage", "Original")
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let body = json.pointer("/GetTemplateResponse/GetTemplateResult/TemplateBody")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("No template returned for {}", resource_id))?;
+            
+            // JSON templates are pretty-printed; YAML templates are shown as-is
+            Ok(serde_json::from_str::<Value>(body).unwrap_or_else(|_| Value::String(body.to_string())))
+        }
+        ("iam", "create_access_key") => {
+            let xml = clients.http.query_request("iam", "CreateAccessKey", &[
+                ("UserName", resource_id)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let key = json.pointer("/CreateAccessKeyResponse/CreateAccessKeyResult/AccessKey")
+                .ok_or_else(|| anyhow!("No access key returned for {}", resource_id))?;
+            Ok(json!({
+                "UserName": key.get("UserName").and_then(|v| v.as_str()).unwrap_or(resource_id),
+                "AccessKeyId": key.get("AccessKeyId").and_then(|v| v.as_str()).unwrap_or("-"),
+                "SecretAccessKey": key.get("SecretAccessKey").and_then(|v| v.as_str()).unwrap_or("-"),
+            }))
+        }
+        ("secretsmanager", "get_secret_value") => {
+            let response = clients.http.json_request("secretsmanager", "GetSecretValue", &json!({
+                "SecretId": resource_id
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let stages: Vec<&str> = json.get("VersionStages")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|s| s.as_str()).collect())
+                .unwrap_or_default();
+            // Binary secrets are returned base64-encoded and shown as such
+            let value = json.get("SecretString")
+                .or_else(|| json.get("SecretBinary"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            Ok(json!({
+                "Name": json.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                "VersionId": json.get("VersionId").and_then(|v| v.as_str()).unwrap_or("-"),
+                "VersionStages": stages.join(", "),
+                "SecretString": value,
+            }))
+        }
+        ("ssm", "get_parameter") => {
+            let response = clients.http.json_request("ssm", "GetParameter", &json!({
+                "Name": resource_id,
+                "WithDecryption": true
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let param = json.get("Parameter")
+                .ok_or_else(|| anyhow!("Parameter {} not found", resource_id))?;
+            Ok(json!({
+                "Name": param.get("Name").and_then(|v| v.as_str()).unwrap_or(resource_id),
+                "Type": param.get("Type").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Version": param.get("Version").and_then(|v| v.as_i64()).map(|v| v.to_string()).unwrap_or("-".to_string()),
+                "Value": param.get("Value").and_then(|v| v.as_str()).unwrap_or(""),
+            }))
+        }
+        _ => Err(anyhow!("Unknown view action: {}.{}", service, action)),
+    }
+}
+
+// =============================================================================
+// List/Describe Functions (read operations)
+// =============================================================================
+
+/// Invoke an AWS API method and return the response as JSON.
+pub async fn invoke_sdk(
+    service: &str,
+    method: &str,
+    clients: &AwsClients,
+    params: &Value,
+) -> Result<Value> {
+    match (service, method) {
+        // =====================================================================
+        // IAM Operations (Query protocol, global service)
+        // =====================================================================
+        ("iam", "list_users") => {
+            let xml = clients.http.query_request("iam", "ListUsers", &[]).await?;
+            let mut result: Vec<Value> = Vec::new();
+            xml_for_each(&xml, &["*", "*", "Users", "member"], |u| {
+                result.push(json!({
+                    "UserId": u.get("UserId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "UserName": u.get("UserName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Arn": u.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Path": u.get("Path").and_then(|v| v.as_str()).unwrap_or("/"),
+                    "CreateDate": u.get("CreateDate").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PasswordLastUsed": u.get("PasswordLastUsed").and_then(|v| v.as_str()).unwrap_or("never"),
+                }));
+            })?;
+            
+            Ok(json!({ "users": result }))
+        }
+
+        ("iam", "list_roles") => {
+            let xml = clients.http.query_request("iam", "ListRoles", &[]).await?;
+            let mut result: Vec<Value> = Vec::new();
+            xml_for_each(&xml, &["*", "*", "Roles", "member"], |r| {
+                result.push(json!({
+                    "RoleId": r.get("RoleId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "RoleName": r.get("RoleName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Arn": r.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Path": r.get("Path").and_then(|v| v.as_str()).unwrap_or("/"),
                     "CreateDate": r.get("CreateDate").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Description": r.get("Description").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
-            }).collect();
+                }));
+            })?;
             
             Ok(json!({ "roles": result }))
         }
@@ -632,11 +1551,9 @@ pub async fn invoke_sdk(
             let xml = clients.http.query_request("iam", "ListPolicies", &[
                 ("Scope", scope)
             ]).await?;
-            let json = xml_to_json(&xml)?;
-            
-            let policies = extract_iam_list(&json, "Policies", "member");
-            let result: Vec<Value> = policies.iter().map(|p| {
-                json!({
+            let mut result: Vec<Value> = Vec::new();
+            xml_for_each(&xml, &["*", "*", "Policies", "member"], |p| {
+                result.push(json!({
                     "PolicyId": p.get("PolicyId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "PolicyName": p.get("PolicyName").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Arn": p.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
@@ -644,8 +1561,8 @@ pub async fn invoke_sdk(
                     "CreateDate": p.get("CreateDate").and_then(|v| v.as_str()).unwrap_or("-"),
                     "AttachmentCount": p.get("AttachmentCount").and_then(|v| v.as_str()).unwrap_or("0"),
                     "IsAttachable": if p.get("IsAttachable").and_then(|v| v.as_str()) == Some("true") { "Yes" } else { "No" },
-                })
-            }).collect();
+                }));
+            })?;
             
             Ok(json!({ "policies": result }))
         }
@@ -713,17 +1630,83 @@ pub async fn invoke_sdk(
             let json = xml_to_json(&xml)?;
             
             let keys = extract_iam_list(&json, "AccessKeyMetadata", "member");
-            let result: Vec<Value> = keys.iter().map(|k| {
-                json!({
-                    "AccessKeyId": k.get("AccessKeyId").and_then(|v| v.as_str()).unwrap_or("-"),
+            let mut result: Vec<Value> = Vec::new();
+            for k in &keys {
+                let key_id = k.get("AccessKeyId").and_then(|v| v.as_str()).unwrap_or("-");
+                let key_user = k.get("UserName").and_then(|v| v.as_str()).unwrap_or(&user_name);
+                
+                // Users have at most two keys, so look up last use per key
+                let last_used = match clients.http.query_request("iam", "GetAccessKeyLastUsed", &[
+                    ("AccessKeyId", key_id)
+                ]).await {
+                    Ok(xml) => xml_to_json(&xml)?
+                        .pointer("/GetAccessKeyLastUsedResponse/GetAccessKeyLastUsedResult/AccessKeyLastUsed")
+                        .cloned()
+                        .unwrap_or(Value::Null),
+                    Err(_) => Value::Null,
+                };
+                
+                result.push(json!({
+                    "AccessKeyId": key_id,
+                    "UserName": key_user,
+                    // User and key id together, for actions that need both
+                    "KeyRef": format!("{}:{}", key_user, key_id),
                     "Status": k.get("Status").and_then(|v| v.as_str()).unwrap_or("-"),
                     "CreateDate": k.get("CreateDate").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
-            }).collect();
+                    "LastUsedDate": last_used.get("LastUsedDate").and_then(|v| v.as_str()).unwrap_or("never"),
+                    "LastUsedService": last_used.get("ServiceName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastUsedRegion": last_used.get("Region").and_then(|v| v.as_str()).unwrap_or("-"),
+                }));
+            }
             
             Ok(json!({ "access_key_metadata": result }))
         }
 
+        ("iam", "list_service_last_accessed") => {
+            // Its own view: the report takes a few seconds to generate
+            let arn = extract_param(params, "arn");
+            let services = iam_service_last_accessed(clients, &arn).await?;
+            Ok(json!({ "services": services }))
+        }
+
+        ("iam", "list_role_policies") | ("iam", "list_user_policies") => {
+            // Inline policies are few per principal, so fetch each document up front
+            let (list_op, get_op, param, principal) = if method == "list_role_policies" {
+                ("ListRolePolicies", "GetRolePolicy", "RoleName", extract_param(params, "role_name"))
+            } else {
+                ("ListUserPolicies", "GetUserPolicy", "UserName", extract_param(params, "user_name"))
+            };
+            let xml = clients.http.query_request("iam", list_op, &[
+                (param, &principal)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let names = extract_iam_list(&json, "PolicyNames", "member");
+            let mut result: Vec<Value> = Vec::new();
+            for name in names.iter().filter_map(|n| n.as_str()) {
+                let xml = clients.http.query_request("iam", get_op, &[
+                    (param, &principal),
+                    ("PolicyName", name),
+                ]).await?;
+                let json = xml_to_json(&xml)?;
+                let document = json.as_object()
+                    .and_then(|o| o.values().next())
+                    .and_then(|v| v.as_object())
+                    .and_then(|o| o.values().next())
+                    .and_then(|v| v.get("PolicyDocument"))
+                    .and_then(|v| v.as_str())
+                    .map(decode_policy_document)
+                    .unwrap_or(Value::Null);
+                result.push(json!({
+                    "PolicyName": name,
+                    param: principal,
+                    "PolicyDocument": document,
+                }));
+            }
+            
+            Ok(json!({ "inline_policies": result }))
+        }
+
         ("iam", "list_attached_role_policies") => {
             let role_name = extract_param(params, "role_name");
             let xml = clients.http.query_request("iam", "ListAttachedRolePolicies", &[
@@ -766,41 +1749,26 @@ pub async fn invoke_sdk(
         // =====================================================================
         ("ec2", "describe_instances") => {
             let xml = clients.http.query_request("ec2", "DescribeInstances", &[]).await?;
-            let json = xml_to_json(&xml)?;
-            
             let mut instances: Vec<Value> = Vec::new();
             
-            // Navigate: DescribeInstancesResponse > reservationSet > item > instancesSet > item
-            if let Some(reservations) = json.pointer("/DescribeInstancesResponse/reservationSet/item") {
-                let reservation_list = match reservations {
-                    Value::Array(arr) => arr.clone(),
-                    obj @ Value::Object(_) => vec![obj.clone()],
-                    _ => vec![],
-                };
-                
-                for reservation in reservation_list {
-                    if let Some(instance_set) = reservation.pointer("/instancesSet/item") {
-                        let instance_list = match instance_set {
-                            Value::Array(arr) => arr.clone(),
-                            obj @ Value::Object(_) => vec![obj.clone()],
-                            _ => vec![],
-                        };
-                        
-                        for instance in instance_list {
-                            let tags = extract_tags(&instance);
-                            instances.push(json!({
-                                "InstanceId": instance.pointer("/instanceId").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "InstanceType": instance.pointer("/instanceType").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "State": instance.pointer("/instanceState/name").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "AvailabilityZone": instance.pointer("/placement/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "PublicIpAddress": instance.pointer("/ipAddress").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "PrivateIpAddress": instance.pointer("/privateIpAddress").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "LaunchTime": instance.pointer("/launchTime").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "Tags": tags,
-                            }));
-                        }
-                    }
-                }
+            // Each DescribeInstancesResponse > reservationSet > item > instancesSet > item,
+            // read one at a time rather than building the whole response
+            xml_for_each(&xml, &["*", "reservationSet", "item", "instancesSet", "item"], |instance| {
+                let tags = extract_tags(&instance);
+                instances.push(json!({
+                    "InstanceId": instance.pointer("/instanceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "InstanceType": instance.pointer("/instanceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": instance.pointer("/instanceState/name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "AvailabilityZone": instance.pointer("/placement/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PublicIpAddress": instance.pointer("/ipAddress").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PrivateIpAddress": instance.pointer("/privateIpAddress").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LaunchTime": instance.pointer("/launchTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Tags": tags,
+                }));
+            })?;
+            
+            if extract_param(params, "with_metrics") == "true" {
+                add_utilization(clients, &mut instances, "AWS/EC2", "InstanceId", &[("CPUUtilization", "c")]).await;
             }
             
             Ok(json!({ "reservations": instances }))
@@ -901,13 +1869,38 @@ pub async fn invoke_sdk(
                 _ => vec![],
             };
             
-            let result: Vec<Value> = bucket_list.iter().map(|b| {
+            let mut result: Vec<Value> = bucket_list.iter().map(|b| {
+                let name = b.pointer("/Name").and_then(|v| v.as_str()).unwrap_or("-");
+                // ListBuckets names each bucket's region; remember it for
+                // requests to the bucket (objects, describe, delete)
+                let region = b.pointer("/BucketRegion").and_then(|v| v.as_str())
+                    .map(String::from)
+                    .or_else(|| crate::aws::http::cached_bucket_region(name));
+                if let Some(region) = &region {
+                    crate::aws::http::remember_bucket_region(name, region);
+                }
                 json!({
-                    "Name": b.pointer("/Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Name": name,
+                    "BucketArn": format!("arn:aws:s3:::{}", name),
+                    "BucketRegion": region,
                     "CreationDate": b.pointer("/CreationDate").and_then(|v| v.as_str()).unwrap_or("-"),
                 })
             }).collect();
             
+            // Look up the rest (endpoints that leave BucketRegion out) concurrently
+            let mut lookups = tokio::task::JoinSet::new();
+            for (index, bucket) in result.iter().enumerate() {
+                if bucket["BucketRegion"].is_null() {
+                    let (http, name) = (clients.http.clone(), bucket["Name"].as_str().unwrap_or("-").to_string());
+                    lookups.spawn(async move { (index, http.get_bucket_region(&name).await) });
+                }
+            }
+            while let Some(lookup) = lookups.join_next().await {
+                if let Ok((index, Ok(region))) = lookup {
+                    result[index]["BucketRegion"] = json!(region);
+                }
+            }
+            
             Ok(json!({ "buckets": result }))
         }
         
@@ -1015,6 +2008,7 @@ pub async fn invoke_sdk(
             let result: Vec<Value> = functions.iter().map(|f| {
                 json!({
                     "FunctionName": f.get("FunctionName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "FunctionArn": f.get("FunctionArn").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Runtime": f.get("Runtime").and_then(|v| v.as_str()).unwrap_or("-"),
                     "MemorySize": f.get("MemorySize").and_then(|v| v.as_i64()).unwrap_or(0),
                     "LastModified": f.get("LastModified").and_then(|v| v.as_str()).unwrap_or("-"),
@@ -1030,19 +2024,28 @@ pub async fn invoke_sdk(
         // =====================================================================
         ("rds", "describe_db_instances") => {
             let xml = clients.http.query_request("rds", "DescribeDBInstances", &[]).await?;
-            let json = xml_to_json(&xml)?;
-            
-            let instances = extract_rds_list(&json, "DBInstances", "DBInstance");
-            let result: Vec<Value> = instances.iter().map(|db| {
-                json!({
+            let mut result: Vec<Value> = Vec::new();
+            xml_for_each(&xml, &["*", "*", "DBInstances", "DBInstance"], |db| {
+                result.push(json!({
                     "DBInstanceIdentifier": db.pointer("/DBInstanceIdentifier").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "DBInstanceArn": db.pointer("/DBInstanceArn").and_then(|v| v.as_str()).unwrap_or("-"),
                     "DBInstanceStatus": db.pointer("/DBInstanceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Engine": db.pointer("/Engine").and_then(|v| v.as_str()).unwrap_or("-"),
                     "DBInstanceClass": db.pointer("/DBInstanceClass").and_then(|v| v.as_str()).unwrap_or("-"),
                     "AvailabilityZone": db.pointer("/AvailabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Endpoint": db.pointer("/Endpoint/Address").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
-            }).collect();
+                }));
+            })?;
+            
+            if extract_param(params, "with_metrics") == "true" {
+                add_utilization(
+                    clients,
+                    &mut result,
+                    "AWS/RDS",
+                    "DBInstanceIdentifier",
+                    &[("CPUUtilization", "c"), ("FreeableMemory", "m")],
+                ).await;
+            }
             
             Ok(json!({ "db_instances": result }))
         }
@@ -1073,6 +2076,49 @@ pub async fn invoke_sdk(
             Ok(json!({ "db_snapshots": result }))
         }
 
+        ("rds", "describe_events") => {
+            let db_id = extract_param(params, "db_instance_identifier");
+            if db_id.is_empty() {
+                return Ok(json!({ "events": [] }));
+            }
+            
+            // Last 7 days of events for the instance
+            let xml = clients.http.query_request("rds", "DescribeEvents", &[
+                ("SourceIdentifier", db_id.as_str()),
+                ("SourceType", "db-instance"),
+                ("Duration", "10080"),
+                ("MaxRecords", "100")
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let events = extract_rds_list(&json, "Events", "Event");
+            let mut result: Vec<Value> = events.iter().enumerate().map(|(i, event)| {
+                let categories = match event.pointer("/EventCategories/EventCategory") {
+                    Some(Value::Array(arr)) => arr.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>().join(", "),
+                    Some(Value::String(s)) => s.clone(),
+                    _ => "-".to_string(),
+                };
+                let date = event.pointer("/Date").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "EventId": format!("{}-{}", date, i),
+                    "Date": date,
+                    "SourceIdentifier": event.pointer("/SourceIdentifier").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "SourceType": event.pointer("/SourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "EventCategories": categories,
+                    "Message": event.pointer("/Message").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            // Most recent first (ISO 8601 timestamps sort lexicographically)
+            result.sort_by(|a, b| {
+                let a_date = a.get("Date").and_then(|v| v.as_str()).unwrap_or("");
+                let b_date = b.get("Date").and_then(|v| v.as_str()).unwrap_or("");
+                b_date.cmp(a_date)
+            });
+            
+            Ok(json!({ "events": result }))
+        }
+
         // =====================================================================
         // DynamoDB Operations (JSON protocol)
         // =====================================================================
@@ -1198,6 +2244,57 @@ pub async fn invoke_sdk(
             Ok(json!({ "tasks": result }))
         }
 
+        ("ecs", "list_service_deployments") | ("ecs", "list_service_events") => {
+            let service_arn = extract_param(params, "service");
+            let response_key = if method == "list_service_deployments" { "deployments" } else { "events" };
+            let Some(cluster) = ecs_cluster_from_arn(&service_arn) else {
+                return Ok(json!({ response_key: [] }));
+            };
+            
+            let desc_response = clients.http.json_request("ecs", "DescribeServices", &json!({
+                "cluster": cluster,
+                "services": [service_arn]
+            }).to_string()).await?;
+            let desc_json: Value = serde_json::from_str(&desc_response)?;
+            
+            let service = desc_json.get("services")
+                .and_then(|v| v.as_array())
+                .and_then(|a| a.first())
+                .cloned()
+                .unwrap_or(Value::Null);
+            let entries = service.get(response_key).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            
+            let result: Vec<Value> = if response_key == "deployments" {
+                entries.iter().map(|d| {
+                    let task_definition = d.get("taskDefinition").and_then(|v| v.as_str()).unwrap_or("-");
+                    json!({
+                        "id": d.get("id").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "status": d.get("status").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "rolloutState": d.get("rolloutState").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "rolloutStateReason": d.get("rolloutStateReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "taskDefinition": task_definition.rsplit('/').next().unwrap_or(task_definition),
+                        "desiredCount": d.get("desiredCount").and_then(|v| v.as_i64()).unwrap_or(0),
+                        "runningCount": d.get("runningCount").and_then(|v| v.as_i64()).unwrap_or(0),
+                        "pendingCount": d.get("pendingCount").and_then(|v| v.as_i64()).unwrap_or(0),
+                        "failedTasks": d.get("failedTasks").and_then(|v| v.as_i64()).unwrap_or(0),
+                        "launchType": d.get("launchType").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "createdAt": format_epoch_seconds_value(d.get("createdAt")),
+                        "updatedAt": format_epoch_seconds_value(d.get("updatedAt")),
+                    })
+                }).collect()
+            } else {
+                entries.iter().map(|e| {
+                    json!({
+                        "id": e.get("id").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "message": e.get("message").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "createdAt": format_epoch_seconds_value(e.get("createdAt")),
+                    })
+                }).collect()
+            };
+            
+            Ok(json!({ response_key: result }))
+        }
+
         // =====================================================================
         // SQS Operations (Query protocol)
         // =====================================================================
@@ -1272,25 +2369,200 @@ pub async fn invoke_sdk(
             Ok(json!({ "stacks": result }))
         }
 
-        // =====================================================================
-        // CloudWatch Logs Operations (JSON protocol)
-        // =====================================================================
-        ("cloudwatchlogs", "describe_log_groups") => {
-            let response = clients.http.json_request("logs", "DescribeLogGroups", "{}").await?;
-            let json: Value = serde_json::from_str(&response)?;
+        ("cloudformation", "list_stack_resources") => {
+            let stack_name = extract_param(params, "stack_name");
+            if stack_name.is_empty() {
+                return Ok(json!({ "stack_resources": [] }));
+            }
             
-            let log_groups = json.get("logGroups").and_then(|v| v.as_array()).cloned().unwrap_or_default();
-            let result: Vec<Value> = log_groups.iter().map(|lg| {
-                json!({
-                    "logGroupName": lg.get("logGroupName").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "logGroupArn": lg.get("arn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "storedBytes": lg.get("storedBytes").and_then(|v| v.as_i64()).unwrap_or(0),
-                    "retentionInDays": lg.get("retentionInDays").map(|v| v.to_string()).unwrap_or("Never".to_string()),
-                    "creationTime": lg.get("creationTime").map(|v| v.to_string()).unwrap_or("-".to_string()),
-                })
-            }).collect();
+            let mut query_params = vec![("StackName", stack_name.as_str())];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            if let Some(token) = page_token {
+                query_params.push(("NextToken", token));
+            }
             
-            Ok(json!({ "log_groups": result }))
+            let xml = clients.http.query_request("cloudformation", "ListStackResources", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let summaries = json.pointer("/ListStackResourcesResponse/ListStackResourcesResult/StackResourceSummaries/member");
+            let summary_list = match summaries {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = summary_list.iter().map(|r| {
+                json!({
+                    "LogicalResourceId": r.pointer("/LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PhysicalResourceId": r.pointer("/PhysicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": r.pointer("/ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatus": r.pointer("/ResourceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatusReason": r.pointer("/ResourceStatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastUpdatedTimestamp": r.pointer("/LastUpdatedTimestamp").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "DriftStatus": r.pointer("/DriftInformation/StackResourceDriftStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let next_token = json.pointer("/ListStackResourcesResponse/ListStackResourcesResult/NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "stack_resources": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        ("cloudformation", "describe_stack_events") => {
+            let stack_name = extract_param(params, "stack_name");
+            if stack_name.is_empty() {
+                return Ok(json!({ "stack_events": [] }));
+            }
+            
+            let mut query_params = vec![("StackName", stack_name.as_str())];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            if let Some(token) = page_token {
+                query_params.push(("NextToken", token));
+            }
+            
+            let xml = clients.http.query_request("cloudformation", "DescribeStackEvents", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+            
+            // Events are returned newest first
+            let events = json.pointer("/DescribeStackEventsResponse/DescribeStackEventsResult/StackEvents/member");
+            let event_list = match events {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = event_list.iter().map(|e| {
+                json!({
+                    "EventId": e.pointer("/EventId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Timestamp": e.pointer("/Timestamp").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LogicalResourceId": e.pointer("/LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PhysicalResourceId": e.pointer("/PhysicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": e.pointer("/ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatus": e.pointer("/ResourceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatusReason": e.pointer("/ResourceStatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let next_token = json.pointer("/DescribeStackEventsResponse/DescribeStackEventsResult/NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "stack_events": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        ("cloudformation", "describe_stack_parameters") | ("cloudformation", "describe_stack_outputs") => {
+            let stack_name = extract_param(params, "stack_name");
+            let (response_key, list_key) = if method == "describe_stack_parameters" {
+                ("parameters", "Parameters")
+            } else {
+                ("outputs", "Outputs")
+            };
+            if stack_name.is_empty() {
+                return Ok(json!({ response_key: [] }));
+            }
+            
+            let xml = clients.http.query_request("cloudformation", "DescribeStacks", &[
+                ("StackName", stack_name.as_str())
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let entries = json.pointer(&format!("/DescribeStacksResponse/DescribeStacksResult/Stacks/member/{}/member", list_key));
+            let entry_list = match entries {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = if response_key == "parameters" {
+                entry_list.iter().map(|p| {
+                    json!({
+                        "ParameterKey": p.pointer("/ParameterKey").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "ParameterValue": p.pointer("/ParameterValue").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "ResolvedValue": p.pointer("/ResolvedValue").and_then(|v| v.as_str()).unwrap_or("-"),
+                    })
+                }).collect()
+            } else {
+                entry_list.iter().map(|o| {
+                    json!({
+                        "OutputKey": o.pointer("/OutputKey").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "OutputValue": o.pointer("/OutputValue").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "Description": o.pointer("/Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "ExportName": o.pointer("/ExportName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    })
+                }).collect()
+            };
+            
+            Ok(json!({ response_key: result }))
+        }
+
+        ("cloudformation", "list_change_sets") => {
+            let stack_name = extract_param(params, "stack_name");
+            if stack_name.is_empty() {
+                return Ok(json!({ "change_sets": [] }));
+            }
+            
+            let mut query_params = vec![("StackName", stack_name.as_str())];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            if let Some(token) = page_token {
+                query_params.push(("NextToken", token));
+            }
+            
+            let xml = clients.http.query_request("cloudformation", "ListChangeSets", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let summaries = json.pointer("/ListChangeSetsResponse/ListChangeSetsResult/Summaries/member");
+            let summary_list = match summaries {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = summary_list.iter().map(|cs| {
+                json!({
+                    "ChangeSetId": cs.pointer("/ChangeSetId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ChangeSetName": cs.pointer("/ChangeSetName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Status": cs.pointer("/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ExecutionStatus": cs.pointer("/ExecutionStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StatusReason": cs.pointer("/StatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreationTime": cs.pointer("/CreationTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Description": cs.pointer("/Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let next_token = json.pointer("/ListChangeSetsResponse/ListChangeSetsResult/NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "change_sets": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        // =====================================================================
+        // CloudWatch Logs Operations (JSON protocol)
+        // =====================================================================
+        ("cloudwatchlogs", "describe_log_groups") => {
+            let response = clients.http.json_request("logs", "DescribeLogGroups", "{}").await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let log_groups = json.get("logGroups").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = log_groups.iter().map(|lg| {
+                json!({
+                    "logGroupName": lg.get("logGroupName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "logGroupArn": lg.get("arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "storedBytes": lg.get("storedBytes").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "retentionInDays": lg.get("retentionInDays").map(|v| v.to_string()).unwrap_or("Never".to_string()),
+                    "creationTime": lg.get("creationTime").map(|v| v.to_string()).unwrap_or("-".to_string()),
+                })
+            }).collect();
+            
+            Ok(json!({ "log_groups": result }))
         }
 
         ("cloudwatchlogs", "describe_log_streams") => {
@@ -1323,11 +2595,11 @@ pub async fn invoke_sdk(
                 // Format timestamps as human-readable dates
                 let last_event = ls.get("lastEventTimestamp")
                     .and_then(|v| v.as_i64())
-                    .map(|ts| format_epoch_millis(ts))
+                    .map(epoch_millis_to_rfc3339)
                     .unwrap_or("-".to_string());
                 let first_event = ls.get("firstEventTimestamp")
                     .and_then(|v| v.as_i64())
-                    .map(|ts| format_epoch_millis(ts))
+                    .map(epoch_millis_to_rfc3339)
                     .unwrap_or("-".to_string());
                     
                 json!({
@@ -1389,6 +2661,41 @@ pub async fn invoke_sdk(
             }))
         }
 
+        ("cloudwatchlogs", "filter_log_events") => {
+            let log_group_name = extract_param(params, "log_group_name");
+            let next_token = params.get("next_token").and_then(|v| v.as_str());
+            let start_time = params.get("start_time").and_then(|v| v.as_i64());
+            
+            let mut request = json!({
+                "logGroupName": log_group_name,
+                "limit": 1000
+            });
+            
+            if let Some(token) = next_token {
+                request["nextToken"] = json!(token);
+            }
+            if let Some(ts) = start_time {
+                request["startTime"] = json!(ts);
+            }
+            
+            let response = clients.http.json_request("logs", "FilterLogEvents", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let events = json.get("events").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = events.iter().map(|ev| {
+                json!({
+                    "timestamp": ev.get("timestamp").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "message": ev.get("message").and_then(|v| v.as_str()).unwrap_or(""),
+                    "logStreamName": ev.get("logStreamName").and_then(|v| v.as_str()).unwrap_or(""),
+                })
+            }).collect();
+            
+            Ok(json!({
+                "events": result,
+                "nextToken": json.get("nextToken").and_then(|v| v.as_str())
+            }))
+        }
+
         // =====================================================================
         // Secrets Manager Operations (JSON protocol)
         // =====================================================================
@@ -1425,9 +2732,259 @@ pub async fn invoke_sdk(
             Ok(response)
         }
 
+        ("secretsmanager", "list_secret_version_ids") => {
+            let secret_id = extract_param(params, "secret_id");
+            if secret_id.is_empty() {
+                return Ok(json!({ "versions": [] }));
+            }
+            
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let mut request = json!({ "SecretId": secret_id, "IncludeDeprecated": true, "MaxResults": 100 });
+            if let Some(token) = page_token {
+                request["NextToken"] = json!(token);
+            }
+            
+            let response = clients.http.json_request("secretsmanager", "ListSecretVersionIds", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let mut versions = json.get("Versions").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            // Newest version first
+            versions.sort_by(|a, b| {
+                let a = a.get("CreatedDate").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                let b = b.get("CreatedDate").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let result: Vec<Value> = versions.iter().map(|version| {
+                let stages: Vec<&str> = version.get("VersionStages")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.iter().filter_map(|s| s.as_str()).collect())
+                    .unwrap_or_default();
+                json!({
+                    "VersionId": version.get("VersionId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "VersionStages": if stages.is_empty() { "-".to_string() } else { stages.join(", ") },
+                    "CreatedDate": format_epoch_seconds_value(version.get("CreatedDate")),
+                    "LastAccessedDate": format_epoch_seconds_value(version.get("LastAccessedDate")),
+                })
+            }).collect();
+            
+            let next_token = json.get("NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "versions": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        // =====================================================================
+        // EventBridge Scheduler Operations (REST-JSON protocol)
+        // =====================================================================
+        ("scheduler", "list_schedules") => {
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let path = match page_token {
+                Some(token) => format!("/schedules?MaxResults=100&NextToken={}", urlencoding::encode(token)),
+                None => "/schedules?MaxResults=100".to_string(),
+            };
+            
+            let response = clients.http.rest_json_request("scheduler", "GET", &path, None).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let summaries = json.get("Schedules").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let mut result: Vec<Value> = Vec::new();
+            for summary in &summaries {
+                let name = summary.get("Name").and_then(|v| v.as_str()).unwrap_or("-");
+                let group = summary.get("GroupName").and_then(|v| v.as_str()).unwrap_or("default");
+                
+                // The expression is only available from GetSchedule
+                let detail = match clients.http.rest_json_request(
+                    "scheduler",
+                    "GET",
+                    &format!("/schedules/{}?groupName={}", urlencoding::encode(name), urlencoding::encode(group)),
+                    None
+                ).await {
+                    Ok(response) => serde_json::from_str::<Value>(&response).unwrap_or(Value::Null),
+                    Err(_) => Value::Null,
+                };
+                
+                let target_arn = summary.pointer("/Target/Arn").and_then(|v| v.as_str()).unwrap_or("-");
+                result.push(json!({
+                    "Name": name,
+                    "GroupName": group,
+                    "Arn": summary.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": summary.get("State").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ScheduleExpression": detail.get("ScheduleExpression").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ScheduleExpressionTimezone": detail.get("ScheduleExpressionTimezone").and_then(|v| v.as_str()).unwrap_or("UTC"),
+                    "TargetArn": target_arn,
+                    "TargetSummary": schedule_target_summary(target_arn),
+                    "LastModificationDate": format_epoch_seconds_value(summary.get("LastModificationDate")),
+                }));
+            }
+            
+            let next_token = json.get("NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "schedules": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Kinesis Operations (JSON protocol)
+        // =====================================================================
+        ("kinesis", "list_streams") => {
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let request_body = if let Some(token) = page_token {
+                json!({ "NextToken": token, "Limit": 100 }).to_string()
+            } else {
+                json!({ "Limit": 100 }).to_string()
+            };
+            
+            let response = clients.http.json_request("kinesis", "ListStreams", &request_body).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let names: Vec<String> = json.get("StreamNames")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|n| n.as_str().map(String::from)).collect())
+                .unwrap_or_default();
+            
+            // ListStreams only returns names; the summary carries shard count and retention
+            let mut result: Vec<Value> = Vec::new();
+            for name in &names {
+                let response = clients.http.json_request("kinesis", "DescribeStreamSummary", &json!({
+                    "StreamName": name
+                }).to_string()).await?;
+                let summary: Value = serde_json::from_str(&response)?;
+                let desc = summary.get("StreamDescriptionSummary").cloned().unwrap_or(Value::Null);
+                result.push(json!({
+                    "StreamName": name,
+                    "StreamARN": desc.get("StreamARN").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StreamStatus": desc.get("StreamStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StreamMode": desc.pointer("/StreamModeDetails/StreamMode").and_then(|v| v.as_str()).unwrap_or("PROVISIONED"),
+                    "OpenShardCount": desc.get("OpenShardCount").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "RetentionPeriodHours": desc.get("RetentionPeriodHours").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "ConsumerCount": desc.get("ConsumerCount").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "EncryptionType": desc.get("EncryptionType").and_then(|v| v.as_str()).unwrap_or("NONE"),
+                    "StreamCreationTimestamp": format_epoch_seconds_value(desc.get("StreamCreationTimestamp")),
+                }));
+            }
+            
+            let has_more = json.get("HasMoreStreams").and_then(|v| v.as_bool()).unwrap_or(false);
+            let next_token = json.get("NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "streams": result });
+            if has_more && let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
+        ("kinesis", "get_recent_records") => {
+            let stream_name = extract_param(params, "stream_name");
+            if stream_name.is_empty() {
+                return Ok(json!({ "records": [] }));
+            }
+            
+            let response = clients.http.json_request("kinesis", "ListShards", &json!({
+                "StreamName": stream_name,
+                "ShardFilter": { "Type": "AT_LATEST" }
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let shard_ids: Vec<String> = json.get("Shards")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|s| s.get("ShardId").and_then(|v| v.as_str()).map(String::from)).collect())
+                .unwrap_or_default();
+            
+            // Peek at the last 15 minutes of each open shard (bounded to keep this quick)
+            let since = chrono::Utc::now().timestamp() - 15 * 60;
+            let mut records: Vec<(f64, Value)> = Vec::new();
+            for shard_id in shard_ids.iter().take(10) {
+                let response = clients.http.json_request("kinesis", "GetShardIterator", &json!({
+                    "StreamName": stream_name,
+                    "ShardId": shard_id,
+                    "ShardIteratorType": "AT_TIMESTAMP",
+                    "Timestamp": since
+                }).to_string()).await?;
+                let json: Value = serde_json::from_str(&response)?;
+                let mut iterator = json.get("ShardIterator").and_then(|v| v.as_str()).map(String::from);
+                
+                // GetRecords can return empty batches while it catches up, so follow a few iterators
+                for _ in 0..5 {
+                    let Some(current) = iterator.take() else { break };
+                    let response = clients.http.json_request("kinesis", "GetRecords", &json!({
+                        "ShardIterator": current,
+                        "Limit": 100
+                    }).to_string()).await?;
+                    let json: Value = serde_json::from_str(&response)?;
+                    
+                    for record in json.get("Records").and_then(|v| v.as_array()).into_iter().flatten() {
+                        let (data, payload) = decode_kinesis_data(record.get("Data").and_then(|v| v.as_str()).unwrap_or(""));
+                        let arrival = record.get("ApproximateArrivalTimestamp").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                        records.push((arrival, json!({
+                            "SequenceNumber": record.get("SequenceNumber").and_then(|v| v.as_str()).unwrap_or("-"),
+                            "PartitionKey": record.get("PartitionKey").and_then(|v| v.as_str()).unwrap_or("-"),
+                            "ShardId": shard_id,
+                            "ApproximateArrivalTimestamp": format_epoch_seconds_value(record.get("ApproximateArrivalTimestamp")),
+                            "Data": data,
+                            "Payload": payload,
+                        })));
+                    }
+                    
+                    let caught_up = json.get("MillisBehindLatest").and_then(|v| v.as_i64()).unwrap_or(0) == 0;
+                    if caught_up {
+                        break;
+                    }
+                    iterator = json.get("NextShardIterator").and_then(|v| v.as_str()).map(String::from);
+                }
+            }
+            
+            // Newest first, capped so the table stays responsive
+            records.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+            let result: Vec<Value> = records.into_iter().take(500).map(|(_, record)| record).collect();
+            
+            Ok(json!({ "records": result }))
+        }
+
         // =====================================================================
         // SSM Operations (JSON protocol)
         // =====================================================================
+        ("ssm", "describe_instance_information") => {
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let request_body = if let Some(token) = page_token {
+                json!({ "NextToken": token, "MaxResults": 50 }).to_string()
+            } else {
+                json!({ "MaxResults": 50 }).to_string()
+            };
+            
+            let response = clients.http.json_request("ssm", "DescribeInstanceInformation", &request_body).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let instances = json.get("InstanceInformationList").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = instances.iter().map(|info| {
+                json!({
+                    "InstanceId": info.get("InstanceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ComputerName": info.get("ComputerName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PingStatus": info.get("PingStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "AgentVersion": info.get("AgentVersion").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "IsLatestVersion": info.get("IsLatestVersion").and_then(|v| v.as_bool()).unwrap_or(false),
+                    "PlatformType": info.get("PlatformType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PlatformName": info.get("PlatformName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PlatformVersion": info.get("PlatformVersion").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "IPAddress": info.get("IPAddress").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": info.get("ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastPingDateTime": format_epoch_seconds_value(info.get("LastPingDateTime")),
+                })
+            }).collect();
+            
+            let next_token = json.get("NextToken").and_then(|v| v.as_str());
+            let mut response = json!({ "instances": result });
+            if let Some(token) = next_token {
+                response["_next_token"] = json!(token);
+            }
+            
+            Ok(response)
+        }
+
         ("ssm", "describe_parameters") => {
             // Build request with pagination support
             let page_token = params.get("_page_token").and_then(|v| v.as_str());
@@ -1547,6 +3104,91 @@ pub async fn invoke_sdk(
             Ok(json!({ "hosted_zones": result }))
         }
 
+        ("route53", "list_resource_record_sets") => {
+            let zone_id = extract_param(params, "hosted_zone_id");
+            if zone_id.is_empty() {
+                return Ok(json!({ "record_sets": [] }));
+            }
+            let zone_id = zone_id.trim_start_matches("/hostedzone/").to_string();
+            
+            // Route53 pages by start position (name, type, identifier) rather than an opaque token
+            let mut path = format!("{}/rrset?maxitems=100", route53_zone_path(&zone_id));
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str())
+                && let Ok(Value::Array(start)) = serde_json::from_str::<Value>(token)
+            {
+                for (key, value) in ["name", "type", "identifier"].iter().zip(&start) {
+                    if let Some(value) = value.as_str().filter(|v| !v.is_empty()) {
+                        path.push_str(&format!("&{}={}", key, urlencoding::encode(value)));
+                    }
+                }
+            }
+            let xml = clients.http.rest_xml_request("route53", "GET", &path, None).await?;
+            let json = xml_to_json(&xml)?;
+            let response_data = json.pointer("/ListResourceRecordSetsResponse").cloned().unwrap_or(Value::Null);
+            
+            let records = match response_data.pointer("/ResourceRecordSets/ResourceRecordSet") {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            let result: Vec<Value> = records.iter().map(|record| {
+                let name = record.get("Name").and_then(|v| v.as_str()).unwrap_or("-");
+                let record_type = record.get("Type").and_then(|v| v.as_str()).unwrap_or("-");
+                let set_identifier = record.get("SetIdentifier").and_then(|v| v.as_str()).unwrap_or("");
+                let ttl = record.get("TTL").and_then(|v| v.as_str()).unwrap_or("-");
+                let values: Vec<String> = match record.pointer("/ResourceRecords/ResourceRecord") {
+                    Some(Value::Array(arr)) => arr.iter().filter_map(|r| r.get("Value").and_then(|v| v.as_str()).map(String::from)).collect(),
+                    Some(obj @ Value::Object(_)) => obj.get("Value").and_then(|v| v.as_str()).map(|v| vec![v.to_string()]).unwrap_or_default(),
+                    _ => vec![],
+                };
+                let alias = record.pointer("/AliasTarget/DNSName").and_then(|v| v.as_str());
+                let routing = if record.get("Weight").is_some() {
+                    "Weighted"
+                } else if record.get("Region").is_some() {
+                    "Latency"
+                } else if record.get("Failover").is_some() {
+                    "Failover"
+                } else if record.get("GeoLocation").is_some() {
+                    "Geolocation"
+                } else if record.get("MultiValueAnswer").is_some() {
+                    "Multivalue"
+                } else {
+                    "Simple"
+                };
+                json!({
+                    "Name": name,
+                    "Type": record_type,
+                    "TTL": ttl,
+                    "Values": match alias {
+                        Some(target) => format!("ALIAS {}", target),
+                        None => values.join(", "),
+                    },
+                    "Routing": routing,
+                    "SetIdentifier": if set_identifier.is_empty() { "-" } else { set_identifier },
+                    "HostedZoneId": zone_id,
+                    // Editable form for simple records; alias/routed records are delete-only
+                    "RecordSpec": if alias.is_none() && routing == "Simple" {
+                        format!("{} {} {} {}", name, record_type, ttl, values.join(","))
+                    } else {
+                        "-".to_string()
+                    },
+                    "RecordRef": json!([zone_id, name, record_type, set_identifier]).to_string(),
+                    "AliasTarget": record.get("AliasTarget").cloned().unwrap_or(Value::Null),
+                })
+            }).collect();
+            
+            let mut response = json!({ "record_sets": result });
+            if response_data.get("IsTruncated").and_then(|v| v.as_str()) == Some("true") {
+                let next = json!([
+                    response_data.get("NextRecordName").and_then(|v| v.as_str()).unwrap_or(""),
+                    response_data.get("NextRecordType").and_then(|v| v.as_str()).unwrap_or(""),
+                    response_data.get("NextRecordIdentifier").and_then(|v| v.as_str()).unwrap_or(""),
+                ]);
+                response["_next_token"] = json!(next.to_string());
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // ElastiCache Operations (Query protocol)
         // =====================================================================
@@ -1611,7 +3253,64 @@ pub async fn invoke_sdk(
             Ok(json!({ "repositories": result }))
         }
 
-        // =====================================================================
+        ("ecr", "describe_images") => {
+            let repository = extract_param(params, "repository");
+            if repository.is_empty() {
+                return Ok(json!({ "images": [] }));
+            }
+            
+            let mut request = json!({
+                "repositoryName": repository,
+                "maxResults": 100
+            });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["nextToken"] = json!(token);
+            }
+            
+            let response = clients.http.json_request("ecr", "DescribeImages", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let images = json.get("imageDetails").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let mut result: Vec<Value> = images.iter().map(|img| {
+                let registry_id = img.get("registryId").and_then(|v| v.as_str()).unwrap_or("-");
+                let repo_name = img.get("repositoryName").and_then(|v| v.as_str()).unwrap_or("-");
+                let digest = img.get("imageDigest").and_then(|v| v.as_str()).unwrap_or("-");
+                let tags: Vec<&str> = img.get("imageTags")
+                    .and_then(|v| v.as_array())
+                    .map(|a| a.iter().filter_map(|t| t.as_str()).collect())
+                    .unwrap_or_default();
+                let size = img.get("imageSizeInBytes").and_then(|v| v.as_u64()).unwrap_or(0);
+                let pushed_at = img.get("imagePushedAt").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                
+                json!({
+                    "imageRef": format!("{}.dkr.ecr.{}.amazonaws.com/{}@{}", registry_id, clients.region, repo_name, digest),
+                    "imageDigest": digest,
+                    "imageTags": if tags.is_empty() { "<untagged>".to_string() } else { tags.join(", ") },
+                    "imageSize": format_bytes(size),
+                    "imagePushedAt": format_epoch_seconds_value(img.get("imagePushedAt")),
+                    "pushedAtEpoch": pushed_at,
+                    "scanStatus": img.pointer("/imageScanStatus/status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "lastPulledAt": format_epoch_seconds_value(img.get("lastRecordedPullTime")),
+                    "repositoryName": repo_name,
+                    "artifactMediaType": img.get("artifactMediaType").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            // Newest images first
+            result.sort_by(|a, b| {
+                let a_time = a.get("pushedAtEpoch").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                let b_time = b.get("pushedAtEpoch").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                b_time.total_cmp(&a_time)
+            });
+            
+            let next_token = json.get("nextToken").and_then(|v| v.as_str()).map(|s| s.to_string());
+            Ok(json!({
+                "images": result,
+                "_next_token": next_token
+            }))
+        }
+
+        // =====================================================================
         // KMS Operations (JSON protocol)
         // =====================================================================
         ("kms", "list_keys_with_details") => {
@@ -1621,6 +3320,21 @@ pub async fn invoke_sdk(
             let keys_list = json.get("Keys").and_then(|v| v.as_array()).cloned().unwrap_or_default();
             let mut keys: Vec<Value> = Vec::new();
             
+            // First alias of each key, for display
+            let mut aliases: HashMap<String, String> = HashMap::new();
+            if let Ok(alias_response) = clients.http.json_request("kms", "ListAliases", "{}").await
+                && let Ok(alias_json) = serde_json::from_str::<Value>(&alias_response)
+            {
+                for alias in alias_json.get("Aliases").and_then(|v| v.as_array()).into_iter().flatten() {
+                    if let (Some(key_id), Some(name)) = (
+                        alias.get("TargetKeyId").and_then(|v| v.as_str()),
+                        alias.get("AliasName").and_then(|v| v.as_str()),
+                    ) {
+                        aliases.entry(key_id.to_string()).or_insert_with(|| name.to_string());
+                    }
+                }
+            }
+            
             for key in keys_list {
                 if let Some(key_id) = key.get("KeyId").and_then(|v| v.as_str()) {
                     if let Ok(desc_response) = clients.http.json_request("kms", "DescribeKey", &json!({
@@ -1628,12 +3342,30 @@ pub async fn invoke_sdk(
                     }).to_string()).await {
                         if let Ok(desc_json) = serde_json::from_str::<Value>(&desc_response) {
                             if let Some(metadata) = desc_json.get("KeyMetadata") {
+                                let key_spec = metadata.get("KeySpec").and_then(|v| v.as_str()).unwrap_or("-");
+                                // Automatic rotation only applies to symmetric encryption keys
+                                let rotation = if key_spec == "SYMMETRIC_DEFAULT" {
+                                    clients.http.json_request("kms", "GetKeyRotationStatus", &json!({
+                                        "KeyId": key_id
+                                    }).to_string()).await.ok()
+                                        .and_then(|r| serde_json::from_str::<Value>(&r).ok())
+                                        .and_then(|r| r.get("KeyRotationEnabled").and_then(|v| v.as_bool()))
+                                        .map(|enabled| if enabled { "Yes" } else { "No" })
+                                        .unwrap_or("-")
+                                } else {
+                                    "-"
+                                };
                                 keys.push(json!({
                                     "KeyId": metadata.get("KeyId").and_then(|v| v.as_str()).unwrap_or("-"),
                                     "KeyArn": metadata.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                                    "Alias": aliases.get(key_id).map(String::as_str).unwrap_or("-"),
+                                    "Description": metadata.get("Description").and_then(|v| v.as_str()).filter(|d| !d.is_empty()).unwrap_or("-"),
+                                    "KeyManager": metadata.get("KeyManager").and_then(|v| v.as_str()).unwrap_or("-"),
                                     "KeyState": metadata.get("KeyState").and_then(|v| v.as_str()).unwrap_or("-"),
                                     "KeyUsage": metadata.get("KeyUsage").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "KeySpec": metadata.get("KeySpec").and_then(|v| v.as_str()).unwrap_or("-"),
+                                    "KeySpec": key_spec,
+                                    "RotationEnabled": rotation,
+                                    "DeletionDate": format_epoch_seconds_value(metadata.get("DeletionDate")),
                                 }));
                             }
                         }
@@ -1644,6 +3376,38 @@ pub async fn invoke_sdk(
             Ok(json!({ "keys": keys }))
         }
 
+        ("kms", "list_aliases") => {
+            let key_id = extract_param(params, "key_id");
+            let mut request = json!({});
+            if !key_id.is_empty() {
+                request["KeyId"] = json!(key_id);
+            }
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["Marker"] = json!(token);
+            }
+            let response = clients.http.json_request("kms", "ListAliases", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let aliases = json.get("Aliases").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = aliases.iter().map(|a| {
+                json!({
+                    "AliasName": a.get("AliasName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "AliasArn": a.get("AliasArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "TargetKeyId": a.get("TargetKeyId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreationDate": format_epoch_seconds_value(a.get("CreationDate")),
+                    "LastUpdatedDate": format_epoch_seconds_value(a.get("LastUpdatedDate")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "aliases": result });
+            if json.get("Truncated").and_then(|v| v.as_bool()) == Some(true)
+                && let Some(marker) = json.get("NextMarker").and_then(|v| v.as_str())
+            {
+                response["_next_token"] = json!(marker);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // CloudFront Operations (REST-XML, global)
         // =====================================================================
@@ -1670,6 +3434,42 @@ pub async fn invoke_sdk(
             Ok(json!({ "distributions": result }))
         }
 
+        ("cloudfront", "list_invalidations") => {
+            let distribution_id = extract_param(params, "distribution_id");
+            if distribution_id.is_empty() {
+                return Ok(json!({ "invalidations": [] }));
+            }
+            let mut path = format!("/2020-05-31/distribution/{}/invalidation?MaxItems=50", distribution_id);
+            if let Some(marker) = params.get("_page_token").and_then(|v| v.as_str()) {
+                path.push_str(&format!("&Marker={}", urlencoding::encode(marker)));
+            }
+            let xml = clients.http.rest_xml_request("cloudfront", "GET", &path, None).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let items = match json.pointer("/InvalidationList/Items/InvalidationSummary") {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            let result: Vec<Value> = items.iter().map(|inv| {
+                let id = inv.pointer("/Id").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "Id": id,
+                    "InvalidationRef": format!("{}|{}", distribution_id, id),
+                    "Status": inv.pointer("/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreateTime": inv.pointer("/CreateTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "invalidations": result });
+            if json.pointer("/InvalidationList/IsTruncated").and_then(|v| v.as_str()) == Some("true")
+                && let Some(marker) = json.pointer("/InvalidationList/NextMarker").and_then(|v| v.as_str())
+            {
+                response["_next_token"] = json!(marker);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // ACM Operations (JSON protocol)
         // =====================================================================
@@ -1685,12 +3485,45 @@ pub async fn invoke_sdk(
                     "Status": cert.get("Status").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Type": cert.get("Type").and_then(|v| v.as_str()).unwrap_or("-"),
                     "InUse": if cert.get("InUse").and_then(|v| v.as_bool()).unwrap_or(false) { "Yes" } else { "No" },
+                    "NotAfter": format_epoch_seconds_value(cert.get("NotAfter")),
+                    "RenewalEligibility": cert.get("RenewalEligibility").and_then(|v| v.as_str()).unwrap_or("-"),
                 })
             }).collect();
             
             Ok(json!({ "certificates": result }))
         }
 
+        ("acm", "list_validation_records") => {
+            let certificate_arn = extract_param(params, "certificate_arn");
+            if certificate_arn.is_empty() {
+                return Ok(json!({ "validation_records": [] }));
+            }
+            let response = clients.http.json_request("acm", "DescribeCertificate", &json!({
+                "CertificateArn": certificate_arn
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let options = json.pointer("/Certificate/DomainValidationOptions").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = options.iter().map(|opt| {
+                let name = opt.pointer("/ResourceRecord/Name").and_then(|v| v.as_str()).unwrap_or("-");
+                let record_type = opt.pointer("/ResourceRecord/Type").and_then(|v| v.as_str()).unwrap_or("-");
+                let value = opt.pointer("/ResourceRecord/Value").and_then(|v| v.as_str()).unwrap_or("-");
+                let has_record = opt.get("ResourceRecord").is_some();
+                json!({
+                    "DomainName": opt.get("DomainName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ValidationMethod": opt.get("ValidationMethod").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ValidationStatus": opt.get("ValidationStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "RecordName": name,
+                    "RecordType": record_type,
+                    "RecordValue": value,
+                    "RecordCopy": if has_record { format!("{} {} {}", name, record_type, value) } else { "-".to_string() },
+                    "ValidationRef": if has_record { json!([name, record_type, value]).to_string() } else { "-".to_string() },
+                })
+            }).collect();
+            
+            Ok(json!({ "validation_records": result }))
+        }
+
         // =====================================================================
         // EventBridge Operations (JSON protocol)
         // =====================================================================
@@ -1747,6 +3580,80 @@ pub async fn invoke_sdk(
             Ok(json!({ "pipelines": result }))
         }
 
+        ("codepipeline", "get_pipeline_state") => {
+            let pipeline_name = extract_param(params, "pipeline_name");
+            if pipeline_name.is_empty() {
+                return Ok(json!({ "actions": [] }));
+            }
+            let response = clients.http.json_request("codepipeline", "GetPipelineState", &json!({
+                "name": pipeline_name
+            }).to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let stages = json.get("stageStates").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let mut result = Vec::new();
+            for stage in &stages {
+                let stage_name = stage.get("stageName").and_then(|v| v.as_str()).unwrap_or("-");
+                let stage_status = stage.pointer("/latestExecution/status").and_then(|v| v.as_str()).unwrap_or("-");
+                let actions = stage.get("actionStates").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+                for action in &actions {
+                    let action_name = action.get("actionName").and_then(|v| v.as_str()).unwrap_or("-");
+                    let status = action.pointer("/latestExecution/status").and_then(|v| v.as_str()).unwrap_or("-");
+                    // Only manual approvals waiting for a decision carry a token
+                    let approval_ref = match action.pointer("/latestExecution/token").and_then(|v| v.as_str()) {
+                        Some(token) if status == "InProgress" => format!("{}|{}|{}|{}", pipeline_name, stage_name, action_name, token),
+                        _ => "-".to_string(),
+                    };
+                    result.push(json!({
+                        "ActionRef": format!("{}/{}", stage_name, action_name),
+                        "Stage": stage_name,
+                        "StageStatus": stage_status,
+                        "Action": action_name,
+                        "Status": status,
+                        "Summary": action.pointer("/latestExecution/summary").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "LastStatusChange": format_epoch_seconds_value(action.pointer("/latestExecution/lastStatusChange")),
+                        "Revision": action.pointer("/currentRevision/revisionId").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "ApprovalRef": approval_ref,
+                    }));
+                }
+            }
+            
+            Ok(json!({ "actions": result }))
+        }
+
+        ("codepipeline", "list_pipeline_executions") => {
+            let pipeline_name = extract_param(params, "pipeline_name");
+            if pipeline_name.is_empty() {
+                return Ok(json!({ "executions": [] }));
+            }
+            let mut request = json!({ "pipelineName": pipeline_name, "maxResults": 25 });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["nextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("codepipeline", "ListPipelineExecutions", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let executions = json.get("pipelineExecutionSummaries").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = executions.iter().map(|exec| {
+                let revision = exec.pointer("/sourceRevisions/0/revisionId").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "ExecutionId": exec.get("pipelineExecutionId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Status": exec.get("status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Trigger": exec.pointer("/trigger/triggerType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Revision": revision.chars().take(12).collect::<String>(),
+                    "RevisionSummary": exec.pointer("/sourceRevisions/0/revisionSummary").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StartTime": format_epoch_seconds_value(exec.get("startTime")),
+                    "LastUpdateTime": format_epoch_seconds_value(exec.get("lastUpdateTime")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "executions": result });
+            if let Some(token) = json.get("nextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // CodeBuild Operations (JSON protocol)
         // =====================================================================
@@ -1776,6 +3683,61 @@ pub async fn invoke_sdk(
             Ok(json!({ "projects": result }))
         }
 
+        ("codebuild", "list_builds_for_project") => {
+            let project_name = extract_param(params, "project_name");
+            if project_name.is_empty() {
+                return Ok(json!({ "builds": [] }));
+            }
+            let mut request = json!({ "projectName": project_name, "sortOrder": "DESCENDING" });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["nextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("codebuild", "ListBuildsForProject", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let ids = json.get("ids").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            
+            let mut builds = Vec::new();
+            if !ids.is_empty() {
+                let response = clients.http.json_request("codebuild", "BatchGetBuilds", &json!({
+                    "ids": ids
+                }).to_string()).await?;
+                let batch: Value = serde_json::from_str(&response)?;
+                builds = batch.get("builds").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            }
+            builds.sort_by_key(|b| std::cmp::Reverse(b.get("buildNumber").and_then(|v| v.as_i64()).unwrap_or(0)));
+            
+            let now = chrono::Utc::now().timestamp() as f64;
+            let result: Vec<Value> = builds.iter().map(|build| {
+                let start = build.get("startTime").and_then(|v| v.as_f64());
+                let end = build.get("endTime").and_then(|v| v.as_f64());
+                // Running builds count up to now
+                let duration = start
+                    .map(|s| format!("{}s", (end.unwrap_or(now) - s).max(0.0) as i64))
+                    .unwrap_or_else(|| "-".to_string());
+                json!({
+                    "id": build.get("id").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "buildNumber": build.get("buildNumber").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "buildStatus": build.get("buildStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "currentPhase": build.get("currentPhase").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "sourceVersion": build.get("resolvedSourceVersion")
+                        .or_else(|| build.get("sourceVersion"))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("-"),
+                    "initiator": build.get("initiator").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "startTime": format_epoch_seconds_value(build.get("startTime")),
+                    "duration": duration,
+                    "logGroupName": build.pointer("/logs/groupName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "logStreamName": build.pointer("/logs/streamName").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "builds": result });
+            if let Some(token) = json.get("nextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // Cognito Operations (JSON protocol)
         // =====================================================================
@@ -1798,6 +3760,78 @@ pub async fn invoke_sdk(
             Ok(json!({ "user_pools": result }))
         }
 
+        ("cognitoidentityprovider", "list_users") => {
+            let user_pool_id = extract_param(params, "user_pool_id");
+            if user_pool_id.is_empty() {
+                return Ok(json!({ "users": [] }));
+            }
+            let mut request = json!({ "UserPoolId": user_pool_id, "Limit": 60 });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["PaginationToken"] = json!(token);
+            }
+            let response = clients.http.json_request("cognito-idp", "ListUsers", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let users = json.get("Users").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = users.iter().map(|user| {
+                let username = user.get("Username").and_then(|v| v.as_str()).unwrap_or("-");
+                let attribute = |name: &str| -> String {
+                    user.get("Attributes")
+                        .and_then(|v| v.as_array())
+                        .and_then(|attrs| attrs.iter().find(|a| a.get("Name").and_then(|v| v.as_str()) == Some(name)))
+                        .and_then(|a| a.get("Value").and_then(|v| v.as_str()))
+                        .unwrap_or("-")
+                        .to_string()
+                };
+                json!({
+                    "Username": username,
+                    "UserRef": format!("{}|{}", user_pool_id, username),
+                    "Email": attribute("email"),
+                    "EmailVerified": attribute("email_verified"),
+                    "UserStatus": user.get("UserStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Enabled": user.get("Enabled").and_then(|v| v.as_bool()).unwrap_or(false),
+                    "UserCreateDate": format_epoch_seconds_value(user.get("UserCreateDate")),
+                    "UserLastModifiedDate": format_epoch_seconds_value(user.get("UserLastModifiedDate")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "users": result });
+            if let Some(token) = json.get("PaginationToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("cognitoidentityprovider", "list_user_pool_clients") => {
+            let user_pool_id = extract_param(params, "user_pool_id");
+            if user_pool_id.is_empty() {
+                return Ok(json!({ "clients": [] }));
+            }
+            let mut request = json!({ "UserPoolId": user_pool_id, "MaxResults": 60 });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("cognito-idp", "ListUserPoolClients", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let app_clients = json.get("UserPoolClients").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = app_clients.iter().map(|client| {
+                let client_id = client.get("ClientId").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "ClientId": client_id,
+                    "ClientRef": format!("{}|{}", user_pool_id, client_id),
+                    "ClientName": client.get("ClientName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "UserPoolId": user_pool_id,
+                })
+            }).collect();
+            
+            let mut response = json!({ "clients": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // CloudTrail Operations (JSON protocol)
         // =====================================================================
@@ -1866,134 +3900,931 @@ pub async fn invoke_sdk(
             Ok(json!({ "work_groups": result }))
         }
 
-        // =====================================================================
-        // ELBv2 Operations (Query protocol)
-        // =====================================================================
-        ("elbv2", "describe_load_balancers") => {
-            let xml = clients.http.query_request("elbv2", "DescribeLoadBalancers", &[]).await?;
-            let json = xml_to_json(&xml)?;
-            
-            let lbs_data = json.pointer("/DescribeLoadBalancersResponse/DescribeLoadBalancersResult/LoadBalancers/member");
-            let lb_list = match lbs_data {
-                Some(Value::Array(arr)) => arr.clone(),
-                Some(obj @ Value::Object(_)) => vec![obj.clone()],
-                _ => vec![],
-            };
+        ("athena", "list_databases") => {
+            let work_group = extract_param(params, "work_group");
+            let mut request = json!({ "CatalogName": "AwsDataCatalog", "MaxResults": 50 });
+            if !work_group.is_empty() {
+                request["WorkGroup"] = json!(work_group);
+            }
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("athena", "ListDatabases", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
             
-            let result: Vec<Value> = lb_list.iter().map(|lb| {
-                let state = lb.pointer("/State/Code").and_then(|v| v.as_str()).unwrap_or("-");
+            let databases = json.get("DatabaseList").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = databases.iter().map(|db| {
+                let name = db.get("Name").and_then(|v| v.as_str()).unwrap_or("-");
                 json!({
-                    "LoadBalancerArn": lb.pointer("/LoadBalancerArn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "LoadBalancerName": lb.pointer("/LoadBalancerName").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "DNSName": lb.pointer("/DNSName").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Type": lb.pointer("/Type").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Scheme": lb.pointer("/Scheme").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "State": state,
-                    "VpcId": lb.pointer("/VpcId").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "CreatedTime": lb.pointer("/CreatedTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Name": name,
+                    "Description": db.get("Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "WorkGroup": work_group,
+                    "QueryContext": format!("{}/{}", work_group, name),
                 })
             }).collect();
             
-            Ok(json!({ "load_balancers": result }))
+            let mut response = json!({ "databases": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
         }
 
-        ("elbv2", "describe_listeners") => {
-            let lb_arn = extract_param(params, "load_balancer_arn");
-            if lb_arn.is_empty() {
-                return Ok(json!({ "listeners": [] }));
+        ("athena", "list_query_executions") => {
+            let work_group = extract_param(params, "work_group");
+            let mut request = json!({ "MaxResults": 50 });
+            if !work_group.is_empty() {
+                request["WorkGroup"] = json!(work_group);
+            }
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
             }
+            let response = clients.http.json_request("athena", "ListQueryExecutions", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let ids = json.get("QueryExecutionIds").and_then(|v| v.as_array()).cloned().unwrap_or_default();
             
-            let xml = clients.http.query_request("elbv2", "DescribeListeners", &[
-                ("LoadBalancerArn", &lb_arn)
-            ]).await?;
-            let json = xml_to_json(&xml)?;
+            let mut executions = Vec::new();
+            if !ids.is_empty() {
+                let response = clients.http.json_request("athena", "BatchGetQueryExecution", &json!({
+                    "QueryExecutionIds": ids
+                }).to_string()).await?;
+                let batch: Value = serde_json::from_str(&response)?;
+                executions = batch.get("QueryExecutions").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            }
             
-            let listeners_data = json.pointer("/DescribeListenersResponse/DescribeListenersResult/Listeners/member");
-            let listener_list = match listeners_data {
-                Some(Value::Array(arr)) => arr.clone(),
-                Some(obj @ Value::Object(_)) => vec![obj.clone()],
-                _ => vec![],
-            };
+            // Batch results are unordered; show the newest queries first
+            executions.sort_by(|a, b| {
+                let a = a.pointer("/Status/SubmissionDateTime").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                let b = b.pointer("/Status/SubmissionDateTime").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                b.total_cmp(&a)
+            });
             
-            let result: Vec<Value> = listener_list.iter().map(|listener| {
-                // Get the default action type
-                let default_action = listener.pointer("/DefaultActions/member")
-                    .and_then(|v| match v {
-                        Value::Array(arr) => arr.first(),
-                        obj @ Value::Object(_) => Some(obj),
-                        _ => None,
-                    })
-                    .and_then(|a| a.pointer("/Type"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("-");
-                
+            let result: Vec<Value> = executions.iter().map(|q| {
+                let query = q.get("Query").and_then(|v| v.as_str()).unwrap_or("");
+                let database = q.pointer("/QueryExecutionContext/Database").and_then(|v| v.as_str()).unwrap_or("-");
+                let wg = q.get("WorkGroup").and_then(|v| v.as_str()).unwrap_or(&work_group);
                 json!({
-                    "ListenerArn": listener.pointer("/ListenerArn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "LoadBalancerArn": listener.pointer("/LoadBalancerArn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Port": listener.pointer("/Port").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Protocol": listener.pointer("/Protocol").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "SslPolicy": listener.pointer("/SslPolicy").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "DefaultActionType": default_action,
+                    "QueryExecutionId": q.get("QueryExecutionId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Query": query.split_whitespace().collect::<Vec<_>>().join(" "),
+                    "QueryString": query,
+                    "State": q.pointer("/Status/State").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StateChangeReason": q.pointer("/Status/StateChangeReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Database": database,
+                    "WorkGroup": wg,
+                    "Submitted": format_epoch_seconds_value(q.pointer("/Status/SubmissionDateTime")),
+                    "DataScanned": q.pointer("/Statistics/DataScannedInBytes").and_then(|v| v.as_u64()).map(format_bytes).unwrap_or_else(|| "-".to_string()),
+                    "Runtime": q.pointer("/Statistics/TotalExecutionTimeInMillis").and_then(|v| v.as_u64())
+                        .map(|ms| format!("{:.1}s", ms as f64 / 1000.0))
+                        .unwrap_or_else(|| "-".to_string()),
+                    "QueryContext": format!("{}/{}", wg, database),
                 })
             }).collect();
             
-            Ok(json!({ "listeners": result }))
+            let mut response = json!({ "query_executions": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
         }
 
-        ("elbv2", "describe_rules") => {
-            let listener_arn = extract_param(params, "listener_arn");
-            if listener_arn.is_empty() {
-                return Ok(json!({ "rules": [] }));
+        ("athena", "list_named_queries") => {
+            let work_group = extract_param(params, "work_group");
+            let mut request = json!({ "MaxResults": 50 });
+            if !work_group.is_empty() {
+                request["WorkGroup"] = json!(work_group);
             }
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("athena", "ListNamedQueries", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let ids = json.get("NamedQueryIds").and_then(|v| v.as_array()).cloned().unwrap_or_default();
             
-            let xml = clients.http.query_request("elbv2", "DescribeRules", &[
-                ("ListenerArn", &listener_arn)
-            ]).await?;
-            let json = xml_to_json(&xml)?;
-            
-            let rules_data = json.pointer("/DescribeRulesResponse/DescribeRulesResult/Rules/member");
-            let rule_list = match rules_data {
-                Some(Value::Array(arr)) => arr.clone(),
-                Some(obj @ Value::Object(_)) => vec![obj.clone()],
-                _ => vec![],
-            };
+            let mut queries = Vec::new();
+            if !ids.is_empty() {
+                let response = clients.http.json_request("athena", "BatchGetNamedQuery", &json!({
+                    "NamedQueryIds": ids
+                }).to_string()).await?;
+                let batch: Value = serde_json::from_str(&response)?;
+                queries = batch.get("NamedQueries").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            }
+            queries.sort_by_key(|q| q.get("Name").and_then(|v| v.as_str()).unwrap_or("").to_lowercase());
             
-            let result: Vec<Value> = rule_list.iter().map(|rule| {
-                // Get the first action type and target group
-                let action = rule.pointer("/Actions/member")
-                    .and_then(|v| match v {
-                        Value::Array(arr) => arr.first().cloned(),
-                        obj @ Value::Object(_) => Some(obj.clone()),
-                        _ => None,
-                    });
-                let action_type = action.as_ref()
-                    .and_then(|a| a.pointer("/Type"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("-");
-                let target_group_arn = action.as_ref()
-                    .and_then(|a| a.pointer("/TargetGroupArn"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("-");
-                
-                // Summarize conditions
-                let conditions = rule.pointer("/Conditions/member");
-                let conditions_summary = match conditions {
-                    Some(Value::Array(arr)) => {
-                        arr.iter()
-                            .filter_map(|c| c.pointer("/Field").and_then(|v| v.as_str()))
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    }
-                    Some(obj @ Value::Object(_)) => {
-                        obj.pointer("/Field").and_then(|v| v.as_str()).unwrap_or("-").to_string()
-                    }
-                    _ => "-".to_string(),
-                };
-                
+            let result: Vec<Value> = queries.iter().map(|q| {
+                let query = q.get("QueryString").and_then(|v| v.as_str()).unwrap_or("");
+                let database = q.get("Database").and_then(|v| v.as_str()).unwrap_or("-");
+                let wg = q.get("WorkGroup").and_then(|v| v.as_str()).unwrap_or(&work_group);
                 json!({
-                    "RuleArn": rule.pointer("/RuleArn").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "Priority": rule.pointer("/Priority").and_then(|v| v.as_str()).unwrap_or("-"),
-                    "IsDefault": if rule.pointer("/IsDefault").and_then(|v| v.as_str()) == Some("true") { "Yes" } else { "No" },
+                    "NamedQueryId": q.get("NamedQueryId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Name": q.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Description": q.get("Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Database": database,
+                    "WorkGroup": wg,
+                    "Query": query.split_whitespace().collect::<Vec<_>>().join(" "),
+                    "QueryString": query,
+                    "QueryContext": format!("{}/{}", wg, database),
+                })
+            }).collect();
+            
+            let mut response = json!({ "named_queries": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("athena", "get_query_results") => {
+            let query_execution_id = extract_param(params, "query_execution_id");
+            if query_execution_id.is_empty() {
+                return Ok(json!({ "rows": [] }));
+            }
+            
+            // Give short queries a moment to finish; auto-refresh keeps polling after that
+            let mut execution = Value::Null;
+            for attempt in 0..10 {
+                if attempt > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                }
+                let response = clients.http.json_request("athena", "GetQueryExecution", &json!({
+                    "QueryExecutionId": query_execution_id
+                }).to_string()).await?;
+                let json: Value = serde_json::from_str(&response)?;
+                execution = json.get("QueryExecution").cloned().unwrap_or(Value::Null);
+                let state = execution.pointer("/Status/State").and_then(|v| v.as_str()).unwrap_or("");
+                if state != "QUEUED" && state != "RUNNING" {
+                    break;
+                }
+            }
+            
+            let state = execution.pointer("/Status/State").and_then(|v| v.as_str()).unwrap_or("-");
+            if state != "SUCCEEDED" {
+                return Ok(json!({
+                    "_columns": ["State", "StateChangeReason"],
+                    "rows": [{
+                        "_row": "0",
+                        "State": state,
+                        "StateChangeReason": execution.pointer("/Status/StateChangeReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                    }]
+                }));
+            }
+            
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let mut request = json!({ "QueryExecutionId": query_execution_id, "MaxResults": 100 });
+            if let Some(token) = page_token {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("athena", "GetQueryResults", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let columns: Vec<String> = json.pointer("/ResultSet/ResultSetMetadata/ColumnInfo")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|c| c.get("Name").and_then(|v| v.as_str()).map(String::from)).collect())
+                .unwrap_or_default();
+            let rows: Vec<Vec<Value>> = json.pointer("/ResultSet/Rows")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().map(|r| {
+                    r.get("Data").and_then(|v| v.as_array()).cloned().unwrap_or_default()
+                        .iter()
+                        .map(|d| d.get("VarCharValue").cloned().unwrap_or(Value::Null))
+                        .collect()
+                }).collect())
+                .unwrap_or_default();
+            
+            // SELECT results repeat the column names as the first row of the first page
+            let skip_header = page_token.is_none() && rows.first().is_some_and(|first| {
+                first.len() == columns.len()
+                    && first.iter().zip(&columns).all(|(v, c)| v.as_str() == Some(c.as_str()))
+            });
+            
+            let result: Vec<Value> = rows.iter().skip(usize::from(skip_header)).enumerate().map(|(i, row)| {
+                let mut item = serde_json::Map::new();
+                item.insert("_row".to_string(), json!(i.to_string()));
+                for (column, value) in columns.iter().zip(row) {
+                    item.insert(column.clone(), value.clone());
+                }
+                Value::Object(item)
+            }).collect();
+            
+            let mut response = json!({ "_columns": columns, "rows": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Security Hub Operations (REST-JSON protocol)
+        // =====================================================================
+        ("securityhub", "get_findings") => {
+            let mut request = json!({
+                "Filters": {
+                    "RecordState": [{ "Value": "ACTIVE", "Comparison": "EQUALS" }]
+                },
+                "SortCriteria": [{ "Field": "SeverityNormalized", "SortOrder": "desc" }],
+                "MaxResults": 100
+            });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.rest_json_request("securityhub", "POST", "/findings", Some(&request.to_string())).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let findings = json.get("Findings").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = findings.iter().map(|f| {
+                let id = f.get("Id").and_then(|v| v.as_str()).unwrap_or("-");
+                let product_arn = f.get("ProductArn").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "Id": id,
+                    "FindingRef": json!([id, product_arn]).to_string(),
+                    "Title": f.get("Title").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Severity": f.pointer("/Severity/Label").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ComplianceStatus": f.pointer("/Compliance/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "WorkflowStatus": f.pointer("/Workflow/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": f.pointer("/Resources/0/Type").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceId": f.pointer("/Resources/0/Id").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Product": f.get("ProductName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "UpdatedAt": f.get("UpdatedAt").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Finding": f,
+                })
+            }).collect();
+            
+            let mut response = json!({ "findings": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Service Quotas Operations (JSON protocol)
+        // =====================================================================
+        ("servicequotas", "list_services") => {
+            let mut request = json!({ "MaxResults": 100 });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("servicequotas", "ListServices", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let services = json.get("Services").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = services.iter().map(|s| {
+                json!({
+                    "ServiceCode": s.get("ServiceCode").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ServiceName": s.get("ServiceName").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "services": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("servicequotas", "list_service_quotas") => {
+            let service_code = extract_param(params, "service_code");
+            if service_code.is_empty() {
+                return Ok(json!({ "quotas": [] }));
+            }
+            let mut request = json!({ "ServiceCode": service_code, "MaxResults": 100 });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("servicequotas", "ListServiceQuotas", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let quotas = json.get("Quotas").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            
+            // Default values come from a separate API; a failure only hides that column
+            let defaults = service_quota_defaults(clients, &service_code).await.unwrap_or_default();
+            
+            // Current usage for quotas that publish a CloudWatch usage metric
+            let metric_queries: Vec<MetricQuery> = quotas.iter().enumerate().filter_map(|(i, q)| {
+                let metric = q.get("UsageMetric")?;
+                Some(MetricQuery {
+                    id: format!("q{}", i),
+                    namespace: metric.get("MetricNamespace")?.as_str()?.to_string(),
+                    metric_name: metric.get("MetricName")?.as_str()?.to_string(),
+                    dimensions: metric.get("MetricDimensions").and_then(|d| d.as_object())
+                        .map(|d| d.iter().map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string())).collect())
+                        .unwrap_or_default(),
+                    stat: metric.get("MetricStatisticRecommendation").and_then(|v| v.as_str()).unwrap_or("Maximum").to_string(),
+                })
+            }).collect();
+            let usage = if metric_queries.is_empty() {
+                HashMap::new()
+            } else {
+                get_metric_data(clients, &metric_queries, 3600).await.unwrap_or_else(|e| {
+                    tracing::warn!("Failed to read quota usage metrics: {}", e);
+                    HashMap::new()
+                })
+            };
+            
+            let result: Vec<Value> = quotas.iter().enumerate().map(|(i, q)| {
+                let quota_code = q.get("QuotaCode").and_then(|v| v.as_str()).unwrap_or("-");
+                let applied = q.get("Value").and_then(|v| v.as_f64());
+                let default = defaults.get(quota_code).copied();
+                let used = usage.get(&format!("q{}", i)).copied();
+                let utilization = match (used, applied) {
+                    (Some(used), Some(applied)) if applied > 0.0 => format!("{:.0}%", used / applied * 100.0),
+                    _ => "-".to_string(),
+                };
+                json!({
+                    "QuotaName": q.get("QuotaName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "QuotaCode": quota_code,
+                    "QuotaRef": format!("{}|{}", service_code, quota_code),
+                    "Value": applied.map(format_quota_value).unwrap_or_else(|| "-".to_string()),
+                    "DefaultValue": default.map(format_quota_value).unwrap_or_else(|| "-".to_string()),
+                    "Usage": used.map(format_quota_value).unwrap_or_else(|| "-".to_string()),
+                    "Utilization": utilization,
+                    "Adjustable": q.get("Adjustable").and_then(|v| v.as_bool()).unwrap_or(false),
+                    "Unit": q.get("Unit").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Quota": q,
+                })
+            }).collect();
+            
+            let mut response = json!({ "quotas": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Health Operations (JSON protocol)
+        // =====================================================================
+        ("health", "describe_events") => {
+            let mut request = json!({
+                "filter": { "eventStatusCodes": ["open", "upcoming"] },
+                "maxResults": 100
+            });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["nextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("health", "DescribeEvents", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let events = json.get("events").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = events.iter().map(|e| {
+                json!({
+                    "Arn": e.get("arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Service": e.get("service").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "EventTypeCode": e.get("eventTypeCode").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Category": e.get("eventTypeCategory").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Region": e.get("region").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Status": e.get("statusCode").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Scope": e.get("eventScopeCode").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StartTime": format_epoch_seconds_value(e.get("startTime")),
+                    "LastUpdatedTime": format_epoch_seconds_value(e.get("lastUpdatedTime")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "events": result });
+            if let Some(token) = json.get("nextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Organizations Operations (JSON protocol)
+        // =====================================================================
+        ("organizations", "list_accounts") => {
+            let mut request = json!({});
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("organizations", "ListAccounts", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let accounts = json.get("Accounts").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = accounts.iter().map(|a| {
+                json!({
+                    "Id": a.get("Id").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Name": a.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Email": a.get("Email").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Status": a.get("Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "JoinedMethod": a.get("JoinedMethod").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "JoinedTimestamp": format_epoch_seconds_value(a.get("JoinedTimestamp")),
+                    "Arn": a.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "accounts": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Resource Groups Tagging API Operations (JSON protocol)
+        // =====================================================================
+        ("tagging", "get_resources") => {
+            // "Key=Value" (or bare "Key") filters from :find
+            let tag_filters: Vec<Value> = params.get(super::search::TAG_FILTER_PARAM)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+                .map(|tag| match tag.split_once('=') {
+                    Some((key, value)) => json!({ "Key": key, "Values": [value] }),
+                    None => json!({ "Key": tag }),
+                })
+                .collect();
+            let mut request = json!({
+                "TagFilters": tag_filters,
+                "ResourcesPerPage": 100
+            });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["PaginationToken"] = json!(token);
+            }
+            let response = clients.http.json_request("tagging", "GetResources", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let mappings = json.get("ResourceTagMappingList").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = mappings.iter().map(|m| {
+                let arn = m.get("ResourceARN").and_then(|v| v.as_str()).unwrap_or("-");
+                let parsed = super::search::parse_arn(arn);
+                let service = parsed.as_ref().map(|a| a.service.as_str()).unwrap_or("-");
+                let (kind, id) = parsed.as_ref().map(|a| a.type_and_id()).unwrap_or(("", arn));
+                let tags: Vec<(String, String)> = m.get("Tags").and_then(|v| v.as_array()).into_iter().flatten()
+                    .filter_map(|t| Some((
+                        t.get("Key").and_then(|v| v.as_str())?.to_string(),
+                        t.get("Value").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    )))
+                    .collect();
+                let name = tags.iter().find(|(k, _)| k == "Name").map(|(_, v)| v.as_str()).unwrap_or(id);
+                json!({
+                    "ResourceARN": arn,
+                    "Name": name,
+                    "Service": service,
+                    "Type": if kind.is_empty() { "-" } else { kind },
+                    "ResourceId": id,
+                    "Tags": tags.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(", "),
+                })
+            }).collect();
+            
+            let mut response = json!({ "resources": result });
+            if let Some(token) = json.get("PaginationToken").and_then(|v| v.as_str()).filter(|t| !t.is_empty()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Resource Explorer Operations (REST-JSON protocol)
+        // =====================================================================
+        ("resource-explorer-2", "search") => {
+            let query = extract_param(params, super::search::SEARCH_QUERY_PARAM);
+            let mut request = json!({
+                "QueryString": query,
+                "MaxResults": 100
+            });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.rest_json_request("resource-explorer-2", "POST", "/Search", Some(&request.to_string())).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let resources = json.get("Resources").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = resources.iter().map(|r| {
+                let arn = r.get("Arn").and_then(|v| v.as_str()).unwrap_or("-");
+                let parsed = super::search::parse_arn(arn);
+                let id = parsed.as_ref().map(|a| a.type_and_id().1).unwrap_or(arn);
+                // Tags come back as a "tags" property
+                let name = r.get("Properties").and_then(|v| v.as_array()).into_iter().flatten()
+                    .filter(|p| p.get("Name").and_then(|v| v.as_str()) == Some("tags"))
+                    .filter_map(|p| p.get("Data").and_then(|v| v.as_array()))
+                    .flatten()
+                    .find(|t| t.get("Key").and_then(|v| v.as_str()) == Some("Name"))
+                    .and_then(|t| t.get("Value").and_then(|v| v.as_str()))
+                    .unwrap_or(id);
+                json!({
+                    "Arn": arn,
+                    "Name": name,
+                    "ResourceType": r.get("ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Region": r.get("Region").and_then(|v| v.as_str()).filter(|s| !s.is_empty()).unwrap_or("global"),
+                    "OwningAccountId": r.get("OwningAccountId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastReportedAt": r.get("LastReportedAt").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "resources": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // Glue Operations (JSON protocol)
+        // =====================================================================
+        ("glue", "get_databases") => {
+            let mut request = json!({});
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("glue", "GetDatabases", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let databases = json.get("DatabaseList").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = databases.iter().map(|db| {
+                json!({
+                    "Name": db.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Description": db.get("Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LocationUri": db.get("LocationUri").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreateTime": format_epoch_seconds_value(db.get("CreateTime")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "databases": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("glue", "get_tables") => {
+            let database_name = extract_param(params, "database_name");
+            if database_name.is_empty() {
+                return Ok(json!({ "tables": [] }));
+            }
+            let mut request = json!({ "DatabaseName": database_name });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("glue", "GetTables", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let tables = json.get("TableList").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = tables.iter().map(|t| {
+                let name = t.get("Name").and_then(|v| v.as_str()).unwrap_or("-");
+                let column_count = t.pointer("/StorageDescriptor/Columns").and_then(|v| v.as_array()).map(|a| a.len()).unwrap_or(0);
+                json!({
+                    "Name": name,
+                    "TableRef": format!("{}/{}", database_name, name),
+                    "TableType": t.get("TableType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Classification": t.pointer("/Parameters/classification").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ColumnCount": column_count,
+                    "Location": t.pointer("/StorageDescriptor/Location").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "UpdateTime": format_epoch_seconds_value(t.get("UpdateTime")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "tables": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("glue", "get_jobs") => {
+            let mut request = json!({});
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("glue", "GetJobs", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let jobs = json.get("Jobs").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = jobs.iter().map(|job| {
+                json!({
+                    "Name": job.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Type": job.pointer("/Command/Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "GlueVersion": job.get("GlueVersion").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "WorkerType": job.get("WorkerType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "NumberOfWorkers": job.get("NumberOfWorkers").and_then(|v| v.as_i64()).map(|n| n.to_string()).unwrap_or_else(|| "-".to_string()),
+                    "LastModifiedOn": format_epoch_seconds_value(job.get("LastModifiedOn")),
+                })
+            }).collect();
+            
+            let mut response = json!({ "jobs": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("glue", "get_job_runs") => {
+            let job_name = extract_param(params, "job_name");
+            if job_name.is_empty() {
+                return Ok(json!({ "job_runs": [] }));
+            }
+            let mut request = json!({ "JobName": job_name });
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("glue", "GetJobRuns", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let runs = json.get("JobRuns").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = runs.iter().map(|run| {
+                json!({
+                    "Id": run.get("Id").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "JobRunState": run.get("JobRunState").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StartedOn": format_epoch_seconds_value(run.get("StartedOn")),
+                    "ExecutionTime": run.get("ExecutionTime").and_then(|v| v.as_i64())
+                        .map(|secs| format!("{}s", secs))
+                        .unwrap_or_else(|| "-".to_string()),
+                    "Attempt": run.get("Attempt").and_then(|v| v.as_i64()).unwrap_or(0),
+                    "TriggerName": run.get("TriggerName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ErrorMessage": run.get("ErrorMessage").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "job_runs": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("glue", "get_crawlers") => {
+            let mut request = json!({});
+            if let Some(token) = params.get("_page_token").and_then(|v| v.as_str()) {
+                request["NextToken"] = json!(token);
+            }
+            let response = clients.http.json_request("glue", "GetCrawlers", &request.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            
+            let crawlers = json.get("Crawlers").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let result: Vec<Value> = crawlers.iter().map(|c| {
+                json!({
+                    "Name": c.get("Name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": c.get("State").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastCrawlStatus": c.pointer("/LastCrawl/Status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LastCrawlStart": format_epoch_seconds_value(c.pointer("/LastCrawl/StartTime")),
+                    "DatabaseName": c.get("DatabaseName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Schedule": c.pointer("/Schedule/ScheduleExpression").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "crawlers": result });
+            if let Some(token) = json.get("NextToken").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        // =====================================================================
+        // ELBv2 Operations (Query protocol)
+        // =====================================================================
+        ("elbv2", "describe_load_balancers") => {
+            let xml = clients.http.query_request("elbv2", "DescribeLoadBalancers", &[]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let lbs_data = json.pointer("/DescribeLoadBalancersResponse/DescribeLoadBalancersResult/LoadBalancers/member");
+            let lb_list = match lbs_data {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = lb_list.iter().map(|lb| {
+                let state = lb.pointer("/State/Code").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "LoadBalancerArn": lb.pointer("/LoadBalancerArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LoadBalancerName": lb.pointer("/LoadBalancerName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "DNSName": lb.pointer("/DNSName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Type": lb.pointer("/Type").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Scheme": lb.pointer("/Scheme").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": state,
+                    "VpcId": lb.pointer("/VpcId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreatedTime": lb.pointer("/CreatedTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            Ok(json!({ "load_balancers": result }))
+        }
+
+        ("elbv2", "describe_listeners") => {
+            let lb_arn = extract_param(params, "load_balancer_arn");
+            if lb_arn.is_empty() {
+                return Ok(json!({ "listeners": [] }));
+            }
+            
+            let xml = clients.http.query_request("elbv2", "DescribeListeners", &[
+                ("LoadBalancerArn", &lb_arn)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let listeners_data = json.pointer("/DescribeListenersResponse/DescribeListenersResult/Listeners/member");
+            let listener_list = match listeners_data {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = listener_list.iter().map(|listener| {
+                // Get the default action type
+                let default_action = listener.pointer("/DefaultActions/member")
+                    .and_then(|v| match v {
+                        Value::Array(arr) => arr.first(),
+                        obj @ Value::Object(_) => Some(obj),
+                        _ => None,
+                    })
+                    .and_then(|a| a.pointer("/Type"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("-");
+                
+                json!({
+                    "ListenerArn": listener.pointer("/ListenerArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LoadBalancerArn": listener.pointer("/LoadBalancerArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Port": listener.pointer("/Port").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Protocol": listener.pointer("/Protocol").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "SslPolicy": listener.pointer("/SslPolicy").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "DefaultActionType": default_action,
+                })
+            }).collect();
+            
+            Ok(json!({ "listeners": result }))
+        }
+
+        ("elb", "describe_load_balancers") => {
+            let page_token = params.get("_page_token").and_then(|v| v.as_str()).map(String::from);
+            let mut query_params: Vec<(&str, &str)> = vec![];
+            if let Some(token) = &page_token {
+                query_params.push(("Marker", token));
+            }
+            let xml = clients.http.query_request("elb", "DescribeLoadBalancers", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let result_path = "/DescribeLoadBalancersResponse/DescribeLoadBalancersResult";
+            let lbs = match json.pointer(&format!("{}/LoadBalancerDescriptions/member", result_path)) {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let members = |lb: &Value, path: &str| -> Vec<Value> {
+                match lb.pointer(path) {
+                    Some(Value::Array(arr)) => arr.clone(),
+                    Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                    _ => vec![],
+                }
+            };
+            
+            let result: Vec<Value> = lbs.iter().map(|lb| {
+                // "HTTP:80->8080, HTTPS:443->8080"
+                let listeners = members(lb, "/ListenerDescriptions/member").iter()
+                    .filter_map(|l| {
+                        let listener = l.get("Listener")?;
+                        Some(format!(
+                            "{}:{}->{}",
+                            listener.get("Protocol").and_then(|v| v.as_str()).unwrap_or("-"),
+                            listener.get("LoadBalancerPort").and_then(|v| v.as_str()).unwrap_or("-"),
+                            listener.get("InstancePort").and_then(|v| v.as_str()).unwrap_or("-"),
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let zones = match lb.pointer("/AvailabilityZones/member") {
+                    Some(Value::Array(arr)) => arr.iter().filter_map(|z| z.as_str()).collect::<Vec<_>>().join(", "),
+                    Some(Value::String(zone)) => zone.clone(),
+                    _ => "-".to_string(),
+                };
+                
+                json!({
+                    "LoadBalancerName": lb.pointer("/LoadBalancerName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "DNSName": lb.pointer("/DNSName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Scheme": lb.pointer("/Scheme").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "VPCId": lb.pointer("/VPCId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Listeners": if listeners.is_empty() { "-".to_string() } else { listeners },
+                    "InstanceCount": members(lb, "/Instances/member").len(),
+                    "AvailabilityZones": zones,
+                    "CreatedTime": lb.pointer("/CreatedTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+            
+            let mut response = json!({ "load_balancers": result });
+            if let Some(marker) = json.pointer(&format!("{}/NextMarker", result_path)).and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(marker);
+            }
+            Ok(response)
+        }
+
+        ("elb", "describe_instance_health") => {
+            let lb_name = extract_param(params, "load_balancer_name");
+            if lb_name.is_empty() {
+                return Ok(json!({ "instances": [] }));
+            }
+            
+            let xml = clients.http.query_request("elb", "DescribeInstanceHealth", &[
+                ("LoadBalancerName", &lb_name)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let states = match json.pointer("/DescribeInstanceHealthResponse/DescribeInstanceHealthResult/InstanceStates/member") {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = states.iter().map(|state| {
+                let instance_id = state.pointer("/InstanceId").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "InstanceId": instance_id,
+                    "State": state.pointer("/State").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ReasonCode": state.pointer("/ReasonCode").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Description": state.pointer("/Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "InstanceRef": format!("{}|{}", lb_name, instance_id),
+                })
+            }).collect();
+            
+            Ok(json!({ "instances": result }))
+        }
+
+        ("elbv2", "describe_listener_certificates") => {
+            let listener_arn = extract_param(params, "listener_arn");
+            if listener_arn.is_empty() {
+                return Ok(json!({ "certificates": [] }));
+            }
+            
+            let mut query_params: Vec<(&str, &str)> = vec![("ListenerArn", &listener_arn)];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str()).map(String::from);
+            if let Some(token) = &page_token {
+                query_params.push(("Marker", token));
+            }
+            let xml = clients.http.query_request("elbv2", "DescribeListenerCertificates", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let result_path = "/DescribeListenerCertificatesResponse/DescribeListenerCertificatesResult";
+            let certificates = match json.pointer(&format!("{}/Certificates/member", result_path)) {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = certificates.iter().map(|cert| {
+                let certificate_arn = cert.pointer("/CertificateArn").and_then(|v| v.as_str()).unwrap_or("-");
+                json!({
+                    "CertificateArn": certificate_arn,
+                    "IsDefault": if cert.pointer("/IsDefault").and_then(|v| v.as_str()) == Some("true") { "Yes" } else { "No" },
+                    "CertRef": format!("{}|{}", listener_arn, certificate_arn),
+                })
+            }).collect();
+            
+            let mut response = json!({ "certificates": result });
+            if let Some(marker) = json.pointer(&format!("{}/NextMarker", result_path)).and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(marker);
+            }
+            Ok(response)
+        }
+
+        ("elbv2", "describe_rules") => {
+            let listener_arn = extract_param(params, "listener_arn");
+            if listener_arn.is_empty() {
+                return Ok(json!({ "rules": [] }));
+            }
+            
+            let xml = clients.http.query_request("elbv2", "DescribeRules", &[
+                ("ListenerArn", &listener_arn)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            
+            let rules_data = json.pointer("/DescribeRulesResponse/DescribeRulesResult/Rules/member");
+            let rule_list = match rules_data {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+            
+            let result: Vec<Value> = rule_list.iter().map(|rule| {
+                // Get the first action type and target group
+                let action = rule.pointer("/Actions/member")
+                    .and_then(|v| match v {
+                        Value::Array(arr) => arr.first().cloned(),
+                        obj @ Value::Object(_) => Some(obj.clone()),
+                        _ => None,
+                    });
+                let action_type = action.as_ref()
+                    .and_then(|a| a.pointer("/Type"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("-");
+                let target_group_arn = action.as_ref()
+                    .and_then(|a| a.pointer("/TargetGroupArn"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("-");
+                
+                // Summarize conditions
+                let conditions = rule.pointer("/Conditions/member");
+                let conditions_summary = match conditions {
+                    Some(Value::Array(arr)) => {
+                        arr.iter()
+                            .filter_map(|c| c.pointer("/Field").and_then(|v| v.as_str()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    }
+                    Some(obj @ Value::Object(_)) => {
+                        obj.pointer("/Field").and_then(|v| v.as_str()).unwrap_or("-").to_string()
+                    }
+                    _ => "-".to_string(),
+                };
+                
+                json!({
+                    "RuleArn": rule.pointer("/RuleArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Priority": rule.pointer("/Priority").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "IsDefault": if rule.pointer("/IsDefault").and_then(|v| v.as_str()) == Some("true") { "Yes" } else { "No" },
                     "ConditionsSummary": if conditions_summary.is_empty() { "-" } else { &conditions_summary },
                     "ActionType": action_type,
                     "TargetGroupArn": target_group_arn,
@@ -2021,9 +4852,32 @@ pub async fn invoke_sdk(
                 _ => vec![],
             };
             
+            let arns: Vec<String> = tg_list.iter()
+                .filter_map(|tg| tg.pointer("/TargetGroupArn").and_then(|v| v.as_str()).map(String::from))
+                .collect();
+            let health = target_group_health_counts(clients, &arns).await;
+            
             let result: Vec<Value> = tg_list.iter().map(|tg| {
+                let arn = tg.pointer("/TargetGroupArn").and_then(|v| v.as_str()).unwrap_or("-");
+                let (healthy_total, health_status) = match health.get(arn) {
+                    Some(&(healthy, total)) => {
+                        let status = if total == 0 {
+                            "empty"
+                        } else if healthy == total {
+                            "healthy"
+                        } else if healthy > 0 {
+                            "degraded"
+                        } else {
+                            "unhealthy"
+                        };
+                        (format!("{}/{}", healthy, total), status)
+                    }
+                    None => ("-".to_string(), "-"),
+                };
                 json!({
-                    "TargetGroupArn": tg.pointer("/TargetGroupArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "TargetGroupArn": arn,
+                    "HealthyTotal": healthy_total,
+                    "HealthStatus": health_status,
                     "TargetGroupName": tg.pointer("/TargetGroupName").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Protocol": tg.pointer("/Protocol").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Port": tg.pointer("/Port").and_then(|v| v.as_str()).unwrap_or("-"),
@@ -2080,10 +4934,748 @@ pub async fn invoke_sdk(
     }
 }
 
+// =============================================================================
+// User-defined API calls
+// =============================================================================
+
+/// Call the API described by a user-defined resource or action. Params are
+/// sent as query params (query), the request body (json, non-GET REST) or the
+/// query string (GET REST) after filling `{param}` placeholders in the path.
+pub async fn invoke_api(service: &str, api: &ApiDef, clients: &AwsClients, params: &Value) -> Result<Value> {
+    let definition = get_service(service)
+        .ok_or_else(|| anyhow!("Unknown service '{}' (declare it under \"services\")", service))?;
+
+    // Sub-resource filters arrive as one-element arrays; send them as plain values
+    let mut params: serde_json::Map<String, Value> = params
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(k, v)| match v {
+            Value::Array(values) if values.len() == 1 => (k.clone(), values[0].clone()),
+            _ => (k.clone(), v.clone()),
+        })
+        .collect();
+    if let Some(token) = params.remove("_page_token") {
+        let name = api.next_token_param.as_deref().unwrap_or("NextToken");
+        params.insert(name.to_string(), token);
+    }
+
+    let action = || api.action.as_deref().ok_or_else(|| anyhow!("'api.action' is required for {} services", service));
+    let mut response = match definition.protocol {
+        Protocol::Query => {
+            let pairs = query_pairs(&params);
+            let pairs: Vec<(&str, &str)> = pairs.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+            let xml = clients.http.query_request(service, action()?, &pairs).await?;
+            xml_to_json(&xml)?
+        }
+        Protocol::Json => {
+            let response = clients.http.json_request(service, action()?, &Value::Object(params).to_string()).await?;
+            serde_json::from_str(&response)?
+        }
+        Protocol::RestJson | Protocol::RestXml => {
+            let mut path = api.path.clone().ok_or_else(|| anyhow!("'api.path' is required for REST services"))?;
+            for (key, value) in params.clone() {
+                let placeholder = format!("{{{}}}", key);
+                if path.contains(&placeholder) {
+                    path = path.replace(&placeholder, &urlencoding::encode(&value_to_param(&value)));
+                    params.remove(&key);
+                }
+            }
+            let body = if api.method == "GET" || params.is_empty() {
+                if !params.is_empty() {
+                    let query = query_pairs(&params)
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+                        .collect::<Vec<_>>()
+                        .join("&");
+                    path = format!("{}{}{}", path, if path.contains('?') { '&' } else { '?' }, query);
+                }
+                None
+            } else {
+                Some(Value::Object(params).to_string())
+            };
+            if definition.protocol == Protocol::RestJson {
+                let response = clients.http.rest_json_request(service, &api.method, &path, body.as_deref()).await?;
+                if response.trim().is_empty() { json!({}) } else { serde_json::from_str(&response)? }
+            } else {
+                let xml = clients.http.rest_xml_request(service, &api.method, &path, body.as_deref()).await?;
+                if xml.trim().is_empty() { json!({}) } else { xml_to_json(&xml)? }
+            }
+        }
+    };
+
+    if let Some(token_path) = &api.next_token_path {
+        let token = super::fetcher::extract_json_value(&response, token_path);
+        if token != "-" && !token.is_empty() && let Value::Object(ref mut map) = response {
+            map.insert("_next_token".to_string(), Value::String(token));
+        }
+    }
+    Ok(response)
+}
+
+/// Run a user-defined action
+pub async fn execute_api_action(
+    service: &str,
+    api: &ApiDef,
+    clients: &AwsClients,
+    resource_id: &str,
+    input: Option<&str>,
+) -> Result<()> {
+    let fill = |text: &str| text.replace("{id}", resource_id).replace("{input}", input.unwrap_or_default());
+    let mut api = api.clone();
+    api.path = api.path.as_deref().map(fill);
+    let params = fill_placeholders(&api.params, &fill);
+    invoke_api(service, &api, clients, &params).await?;
+    Ok(())
+}
+
+/// Apply `fill` to every string in a params document
+fn fill_placeholders(value: &Value, fill: &dyn Fn(&str) -> String) -> Value {
+    match value {
+        Value::String(s) => Value::String(fill(s)),
+        Value::Array(values) => Value::Array(values.iter().map(|v| fill_placeholders(v, fill)).collect()),
+        Value::Object(map) => Value::Object(map.iter().map(|(k, v)| (k.clone(), fill_placeholders(v, fill))).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Flatten params to Query protocol pairs; lists become `Name.member.N`
+fn query_pairs(params: &serde_json::Map<String, Value>) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for (key, value) in params {
+        match value {
+            Value::Array(values) => {
+                for (i, v) in values.iter().enumerate() {
+                    pairs.push((format!("{}.member.{}", key, i + 1), value_to_param(v)));
+                }
+            }
+            Value::Null => {}
+            _ => pairs.push((key.clone(), value_to_param(value))),
+        }
+    }
+    pairs
+}
+
+fn value_to_param(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
 // =============================================================================
 // XML Parsing Helpers
 // =============================================================================
 
+// =============================================================================
+// Account identity and regions (header, region picker)
+// =============================================================================
+
+/// Account and principal the current credentials act as
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountIdentity {
+    pub account: String,
+    /// First account alias, if one is set and readable
+    pub alias: Option<String>,
+    /// Caller ARN, e.g. "arn:aws:sts::123456789012:assumed-role/Admin/alice"
+    pub arn: String,
+}
+
+/// Look up the account, alias and caller ARN of `clients`. A missing
+/// iam:ListAccountAliases permission only leaves the alias out.
+pub async fn get_account_identity(clients: &AwsClients) -> Result<AccountIdentity> {
+    if crate::demo::enabled() {
+        return Ok(AccountIdentity {
+            account: crate::demo::ACCOUNT.to_string(),
+            alias: Some("demo".to_string()),
+            arn: format!("arn:aws:iam::{}:user/demo", crate::demo::ACCOUNT),
+        });
+    }
+    let xml = clients.http.query_request("sts", "GetCallerIdentity", &[]).await?;
+    let json = xml_to_json(&xml)?;
+    let result = json.pointer("/GetCallerIdentityResponse/GetCallerIdentityResult");
+    let field = |name: &str| {
+        result
+            .and_then(|r| r.get(name))
+            .and_then(|v| v.as_str())
+            .unwrap_or("-")
+            .to_string()
+    };
+    let (account, arn) = (field("Account"), field("Arn"));
+
+    let alias = match clients.http.query_request("iam", "ListAccountAliases", &[]).await {
+        Ok(xml) => {
+            let mut aliases = Vec::new();
+            xml_for_each(&xml, &["*", "*", "AccountAliases", "member"], |alias| {
+                if let Some(alias) = alias.as_str() {
+                    aliases.push(alias.to_string());
+                }
+            })?;
+            aliases.into_iter().next()
+        }
+        Err(e) => {
+            tracing::debug!("ListAccountAliases failed: {}", e);
+            None
+        }
+    };
+
+    Ok(AccountIdentity { account, alias, arn })
+}
+
+/// Regions of the account with their opt-in status ("opt-in-not-required",
+/// "opted-in" or "not-opted-in"), sorted by name
+pub async fn describe_regions(clients: &AwsClients) -> Result<Vec<(String, String)>> {
+    let xml = clients.http.query_request("ec2", "DescribeRegions", &[("AllRegions", "true")]).await?;
+    let mut regions = Vec::new();
+    xml_for_each(&xml, &["*", "regionInfo", "item"], |item| {
+        if let Some(name) = item.get("regionName").and_then(|v| v.as_str()) {
+            let status = item.get("optInStatus").and_then(|v| v.as_str()).unwrap_or("opt-in-not-required");
+            regions.push((name.to_string(), status.to_string()));
+        }
+    })?;
+    regions.sort();
+    Ok(regions)
+}
+
+// =============================================================================
+// Tagging (used by the tag editor)
+// =============================================================================
+
+/// Read a resource's tags, sorted by key. `api` is "ec2" (id is a resource id)
+/// or "rgta" (id is an ARN).
+pub async fn get_resource_tags(clients: &AwsClients, api: &str, id: &str) -> Result<Vec<(String, String)>> {
+    let mut tags: Vec<(String, String)> = if api == "ec2" {
+        let xml = clients.http.query_request("ec2", "DescribeTags", &[
+            ("Filter.1.Name", "resource-id"),
+            ("Filter.1.Value.1", id),
+        ]).await?;
+        let json = xml_to_json(&xml)?;
+        let items = match json.pointer("/DescribeTagsResponse/tagSet/item") {
+            Some(Value::Array(arr)) => arr.clone(),
+            Some(obj @ Value::Object(_)) => vec![obj.clone()],
+            _ => vec![],
+        };
+        items.iter().filter_map(|t| {
+            let key = t.get("key").and_then(|v| v.as_str())?;
+            let value = t.get("value").and_then(|v| v.as_str()).unwrap_or("");
+            Some((key.to_string(), value.to_string()))
+        }).collect()
+    } else {
+        let response = clients.http.json_request("tagging", "GetResources", &json!({
+            "ResourceARNList": [id]
+        }).to_string()).await?;
+        let json: Value = serde_json::from_str(&response)?;
+        json.pointer("/ResourceTagMappingList/0/Tags").and_then(|v| v.as_array()).cloned().unwrap_or_default()
+            .iter()
+            .filter_map(|t| {
+                let key = t.get("Key").and_then(|v| v.as_str())?;
+                let value = t.get("Value").and_then(|v| v.as_str()).unwrap_or("");
+                Some((key.to_string(), value.to_string()))
+            })
+            .collect()
+    };
+    tags.sort();
+    Ok(tags)
+}
+
+/// Add or overwrite one tag on a resource
+pub async fn tag_resource(clients: &AwsClients, api: &str, id: &str, key: &str, value: &str) -> Result<()> {
+    if api == "ec2" {
+        clients.http.query_request("ec2", "CreateTags", &[
+            ("ResourceId.1", id),
+            ("Tag.1.Key", key),
+            ("Tag.1.Value", value),
+        ]).await?;
+        return Ok(());
+    }
+    let response = clients.http.json_request("tagging", "TagResources", &json!({
+        "ResourceARNList": [id],
+        "Tags": { key: value }
+    }).to_string()).await?;
+    check_tagging_failures(&response)
+}
+
+/// Remove one tag from a resource
+pub async fn untag_resource(clients: &AwsClients, api: &str, id: &str, key: &str) -> Result<()> {
+    if api == "ec2" {
+        clients.http.query_request("ec2", "DeleteTags", &[
+            ("ResourceId.1", id),
+            ("Tag.1.Key", key),
+        ]).await?;
+        return Ok(());
+    }
+    let response = clients.http.json_request("tagging", "UntagResources", &json!({
+        "ResourceARNList": [id],
+        "TagKeys": [key]
+    }).to_string()).await?;
+    check_tagging_failures(&response)
+}
+
+/// Healthy and total target counts per target group, keyed by ARN.
+/// Groups whose health can't be read are left out.
+async fn target_group_health_counts(clients: &AwsClients, arns: &[String]) -> HashMap<String, (usize, usize)> {
+    // DescribeTargetHealth takes one group per call, so run a few at a time
+    const CONCURRENCY: usize = 8;
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for arn in arns {
+        let clients = clients.clone();
+        let arn = arn.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire().await.ok()?;
+            let xml = clients.http.query_request("elbv2", "DescribeTargetHealth", &[
+                ("TargetGroupArn", &arn)
+            ]).await.ok()?;
+            let json = xml_to_json(&xml).ok()?;
+            let states: Vec<String> = match json.pointer("/DescribeTargetHealthResponse/DescribeTargetHealthResult/TargetHealthDescriptions/member") {
+                Some(Value::Array(arr)) => arr.iter()
+                    .map(|t| t.pointer("/TargetHealth/State").and_then(|v| v.as_str()).unwrap_or("-").to_string())
+                    .collect(),
+                Some(obj @ Value::Object(_)) => vec![
+                    obj.pointer("/TargetHealth/State").and_then(|v| v.as_str()).unwrap_or("-").to_string()
+                ],
+                _ => vec![],
+            };
+            let healthy = states.iter().filter(|s| *s == "healthy").count();
+            Some((arn, (healthy, states.len())))
+        });
+    }
+    
+    let mut counts = HashMap::new();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok(Some((arn, count))) = joined {
+            counts.insert(arn, count);
+        }
+    }
+    counts
+}
+
+/// The tagging API reports per-resource failures in a 200 response
+fn check_tagging_failures(response: &str) -> Result<()> {
+    let json: Value = serde_json::from_str(response)?;
+    if let Some((_, failure)) = json.get("FailedResourcesMap").and_then(|v| v.as_object()).and_then(|m| m.iter().next()) {
+        return Err(anyhow!("{}", failure.get("ErrorMessage").and_then(|v| v.as_str()).unwrap_or("Tagging failed")));
+    }
+    Ok(())
+}
+
+/// Default values of a service's quotas, keyed by quota code
+async fn service_quota_defaults(clients: &AwsClients, service_code: &str) -> Result<HashMap<String, f64>> {
+    let mut defaults = HashMap::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let mut request = json!({ "ServiceCode": service_code, "MaxResults": 100 });
+        if let Some(token) = &next_token {
+            request["NextToken"] = json!(token);
+        }
+        let response = clients.http.json_request("servicequotas", "ListAWSDefaultServiceQuotas", &request.to_string()).await?;
+        let json: Value = serde_json::from_str(&response)?;
+        for q in json.get("Quotas").and_then(|v| v.as_array()).into_iter().flatten() {
+            if let (Some(code), Some(value)) = (q.get("QuotaCode").and_then(|v| v.as_str()), q.get("Value").and_then(|v| v.as_f64())) {
+                defaults.insert(code.to_string(), value);
+            }
+        }
+        next_token = json.get("NextToken").and_then(|v| v.as_str()).map(String::from);
+        if next_token.is_none() {
+            break;
+        }
+    }
+    Ok(defaults)
+}
+
+/// Show whole-number quota values without a trailing ".0"
+fn format_quota_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// One CloudWatch metric read by [`get_metric_data`]
+struct MetricQuery {
+    /// Query id (lowercase letter first, e.g. "q0")
+    id: String,
+    namespace: String,
+    metric_name: String,
+    dimensions: Vec<(String, String)>,
+    /// Statistic, e.g. "Maximum" or "Average"
+    stat: String,
+}
+
+/// Latest datapoint of each metric over the last `window_secs` seconds,
+/// keyed by query id (metrics without data are left out)
+async fn get_metric_data(clients: &AwsClients, queries: &[MetricQuery], window_secs: i64) -> Result<HashMap<String, f64>> {
+    let end = chrono::Utc::now();
+    let start = end - chrono::Duration::seconds(window_secs);
+    let mut values = HashMap::new();
+    
+    // GetMetricData accepts at most 500 queries per call
+    for chunk in queries.chunks(500) {
+        let mut owned: Vec<(String, String)> = vec![
+            ("StartTime".to_string(), start.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+            ("EndTime".to_string(), end.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+        ];
+        for (i, query) in chunk.iter().enumerate() {
+            let prefix = format!("MetricDataQueries.member.{}", i + 1);
+            owned.push((format!("{}.Id", prefix), query.id.clone()));
+            owned.push((format!("{}.MetricStat.Metric.Namespace", prefix), query.namespace.clone()));
+            owned.push((format!("{}.MetricStat.Metric.MetricName", prefix), query.metric_name.clone()));
+            for (j, (name, value)) in query.dimensions.iter().enumerate() {
+                let dim = format!("{}.MetricStat.Metric.Dimensions.member.{}", prefix, j + 1);
+                owned.push((format!("{}.Name", dim), name.clone()));
+                owned.push((format!("{}.Value", dim), value.clone()));
+            }
+            owned.push((format!("{}.MetricStat.Period", prefix), window_secs.to_string()));
+            owned.push((format!("{}.MetricStat.Stat", prefix), query.stat.clone()));
+        }
+        let params: Vec<(&str, &str)> = owned.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        
+        let xml = clients.http.query_request("cloudwatch", "GetMetricData", &params).await?;
+        let json = xml_to_json(&xml)?;
+        let results = match json.pointer("/GetMetricDataResponse/GetMetricDataResult/MetricDataResults/member") {
+            Some(Value::Array(arr)) => arr.clone(),
+            Some(obj @ Value::Object(_)) => vec![obj.clone()],
+            _ => vec![],
+        };
+        for result in results {
+            let Some(id) = result.get("Id").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            // Values are returned newest first
+            let latest = match result.pointer("/Values/member") {
+                Some(Value::Array(arr)) => arr.first().cloned(),
+                other => other.cloned(),
+            };
+            if let Some(value) = latest.and_then(|v| v.as_str().and_then(|s| s.parse::<f64>().ok())) {
+                values.insert(id.to_string(), value);
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Add the recent average of each metric (e.g. CPUUtilization) to list items,
+/// read for every item in one batched GetMetricData call. Items are matched
+/// on `dimension`, which is also their field holding the id; `metrics` pairs
+/// metric names with query id prefixes. A failed call leaves the values as "-".
+async fn add_utilization(
+    clients: &AwsClients,
+    items: &mut [Value],
+    namespace: &str,
+    dimension: &str,
+    metrics: &[(&str, &str)],
+) {
+    let mut queries = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let id = extract_param(item, dimension);
+        for (metric_name, prefix) in metrics {
+            queries.push(MetricQuery {
+                id: format!("{}{}", prefix, i),
+                namespace: namespace.to_string(),
+                metric_name: metric_name.to_string(),
+                dimensions: vec![(dimension.to_string(), id.clone())],
+                stat: "Average".to_string(),
+            });
+        }
+    }
+    let values = if queries.is_empty() {
+        HashMap::new()
+    } else {
+        // Average over the last 10 minutes
+        get_metric_data(clients, &queries, 600).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to read {} utilization metrics: {}", namespace, e);
+            HashMap::new()
+        })
+    };
+    
+    for (i, item) in items.iter_mut().enumerate() {
+        for (metric_name, prefix) in metrics {
+            let value = values.get(&format!("{}{}", prefix, i));
+            let display = match (*metric_name, value) {
+                (_, None) => "-".to_string(),
+                ("FreeableMemory", Some(bytes)) => format_bytes(*bytes as u64),
+                (_, Some(percent)) => format!("{:.0}%", percent),
+            };
+            item[*metric_name] = json!(display);
+        }
+    }
+}
+
+/// Run a service-last-accessed report for an IAM user or role and return the
+/// services it can reach, most recently used first
+async fn iam_service_last_accessed(clients: &AwsClients, arn: &str) -> Result<Value> {
+    let xml = clients.http.query_request("iam", "GenerateServiceLastAccessedDetails", &[
+        ("Arn", arn)
+    ]).await?;
+    let json = xml_to_json(&xml)?;
+    let job_id = json.pointer("/GenerateServiceLastAccessedDetailsResponse/GenerateServiceLastAccessedDetailsResult/JobId")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("No JobId returned"))?
+        .to_string();
+    
+    // The report is generated asynchronously and usually completes within a few seconds
+    for _ in 0..20 {
+        let xml = clients.http.query_request("iam", "GetServiceLastAccessedDetails", &[
+            ("JobId", &job_id)
+        ]).await?;
+        let json = xml_to_json(&xml)?;
+        let status = json.pointer("/GetServiceLastAccessedDetailsResponse/GetServiceLastAccessedDetailsResult/JobStatus")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        
+        match status {
+            "COMPLETED" => {
+                let services = extract_iam_list(&json, "ServicesLastAccessed", "member");
+                let mut result: Vec<Value> = services.iter().map(|svc| {
+                    json!({
+                        "ServiceName": svc.get("ServiceName").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "ServiceNamespace": svc.get("ServiceNamespace").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "LastAuthenticated": svc.get("LastAuthenticated").and_then(|v| v.as_str()).unwrap_or("never"),
+                        "LastAuthenticatedRegion": svc.get("LastAuthenticatedRegion").and_then(|v| v.as_str()).unwrap_or("-"),
+                    })
+                }).collect();
+                // ISO 8601 timestamps sort lexically; "never" sorts after them
+                result.sort_by(|a, b| {
+                    let a = a["LastAuthenticated"].as_str().unwrap_or("");
+                    let b = b["LastAuthenticated"].as_str().unwrap_or("");
+                    match (a == "never", b == "never") {
+                        (false, true) => std::cmp::Ordering::Less,
+                        (true, false) => std::cmp::Ordering::Greater,
+                        _ => b.cmp(a),
+                    }
+                });
+                return Ok(Value::Array(result));
+            }
+            "FAILED" => return Err(anyhow!("Service last accessed report failed")),
+            _ => tokio::time::sleep(std::time::Duration::from_millis(500)).await,
+        }
+    }
+    
+    Err(anyhow!("Timed out waiting for service last accessed report"))
+}
+
+/// Replace the text of a direct child element of the XML document root
+/// (e.g. the distribution-level `Enabled`, not the nested `Logging/Enabled`)
+fn set_root_child_text(xml: &str, tag: &str, value: &str) -> Result<String> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0usize;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                depth += 1;
+                if depth == 2 && e.name().as_ref() == tag.as_bytes() {
+                    let start = reader.buffer_position() as usize;
+                    // Text (if any) runs up to the matching end tag
+                    let end = match reader.read_event() {
+                        Ok(Event::Text(_)) => reader.buffer_position() as usize,
+                        Ok(Event::End(_)) => start,
+                        _ => return Err(anyhow!("Unexpected content in <{}>", tag)),
+                    };
+                    return Ok(format!("{}{}{}", &xml[..start], value, &xml[end..]));
+                }
+            }
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) => return Err(anyhow!("<{}> not found", tag)),
+            Err(e) => return Err(anyhow!("XML parse error: {}", e)),
+            _ => {}
+        }
+    }
+}
+
+/// Route53 API path for a hosted zone ID, with or without the "/hostedzone/" prefix
+fn route53_zone_path(zone_id: &str) -> String {
+    format!("/2013-04-01/hostedzone/{}", zone_id.trim_start_matches("/hostedzone/"))
+}
+
+/// Escape text for inclusion in an XML request body
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Fetch one record set by name, type and set identifier (Route53 has no
+/// GetResourceRecordSet, so list from that position and check the first match)
+async fn route53_find_record_set(
+    clients: &AwsClients,
+    zone_id: &str,
+    name: &str,
+    record_type: &str,
+    set_identifier: &str,
+) -> Result<Value> {
+    let mut path = format!(
+        "{}/rrset?maxitems=1&name={}&type={}",
+        route53_zone_path(zone_id),
+        urlencoding::encode(name),
+        urlencoding::encode(record_type)
+    );
+    if !set_identifier.is_empty() {
+        path.push_str(&format!("&identifier={}", urlencoding::encode(set_identifier)));
+    }
+    let xml = clients.http.rest_xml_request("route53", "GET", &path, None).await?;
+    let json = xml_to_json(&xml)?;
+    let record = match json.pointer("/ListResourceRecordSetsResponse/ResourceRecordSets/ResourceRecordSet") {
+        Some(Value::Array(arr)) => arr.first().cloned(),
+        Some(obj @ Value::Object(_)) => Some(obj.clone()),
+        _ => None,
+    };
+    record
+        .filter(|r| {
+            r.get("Name").and_then(|v| v.as_str()) == Some(name)
+                && r.get("Type").and_then(|v| v.as_str()) == Some(record_type)
+                && r.get("SetIdentifier").and_then(|v| v.as_str()).unwrap_or("") == set_identifier
+        })
+        .ok_or_else(|| anyhow!("Record {} {} not found", name, record_type))
+}
+
+/// Serialize a record set (as parsed by xml_to_json) back to Route53 XML,
+/// keeping the element order the API schema expects
+fn route53_record_set_xml(record: &Value) -> String {
+    let text = |path: &str| record.pointer(path).and_then(|v| v.as_str()).map(xml_escape);
+    let mut xml = String::from("<ResourceRecordSet>");
+    for field in ["Name", "Type", "SetIdentifier", "Weight", "Region"] {
+        if let Some(value) = text(&format!("/{}", field)) {
+            xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+        }
+    }
+    if record.get("GeoLocation").is_some() {
+        xml.push_str("<GeoLocation>");
+        for field in ["ContinentCode", "CountryCode", "SubdivisionCode"] {
+            if let Some(value) = text(&format!("/GeoLocation/{}", field)) {
+                xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+            }
+        }
+        xml.push_str("</GeoLocation>");
+    }
+    for field in ["Failover", "MultiValueAnswer", "TTL"] {
+        if let Some(value) = text(&format!("/{}", field)) {
+            xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+        }
+    }
+    let values: Vec<Value> = match record.pointer("/ResourceRecords/ResourceRecord") {
+        Some(Value::Array(arr)) => arr.clone(),
+        Some(obj @ Value::Object(_)) => vec![obj.clone()],
+        _ => vec![],
+    };
+    if !values.is_empty() {
+        xml.push_str("<ResourceRecords>");
+        for value in values.iter().filter_map(|r| r.get("Value").and_then(|v| v.as_str())) {
+            xml.push_str(&format!("<ResourceRecord><Value>{}</Value></ResourceRecord>", xml_escape(value)));
+        }
+        xml.push_str("</ResourceRecords>");
+    }
+    if record.get("AliasTarget").is_some() {
+        xml.push_str("<AliasTarget>");
+        for field in ["HostedZoneId", "DNSName", "EvaluateTargetHealth"] {
+            if let Some(value) = text(&format!("/AliasTarget/{}", field)) {
+                xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+            }
+        }
+        xml.push_str("</AliasTarget>");
+    }
+    for field in ["HealthCheckId", "TrafficPolicyInstanceId"] {
+        if let Some(value) = text(&format!("/{}", field)) {
+            xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+        }
+    }
+    if record.get("CidrRoutingConfig").is_some() {
+        xml.push_str("<CidrRoutingConfig>");
+        for field in ["CollectionId", "LocationName"] {
+            if let Some(value) = text(&format!("/CidrRoutingConfig/{}", field)) {
+                xml.push_str(&format!("<{0}>{1}</{0}>", field, value));
+            }
+        }
+        xml.push_str("</CidrRoutingConfig>");
+    }
+    xml.push_str("</ResourceRecordSet>");
+    xml
+}
+
+/// Parse a simple record spec: `name TYPE ttl value[,value...]`
+fn parse_record_spec(spec: &str) -> Result<(String, String, u32, Vec<String>)> {
+    let usage = || anyhow!("Expected: name TYPE ttl value[,value...]");
+    let mut rest = spec.trim();
+    let mut next = || -> Option<String> {
+        let (word, remainder) = rest.split_once(char::is_whitespace)?;
+        rest = remainder.trim_start();
+        Some(word.to_string())
+    };
+    let name = next().ok_or_else(usage)?;
+    let record_type = next().ok_or_else(usage)?.to_uppercase();
+    let ttl = next().ok_or_else(usage)?
+        .parse::<u32>()
+        .map_err(|_| anyhow!("TTL must be a number of seconds"))?;
+    let values: Vec<String> = rest.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect();
+    if values.is_empty() {
+        return Err(usage());
+    }
+    Ok((name, record_type, ttl, values))
+}
+
+/// Submit a single-change ChangeResourceRecordSets batch
+async fn route53_change(clients: &AwsClients, zone_id: &str, change_action: &str, record_xml: &str) -> Result<()> {
+    let body = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><ChangeResourceRecordSetsRequest xmlns="https://route53.amazonaws.com/doc/2013-04-01/"><ChangeBatch><Comment>Changed with taws</Comment><Changes><Change><Action>{}</Action>{}</Change></Changes></ChangeBatch></ChangeResourceRecordSetsRequest>"#,
+        change_action, record_xml
+    );
+    clients.http.rest_xml_request(
+        "route53",
+        "POST",
+        &format!("{}/rrset", route53_zone_path(zone_id)),
+        Some(&body)
+    ).await?;
+    Ok(())
+}
+
+/// Split an EventBridge Scheduler ARN (...:schedule/group/name) into group and name
+fn parse_schedule_arn(arn: &str) -> Option<(&str, &str)> {
+    let (_, path) = arn.split_once(":schedule/")?;
+    path.split_once('/')
+}
+
+/// Short "service: resource" summary of a schedule target ARN
+fn schedule_target_summary(target_arn: &str) -> String {
+    let parts: Vec<&str> = target_arn.splitn(6, ':').collect();
+    match parts.as_slice() {
+        // Universal targets: arn:aws:scheduler:::aws-sdk:service:apiAction
+        [_, _, "scheduler", _, _, rest] => rest.trim_start_matches("aws-sdk:").to_string(),
+        [_, _, service, _, _, resource] => format!("{}: {}", service, resource),
+        _ => target_arn.to_string(),
+    }
+}
+
+/// Decode a base64 Kinesis record payload into a one-line display string and,
+/// when the payload is JSON, the parsed document for the describe view
+fn decode_kinesis_data(encoded: &str) -> (String, Value) {
+    use base64::Engine;
+    
+    let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(encoded) else {
+        return (encoded.to_string(), Value::Null);
+    };
+    match String::from_utf8(bytes) {
+        Ok(text) => match serde_json::from_str::<Value>(&text) {
+            Ok(json) => (json.to_string(), json),
+            Err(_) => (text.replace(['\n', '\r'], " "), Value::String(text)),
+        },
+        Err(e) => (format!("<binary, {} bytes>", e.as_bytes().len()), Value::Null),
+    }
+}
+
+/// Decode a URL-encoded IAM policy document into JSON (raw string if it does not parse)
+fn decode_policy_document(encoded: &str) -> Value {
+    let decoded = urlencoding::decode(encoded)
+        .map(|s| s.into_owned())
+        .unwrap_or_else(|_| encoded.to_string());
+    serde_json::from_str(&decoded).unwrap_or(Value::String(decoded))
+}
+
 /// Extract list from IAM response
 fn extract_iam_list(json: &Value, list_key: &str, item_key: &str) -> Vec<Value> {
     // IAM structure: { "XXXResponse": { "XXXResult": { "ListKey": { "member": [...] } } } }
@@ -0,0 +1,240 @@
+//! Cross-service search helpers
+//!
+//! Parses `:find` arguments and maps ARNs returned by cross-service APIs
+//! (Resource Groups Tagging API, Resource Explorer) and pasted into `:goto`
+//! to the taws view that lists them.
+
+use super::fetcher::ResourceFilter;
+use super::registry::get_resource_key_by_cfn_type;
+use anyhow::{anyhow, Result};
+
+/// Filter param carrying `key=value` (or bare `key`) tag filters
+pub const TAG_FILTER_PARAM: &str = "tag_filters";
+
+/// Filter param carrying a Resource Explorer query string
+pub const SEARCH_QUERY_PARAM: &str = "query";
+
+/// Parse `:find` arguments (`tag:Key=Value`, `tag:Key`) into filters
+pub fn parse_find_args(args: &[&str]) -> Result<Vec<ResourceFilter>> {
+    let mut tags = Vec::new();
+    for arg in args {
+        let Some(tag) = arg.strip_prefix("tag:") else {
+            return Err(anyhow!("Expected tag:<key>=<value>, got '{}'", arg));
+        };
+        if tag.is_empty() || tag.starts_with('=') {
+            return Err(anyhow!("Expected tag:<key>=<value>, got '{}'", arg));
+        }
+        tags.push(tag.to_string());
+    }
+    if tags.is_empty() {
+        return Err(anyhow!("Usage: find tag:<key>=<value> [tag:<key>=<value> ...]"));
+    }
+    Ok(vec![ResourceFilter::new(TAG_FILTER_PARAM, tags)])
+}
+
+/// Parts of an ARN (`arn:partition:service:region:account:resource`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arn {
+    pub partition: String,
+    pub service: String,
+    pub region: String,
+    pub account: String,
+    pub resource: String,
+}
+
+pub fn parse_arn(arn: &str) -> Option<Arn> {
+    let mut parts = arn.splitn(6, ':');
+    if parts.next()? != "arn" {
+        return None;
+    }
+    let arn = Arn {
+        partition: parts.next()?.to_string(),
+        service: parts.next()?.to_string(),
+        region: parts.next()?.to_string(),
+        account: parts.next()?.to_string(),
+        resource: parts.next()?.to_string(),
+    };
+    (!arn.service.is_empty() && !arn.resource.is_empty()).then_some(arn)
+}
+
+impl Arn {
+    /// Resource type and id, e.g. ("instance", "i-123") for `instance/i-123`.
+    /// Services whose resource part is a bare name (S3, SNS, SQS, ...) have no type.
+    pub fn type_and_id(&self) -> (&str, &str) {
+        let bare = ARN_TYPES.iter().any(|(service, kind, _, _)| *service == self.service && kind.is_empty());
+        match self.resource.split_once(['/', ':']) {
+            Some((kind, id)) if !bare => (kind, id),
+            _ => ("", self.resource.as_str()),
+        }
+    }
+}
+
+/// Which part of the resource path (after the type) is the name taws lists
+#[derive(Clone, Copy)]
+enum NamePart {
+    /// Last `/` segment, without any `:qualifier`
+    Last,
+    /// `/` segment at this index
+    Segment(usize),
+    /// Everything (names that may contain `/`)
+    Whole,
+}
+
+/// ARN service and resource type to CloudFormation type (which maps to a view).
+/// An empty resource type means the resource part is the bare name.
+const ARN_TYPES: &[(&str, &str, &str, NamePart)] = &[
+    ("acm", "certificate", "AWS::CertificateManager::Certificate", NamePart::Last),
+    ("athena", "workgroup", "AWS::Athena::WorkGroup", NamePart::Last),
+    ("autoscaling", "autoScalingGroup", "AWS::AutoScaling::AutoScalingGroup", NamePart::Last),
+    ("cloudformation", "stack", "AWS::CloudFormation::Stack", NamePart::Segment(0)),
+    ("cloudfront", "distribution", "AWS::CloudFront::Distribution", NamePart::Last),
+    ("cloudtrail", "trail", "AWS::CloudTrail::Trail", NamePart::Last),
+    ("codebuild", "project", "AWS::CodeBuild::Project", NamePart::Last),
+    ("codepipeline", "", "AWS::CodePipeline::Pipeline", NamePart::Whole),
+    ("cognito-idp", "userpool", "AWS::Cognito::UserPool", NamePart::Last),
+    ("dynamodb", "table", "AWS::DynamoDB::Table", NamePart::Segment(0)),
+    ("ec2", "instance", "AWS::EC2::Instance", NamePart::Last),
+    ("ec2", "vpc", "AWS::EC2::VPC", NamePart::Last),
+    ("ec2", "subnet", "AWS::EC2::Subnet", NamePart::Last),
+    ("ec2", "security-group", "AWS::EC2::SecurityGroup", NamePart::Last),
+    ("ecr", "repository", "AWS::ECR::Repository", NamePart::Whole),
+    ("ecs", "cluster", "AWS::ECS::Cluster", NamePart::Last),
+    ("eks", "cluster", "AWS::EKS::Cluster", NamePart::Last),
+    ("elasticache", "cluster", "AWS::ElastiCache::CacheCluster", NamePart::Last),
+    ("elasticloadbalancing", "loadbalancer", "AWS::ElasticLoadBalancingV2::LoadBalancer", NamePart::Segment(1)),
+    ("events", "event-bus", "AWS::Events::EventBus", NamePart::Last),
+    ("glue", "database", "AWS::Glue::Database", NamePart::Last),
+    ("glue", "job", "AWS::Glue::Job", NamePart::Last),
+    ("glue", "crawler", "AWS::Glue::Crawler", NamePart::Last),
+    ("iam", "user", "AWS::IAM::User", NamePart::Last),
+    ("iam", "role", "AWS::IAM::Role", NamePart::Last),
+    ("iam", "policy", "AWS::IAM::ManagedPolicy", NamePart::Last),
+    ("iam", "group", "AWS::IAM::Group", NamePart::Last),
+    ("kinesis", "stream", "AWS::Kinesis::Stream", NamePart::Last),
+    ("kms", "key", "AWS::KMS::Key", NamePart::Last),
+    ("lambda", "function", "AWS::Lambda::Function", NamePart::Last),
+    ("logs", "log-group", "AWS::Logs::LogGroup", NamePart::Whole),
+    ("organizations", "account", "AWS::Organizations::Account", NamePart::Last),
+    ("rds", "db", "AWS::RDS::DBInstance", NamePart::Last),
+    ("route53", "hostedzone", "AWS::Route53::HostedZone", NamePart::Last),
+    ("s3", "", "AWS::S3::Bucket", NamePart::Segment(0)),
+    ("secretsmanager", "secret", "AWS::SecretsManager::Secret", NamePart::Whole),
+    ("sns", "", "AWS::SNS::Topic", NamePart::Whole),
+    ("sqs", "", "AWS::SQS::Queue", NamePart::Whole),
+    ("ssm", "parameter", "AWS::SSM::Parameter", NamePart::Whole),
+];
+
+/// The taws view that lists an ARN's resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArnTarget {
+    pub resource_key: &'static str,
+    /// Region from the ARN (empty for global resources)
+    pub region: String,
+    /// Name or id to filter the view by
+    pub name: String,
+}
+
+/// Map an ARN to the view listing it, if taws has one
+pub fn resource_for_arn(arn: &str) -> Option<ArnTarget> {
+    let arn = parse_arn(arn)?;
+    let (kind, rest) = arn.type_and_id();
+
+    let (_, _, cfn_type, part) = ARN_TYPES.iter().find(|(service, k, _, _)| *service == arn.service && *k == kind)?;
+    let name = match part {
+        NamePart::Last => {
+            let last = rest.rsplit('/').next().unwrap_or(rest);
+            last.split(':').next().unwrap_or(last)
+        }
+        NamePart::Segment(index) => rest.split('/').nth(*index)?,
+        NamePart::Whole => rest.trim_end_matches(":*"),
+    };
+    // Secret ARNs end in a random "-AbCdEf" suffix that the name does not have
+    let name = match (arn.service.as_str(), name.rsplit_once('-')) {
+        ("secretsmanager", Some((base, suffix))) if suffix.len() == 6 => base,
+        _ => name,
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(ArnTarget {
+        resource_key: get_resource_key_by_cfn_type(cfn_type)?,
+        region: arn.region.clone(),
+        name: name.to_string(),
+    })
+}
+
+/// Prefixes of EC2 ids that name their resource type, and the view listing them
+const ID_PREFIXES: &[(&str, &str)] = &[
+    ("i-", "ec2-instances"),
+    ("vpc-", "vpc"),
+    ("subnet-", "subnets"),
+    ("sg-", "security-groups"),
+];
+
+/// Map a bare EC2 id (`i-0123456789abcdef0`, `vpc-...`) to the view listing it
+pub fn resource_for_id(id: &str) -> Option<ArnTarget> {
+    let (_, resource_key) = ID_PREFIXES.iter().find(|(prefix, _)| {
+        id.strip_prefix(prefix)
+            .is_some_and(|hex| hex.len() >= 8 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+    })?;
+    Some(ArnTarget {
+        resource_key,
+        region: String::new(),
+        name: id.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_find_args() {
+        let filters = parse_find_args(&["tag:Env=prod", "tag:Team"]).unwrap();
+        assert_eq!(filters[0].name, TAG_FILTER_PARAM);
+        assert_eq!(filters[0].values, vec!["Env=prod", "Team"]);
+        assert!(parse_find_args(&[]).is_err());
+        assert!(parse_find_args(&["Env=prod"]).is_err());
+        assert!(parse_find_args(&["tag:=prod"]).is_err());
+    }
+
+    #[test]
+    fn test_resource_for_id() {
+        let target = resource_for_id("i-0123456789abcdef0").unwrap();
+        assert_eq!((target.resource_key, target.name.as_str()), ("ec2-instances", "i-0123456789abcdef0"));
+        assert_eq!(resource_for_id("sg-0a1b2c3d").unwrap().resource_key, "security-groups");
+        assert!(resource_for_id("iam-roles").is_none());
+        assert!(resource_for_id("vpc-").is_none());
+        assert!(resource_for_id("i-not-an-id").is_none());
+    }
+
+    #[test]
+    fn test_resource_for_arn() {
+        let target = resource_for_arn("arn:aws:ec2:eu-west-1:123456789012:instance/i-0abc").unwrap();
+        assert_eq!(target.resource_key, "ec2-instances");
+        assert_eq!(target.region, "eu-west-1");
+        assert_eq!(target.name, "i-0abc");
+
+        let bucket = resource_for_arn("arn:aws:s3:::my-bucket").unwrap();
+        assert_eq!((bucket.resource_key, bucket.name.as_str()), ("s3-buckets", "my-bucket"));
+
+        let function = resource_for_arn("arn:aws:lambda:us-east-1:123456789012:function:api:live").unwrap();
+        assert_eq!(function.name, "api");
+
+        let role = resource_for_arn("arn:aws:iam::123456789012:role/service/deployer").unwrap();
+        assert_eq!((role.resource_key, role.name.as_str()), ("iam-roles", "deployer"));
+
+        let logs = resource_for_arn("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/api:*").unwrap();
+        assert_eq!(logs.name, "/aws/lambda/api");
+
+        let lb = resource_for_arn("arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/app/web/50dc6c495c0c9188").unwrap();
+        assert_eq!(lb.name, "web");
+
+        let secret = resource_for_arn("arn:aws:secretsmanager:us-east-1:123456789012:secret:db-password-AbC123").unwrap();
+        assert_eq!(secret.name, "db-password");
+
+        assert!(resource_for_arn("arn:aws:made-up:us-east-1:123456789012:thing/x").is_none());
+        assert!(resource_for_arn("not-an-arn").is_none());
+    }
+}
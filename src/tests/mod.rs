@@ -0,0 +1,216 @@
+//! App-level tests: drive `App` with key events against recorded AWS
+//! responses served by a mock transport, so parser changes that break a
+//! view show up here
+
+use crate::app::{App, Mode};
+use crate::aws::client::AwsClients;
+use crate::aws::http::{HttpRequest, HttpResponse, Transport};
+use crate::config::Config;
+use crate::resource::extract_json_value;
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+const EC2_DESCRIBE_INSTANCES: &str = include_str!("recordings/ec2_describe_instances.xml");
+const S3_LIST_BUCKETS: &str = include_str!("recordings/s3_list_buckets.xml");
+const S3_LIST_OBJECTS_V2: &str = include_str!("recordings/s3_list_objects_v2.xml");
+const LOGS_DESCRIBE_LOG_GROUPS: &str = include_str!("recordings/logs_describe_log_groups.json");
+const LOGS_DESCRIBE_LOG_STREAMS: &str = include_str!("recordings/logs_describe_log_streams.json");
+const LOGS_GET_LOG_EVENTS: &str = include_str!("recordings/logs_get_log_events.json");
+const LOGS_FILTER_LOG_EVENTS: &str = include_str!("recordings/logs_filter_log_events.json");
+
+/// Answers each request with the response of the first route whose pattern
+/// appears in "METHOD URL TARGET BODY", and keeps the requests it was sent
+struct MockTransport {
+    routes: Vec<(&'static str, &'static str)>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockTransport {
+    fn new(routes: &[(&'static str, &'static str)]) -> Arc<Self> {
+        Arc::new(Self {
+            routes: routes.to_vec(),
+            requests: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Requests sent so far, as matched against the routes
+    fn sent(&self) -> Vec<String> {
+        self.requests.lock().unwrap().iter().map(summary).collect()
+    }
+}
+
+fn summary(request: &HttpRequest) -> String {
+    let target = request
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("x-amz-target"))
+        .map_or("", |(_, value)| value.as_str());
+    format!("{} {} {} {}", request.method, request.url, target, request.body)
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: HttpRequest) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + '_>> {
+        let line = summary(&request);
+        self.requests.lock().unwrap().push(request);
+        let response = match self.routes.iter().find(|(pattern, _)| line.contains(pattern)) {
+            Some((_, body)) => HttpResponse { status: 200, headers: Vec::new(), body: body.to_string() },
+            None => HttpResponse {
+                status: 400,
+                headers: Vec::new(),
+                body: format!("<Error><Code>NoRecording</Code><Message>{}</Message></Error>", line),
+            },
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// An app on the EC2 instances view whose requests go to `mock`
+fn app_with(mock: &Arc<MockTransport>) -> App {
+    let mut clients = AwsClients::offline("test", "us-east-1");
+    clients.http.set_transport(mock.clone());
+    App::from_initialized(
+        clients,
+        "test".to_string(),
+        "us-east-1".to_string(),
+        vec!["test".to_string()],
+        vec!["us-east-1".to_string(), "eu-west-1".to_string()],
+        Vec::new(),
+        Config::default(),
+        false,
+        None,
+    )
+}
+
+async fn press(app: &mut App, code: KeyCode) {
+    crate::event::handle_key_event(app, KeyEvent::new(code, KeyModifiers::NONE))
+        .await
+        .unwrap();
+}
+
+async fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c)).await;
+    }
+}
+
+/// Run a `:` command
+async fn command(app: &mut App, text: &str) {
+    press(app, KeyCode::Char(':')).await;
+    type_text(app, text).await;
+    press(app, KeyCode::Enter).await;
+}
+
+/// Values of `path` in the rows shown
+fn column(app: &App, path: &str) -> Vec<String> {
+    app.filtered_indices
+        .iter()
+        .map(|&i| extract_json_value(&app.items[i], path))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_ec2_instances() {
+    let mock = MockTransport::new(&[("Action=DescribeInstances", EC2_DESCRIBE_INSTANCES)]);
+    let mut app = app_with(&mock);
+    app.refresh_current().await.unwrap();
+
+    assert_eq!(column(&app, "InstanceId"), ["i-0a1b2c3d4e5f60001", "i-0a1b2c3d4e5f60002"]);
+    assert_eq!(column(&app, "tag:Name"), ["web-1", "db-1"]);
+    assert_eq!(column(&app, "State"), ["running", "stopped"]);
+    assert_eq!(column(&app, "PublicIpAddress"), ["54.210.12.34", "-"]);
+
+    press(&mut app, KeyCode::Char('/')).await;
+    type_text(&mut app, "db").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(column(&app, "InstanceId"), ["i-0a1b2c3d4e5f60002"]);
+}
+
+#[tokio::test]
+async fn test_s3_buckets_and_objects() {
+    let mock = MockTransport::new(&[
+        ("list-type=2", S3_LIST_OBJECTS_V2),
+        ("GET https://s3.", S3_LIST_BUCKETS),
+    ]);
+    let mut app = app_with(&mock);
+    command(&mut app, "s3-buckets").await;
+
+    assert_eq!(app.current_resource_key, "s3-buckets");
+    assert_eq!(column(&app, "Name"), ["harness-assets", "harness-backups-eu"]);
+    assert_eq!(column(&app, "BucketRegion"), ["us-east-1", "eu-west-1"]);
+
+    // Objects of the second bucket, requested from its own region
+    press(&mut app, KeyCode::Char('j')).await;
+    press(&mut app, KeyCode::Char('o')).await;
+    assert_eq!(app.current_resource_key, "s3-objects");
+    assert_eq!(column(&app, "DisplayName"), ["daily/", "weekly/", "README.txt"]);
+    assert_eq!(column(&app, "StorageClass"), ["FOLDER", "FOLDER", "STANDARD"]);
+    let sent = mock.sent();
+    let objects_request = sent.last().unwrap();
+    assert!(objects_request.contains("harness-backups-eu"), "{}", objects_request);
+    assert!(objects_request.contains("eu-west-1"), "{}", objects_request);
+}
+
+#[tokio::test]
+async fn test_log_groups_streams_and_tail() {
+    let mock = MockTransport::new(&[
+        ("DescribeLogGroups", LOGS_DESCRIBE_LOG_GROUPS),
+        ("DescribeLogStreams", LOGS_DESCRIBE_LOG_STREAMS),
+        ("GetLogEvents", LOGS_GET_LOG_EVENTS),
+    ]);
+    let mut app = app_with(&mock);
+    command(&mut app, "cloudwatch-log-groups").await;
+
+    assert_eq!(column(&app, "logGroupName"), ["/aws/lambda/orders-api", "/ecs/web"]);
+    assert_eq!(column(&app, "retentionInDays"), ["14", "Never"]);
+
+    press(&mut app, KeyCode::Char('s')).await;
+    assert_eq!(app.current_resource_key, "cloudwatch-log-streams");
+    assert_eq!(column(&app, "logStreamName"), ["2025/02/01/[$LATEST]abc123"]);
+    assert!(mock.sent().last().unwrap().contains(r#""logGroupName":"/aws/lambda/orders-api""#));
+
+    press(&mut app, KeyCode::Char('t')).await;
+    assert_eq!(app.mode, Mode::LogTail);
+    let state = app.log_tail_state.as_ref().unwrap();
+    assert_eq!(state.events.len(), 3);
+    assert!(state.events[1].message.contains("order created"));
+    assert_eq!(state.next_forward_token.as_deref(), Some("f/3809"));
+
+    // Enter on a structured line pretty-prints it; other lines have no JSON
+    press(&mut app, KeyCode::Char('k')).await;
+    press(&mut app, KeyCode::Enter).await;
+    let popup = app.log_tail_state.as_ref().unwrap().json_popup.clone().unwrap();
+    assert!(popup.contains("\n  \"orderId\": 42"), "{}", popup);
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.mode, Mode::LogTail);
+    press(&mut app, KeyCode::Char('k')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(app.log_tail_state.as_ref().unwrap().json_popup.is_none());
+
+    press(&mut app, KeyCode::Char('w')).await;
+    assert!(app.log_tail_state.as_ref().unwrap().wrap);
+}
+
+#[tokio::test]
+async fn test_log_group_tail() {
+    let mock = MockTransport::new(&[
+        ("DescribeLogGroups", LOGS_DESCRIBE_LOG_GROUPS),
+        ("FilterLogEvents", LOGS_FILTER_LOG_EVENTS),
+    ]);
+    let mut app = app_with(&mock);
+    command(&mut app, "cloudwatch-log-groups").await;
+
+    // Every stream of the group, polled without Live Tail
+    press(&mut app, KeyCode::Char('j')).await;
+    press(&mut app, KeyCode::Char('t')).await;
+    assert_eq!(app.mode, Mode::LogTail);
+    assert!(app.log_live_tail.is_none());
+    let state = app.log_tail_state.as_ref().unwrap();
+    assert!(state.log_streams.is_empty());
+    let streams: Vec<&str> = state.events.iter().map(|e| e.stream.as_str()).collect();
+    assert_eq!(streams, ["web/web/0a1b", "web/web/9f8e"]);
+    assert_eq!(state.since, Some(1738396802001));
+    assert!(mock.sent().last().unwrap().contains(r#""logGroupName":"/ecs/web""#));
+}
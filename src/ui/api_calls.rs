@@ -0,0 +1,80 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" API Calls[{}] ", app.api_calls.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.api_calls.is_empty() {
+        let empty = Paragraph::new("No API calls recorded yet.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let header_cells = [" TIME", "SERVICE", "OPERATION", "STATUS", "DURATION", "BYTES"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.api_calls.iter().map(|call| {
+        let status_color = if call.failed() { Color::Red } else { Color::Green };
+        let status = call.status.map_or_else(|| "-".to_string(), |s| s.to_string());
+        Row::new(vec![
+            Cell::from(format!(" {}", call.time)),
+            Cell::from(call.service.clone()),
+            Cell::from(call.operation.clone()),
+            Cell::from(status).style(Style::default().fg(status_color)),
+            Cell::from(format!("{}ms", call.duration.as_millis())),
+            Cell::from(call.bytes.to_string()),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(16),
+        Constraint::Min(20),
+        Constraint::Length(7),
+        Constraint::Length(10),
+        Constraint::Length(10),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.api_calls_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
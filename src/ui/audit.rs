@@ -0,0 +1,77 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" Audit[{}] ", app.audit_entries.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.audit_entries.is_empty() {
+        let empty = Paragraph::new(format!("No entries in {}.", crate::audit::audit_log_path().display()))
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let header_cells = [" TIME", "PROFILE", "REGION", "EVENT", "TARGET", "OUTCOME"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.audit_entries.iter().map(|entry| {
+        let outcome_color = if entry.outcome.starts_with("error") { Color::Red } else { Color::Green };
+        Row::new(vec![
+            Cell::from(format!(" {}", entry.timestamp)),
+            Cell::from(entry.profile.clone()).style(Style::default().fg(Color::Magenta)),
+            Cell::from(entry.region.clone()),
+            Cell::from(entry.event.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(entry.target.clone()),
+            Cell::from(entry.outcome.clone()).style(Style::default().fg(outcome_color)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(22),
+        Constraint::Length(16),
+        Constraint::Length(16),
+        Constraint::Length(32),
+        Constraint::Min(24),
+        Constraint::Min(12),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.audit_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
@@ -0,0 +1,71 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(picker) = &app.column_picker else {
+        return;
+    };
+
+    // Create bordered box with centered title
+    let name = app
+        .current_resource()
+        .map(|r| r.display_name.as_str())
+        .unwrap_or("");
+    let shown = picker.columns.iter().filter(|(_, shown)| *shown).count();
+    let title = format!(" Columns({})[{}/{}] ", name, shown, picker.columns.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let header_cells = [" SHOW", "COLUMN"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = picker.columns.iter().map(|(header, shown)| {
+        let (mark, color) = if *shown {
+            (" [x]", Color::Green)
+        } else {
+            (" [ ]", Color::DarkGray)
+        };
+        Row::new(vec![
+            Cell::from(mark).style(Style::default().fg(color)),
+            Cell::from(header.clone()).style(Style::default().fg(color)),
+        ])
+    });
+
+    let widths = [Constraint::Length(8), Constraint::Min(20)];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(picker.selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
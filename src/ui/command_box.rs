@@ -21,9 +21,11 @@ pub fn render(f: &mut Frame, app: &App) {
         ])
         .split(area);
 
-    // Input box - show total resource count
-    let total_count = app.get_available_commands().len();
-    let title = format!(" Resource Types ({}) ", total_count);
+    // Input box - show total resource count (or history matches while searching)
+    let title = match &app.history_search {
+        Some(_) => format!(" History ({}) - Ctrl+R: older match ", app.command_suggestions.len()),
+        None => format!(" Resource Types ({}) ", app.get_available_commands().len()),
+    };
     let input_block = Block::default()
         .title(title)
         .title_style(
@@ -35,7 +37,12 @@ pub fn render(f: &mut Frame, app: &App) {
         .border_style(Style::default().fg(Color::Cyan));
 
     // Build input with ghost text preview
-    let input_line = if let Some(preview) = &app.command_preview {
+    let input_line = if let Some(query) = &app.history_search {
+        Line::from(vec![
+            Span::styled("history> ", Style::default().fg(Color::Yellow)),
+            Span::styled(query, Style::default().fg(Color::White)),
+        ])
+    } else if let Some(preview) = &app.command_preview {
         // Show typed text in white, remaining preview in dark gray (ghost)
         let typed = &app.command_text;
         if preview.starts_with(typed) && preview.len() > typed.len() {
@@ -0,0 +1,120 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+/// Height of the monthly totals chart (including its border)
+const CHART_HEIGHT: u16 = 12;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(report) = &app.costs else {
+        return;
+    };
+
+    let title = format!(" Costs[{}] ({}) ", report.group_label.to_lowercase(), report.unit);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if report.rows.is_empty() {
+        let empty = Paragraph::new("No spend recorded for this period.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(CHART_HEIGHT), Constraint::Min(1)])
+        .split(inner_area);
+
+    // Monthly totals, one bar per month
+    let bars: Vec<Bar> = report
+        .months
+        .iter()
+        .zip(&report.totals)
+        .map(|(month, total)| {
+            Bar::default()
+                .value(total.max(0.0).round() as u64)
+                .text_value(format_amount(*total))
+                .label(month.clone())
+        })
+        .collect();
+    let bar_width = report
+        .totals
+        .iter()
+        .map(|t| format_amount(*t).len())
+        .max()
+        .unwrap_or(8)
+        .max(8) as u16;
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(" Monthly total ")
+                .title_alignment(Alignment::Center),
+        )
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(2)
+        .bar_style(Style::default().fg(Color::Cyan))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .label_style(Style::default().fg(Color::Yellow));
+    f.render_widget(chart, chunks[0]);
+
+    // Per-key breakdown
+    let mut headers = vec![format!(" {}", report.group_label)];
+    headers.extend(report.months.iter().cloned());
+    headers.push("TOTAL".to_string());
+    let header_cells = headers.into_iter().map(|h| {
+        Cell::from(h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+    let header = Row::new(header_cells).height(1);
+
+    let rows = report.rows.iter().map(|row| {
+        let mut cells = vec![Cell::from(format!(" {}", row.key))];
+        cells.extend(row.amounts.iter().map(|a| Cell::from(format_amount(*a))));
+        cells.push(Cell::from(format_amount(row.total)).style(Style::default().fg(Color::Green)));
+        Row::new(cells)
+    });
+
+    let mut widths = vec![Constraint::Min(30)];
+    widths.extend(report.months.iter().map(|_| Constraint::Length(10)));
+    widths.push(Constraint::Length(12));
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.costs_selected));
+
+    f.render_stateful_widget(table, chunks[1], &mut state);
+}
+
+fn format_amount(amount: f64) -> String {
+    format!("{:.2}", amount)
+}
@@ -12,6 +12,9 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Confirm => render_confirm_dialog(f, app),
         Mode::Warning => render_warning_dialog(f, app),
         Mode::SsoLogin => render_sso_dialog(f, app),
+        Mode::Mfa => render_mfa_dialog(f, app),
+        Mode::Input => render_input_dialog(f, app),
+        Mode::Secret => render_secret_dialog(f, app),
         _ => {}
     }
 }
@@ -21,7 +24,8 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
         return;
     };
 
-    let area = centered_rect(60, 9, f.area());
+    let height = if pending.expected_text.is_some() { 10 } else { 9 };
+    let area = centered_rect(60, height, f.area());
 
     f.render_widget(Clear, area);
 
@@ -52,7 +56,7 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
     };
 
     // Build the dialog content
-    let text = vec![
+    let mut text = vec![
         Line::from(Span::styled(
             format!("<{}>", title),
             Style::default()
@@ -65,12 +69,28 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
             Style::default().fg(Color::White),
         )),
         Line::from(""),
-        Line::from(vec![
+    ];
+    if let Some(expected) = &pending.expected_text {
+        // Typed confirmation replaces the buttons with an input line
+        let matches = pending.typed_text == *expected;
+        text.push(Line::from(Span::styled(
+            format!("Type '{}' and press Enter to confirm (Esc to cancel)", expected),
+            Style::default().fg(Color::DarkGray),
+        )));
+        text.push(Line::from(vec![
+            Span::styled(
+                pending.typed_text.clone(),
+                Style::default().fg(if matches { Color::Green } else { Color::White }),
+            ),
+            Span::styled("█", Style::default().fg(Color::Cyan)),
+        ]));
+    } else {
+        text.push(Line::from(vec![
             Span::styled(" Cancel ", cancel_style),
             Span::raw("    "),
             Span::styled(" OK ", ok_style),
-        ]),
-    ];
+        ]));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -122,6 +142,170 @@ fn render_warning_dialog(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+fn render_input_dialog(f: &mut Frame, app: &App) {
+    let Some(prompt) = &app.input_prompt else {
+        return;
+    };
+
+    let area = centered_rect(60, 8, f.area());
+
+    f.render_widget(Clear, area);
+
+    let masked = prompt.action.prompt.as_ref().is_some_and(|p| p.masked);
+    let input_text = if masked {
+        "*".repeat(prompt.text.chars().count())
+    } else {
+        prompt.text.clone()
+    };
+
+    let text = vec![
+        Line::from(Span::styled(
+            format!("<{}>", prompt.action.display_name),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            &prompt.message,
+            Style::default().fg(Color::White),
+        )),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{}_", input_text), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter: submit | Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_secret_dialog(f: &mut Frame, app: &App) {
+    let Some(dialog) = &app.secret_dialog else {
+        return;
+    };
+
+    let area = centered_rect(70, 9 + dialog.fields.len() as u16, f.area());
+
+    f.render_widget(Clear, area);
+
+    let secret_text = if dialog.revealed {
+        dialog.secret.clone()
+    } else {
+        "*".repeat(dialog.secret.chars().count().min(40))
+    };
+
+    let mut text = vec![
+        Line::from(Span::styled(
+            format!("<{}>", dialog.title),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for (label, value) in &dialog.fields {
+        text.push(Line::from(vec![
+            Span::styled(format!("{}: ", label), Style::default().fg(Color::DarkGray)),
+            Span::styled(value.as_str(), Style::default().fg(Color::White)),
+        ]));
+    }
+    text.push(Line::from(vec![
+        Span::styled(format!("{}: ", dialog.secret_label), Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            secret_text,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    text.push(Line::from(""));
+    if let Some(note) = &dialog.note {
+        text.push(Line::from(Span::styled(
+            note.as_str(),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(
+        "y: reveal/hide | c: copy | Esc: close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_mfa_dialog(f: &mut Frame, app: &App) {
+    let Some(state) = &app.mfa_state else {
+        return;
+    };
+
+    let area = centered_rect(60, 10, f.area());
+
+    f.render_widget(Clear, area);
+
+    let mut text = vec![
+        Line::from(Span::styled(
+            "<MFA Required>",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Enter the MFA code for profile '{}'", state.profile),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(Span::styled(
+            &state.mfa_serial,
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{}_", state.code), Style::default().fg(Color::White)),
+        ]),
+    ];
+    if let Some(error) = &state.error {
+        text.push(Line::from(Span::styled(error.as_str(), Style::default().fg(Color::Red))));
+    } else {
+        text.push(Line::from(""));
+    }
+    text.push(Line::from(Span::styled(
+        "Enter: submit | Esc: cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
+
 fn render_sso_dialog(f: &mut Frame, app: &App) {
     let Some(ref sso_state) = app.sso_state else {
         return;
@@ -0,0 +1,62 @@
+use crate::app::App;
+use crate::diff::DiffKind;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let title = format!(" Diff: {} ", app.diff_title);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.diff_lines.is_empty() {
+        let same = Paragraph::new("No differences.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(same, inner_area);
+        return;
+    }
+
+    // Removed lines come from the marked item, added lines from the selected one
+    let lines: Vec<Line> = app
+        .diff_lines
+        .iter()
+        .map(|line| match line.kind {
+            DiffKind::Same => Line::from(format!("  {}", line.text)),
+            DiffKind::Removed => Line::styled(format!("- {}", line.text), Style::default().fg(Color::Red)),
+            DiffKind::Added => Line::styled(format!("+ {}", line.text), Style::default().fg(Color::Green)),
+            DiffKind::Gap => Line::styled(line.text.clone(), Style::default().fg(Color::DarkGray)),
+        })
+        .collect();
+
+    let total_lines = lines.len();
+    let visible_lines = inner_area.height as usize;
+    let max_scroll = total_lines.saturating_sub(visible_lines);
+    let scroll = app.diff_scroll.min(max_scroll);
+
+    let paragraph = Paragraph::new(lines).scroll((scroll as u16, 0));
+    f.render_widget(paragraph, inner_area);
+
+    // Render scrollbar if content exceeds visible area
+    if total_lines > visible_lines {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"));
+        let mut scrollbar_state = ScrollbarState::new(max_scroll + visible_lines).position(scroll);
+        f.render_stateful_widget(scrollbar, inner_area, &mut scrollbar_state);
+    }
+}
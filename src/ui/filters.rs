@@ -0,0 +1,73 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let filters = app.saved_filters();
+
+    // Create bordered box with centered title
+    let name = app
+        .current_resource()
+        .map(|r| r.display_name.as_str())
+        .unwrap_or("");
+    let title = format!(" Filters({})[{}] ", name, filters.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let header_cells = [" NAME", "FILTER", "AUTO"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = filters.iter().map(|saved| {
+        let (auto, auto_color) = if saved.auto {
+            ("yes", Color::Green)
+        } else {
+            ("-", Color::DarkGray)
+        };
+        Row::new(vec![
+            Cell::from(format!(" {}", saved.name)).style(Style::default().fg(Color::Magenta)),
+            Cell::from(saved.filter.clone()),
+            Cell::from(auto).style(Style::default().fg(auto_color)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(65),
+        Constraint::Percentage(10),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.filters_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
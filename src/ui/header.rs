@@ -33,12 +33,48 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         .map(|r| r.display_name.as_str())
         .unwrap_or(&app.current_resource_key);
 
+    let mut profile_spans = vec![
+        Span::styled("Profile:", Style::default().fg(Color::DarkGray)),
+        Span::raw(" "),
+        Span::styled(
+            &app.profile,
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    // Show the role hop (e.g. into an Organizations member account)
+    if let Some(role_arn) = &app.clients.assumed_role {
+        let role = role_arn.split(':').skip(4).collect::<Vec<_>>().join(":");
+        profile_spans.push(Span::styled(
+            format!(" → {}", role),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    // Remaining lifetime of temporary credentials (SSO, assumed role, IMDS)
+    if let Some(remaining) = app.credentials_remaining() {
+        profile_spans.push(Span::styled(
+            format!(" [{}]", format_remaining(remaining)),
+            Style::default().fg(expiry_color(remaining)),
+        ));
+    }
+
+    // Account of the current credentials, with its alias
+    let account = match &app.identity {
+        Some(identity) => match &identity.alias {
+            Some(alias) => format!("{} ({})", identity.account, alias),
+            None => identity.account.clone(),
+        },
+        None => "…".to_string(),
+    };
+
     let mut lines = vec![
+        Line::from(profile_spans),
         Line::from(vec![
-            Span::styled("Profile:", Style::default().fg(Color::DarkGray)),
+            Span::styled("Account:", Style::default().fg(Color::DarkGray)),
             Span::raw(" "),
             Span::styled(
-                &app.profile,
+                account,
                 Style::default()
                     .fg(Color::Magenta)
                     .add_modifier(Modifier::BOLD),
@@ -48,7 +84,11 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("Region: ", Style::default().fg(Color::DarkGray)),
             Span::raw(" "),
             Span::styled(
-                &app.region,
+                if app.all_regions {
+                    format!("all ({})", app.available_regions.len())
+                } else {
+                    app.region.clone()
+                },
                 Style::default()
                     .fg(Color::Magenta)
                     .add_modifier(Modifier::BOLD),
@@ -89,7 +129,7 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
     }
 
     // Show custom endpoint indicator
-    if app.endpoint_url.is_some() {
+    if app.endpoint_url.is_some() || !app.config.endpoints.is_empty() {
         lines.push(Line::from(vec![
             Span::styled("Endpoint:", Style::default().fg(Color::DarkGray)),
             Span::styled(
@@ -101,10 +141,60 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         ]));
     }
 
+    // API calls of the last refresh cycle, to spot auto-refresh hammering the account
+    let stats = crate::aws::api_log::cycle_stats();
+    if lines.len() < area.height as usize {
+        let throttled_color = if stats.throttled > 0 { Color::Red } else { Color::DarkGray };
+        lines.push(Line::from(vec![
+            Span::styled("API:     ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{} calls, avg {}ms, ", stats.calls, stats.average().as_millis()),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(format!("{} throttled", stats.throttled), Style::default().fg(throttled_color)),
+        ]));
+    }
+
+    // Caller principal, e.g. "assumed-role/Admin/alice", when there's room
+    if let Some(identity) = &app.identity
+        && lines.len() < area.height as usize
+    {
+        let caller = identity.arn.splitn(6, ':').nth(5).unwrap_or(&identity.arn);
+        lines.push(Line::from(vec![
+            Span::styled("Caller:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled(caller.to_string(), Style::default().fg(Color::White)),
+        ]));
+    }
+
     let paragraph = Paragraph::new(lines);
     f.render_widget(paragraph, area);
 }
 
+/// Compact remaining time, e.g. "7h12m", "42m", "3m05s" or "expired"
+fn format_remaining(remaining: chrono::TimeDelta) -> String {
+    let secs = remaining.num_seconds();
+    if secs <= 0 {
+        "expired".to_string()
+    } else if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Warn when credentials are about to expire
+fn expiry_color(remaining: chrono::TimeDelta) -> Color {
+    if remaining <= chrono::TimeDelta::minutes(2) {
+        Color::Red
+    } else if remaining <= chrono::TimeDelta::minutes(10) {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    }
+}
+
 fn render_shortcuts_column(f: &mut Frame, app: &App, area: Rect) {
     // If current resource has sub-resources, show those as shortcuts
     // Otherwise show region shortcuts
@@ -119,17 +209,17 @@ fn render_shortcuts_column(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_region_shortcuts(f: &mut Frame, app: &App, area: Rect) {
-    let regions = [
-        ("0", "us-east-1"),
-        ("1", "us-west-2"),
-        ("2", "eu-west-1"),
-        ("3", "eu-central-1"),
-        ("4", "ap-northeast-1"),
-        ("5", "ap-southeast-1"),
-    ];
+    let shortcuts = app.config.region_shortcuts();
+    // Keep to the header's height, the rest are listed in help
+    let shown = if shortcuts.len() > area.height as usize {
+        area.height.saturating_sub(1) as usize
+    } else {
+        shortcuts.len()
+    };
 
-    let lines: Vec<Line> = regions
+    let mut lines: Vec<Line> = shortcuts
         .iter()
+        .take(shown)
         .map(|(key, region)| {
             let is_current = *region == app.region;
             let style = if is_current {
@@ -143,11 +233,18 @@ fn render_region_shortcuts(f: &mut Frame, app: &App, area: Rect) {
             Line::from(vec![
                 Span::styled(format!("<{}>", key), Style::default().fg(Color::Yellow)),
                 Span::raw(" "),
-                Span::styled(*region, style),
+                Span::styled(region.to_string(), style),
             ])
         })
         .collect();
 
+    if shortcuts.len() > shown {
+        lines.push(Line::from(Span::styled(
+            format!("  +{} more", shortcuts.len() - shown),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
     let paragraph = Paragraph::new(lines);
     f.render_widget(paragraph, area);
 }
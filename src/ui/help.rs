@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::keys::KeyAction;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -7,58 +8,121 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, _app: &App) {
+pub fn render(f: &mut Frame, app: &App) {
     let area = centered_rect(60, 70, f.area());
 
     f.render_widget(Clear, area);
 
-    let help_text = vec![
+    // Keys as remapped in the config
+    let keys = |action| app.keymap.label(action);
+    let shortcuts = app.config.region_shortcuts();
+
+    let mut help_text = vec![
         Line::from(""),
         create_section("Navigation"),
-        create_key_line("j / ↓", "Move down"),
-        create_key_line("k / ↑", "Move up"),
-        create_key_line("gg / Home", "Go to top"),
-        create_key_line("G / End", "Go to bottom"),
-        create_key_line("Ctrl+d", "Page down"),
-        create_key_line("Ctrl+u", "Page up"),
-        create_key_line("]", "Next page (load more)"),
-        create_key_line("[", "Previous page"),
+        create_key_line(keys(KeyAction::Down), "Move down"),
+        create_key_line(keys(KeyAction::Up), "Move up"),
+        create_key_line(format!("gg / {}", keys(KeyAction::Top)), "Go to top"),
+        create_key_line(keys(KeyAction::Bottom), "Go to bottom"),
+        create_key_line(keys(KeyAction::PageDown), "Page down"),
+        create_key_line(keys(KeyAction::PageUp), "Page up"),
+        create_key_line(keys(KeyAction::NextPage), "Next page (load more)"),
+        create_key_line(keys(KeyAction::PrevPage), "Previous page"),
         Line::from(""),
         create_section("Views"),
-        create_key_line("d / Enter", "Show details panel"),
+        create_key_line(format!("{} / {}", keys(KeyAction::Describe), keys(KeyAction::Open)), "Show details panel"),
         create_key_line("J", "Show JSON view"),
-        create_key_line("?", "Toggle help"),
+        create_key_line(keys(KeyAction::SplitView), "Toggle split view (list + details)"),
+        create_key_line(keys(KeyAction::ZoomColumn), "Zoom column (show values in full)"),
+        create_key_line(format!("{} / {}", keys(KeyAction::ColumnLeft), keys(KeyAction::ColumnRight)), "Zoom previous/next column"),
+        create_key_line(keys(KeyAction::Columns), "Pick and reorder columns"),
+        create_key_line(format!("{} / {}", keys(KeyAction::Mark), keys(KeyAction::Diff)), "Mark item / diff with selected"),
+        create_key_line(keys(KeyAction::Tags), "Tags panel (add/edit/delete)"),
+        create_key_line(keys(KeyAction::Help), "Toggle help"),
+        Line::from(""),
+        create_section("Tabs"),
+        create_key_line(keys(KeyAction::NewTab), "New tab (copy of current)"),
+        create_key_line(keys(KeyAction::NextTab), "Next tab"),
+        create_key_line(keys(KeyAction::PrevTab), "Previous tab"),
+        create_key_line(keys(KeyAction::CloseTab), "Close tab"),
         Line::from(""),
-        create_section("EC2 Actions"),
-        create_key_line("s", "Start instance"),
-        create_key_line("S", "Stop instance"),
-        create_key_line("r", "Reboot instance"),
-        create_key_line("Ctrl+d", "Terminate instance"),
+        create_section("Region Shortcuts"),
+    ];
+    help_text.extend(shortcuts.iter().map(|(key, region)| create_key_line(key.to_string(), region)));
+    // Actions and sub-resources of the view help was opened from
+    if let Some(resource) = app.current_resource() {
+        let actions: Vec<_> = resource.actions.iter().filter(|a| a.shortcut.is_some()).collect();
+        if !actions.is_empty() {
+            help_text.push(Line::from(""));
+            help_text.push(create_section(&format!("{} Actions", resource.display_name)));
+            for action in actions {
+                let destructive = action.confirm.as_ref().is_some_and(|c| c.destructive);
+                help_text.push(create_action_line(
+                    app.keymap.action_label(&action.sdk_method, action.shortcut.as_deref()),
+                    &action.display_name,
+                    destructive,
+                ));
+            }
+        }
+        if !resource.sub_resources.is_empty() {
+            help_text.push(Line::from(""));
+            help_text.push(create_section("Sub-resources"));
+            for sub in &resource.sub_resources {
+                help_text.push(create_key_line(sub.shortcut.clone(), &sub.display_name));
+            }
+        }
+    }
+    help_text.extend([
         Line::from(""),
         create_section("Log Tail Mode"),
-        create_key_line("t", "Tail logs (on log stream)"),
+        create_key_line("t", "Tail logs (on log group or stream)"),
         create_key_line("j / k", "Scroll up/down"),
         create_key_line("G", "Go to bottom (live mode)"),
         create_key_line("g", "Go to top"),
         create_key_line("SPACE", "Pause/resume"),
+        create_key_line("w", "Wrap long lines"),
+        create_key_line("Enter", "Show line as JSON"),
         create_key_line("q / Esc", "Exit log tail"),
         Line::from(""),
         create_section("Auto-refresh"),
         create_key_line("", "List refreshes every 5s"),
+        create_key_line(keys(KeyAction::Refresh), "Refresh now (skip cache)"),
         Line::from(""),
         create_section("Modes"),
-        create_key_line("/", "Filter mode"),
-        create_key_line(":", "Resources mode"),
+        create_key_line(keys(KeyAction::Filter), "Filter mode"),
+        create_key_line(keys(KeyAction::Command), "Resources mode"),
+        create_key_line(": Up / Ctrl+r", "Command history / search"),
+        create_key_line(keys(KeyAction::Palette), "Fuzzy palette"),
+        create_key_line(keys(KeyAction::Bookmark), "Bookmark highlighted row"),
+        create_key_line(keys(KeyAction::Jumps), "Bookmarks and recent views"),
+        create_key_line(keys(KeyAction::Watch), "Watch row for state changes"),
         Line::from(""),
         create_section("Resources"),
         create_key_line(":ec2", "EC2 instances view"),
         create_key_line(":vpc", "VPC view"),
         create_key_line(":profiles", "List AWS profiles"),
         create_key_line(":regions", "List AWS regions"),
+        create_key_line(":regions all", "Toggle all-regions view"),
+        create_key_line(":tunnels", "Active port forwards"),
+        create_key_line(":costs", "Monthly spend by service"),
+        create_key_line(":find tag:K=V", "Find resources by tag"),
+        create_key_line(":search <query>", "Resource Explorer search"),
+        create_key_line(":goto <arn-or-id>", "Open and describe an item"),
+        create_key_line(":sso sessions", "Cached SSO tokens"),
+        create_key_line(":sso logout", "Delete SSO token"),
+        create_key_line(":audit", "Audit log of changes"),
+        create_key_line(":notifications", "Past toasts and errors"),
+        create_key_line(":error", "Details of the last AWS error"),
+        create_key_line(":api", "Recent AWS API calls"),
+        create_key_line(":metrics", "EC2/RDS utilization columns"),
+        create_key_line(":keybar", "Keys of the current view at the bottom"),
+        create_key_line(":filter-save <name>", "Save the current filter"),
+        create_key_line(":filters", "Apply a saved filter"),
+        create_key_line(":bookmark", "Bookmark the current view"),
         Line::from(""),
-        create_key_line("Esc", "Close / Cancel"),
+        create_key_line("Esc", "Close / Cancel (also a loading list)"),
         create_key_line("Ctrl+c", "Quit application"),
-    ];
+    ]);
 
     let block = Block::default()
         .title(" Help ")
@@ -75,7 +139,7 @@ pub fn render(f: &mut Frame, _app: &App) {
     f.render_widget(paragraph, area);
 }
 
-fn create_section(title: &str) -> Line<'_> {
+fn create_section(title: &str) -> Line<'static> {
     Line::from(vec![Span::styled(
         format!("  {} ", title),
         Style::default()
@@ -84,7 +148,9 @@ fn create_section(title: &str) -> Line<'_> {
     )])
 }
 
-fn create_key_line<'a>(key: &'a str, description: &'a str) -> Line<'a> {
+/// A resource action, in red when it is destructive
+fn create_action_line(key: String, name: &str, destructive: bool) -> Line<'_> {
+    let color = if destructive { Color::Red } else { Color::White };
     Line::from(vec![
         Span::raw("    "),
         Span::styled(
@@ -94,6 +160,20 @@ fn create_key_line<'a>(key: &'a str, description: &'a str) -> Line<'a> {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
+        Span::styled(name, Style::default().fg(color)),
+    ])
+}
+
+fn create_key_line(key: impl Into<String>, description: &str) -> Line<'_> {
+    Line::from(vec![
+        Span::raw("    "),
+        Span::styled(
+            format!("{:>15}", key.into()),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
         Span::styled(description, Style::default().fg(Color::White)),
     ])
 }
@@ -0,0 +1,69 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let entries = app.jump_entries();
+
+    // Create bordered box with centered title
+    let title = format!(" Jumps({}@{})[{}] ", app.profile, app.region, entries.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let header_cells = [" KIND", "LOCATION", "RESOURCE"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = entries.iter().map(|(bookmark, location)| {
+        let (kind, kind_color) = if *bookmark {
+            ("bookmark", Color::Magenta)
+        } else {
+            ("recent", Color::DarkGray)
+        };
+        Row::new(vec![
+            Cell::from(format!(" {}", kind)).style(Style::default().fg(kind_color)),
+            Cell::from(location.label.clone()),
+            Cell::from(location.resource_key.clone()).style(Style::default().fg(Color::DarkGray)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Percentage(12),
+        Constraint::Percentage(63),
+        Constraint::Percentage(25),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.jumps_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
@@ -0,0 +1,105 @@
+//! Bottom bar listing the keys that work in the current view (`:keybar`)
+
+use crate::app::{App, Mode};
+use crate::keys::KeyAction;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let mut spans = Vec::new();
+    for (key, description, destructive) in hints(app) {
+        spans.push(Span::styled(
+            format!(" <{}>", key),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        let color = if destructive { Color::Red } else { Color::DarkGray };
+        spans.push(Span::styled(format!(" {}", description), Style::default().fg(color)));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// (key, description, destructive) for the current mode and resource
+fn hints(app: &App) -> Vec<(String, String, bool)> {
+    let fixed = |pairs: &[(&str, &str)]| -> Vec<(String, String, bool)> {
+        pairs.iter().map(|(k, d)| (k.to_string(), d.to_string(), false)).collect()
+    };
+    let keys = |action| app.keymap.label(action);
+
+    match app.mode {
+        Mode::Normal if app.filter_active => fixed(&[("enter", "Apply"), ("esc", "Clear")]),
+        Mode::Normal => {
+            let mut hints = vec![(keys(KeyAction::Describe), "Describe".to_string(), false)];
+            if let Some(resource) = app.current_resource() {
+                for action in &resource.actions {
+                    if action.shortcut.is_none() || (app.readonly && !action.read_only) {
+                        continue;
+                    }
+                    let destructive = action.confirm.as_ref().is_some_and(|c| c.destructive);
+                    let key = app.keymap.action_label(&action.sdk_method, action.shortcut.as_deref());
+                    hints.push((key, action.display_name.clone(), destructive));
+                }
+                for sub in &resource.sub_resources {
+                    hints.push((sub.shortcut.clone(), sub.display_name.clone(), false));
+                }
+            }
+            hints.push((keys(KeyAction::Filter), "Filter".to_string(), false));
+            hints.push((keys(KeyAction::Command), "Command".to_string(), false));
+            if app.parent_context.is_some() {
+                hints.push(("esc".to_string(), "Back".to_string(), false));
+            }
+            hints.push((keys(KeyAction::Help), "Help".to_string(), false));
+            hints
+        }
+        Mode::Describe => fixed(&[
+            ("j/k", "Move"),
+            ("enter", "Fold"),
+            ("E/C", "Expand/collapse all"),
+            ("/", "Search"),
+            ("n/N", "Next/prev match"),
+            (".", "Query"),
+            ("y", "YAML"),
+            ("esc", "Back"),
+        ]),
+        Mode::LogTail if app.log_tail_state.as_ref().is_some_and(|state| state.json_popup.is_some()) => {
+            fixed(&[("j/k", "Scroll"), ("esc", "Close")])
+        }
+        Mode::LogTail => fixed(&[
+            ("j/k", "Scroll"),
+            ("G", "Follow"),
+            ("space", "Pause"),
+            ("w", "Wrap"),
+            ("enter", "JSON"),
+            ("esc", "Exit"),
+        ]),
+        Mode::Diff => fixed(&[("j/k", "Scroll"), ("esc", "Back")]),
+        Mode::Profiles => fixed(&[("enter", "Switch"), ("a", "Resolve account"), ("esc", "Back")]),
+        Mode::Regions => fixed(&[("enter", "Switch"), ("esc", "Back")]),
+        Mode::Jumps => fixed(&[("enter", "Go"), ("x", "Delete bookmark"), ("esc", "Back")]),
+        Mode::Filters => fixed(&[("enter", "Apply"), ("a", "Auto-apply"), ("x", "Delete"), ("esc", "Back")]),
+        Mode::Tunnels => fixed(&[("x", "Stop"), ("esc", "Back")]),
+        Mode::SsoSessions => fixed(&[("x", "Delete token"), ("esc", "Back")]),
+        Mode::Tags => fixed(&[("a", "Add"), ("e", "Edit"), ("x", "Delete"), ("esc", "Back")]),
+        Mode::Columns => fixed(&[
+            ("space", "Show/hide"),
+            ("J/K", "Move"),
+            ("r", "Reset"),
+            ("enter", "Save"),
+            ("esc", "Cancel"),
+        ]),
+        Mode::Notifications => fixed(&[("enter", "Error details"), ("c", "Clear"), ("esc", "Back")]),
+        Mode::ApiCalls => fixed(&[
+            ("enter", "Request/response"),
+            ("r", "Reload"),
+            ("c", "Clear"),
+            ("esc", "Back"),
+        ]),
+        _ => fixed(&[("esc", "Back")]),
+    }
+}
@@ -1,70 +1,154 @@
+mod api_calls;
+mod audit;
+mod columns;
 mod command_box;
+mod costs;
 mod dialog;
+mod diff;
+mod filters;
 mod header;
 mod help;
+mod jumps;
+mod key_bar;
+mod notifications;
+mod palette;
 mod profiles;
 mod regions;
 pub mod splash;
+mod sso_sessions;
+mod tags;
+mod toasts;
+mod tunnels;
 
 use crate::app::{App, Mode};
-use crate::resource::{extract_json_value, get_color_for_value, ColumnDef};
+use crate::resource::{extract_json_value, format_relative, format_timestamp_text, get_color_for_value, ColumnDef};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
-        Table, TableState,
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, TableState, Tabs,
     },
     Frame,
 };
 
+/// Characters kept of the other columns while one column is zoomed
+const ZOOMED_OUT_LEN: usize = 12;
+
 pub fn render(f: &mut Frame, app: &App) {
+    // Key fields of the highlighted row, shown above the crumb in the list view
+    let preview = match app.mode {
+        Mode::Normal => app.selected_item().map(row_preview).filter(|p| !p.is_empty()),
+        _ => None,
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(6), // Header (multi-line)
             Constraint::Min(1),    // Main content (table or describe)
+            Constraint::Length(u16::from(preview.is_some())), // Row preview
             Constraint::Length(1), // Footer/crumb
+            Constraint::Length(u16::from(app.config.key_bar)), // Key bar
         ])
         .split(f.area());
 
     // Header - multi-line with context info
     header::render(f, app, chunks[0]);
 
+    // Workspace tab bar, only when more than one tab is open
+    let main_area = if app.workspaces.len() > 1 {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(chunks[1]);
+        render_workspace_tabs(f, app, parts[0]);
+        parts[1]
+    } else {
+        chunks[1]
+    };
+
     // Main content - depends on mode and view
     match app.mode {
         Mode::Profiles => {
-            profiles::render(f, app, chunks[1]);
+            profiles::render(f, app, main_area);
         }
         Mode::Regions => {
-            regions::render(f, app, chunks[1]);
+            regions::render(f, app, main_area);
+        }
+        Mode::Tunnels => {
+            tunnels::render(f, app, main_area);
+        }
+        Mode::Costs => {
+            costs::render(f, app, main_area);
+        }
+        Mode::Tags => {
+            tags::render(f, app, main_area);
+        }
+        Mode::SsoSessions => {
+            sso_sessions::render(f, app, main_area);
+        }
+        Mode::Audit => {
+            audit::render(f, app, main_area);
+        }
+        Mode::Columns => {
+            columns::render(f, app, main_area);
+        }
+        Mode::Diff => {
+            diff::render(f, app, main_area);
+        }
+        Mode::Filters => {
+            filters::render(f, app, main_area);
+        }
+        Mode::Jumps => {
+            jumps::render(f, app, main_area);
+        }
+        Mode::Notifications => {
+            notifications::render(f, app, main_area);
+        }
+        Mode::ApiCalls => {
+            api_calls::render(f, app, main_area);
         }
         Mode::Describe => {
-            render_describe_view(f, app, chunks[1]);
+            render_describe_view(f, app, main_area);
         }
         Mode::LogTail => {
-            render_log_tail_view(f, app, chunks[1]);
+            render_log_tail_view(f, app, main_area);
         }
         _ => {
-            render_main_content(f, app, chunks[1]);
+            render_main_content(f, app, main_area);
         }
     }
 
+    if let Some(preview) = preview {
+        render_row_preview(f, &preview, chunks[2]);
+    }
+
     // Footer/crumb
-    render_crumb(f, app, chunks[2]);
+    render_crumb(f, app, chunks[3]);
+
+    if app.config.key_bar {
+        key_bar::render(f, app, chunks[4]);
+    }
+
+    // Running and finished actions, above the footer
+    toasts::render(f, app, main_area);
 
     // Overlays
     match app.mode {
         Mode::Help => {
             help::render(f, app);
         }
-        Mode::Confirm | Mode::Warning => {
+        Mode::Confirm | Mode::Warning | Mode::Input | Mode::Secret | Mode::SsoLogin | Mode::Mfa => {
             dialog::render(f, app);
         }
         Mode::Command => {
             command_box::render(f, app);
         }
+        Mode::Palette => {
+            palette::render(f, app);
+        }
         _ => {}
     }
 }
@@ -80,10 +164,70 @@ fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
             .split(area);
 
         render_filter_bar(f, app, chunks[0]);
-        render_dynamic_table(f, app, chunks[1]);
+        render_list_area(f, app, chunks[1]);
     } else {
+        render_list_area(f, app, area);
+    }
+}
+
+/// Render the table, next to the describe panel in split view
+fn render_list_area(f: &mut Frame, app: &App, area: Rect) {
+    if !app.split_view {
         render_dynamic_table(f, app, area);
+        return;
     }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    render_dynamic_table(f, app, chunks[0]);
+    render_split_describe(f, app, chunks[1]);
+}
+
+/// Describe panel for the highlighted row. Shows the list data until the
+/// (debounced) describe call for the row has returned.
+fn render_split_describe(f: &mut Frame, app: &App, area: Rect) {
+    let (data, loading) = match app.split_describe_data() {
+        Some(data) => (Some(data), false),
+        None => (app.selected_item(), app.split_pending.is_some() || app.split_task.is_some()),
+    };
+
+    let lines: Vec<Line> = match data {
+        Some(serde_json::Value::String(text)) => text.lines().map(highlight_yaml_line).collect(),
+        Some(data) => serde_json::to_string_pretty(data)
+            .unwrap_or_default()
+            .lines()
+            .map(highlight_json_line)
+            .collect(),
+        None => vec![Line::from(Span::styled(
+            "No item selected",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+
+    let name = app
+        .current_resource()
+        .map(|r| r.display_name.as_str())
+        .unwrap_or("");
+    let title = if loading {
+        format!(" {} Details (loading...) ", name)
+    } else {
+        format!(" {} Details ", name)
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, area);
 }
 
 fn render_filter_bar(f: &mut Frame, app: &App, area: Rect) {
@@ -115,7 +259,7 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
 
     // Build title with count, region info, and pagination
     let title = {
-        let count = app.filtered_items.len();
+        let count = app.filtered_indices.len();
         let total = app.items.len();
         let is_global = resource.is_global;
 
@@ -167,32 +311,69 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
+    let columns = app.table_columns();
+    let zoomed = app.zoomed_column();
+
     // Build header from column definitions with left padding
-    let header_cells = resource.columns.iter().map(|col| {
-        Cell::from(format!(" {}", col.header)).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )
+    let header_cells = columns.iter().enumerate().map(|(i, col)| {
+        let style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let style = if zoomed == Some(i) {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        };
+        Cell::from(format!(" {}", col.header)).style(style)
     });
     let header = Row::new(header_cells).height(1);
 
-    // Build rows from filtered items with left padding
-    let rows = app.filtered_items.iter().map(|item| {
-        let cells = resource.columns.iter().map(|col| {
+    // Only rows in the visible window are built, so huge lists stay cheap to draw.
+    // The window follows the selection (like the table widget's own scrolling).
+    let height = (inner_area.height as usize).saturating_sub(1).max(1);
+    let len = app.filtered_indices.len();
+    let mut offset = app.list_offset.get().min(len.saturating_sub(height));
+    if app.selected < offset {
+        offset = app.selected;
+    } else if app.selected >= offset + height {
+        offset = app.selected + 1 - height;
+    }
+    app.list_offset.set(offset);
+
+    // Build rows from filtered items with left padding; the item marked
+    // for comparison is shown in magenta
+    let rows = app.filtered_items().skip(offset).take(height).map(|item| {
+        let marked = app.is_diff_marked(&extract_json_value(item, &resource.id_field));
+        let cells = columns.iter().enumerate().map(|(i, col)| {
             let value = extract_json_value(item, &col.json_path);
-            let style = get_cell_style(&value, col);
+            let style = match &col.color_field {
+                Some(field) => get_cell_style(&extract_json_value(item, field), col),
+                None => get_cell_style(&value, col),
+            };
             let display_value = format_cell_value(&value, col);
-            Cell::from(format!(" {}", truncate_string(&display_value, 38))).style(style)
+            let max_len = match zoomed {
+                Some(z) if z == i => usize::MAX,
+                Some(_) => ZOOMED_OUT_LEN,
+                None => 38,
+            };
+            Cell::from(format!(" {}", truncate_string(&display_value, max_len))).style(style)
         });
-        Row::new(cells)
+        if marked {
+            Row::new(cells).style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))
+        } else {
+            Row::new(cells)
+        }
     });
 
-    // Build column widths
-    let widths: Vec<Constraint> = resource
-        .columns
+    // Build column widths; a zoomed column takes all the space the others leave
+    let widths: Vec<Constraint> = columns
         .iter()
-        .map(|col| Constraint::Percentage(col.width))
+        .enumerate()
+        .map(|(i, col)| match zoomed {
+            Some(z) if z == i => Constraint::Fill(1),
+            Some(_) => Constraint::Length(ZOOMED_OUT_LEN as u16 + 1),
+            None => Constraint::Percentage(col.width),
+        })
         .collect();
 
     let table = Table::new(rows, widths).header(header).row_highlight_style(
@@ -203,7 +384,7 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     );
 
     let mut state = TableState::default();
-    state.select(Some(app.selected));
+    state.select(Some(app.selected - offset));
 
     f.render_stateful_widget(table, inner_area, &mut state);
 }
@@ -220,6 +401,14 @@ fn get_cell_style(value: &str, col: &ColumnDef) -> Style {
 
 /// Format cell value, adding indicators for transitional states
 fn format_cell_value(value: &str, col: &ColumnDef) -> String {
+    if col.relative_time
+        && let Some(relative) = format_relative(value, chrono::Utc::now())
+    {
+        return relative;
+    }
+    if let Some(timestamp) = format_timestamp_text(value) {
+        return timestamp;
+    }
     // Check if this is a state/status column with transitional states
     if col.color_map.is_some() {
         let lower = value.to_lowercase();
@@ -235,7 +424,9 @@ fn format_cell_value(value: &str, col: &ColumnDef) -> String {
             || lower.contains("shutting-down")
             || lower.contains("terminating")
             || lower.contains("in-progress")
+            || lower.contains("in_progress")
             || lower.contains("initializing")
+            || lower.contains("backing-up")
         {
             return format!("{} ↻", value);
         }
@@ -253,19 +444,57 @@ fn truncate_string(s: &str, max_len: usize) -> String {
 }
 
 fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
-    let json = app
-        .selected_item_json()
-        .unwrap_or_else(|| "No item selected".to_string());
+    let tree = app.describe_lines();
 
-    // Apply JSON syntax highlighting
-    let lines: Vec<Line> = json.lines().map(|l| highlight_json_line(l)).collect();
+    // Apply JSON syntax highlighting (YAML and raw text documents get YAML-style
+    // highlighting), with a fold marker on every line that opens an object or array
+    let is_raw_text = app.describe_yaml || matches!(app.describe_document(), Some(serde_json::Value::String(_)));
+    let mut lines: Vec<Line> = if tree.is_empty() {
+        vec![Line::from("No item selected")]
+    } else if is_raw_text {
+        tree.iter().map(|l| highlight_yaml_line(&l.text)).collect()
+    } else {
+        tree.iter()
+            .map(|l| {
+                let marker = match &l.node {
+                    Some(node) if app.describe_collapsed.contains(node) => "▸ ",
+                    Some(_) => "▾ ",
+                    None => "  ",
+                };
+                let mut line = highlight_json_line(&l.text);
+                line.spans.insert(0, Span::styled(marker, Style::default().fg(Color::DarkGray)));
+                line
+            })
+            .collect()
+    };
     let total_lines = lines.len();
 
-    let title = if let Some(resource) = app.current_resource() {
+    // Cursor line of the tree
+    if let Some(line) = lines.get_mut(app.describe_cursor) {
+        *line = std::mem::take(line).style(Style::default().bg(Color::DarkGray));
+    }
+
+    // Mark search matches, the current one in a different color
+    let matches = app.describe_match_lines();
+    for (i, &line) in matches.iter().enumerate() {
+        if let Some(l) = lines.get_mut(line) {
+            let current = i == app.describe_match && !app.describe_search_active;
+            *l = highlight_matches(std::mem::take(l), &app.describe_search, current);
+        }
+    }
+
+    let title = if let Some(title) = &app.describe_title {
+        format!(" {} ", title)
+    } else if let Some(resource) = app.current_resource() {
         format!(" {} Details ", resource.display_name)
     } else {
         " Details ".to_string()
     };
+    // Name the expression the document is projected through
+    let title = match &app.describe_projection {
+        Some(_) => format!("{}| {} ", title, app.describe_query.trim()),
+        None => title,
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -282,6 +511,7 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
 
     // Calculate max scroll based on inner area (content area without borders)
     let visible_lines = inner_area.height as usize;
+    app.describe_height.set(visible_lines);
     let max_scroll = total_lines.saturating_sub(visible_lines);
     let scroll = app.describe_scroll.min(max_scroll);
 
@@ -308,13 +538,19 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
     };
 
     // Build title with stream info and status
-    let status = if state.paused { "PAUSED" } else { "LIVE" };
-    let status_color = if state.paused {
-        Color::Yellow
+    let (status, status_color) = if state.paused {
+        ("PAUSED", Color::Yellow)
+    } else if app.log_live_tail.is_some() {
+        ("LIVE", Color::Green)
     } else {
-        Color::Green
+        ("POLLING", Color::Green)
     };
-    let title = format!(" {} | {} ", state.log_stream, status);
+    let single_stream = state.log_streams.len() == 1;
+    let source = match state.log_streams.as_slice() {
+        [stream] => stream.clone(),
+        _ => format!("{} (all streams)", state.log_group),
+    };
+    let title = format!(" {} | {} ", source, status);
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -339,8 +575,10 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    // Build lines from log events with syntax highlighting
-    let lines: Vec<Line> = state
+    // Build one item per log event with syntax highlighting, wrapped to the
+    // pane width (less the scrollbar) if wrapping is on
+    let width = inner_area.width.saturating_sub(1) as usize;
+    let items: Vec<ListItem> = state
         .events
         .iter()
         .map(|event| {
@@ -366,35 +604,165 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(Color::White)
             };
 
-            Line::from(vec![
-                Span::styled(
-                    format!("[{}] ", timestamp),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::styled(message.trim_end().to_string(), msg_style),
-            ])
+            let mut spans = vec![Span::styled(
+                format!("[{}] ", timestamp),
+                Style::default().fg(Color::DarkGray),
+            )];
+            if !single_stream {
+                spans.push(Span::styled(format!("{} ", event.stream), Style::default().fg(Color::Cyan)));
+            }
+            spans.push(Span::styled(message.trim_end().to_string(), msg_style));
+            if state.wrap {
+                ListItem::new(wrap_spans(spans, width))
+            } else {
+                ListItem::new(Line::from(spans))
+            }
         })
         .collect();
 
-    let total_lines = lines.len();
-    let visible_lines = inner_area.height as usize;
-    let max_scroll = total_lines.saturating_sub(visible_lines);
-    let scroll = state.scroll.min(max_scroll);
-
-    let paragraph = Paragraph::new(lines.clone()).scroll((scroll as u16, 0));
-    f.render_widget(paragraph, inner_area);
+    let total_items = items.len();
+    let selected = state.scroll.min(total_items.saturating_sub(1));
+    let mut list_state = ListState::default().with_selected(Some(selected));
+    let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray));
+    f.render_stateful_widget(list, inner_area, &mut list_state);
 
     // Render scrollbar if content exceeds visible area
-    if total_lines > visible_lines {
+    if total_items > inner_area.height as usize {
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some("↑"))
             .end_symbol(Some("↓"));
-        let mut scrollbar_state = ScrollbarState::new(max_scroll + visible_lines).position(scroll);
+        let mut scrollbar_state = ScrollbarState::new(total_items).position(selected);
         f.render_stateful_widget(scrollbar, inner_area, &mut scrollbar_state);
     }
+
+    if let Some(ref json) = state.json_popup {
+        render_log_json_popup(f, json, state.json_popup_scroll, area);
+    }
+}
+
+/// Break styled spans into lines of at most `width` characters, and at newlines
+fn wrap_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Line<'static>> {
+    let width = width.max(1);
+    let mut lines = vec![Line::default()];
+    let mut used = 0;
+    for span in spans {
+        let mut chunk = String::new();
+        for c in span.content.chars() {
+            if c == '\n' || used == width {
+                if !chunk.is_empty() {
+                    lines.last_mut().unwrap().spans.push(Span::styled(std::mem::take(&mut chunk), span.style));
+                }
+                lines.push(Line::default());
+                used = 0;
+                if c == '\n' {
+                    continue;
+                }
+            }
+            chunk.push(c);
+            used += 1;
+        }
+        if !chunk.is_empty() {
+            lines.last_mut().unwrap().spans.push(Span::styled(chunk, span.style));
+        }
+    }
+    lines
+}
+
+/// The selected log line's message as pretty-printed JSON, over the log tail
+fn render_log_json_popup(f: &mut Frame, json: &str, scroll: usize, area: Rect) {
+    let popup = area.inner(Margin { horizontal: area.width / 10, vertical: area.height / 10 });
+    f.render_widget(Clear, popup);
+
+    let title = format!(" JSON ({}/{}) ", scroll + 1, json.lines().count());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let paragraph = Paragraph::new(json.to_string())
+        .block(block)
+        .style(Style::default().fg(Color::White))
+        .scroll((scroll as u16, 0));
+    f.render_widget(paragraph, popup);
+}
+
+/// Give every occurrence of `query` (ASCII case-insensitive) in the line a
+/// match background, splitting the syntax-highlighted spans as needed
+fn highlight_matches(line: Line<'static>, query: &str, current: bool) -> Line<'static> {
+    let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    let lower = text.to_ascii_lowercase();
+    let query = query.to_ascii_lowercase();
+    let ranges: Vec<(usize, usize)> = lower
+        .match_indices(&query)
+        .map(|(start, m)| (start, start + m.len()))
+        .collect();
+    let match_style = if current {
+        Style::default().bg(Color::LightRed).fg(Color::Black)
+    } else {
+        Style::default().bg(Color::Yellow).fg(Color::Black)
+    };
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for span in line.spans {
+        let content = span.content.as_ref();
+        let end = offset + content.len();
+        // Cut points inside this span: match starts and ends
+        let mut cuts: Vec<usize> = ranges
+            .iter()
+            .flat_map(|&(s, e)| [s, e])
+            .filter(|&cut| cut > offset && cut < end)
+            .collect();
+        cuts.push(end);
+        let mut start = offset;
+        for cut in cuts {
+            let piece = content[start - offset..cut - offset].to_string();
+            let in_match = ranges.iter().any(|&(s, e)| start >= s && start < e);
+            let style = if in_match { span.style.patch(match_style) } else { span.style };
+            spans.push(Span::styled(piece, style));
+            start = cut;
+        }
+        offset = end;
+    }
+    Line::from(spans)
 }
 
 /// Apply JSON syntax highlighting to a single line
+/// Simple YAML syntax highlighting: keys, comments and list markers
+fn highlight_yaml_line(line: &str) -> Line<'static> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+
+    if trimmed.starts_with('#') {
+        return Line::from(Span::styled(
+            line.to_string(),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let mut spans = vec![Span::raw(indent.to_string())];
+    let mut rest = trimmed;
+    if let Some(item) = rest.strip_prefix("- ") {
+        spans.push(Span::styled("- ", Style::default().fg(Color::Yellow)));
+        rest = item;
+    }
+
+    match rest.split_once(':') {
+        Some((key, value)) if !key.contains(' ') || key.starts_with('"') => {
+            spans.push(Span::styled(key.to_string(), Style::default().fg(Color::Cyan)));
+            spans.push(Span::styled(":", Style::default().fg(Color::White)));
+            spans.push(Span::styled(value.to_string(), Style::default().fg(Color::Green)));
+        }
+        _ => spans.push(Span::styled(rest.to_string(), Style::default().fg(Color::Green))),
+    }
+
+    Line::from(spans)
+}
+
 fn highlight_json_line(line: &str) -> Line<'static> {
     let mut spans: Vec<Span<'static>> = Vec::new();
     let mut chars = line.chars().peekable();
@@ -505,6 +873,104 @@ fn get_json_value_style(value: &str) -> Style {
     }
 }
 
+/// ARN, endpoint and tags of a list item, for the row preview footer
+fn row_preview(item: &serde_json::Value) -> Vec<(&'static str, String)> {
+    let Some(map) = item.as_object() else {
+        return Vec::new();
+    };
+    let mut fields = Vec::new();
+
+    // First top-level value that is an ARN
+    if let Some(arn) = map
+        .values()
+        .filter_map(|v| v.as_str())
+        .find(|v| v.starts_with("arn:"))
+    {
+        fields.push(("ARN", arn.to_string()));
+    }
+
+    // Endpoints, DNS names and URLs (RDS-style endpoints are objects)
+    let endpoint = map.iter().find_map(|(key, value)| {
+        let is_endpoint = key.contains("Endpoint")
+            || key.eq_ignore_ascii_case("DnsName")
+            || key.ends_with("Url");
+        if !is_endpoint {
+            return None;
+        }
+        match value {
+            serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+            serde_json::Value::Object(obj) => {
+                let address = obj.get("Address").and_then(|v| v.as_str())?;
+                Some(match obj.get("Port") {
+                    Some(serde_json::Value::String(port)) => format!("{}:{}", address, port),
+                    Some(port) => format!("{}:{}", address, port),
+                    None => address.to_string(),
+                })
+            }
+            _ => None,
+        }
+    });
+    if let Some(endpoint) = endpoint {
+        fields.push(("Endpoint", endpoint));
+    }
+
+    // Tags as a map or as a [{Key, Value}] list
+    let tags: Vec<String> = match map.get("Tags") {
+        Some(serde_json::Value::Object(tags)) => tags
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v.as_str().unwrap_or_default()))
+            .collect(),
+        Some(serde_json::Value::Array(tags)) => tags
+            .iter()
+            .filter_map(|tag| {
+                let key = tag.get("Key").and_then(|v| v.as_str())?;
+                let value = tag.get("Value").and_then(|v| v.as_str()).unwrap_or_default();
+                Some(format!("{}={}", key, value))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    if !tags.is_empty() {
+        fields.push(("Tags", tags.join(", ")));
+    }
+
+    fields
+}
+
+fn render_row_preview(f: &mut Frame, preview: &[(&str, String)], area: Rect) {
+    let mut spans = Vec::new();
+    for (i, (label, value)) in preview.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+        }
+        spans.push(Span::styled(
+            format!("{}: ", label),
+            Style::default().fg(Color::Yellow),
+        ));
+        spans.push(Span::styled(value.clone(), Style::default().fg(Color::White)));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_workspace_tabs(f: &mut Frame, app: &App, area: Rect) {
+    let titles: Vec<Line> = app
+        .workspace_labels()
+        .into_iter()
+        .enumerate()
+        .map(|(i, label)| Line::from(format!(" {}:{} ", i + 1, label)))
+        .collect();
+    let tabs = Tabs::new(titles)
+        .select(app.active_workspace)
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )
+        .divider("|");
+    f.render_widget(tabs, area);
+}
+
 fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
     // Build breadcrumb from navigation
     let breadcrumb = app.get_breadcrumb();
@@ -540,23 +1006,69 @@ fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
         String::new()
     };
 
-    let status_text = if let Some(err) = &app.error_message {
-        format!("Error: {}", err)
-    } else if app.loading {
+    let status_text = if app.loading {
         "Loading...".to_string()
+    } else if app.mode == Mode::Describe && app.describe_query_active {
+        format!("{}_ | Enter: apply | Esc: cancel", app.describe_query)
+    } else if app.mode == Mode::Describe && app.describe_search_active {
+        format!("/{}_ | Enter: search | Esc: cancel", app.describe_search)
+    } else if app.mode == Mode::Describe && !app.describe_search.is_empty() {
+        let matches = app.describe_match_lines().len();
+        let position = if matches == 0 { 0 } else { app.describe_match + 1 };
+        format!(
+            "/{} [{}/{}] | n/N: next/prev match | Esc: clear search",
+            app.describe_search, position, matches
+        )
     } else if app.mode == Mode::Describe {
-        "j/k: scroll | q/d/Esc: back".to_string()
+        "j/k: move | Enter/Space: fold | E/C: expand/collapse all | y: YAML/JSON | /: search | .: expression | q/d/Esc: back".to_string()
     } else if app.mode == Mode::LogTail {
-        "j/k: scroll | G: bottom (live) | g: top | SPACE: pause | q: exit".to_string()
+        "j/k: scroll | G: bottom (live) | g: top | SPACE: pause | w: wrap | Enter: JSON | q: exit".to_string()
+    } else if app.mode == Mode::Tunnels {
+        "j/k: select | x/Ctrl+d: stop tunnel | q/Esc: back".to_string()
+    } else if app.mode == Mode::SsoSessions {
+        "j/k: select | x/Ctrl+d: log out (delete token) | q/Esc: back".to_string()
+    } else if app.mode == Mode::Audit {
+        "j/k: select | g/G: newest/oldest | q/Esc: back".to_string()
+    } else if app.mode == Mode::Notifications {
+        "j/k: select | g/G: newest/oldest | Enter: details | c: clear | q/Esc: back".to_string()
+    } else if app.mode == Mode::ApiCalls {
+        "j/k: select | g/G: newest/oldest | Enter: request/response | r: reload | c: clear | q/Esc: back".to_string()
+    } else if app.mode == Mode::Diff {
+        "- marked | + selected | j/k: scroll | q/Esc: back".to_string()
+    } else if app.mode == Mode::Columns {
+        "j/k: select | Space: show/hide | J/K: move | r: reset | Enter: save | q/Esc: cancel".to_string()
+    } else if app.mode == Mode::Palette {
+        "type to search | Up/Down: select | Enter: go | Esc: cancel".to_string()
+    } else if app.mode == Mode::Jumps {
+        "j/k: select | Enter: go | x/Ctrl+d: delete bookmark | q/Esc: back".to_string()
+    } else if app.mode == Mode::Filters {
+        "j/k: select | Enter: apply | a: auto-apply on/off | x/Ctrl+d: delete | q/Esc: back".to_string()
+    } else if app.mode == Mode::Costs {
+        "j/k: select | q/Esc: back".to_string()
+    } else if let Some(state) = app.tags_state.as_ref().filter(|_| app.mode == Mode::Tags) {
+        if state.editing.is_some() {
+            "Type key=value | Enter: save | Esc: cancel".to_string()
+        } else if state.confirm_delete {
+            "Delete tag? y: yes | any other key: no".to_string()
+        } else {
+            "j/k: select | a: add | e/Enter: edit | x/Ctrl+d: delete | q/Esc: back".to_string()
+        }
     } else if app.filter_active {
-        "Type to filter | Enter: apply | Esc: clear".to_string()
+        "Type to filter (col=value, col!=value, !text, * wildcard) | Enter: apply | Esc: clear".to_string()
+    } else if let Some(mark) = app.diff_mark.as_ref().filter(|_| app.mode == Mode::Normal) {
+        format!(
+            "Marked {} | {}: compare with selected | {}: unmark{}{}",
+            mark.id,
+            app.keymap.label(crate::keys::KeyAction::Diff),
+            app.keymap.label(crate::keys::KeyAction::Mark),
+            shortcuts_hint,
+            pagination_hint
+        )
     } else {
         format!("{}{}", shortcuts_hint, pagination_hint)
     };
 
-    let style = if app.error_message.is_some() {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
-    } else if app.loading {
+    let style = if app.loading {
         Style::default().fg(Color::Yellow)
     } else {
         Style::default().fg(Color::DarkGray)
@@ -0,0 +1,76 @@
+use crate::app::App;
+use crate::notifications::Level;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" Notifications[{}] ", app.notifications.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.notifications.len() == 0 {
+        let empty = Paragraph::new("No notifications yet.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let header_cells = [" TIME", "LEVEL", "MESSAGE"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.notifications.history().map(|notification| {
+        let level_color = match notification.level {
+            Level::Info => Color::Cyan,
+            Level::Success => Color::Green,
+            Level::Error => Color::Red,
+        };
+        Row::new(vec![
+            Cell::from(format!(" {}", notification.time)),
+            Cell::from(notification.level.label()).style(Style::default().fg(level_color)),
+            Cell::from(notification.text.clone()),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(7),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.notifications_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
@@ -0,0 +1,100 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App) {
+    let Some(palette) = app.palette.as_ref() else {
+        return;
+    };
+    let area = centered_rect(60, 50, f.area());
+
+    f.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Query box
+            Constraint::Min(1),    // Matches
+        ])
+        .split(area);
+
+    let input_block = Block::default()
+        .title(format!(" Go to ({}) ", palette.matches.len()))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::raw("> "),
+        Span::styled(&palette.query, Style::default().fg(Color::White)),
+    ]))
+    .block(input_block);
+
+    f.render_widget(input, chunks[0]);
+
+    let matches_block = Block::default()
+        .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let inner_height = matches_block.inner(chunks[1]).height as usize;
+
+    // Keep the selected entry visible
+    let scroll_offset = (palette.selected + 1).saturating_sub(inner_height);
+
+    let lines: Vec<Line> = palette
+        .matches
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+        .map(|(i, entry)| {
+            let selected = i == palette.selected;
+            let style = |color: Color| {
+                if selected {
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(color)
+                }
+            };
+            Line::from(vec![
+                Span::styled(format!("  {:<9}", entry.kind), style(Color::Yellow)),
+                Span::styled(entry.label.clone(), style(Color::White)),
+                Span::styled(format!("  {}", entry.detail), style(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(matches_block), chunks[1]);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -1,6 +1,6 @@
 use crate::app::App;
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Rect},
     style::{Color, Modifier, Style},
     text::Span,
     widgets::{Block, Borders, Cell, Row, Table, TableState},
@@ -9,7 +9,7 @@ use ratatui::{
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Create bordered box with centered title
-    let title = format!(" Profiles[{}] ", app.available_profiles.len());
+    let title = format!(" Profiles[{}] <a> account ", app.available_profiles.len());
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::DarkGray))
@@ -24,7 +24,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let header_cells = [" PROFILE"].iter().map(|h| {
+    let header_cells = [" PROFILE", "REGION", "AUTH", "SSO SESSION", "ACCOUNT"].iter().map(|h| {
         Cell::from(*h).style(
             Style::default()
                 .fg(Color::Yellow)
@@ -47,12 +47,36 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             "   "
         };
 
+        let details = app.profile_details.get(profile).cloned().unwrap_or_default();
+        // Resolved with `a`, else what the config says
+        let account = if let Some(identity) = app.profile_accounts.get(profile) {
+            match &identity.alias {
+                Some(alias) => format!("{} ({})", identity.account, alias),
+                None => identity.account.clone(),
+            }
+        } else if app.profile_lookups.iter().any(|(p, _)| p == profile) {
+            "resolving…".to_string()
+        } else {
+            details.account.unwrap_or_else(|| "-".to_string())
+        };
+        let dim = Style::default().fg(Color::DarkGray);
+
         Row::new(vec![
-            Cell::from(format!("{}{}", marker, profile)).style(style)
+            Cell::from(format!("{}{}", marker, profile)).style(style),
+            Cell::from(details.region.unwrap_or_else(|| "-".to_string())),
+            Cell::from(if details.auth.is_empty() { "-" } else { details.auth }).style(dim),
+            Cell::from(details.sso_session.unwrap_or_else(|| "-".to_string())).style(dim),
+            Cell::from(account),
         ])
     });
 
-    let widths = [ratatui::layout::Constraint::Percentage(100)];
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(15),
+        Constraint::Percentage(12),
+        Constraint::Percentage(18),
+        Constraint::Percentage(25),
+    ];
 
     let table = Table::new(rows, widths).header(header).row_highlight_style(
         Style::default()
@@ -24,7 +24,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let header_cells = [" REGION"].iter().map(|h| {
+    let header_cells = [" REGION", "OPT-IN"].iter().map(|h| {
         Cell::from(*h).style(
             Style::default()
                 .fg(Color::Yellow)
@@ -43,12 +43,23 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 
         let marker = if region == &app.region { " * " } else { "   " };
 
+        // Regions that had to be enabled for the account
+        let opt_in = match app.region_opt_in.get(region).map(String::as_str) {
+            Some("opted-in") => "opted in",
+            Some(_) => "default",
+            None => "-",
+        };
+
         Row::new(vec![
-            Cell::from(format!("{}{}", marker, region)).style(style)
+            Cell::from(format!("{}{}", marker, region)).style(style),
+            Cell::from(opt_in).style(Style::default().fg(Color::DarkGray)),
         ])
     });
 
-    let widths = [ratatui::layout::Constraint::Percentage(100)];
+    let widths = [
+        ratatui::layout::Constraint::Percentage(60),
+        ratatui::layout::Constraint::Percentage(40),
+    ];
 
     let table = Table::new(rows, widths).header(header).row_highlight_style(
         Style::default()
@@ -0,0 +1,84 @@
+use crate::app::App;
+use crate::resource::format_datetime;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" SSO Sessions[{}] ", app.sso_sessions.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.sso_sessions.is_empty() {
+        let empty = Paragraph::new("No cached SSO tokens in ~/.aws/sso/cache.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let header_cells = [" SESSION", "START URL", "REGION", "EXPIRES"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.sso_sessions.iter().map(|session| {
+        let name = if session.names.is_empty() {
+            "-".to_string()
+        } else {
+            session.names.join(", ")
+        };
+        let expires = match session.expires_at {
+            Some(t) if session.is_expired() => format!("{} (expired)", format_datetime(t)),
+            Some(t) => format_datetime(t),
+            None => "-".to_string(),
+        };
+        let expires_color = if session.is_expired() { Color::Red } else { Color::Green };
+        Row::new(vec![
+            Cell::from(format!(" {}", name)).style(Style::default().fg(Color::Magenta)),
+            Cell::from(session.start_url.clone()),
+            Cell::from(session.region.clone()),
+            Cell::from(expires).style(Style::default().fg(expires_color)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(24),
+        Constraint::Min(30),
+        Constraint::Length(16),
+        Constraint::Length(32),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.sso_sessions_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
@@ -0,0 +1,87 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(state) = &app.tags_state else {
+        return;
+    };
+
+    let title = format!(" Tags({})[{}] ", state.title, state.tags.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Reserve a line at the bottom for the key=value being typed
+    let (list_area, edit_area) = if state.editing.is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner_area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (inner_area, None)
+    };
+
+    if state.tags.is_empty() {
+        let empty = Paragraph::new("No tags. Press 'a' to add one.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, list_area);
+    } else {
+        let header_cells = [" KEY", "VALUE"].iter().map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+        let header = Row::new(header_cells).height(1);
+
+        let rows = state.tags.iter().map(|(key, value)| {
+            Row::new(vec![
+                Cell::from(format!(" {}", key)).style(Style::default().fg(Color::Green)),
+                Cell::from(value.clone()),
+            ])
+        });
+
+        let widths = [Constraint::Percentage(35), Constraint::Percentage(65)];
+
+        let highlight = if state.confirm_delete {
+            Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().bg(Color::DarkGray).fg(Color::White).add_modifier(Modifier::BOLD)
+        };
+        let table = Table::new(rows, widths).header(header).row_highlight_style(highlight);
+
+        let mut table_state = TableState::default();
+        table_state.select(Some(state.selected));
+
+        f.render_stateful_widget(table, list_area, &mut table_state);
+    }
+
+    if let (Some((original, text)), Some(edit_area)) = (&state.editing, edit_area) {
+        let label = if original.is_some() { " Edit: " } else { " Add: " };
+        let line = Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(text.clone()),
+            Span::styled("█", Style::default().fg(Color::Cyan)),
+        ]);
+        f.render_widget(Paragraph::new(line), edit_area);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::app::App;
+use crate::notifications::Level;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Widest a toast gets before its text is cut
+const MAX_WIDTH: usize = 60;
+
+/// Stack toasts in the bottom-right corner of `area`: running actions first,
+/// then recent notifications, newest at the bottom
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<(String, Color)> = app
+        .running_actions
+        .iter()
+        .map(|action| (format!("⏳ {}", action.label), Color::Yellow))
+        .chain(app.notifications.toasts().map(|notification| {
+            let color = match notification.level {
+                Level::Info => Color::Cyan,
+                Level::Success => Color::Green,
+                Level::Error => Color::Red,
+            };
+            (notification.text.clone(), color)
+        }))
+        .collect();
+    if lines.is_empty() {
+        return;
+    }
+
+    let width = lines
+        .iter()
+        .map(|(text, _)| text.chars().count().min(MAX_WIDTH))
+        .max()
+        .unwrap_or(0) as u16
+        + 4;
+    let height = lines.len() as u16 + 2;
+    if width >= area.width || height > area.height {
+        return;
+    }
+    let toast_area = Rect::new(
+        area.x + area.width - width - 1,
+        area.y + area.height - height,
+        width,
+        height,
+    );
+
+    let text: Vec<Line> = lines
+        .into_iter()
+        .map(|(text, color)| {
+            let text: String = text.chars().take(MAX_WIDTH).collect();
+            Line::from(Span::styled(
+                format!(" {}", text),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ))
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+
+    f.render_widget(Clear, toast_area);
+    f.render_widget(Paragraph::new(text).block(block), toast_area);
+}
@@ -0,0 +1,72 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" Tunnels[{}] ", app.tunnels.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.tunnels.is_empty() {
+        let empty = Paragraph::new("No active tunnels. Use port forward on an EC2 or RDS instance to start one.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    let header_cells = [" LOCAL", "TARGET", "REMOTE", "STARTED"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.tunnels.iter().map(|tunnel| {
+        Row::new(vec![
+            Cell::from(format!(" localhost:{}", tunnel.local_port)).style(Style::default().fg(Color::Green)),
+            Cell::from(tunnel.target.clone()),
+            Cell::from(tunnel.remote.clone()),
+            Cell::from(tunnel.started.clone()),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(18),
+        Constraint::Length(22),
+        Constraint::Min(30),
+        Constraint::Length(10),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.tunnels_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
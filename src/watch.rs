@@ -0,0 +1,163 @@
+//! Watched resources (`W`): polled in the background, with a notification
+//! (and optionally a terminal bell or desktop notification) when their state
+//! changes, e.g. a stack reaching CREATE_COMPLETE
+
+use crate::aws::client::AwsClients;
+use crate::resource::{extract_json_value, fetch_resources, ResourceFilter};
+use anyhow::Result;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// How often a watched resource is polled
+const WATCH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// What a finished poll found
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    State { from: String, to: String },
+    /// The item is no longer listed (deleted, terminated and gone, ...)
+    Gone,
+}
+
+/// A resource being polled for state changes
+pub struct Watch {
+    pub resource_key: String,
+    pub id: String,
+    /// Shown in notifications, e.g. "CloudFormation Stacks my-stack"
+    pub label: String,
+    /// Last state seen
+    pub state: String,
+    id_field: String,
+    state_field: String,
+    /// Filters of the view it was listed in (parent context)
+    filters: Vec<ResourceFilter>,
+    clients: AwsClients,
+    last_poll: Instant,
+    task: Option<JoinHandle<Result<Option<String>>>>,
+}
+
+impl Watch {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        resource_key: &str,
+        id: &str,
+        label: String,
+        state: String,
+        id_field: &str,
+        state_field: &str,
+        filters: Vec<ResourceFilter>,
+        clients: AwsClients,
+    ) -> Self {
+        Self {
+            resource_key: resource_key.to_string(),
+            id: id.to_string(),
+            label,
+            state,
+            id_field: id_field.to_string(),
+            state_field: state_field.to_string(),
+            filters,
+            clients,
+            last_poll: Instant::now(),
+            task: None,
+        }
+    }
+
+    /// Start a background poll if one is due
+    pub fn start_poll(&mut self) {
+        if self.task.is_some() || self.last_poll.elapsed() < WATCH_INTERVAL {
+            return;
+        }
+        let (resource_key, id, id_field, state_field) = (
+            self.resource_key.clone(),
+            self.id.clone(),
+            self.id_field.clone(),
+            self.state_field.clone(),
+        );
+        let (filters, clients) = (self.filters.clone(), self.clients.clone());
+        self.task = Some(tokio::spawn(async move {
+            let items = fetch_resources(&resource_key, &clients, &filters).await?;
+            Ok(state_in(&items, &id_field, &id, &state_field))
+        }));
+    }
+
+    /// What the last poll found, once it has finished. Failed polls are
+    /// logged and retried at the next interval.
+    pub async fn take_change(&mut self) -> Option<Change> {
+        if !self.task.as_ref().is_some_and(JoinHandle::is_finished) {
+            return None;
+        }
+        let result = self.task.take()?.await;
+        self.last_poll = Instant::now();
+        match result {
+            Ok(Ok(Some(state))) if state != self.state => {
+                let from = std::mem::replace(&mut self.state, state.clone());
+                Some(Change::State { from, to: state })
+            }
+            Ok(Ok(Some(_))) => None,
+            Ok(Ok(None)) => Some(Change::Gone),
+            Ok(Err(e)) => {
+                tracing::warn!("Watch {} {}: {}", self.resource_key, self.id, e);
+                None
+            }
+            Err(e) => {
+                tracing::warn!("Watch {} {}: {}", self.resource_key, self.id, e);
+                None
+            }
+        }
+    }
+}
+
+/// State of the item with this id in a fetched list, None if it isn't listed
+fn state_in(items: &[Value], id_field: &str, id: &str, state_field: &str) -> Option<String> {
+    items
+        .iter()
+        .find(|item| extract_json_value(item, id_field) == id)
+        .map(|item| extract_json_value(item, state_field))
+}
+
+/// Ring the terminal bell and/or post a desktop notification (notify-send on
+/// Linux, osascript on macOS)
+pub fn alert(text: &str, bell: bool, desktop: bool) {
+    if bell {
+        use std::io::Write;
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(b"\x07");
+        let _ = stdout.flush();
+    }
+    if desktop {
+        let mut command = if cfg!(target_os = "macos") {
+            let mut command = tokio::process::Command::new("osascript");
+            let script = format!("display notification {:?} with title \"taws\"", text);
+            command.arg("-e").arg(script);
+            command
+        } else {
+            let mut command = tokio::process::Command::new("notify-send");
+            command.arg("taws").arg(text);
+            command
+        };
+        command
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null());
+        if let Err(e) = command.spawn() {
+            tracing::warn!("Desktop notification failed: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_state_in() {
+        let items = vec![
+            json!({ "StackName": "web", "StackStatus": "CREATE_IN_PROGRESS" }),
+            json!({ "StackName": "db", "StackStatus": "UPDATE_COMPLETE" }),
+        ];
+        assert_eq!(state_in(&items, "StackName", "web", "StackStatus").as_deref(), Some("CREATE_IN_PROGRESS"));
+        assert_eq!(state_in(&items, "StackName", "db", "StackStatus").as_deref(), Some("UPDATE_COMPLETE"));
+        assert_eq!(state_in(&items, "StackName", "cache", "StackStatus"), None);
+    }
+}